@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+// 登录请求结构
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginRequest {
+    pub username: String,
+    pub password: String,
+}
+
+// 登录响应数据结构
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoginData {
+    #[serde(rename = "accessToken")]
+    pub access_token: String,
+    #[serde(rename = "tokenType")]
+    pub token_type: String,
+    #[serde(rename = "expiresIn")]
+    pub expires_in: u32,
+    pub username: String,
+    #[serde(rename = "userId")]
+    pub user_id: u32,
+    // 刷新token（后端未启用刷新机制时为空）
+    #[serde(rename = "refreshToken", default)]
+    pub refresh_token: Option<String>,
+    // 刷新token有效期（秒）
+    #[serde(rename = "refreshExpiresIn", default)]
+    pub refresh_expires_in: Option<u32>,
+    // 角色名称
+    #[serde(default)]
+    pub roles: Vec<String>,
+    // 权限编码（见 permission::Permission）
+    #[serde(default)]
+    pub permissions: Vec<String>,
+}
+
+// 刷新token请求结构
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshRequest {
+    #[serde(rename = "refreshToken")]
+    pub refresh_token: String,
+}
+
+// 会话事件负载（session-refreshed / session-expired）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionEventPayload {
+    pub username: String,
+    #[serde(rename = "expiresIn")]
+    pub expires_in: u32,
+}
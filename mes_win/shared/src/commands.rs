@@ -0,0 +1,404 @@
+// Tauri命令参数
+//
+// 结构体字段名即命令函数的参数名，前端直接序列化后作为 invoke 的参数
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use crate::dashboard::DashboardLayout;
+use crate::inventory::finished_goods::{CompletionRegistration, ProductStockQuery, Shipment};
+use crate::inventory::material::{GoodsReceipt, MaterialIssue, MaterialStockQuery};
+use crate::inventory::warehouse::{BinTransfer, CountReview, CycleCountDraft, CycleCountLine};
+use crate::master_data::MasterDataKind;
+use crate::production::routing::Routing;
+use crate::production::schedule::{RescheduleRequest, ScheduleQuery};
+use crate::production::work_order::{WorkOrderDraft, WorkOrderQuery, WorkOrderStatus};
+use crate::quality::inspection::{InspectionLotDraft, InspectionLotQuery, InspectionRecord};
+use crate::quality::ncr::{AttachmentUpload, NcrDraft, NcrQuery, NcrStatus, NcrUpdate};
+use crate::quality::plan::InspectionPlan;
+use crate::quality::spc::SpcQuery;
+use crate::report::{ExportFormat, ReportRequest};
+use crate::settings::LocalSettings;
+use crate::user::{Role, UserDraft, UserQuery};
+use crate::window::WindowSizeArgs;
+
+// login(username, password)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginArgs {
+    pub username: String,
+    pub password: String,
+}
+
+// switch_backend_profile(name)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwitchProfileArgs {
+    pub name: String,
+}
+
+// resize_window(args)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResizeWindowArgs {
+    pub args: WindowSizeArgs,
+}
+
+// list_work_orders(query)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListWorkOrdersArgs {
+    pub query: WorkOrderQuery,
+}
+
+// get_work_order(id)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkOrderIdArgs {
+    pub id: u64,
+}
+
+// create_work_order(draft)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWorkOrderArgs {
+    pub draft: WorkOrderDraft,
+}
+
+// update_work_order(id, draft)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateWorkOrderArgs {
+    pub id: u64,
+    pub draft: WorkOrderDraft,
+}
+
+// transition_work_order(id, target)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransitionWorkOrderArgs {
+    pub id: u64,
+    pub target: WorkOrderStatus,
+}
+
+// get_schedule(query)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetScheduleArgs {
+    pub query: ScheduleQuery,
+}
+
+// reschedule(request)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RescheduleArgs {
+    pub request: RescheduleRequest,
+}
+
+// list_routings(keyword)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListRoutingsArgs {
+    pub keyword: Option<String>,
+}
+
+// get_routing(id) / publish_routing(id) / new_routing_version(id)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoutingIdArgs {
+    pub id: u64,
+}
+
+// save_routing(routing)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveRoutingArgs {
+    pub routing: Routing,
+}
+
+// list_material_stock(query)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListMaterialStockArgs {
+    pub query: MaterialStockQuery,
+}
+
+// receive_material(receipt)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiveMaterialArgs {
+    pub receipt: GoodsReceipt,
+}
+
+// issue_material(issue)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueMaterialArgs {
+    pub issue: MaterialIssue,
+}
+
+// trace_lot(lot)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceLotArgs {
+    pub lot: String,
+}
+
+// list_product_stock(query)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListProductStockArgs {
+    pub query: ProductStockQuery,
+}
+
+// register_completion(registration)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterCompletionArgs {
+    pub registration: CompletionRegistration,
+}
+
+// ship_products(shipment)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipProductsArgs {
+    pub shipment: Shipment,
+}
+
+// find_serial(serial)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FindSerialArgs {
+    pub serial: String,
+}
+
+// list_bin_stock(bin)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListBinStockArgs {
+    pub bin: String,
+}
+
+// transfer_stock(transfer)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferStockArgs {
+    pub transfer: BinTransfer,
+}
+
+// list_cycle_counts(warehouse)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListCycleCountsArgs {
+    pub warehouse: Option<String>,
+}
+
+// start_cycle_count(draft)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartCycleCountArgs {
+    pub draft: CycleCountDraft,
+}
+
+// get_cycle_count(id)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CycleCountIdArgs {
+    pub id: u64,
+}
+
+// submit_cycle_count(id, lines)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitCycleCountArgs {
+    pub id: u64,
+    pub lines: Vec<CycleCountLine>,
+}
+
+// review_cycle_count(id, review)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewCycleCountArgs {
+    pub id: u64,
+    pub review: CountReview,
+}
+
+// list_inspection_lots(query)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListInspectionLotsArgs {
+    pub query: InspectionLotQuery,
+}
+
+// open_inspection_lot(draft)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenInspectionLotArgs {
+    pub draft: InspectionLotDraft,
+}
+
+// get_inspection_lot(id)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InspectionLotIdArgs {
+    pub id: u64,
+}
+
+// submit_inspection(id, record)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitInspectionArgs {
+    pub id: u64,
+    pub record: InspectionRecord,
+}
+
+// list_inspection_plans(keyword)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListInspectionPlansArgs {
+    pub keyword: Option<String>,
+}
+
+// get_inspection_plan(id) / publish_inspection_plan(id) / new_inspection_plan_version(id)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InspectionPlanIdArgs {
+    pub id: u64,
+}
+
+// save_inspection_plan(plan)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveInspectionPlanArgs {
+    pub plan: InspectionPlan,
+}
+
+// list_ncrs(query)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListNcrsArgs {
+    pub query: NcrQuery,
+}
+
+// get_ncr(id)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NcrIdArgs {
+    pub id: u64,
+}
+
+// create_ncr(draft)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateNcrArgs {
+    pub draft: NcrDraft,
+}
+
+// create_ncr_from_inspection(inspection)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateNcrFromInspectionArgs {
+    pub inspection: u64,
+}
+
+// update_ncr(id, update)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateNcrArgs {
+    pub id: u64,
+    pub update: NcrUpdate,
+}
+
+// transition_ncr(id, target, comment)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransitionNcrArgs {
+    pub id: u64,
+    pub target: NcrStatus,
+    pub comment: Option<String>,
+}
+
+// upload_ncr_attachment(id, attachment)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadNcrAttachmentArgs {
+    pub id: u64,
+    pub attachment: AttachmentUpload,
+}
+
+// get_spc_dataset(query)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetSpcDatasetArgs {
+    pub query: SpcQuery,
+}
+
+// save_dashboard_layout(layout)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveDashboardLayoutArgs {
+    pub layout: DashboardLayout,
+}
+
+// run_report(request)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunReportArgs {
+    pub request: ReportRequest,
+}
+
+// export_report(request, format, folder)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportReportArgs {
+    pub request: ReportRequest,
+    pub format: ExportFormat,
+    pub folder: String,
+}
+
+// reveal_export(path)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevealExportArgs {
+    pub path: String,
+}
+
+// list_users(query)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListUsersArgs {
+    pub query: UserQuery,
+}
+
+// create_user(draft)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateUserArgs {
+    pub draft: UserDraft,
+}
+
+// unlock_user(id)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserIdArgs {
+    pub id: u64,
+}
+
+// set_user_enabled(id, enabled)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetUserEnabledArgs {
+    pub id: u64,
+    pub enabled: bool,
+}
+
+// reset_user_password(id, password)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResetUserPasswordArgs {
+    pub id: u64,
+    pub password: String,
+}
+
+// assign_user_roles(id, roles)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignUserRolesArgs {
+    pub id: u64,
+    pub roles: Vec<String>,
+}
+
+// create_role(role) / update_role(role)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleArgs {
+    pub role: Role,
+}
+
+// delete_role(code)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleCodeArgs {
+    pub code: String,
+}
+
+// save_local_settings(settings)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalSettingsArgs {
+    pub settings: LocalSettings,
+}
+
+// save_plant_settings(values)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlantSettingsArgs {
+    pub values: BTreeMap<String, String>,
+}
+
+// retry_offline_transaction(id) / discard_offline_transaction(id)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfflineTransactionIdArgs {
+    pub id: u64,
+}
+
+// sync_master_data(full)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncMasterDataArgs {
+    pub full: bool,
+}
+
+// lookup_master_data(kind, keyword, limit)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LookupMasterDataArgs {
+    pub kind: MasterDataKind,
+    pub keyword: String,
+    pub limit: Option<u32>,
+}
+
+// search(keyword)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchArgs {
+    pub keyword: String,
+}
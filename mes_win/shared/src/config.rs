@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// 后端服务器配置档案
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServerProfile {
+    /// 档案标识，如 dev / test / plant-A
+    pub name: String,
+    /// 在登录界面显示的名称
+    pub label: String,
+    /// 后端服务根地址，如 http://127.0.0.1:8080
+    #[serde(rename = "baseUrl")]
+    pub base_url: String,
+}
+
+impl ServerProfile {
+    pub fn new(name: &str, label: &str, base_url: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            label: label.to_string(),
+            base_url: base_url.to_string(),
+        }
+    }
+}
+
+/// 后端档案列表（list_backend_profiles / switch_backend_profile 的返回值）
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct BackendProfiles {
+    /// 当前启用的档案标识
+    pub active: String,
+    /// 所有可用的服务器档案
+    pub profiles: Vec<ServerProfile>,
+}
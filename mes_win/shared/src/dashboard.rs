@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use crate::error::FieldError;
+
+/// 自动刷新间隔范围（秒）
+pub const MIN_REFRESH_SECONDS: u32 = 10;
+pub const MAX_REFRESH_SECONDS: u32 = 3600;
+
+/// 仪表板指标卡片
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum KpiCard {
+    ActiveOrders,
+    OutputVsPlan,
+    Oee,
+    FirstPassYield,
+    OpenNcrs,
+    LowStock,
+}
+
+impl KpiCard {
+    pub const ALL: [KpiCard; 6] = [
+        KpiCard::ActiveOrders,
+        KpiCard::OutputVsPlan,
+        KpiCard::Oee,
+        KpiCard::FirstPassYield,
+        KpiCard::OpenNcrs,
+        KpiCard::LowStock,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            KpiCard::ActiveOrders => "生产订单",
+            KpiCard::OutputVsPlan => "产量达成",
+            KpiCard::Oee => "设备综合效率",
+            KpiCard::FirstPassYield => "一次合格率",
+            KpiCard::OpenNcrs => "质量问题",
+            KpiCard::LowStock => "库存状态",
+        }
+    }
+
+    /// 卡片图标配色（production / inventory / quality）
+    pub fn category(&self) -> &'static str {
+        match self {
+            KpiCard::ActiveOrders | KpiCard::OutputVsPlan | KpiCard::Oee => "production",
+            KpiCard::FirstPassYield | KpiCard::OpenNcrs => "quality",
+            KpiCard::LowStock => "inventory",
+        }
+    }
+}
+
+/// OEE三要素（0~1）
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OeeFactors {
+    /// 时间开动率
+    pub availability: f64,
+    /// 性能开动率
+    pub performance: f64,
+    /// 合格品率
+    pub quality: f64,
+}
+
+impl OeeFactors {
+    pub fn oee(&self) -> f64 {
+        self.availability * self.performance * self.quality
+    }
+}
+
+/// 低于安全库存的物料
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LowStockItem {
+    pub material_code: String,
+    pub material_name: String,
+    pub unit: String,
+    pub on_hand: f64,
+    pub safety_stock: f64,
+}
+
+/// 仪表板指标（当日）
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DashboardKpis {
+    /// 生产中的工单数
+    pub active_orders: u32,
+    /// 已下达待开工的工单数
+    #[serde(default)]
+    pub released_orders: u32,
+    pub planned_output: f64,
+    pub actual_output: f64,
+    /// 无设备数据时为空
+    #[serde(default)]
+    pub oee: Option<OeeFactors>,
+    /// 当日无检验时为空
+    #[serde(default)]
+    pub first_pass_yield: Option<f64>,
+    /// 未关闭的不合格报告数
+    pub open_ncrs: u32,
+    #[serde(default)]
+    pub critical_ncrs: u32,
+    #[serde(default)]
+    pub low_stock: Vec<LowStockItem>,
+    /// 数据时间
+    #[serde(default)]
+    pub refreshed_at: String,
+}
+
+impl DashboardKpis {
+    /// 产量达成率，计划为0时为空
+    pub fn output_attainment(&self) -> Option<f64> {
+        (self.planned_output > 0.0).then(|| self.actual_output / self.planned_output)
+    }
+}
+
+/// 仪表板布局（按用户保存在本地）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DashboardLayout {
+    /// 显示的卡片及顺序
+    pub cards: Vec<KpiCard>,
+    /// 自动刷新间隔（秒）
+    pub refresh_seconds: u32,
+}
+
+impl Default for DashboardLayout {
+    fn default() -> Self {
+        Self {
+            cards: KpiCard::ALL.to_vec(),
+            refresh_seconds: 60,
+        }
+    }
+}
+
+impl DashboardLayout {
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        if !(MIN_REFRESH_SECONDS..=MAX_REFRESH_SECONDS).contains(&self.refresh_seconds) {
+            errors.push(FieldError {
+                field: "refreshSeconds".to_string(),
+                message: format!("刷新间隔须在 {} 到 {} 秒之间", MIN_REFRESH_SECONDS, MAX_REFRESH_SECONDS),
+            });
+        }
+        let mut seen = std::collections::HashSet::new();
+        if !self.cards.iter().all(|card| seen.insert(*card)) {
+            errors.push(FieldError {
+                field: "cards".to_string(),
+                message: "卡片不能重复".to_string(),
+            });
+        }
+        errors
+    }
+}
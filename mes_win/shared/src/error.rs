@@ -0,0 +1,170 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+
+/// 字段校验错误
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+/// 统一的API错误类型
+///
+/// 所有Tauri命令都返回该类型，序列化为 `{ "kind": "...", ... }`，
+/// 前端据此按错误类型处理，而不是匹配错误字符串
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ApiError {
+    /// 网络连接失败
+    Network { message: String },
+    /// 请求超时
+    Timeout { message: String },
+    /// 未登录或登录已失效
+    Unauthorized { message: String },
+    /// 没有操作权限
+    Forbidden { message: String },
+    /// 请求参数校验失败
+    Validation { message: String, fields: Vec<FieldError> },
+    /// 后端业务错误或服务器错误
+    Server { code: u32, message: String },
+    /// 响应格式无法解析
+    Decode { message: String },
+    /// 后端不可达，写操作已保存到离线队列，恢复连接后自动提交
+    Queued { id: u64, message: String },
+}
+
+impl ApiError {
+    /// 未登录错误
+    pub fn unauthorized() -> Self {
+        ApiError::Unauthorized {
+            message: "用户未登录或token已过期".to_string(),
+        }
+    }
+
+    /// 无权限错误
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        ApiError::Forbidden {
+            message: message.into(),
+        }
+    }
+
+    /// 网络不可达或请求超时（可转入离线队列的失败）
+    pub fn is_unreachable(&self) -> bool {
+        matches!(self, ApiError::Network { .. } | ApiError::Timeout { .. })
+    }
+
+    /// 单字段校验错误
+    pub fn invalid_field(field: &str, message: impl Into<String>) -> Self {
+        let message = message.into();
+        ApiError::Validation {
+            message: message.clone(),
+            fields: vec![FieldError {
+                field: field.to_string(),
+                message,
+            }],
+        }
+    }
+
+    /// 根据HTTP状态码、后端 `code` 字段和错误信息构造错误
+    ///
+    /// 后端有时以HTTP 200返回业务错误码，因此优先使用 `code` 字段判断
+    pub fn from_response(status: u16, code: u32, message: String, data: Option<&serde_json::Value>) -> Self {
+        let effective_code = if (200..300).contains(&code) || code == 0 {
+            status as u32
+        } else {
+            code
+        };
+
+        match effective_code {
+            401 => ApiError::Unauthorized { message },
+            403 => ApiError::Forbidden { message },
+            400 | 422 => ApiError::Validation {
+                fields: data.map(parse_field_errors).unwrap_or_default(),
+                message,
+            },
+            _ => ApiError::Server {
+                code: effective_code,
+                message,
+            },
+        }
+    }
+
+    /// 仅根据HTTP状态码构造错误（响应体无法解析时使用）
+    pub fn from_status(status: u16) -> Self {
+        Self::from_response(status, status as u32, format!("请求失败（HTTP {}）", status), None)
+    }
+
+    /// 错误提示信息
+    pub fn message(&self) -> &str {
+        match self {
+            ApiError::Network { message }
+            | ApiError::Timeout { message }
+            | ApiError::Unauthorized { message }
+            | ApiError::Forbidden { message }
+            | ApiError::Validation { message, .. }
+            | ApiError::Server { message, .. }
+            | ApiError::Decode { message }
+            | ApiError::Queued { message, .. } => message,
+        }
+    }
+
+    /// 面向用户的提示信息
+    pub fn user_message(&self) -> String {
+        match self {
+            ApiError::Network { .. } => "无法连接服务器，请检查网络或切换服务器".to_string(),
+            ApiError::Timeout { .. } => "服务器响应超时，请稍后重试".to_string(),
+            ApiError::Forbidden { .. } => "没有执行该操作的权限".to_string(),
+            ApiError::Server { code, message } if *code >= 500 => {
+                format!("服务器内部错误({})：{}", code, message)
+            }
+            _ => self.message().to_string(),
+        }
+    }
+}
+
+/// 解析后端返回的字段错误
+///
+/// 支持 `{"field": "message"}` 和 `[{"field": "...", "message": "..."}]` 两种格式
+fn parse_field_errors(data: &serde_json::Value) -> Vec<FieldError> {
+    if let Ok(fields) = serde_json::from_value::<Vec<FieldError>>(data.clone()) {
+        return fields;
+    }
+
+    data.as_object()
+        .map(|object| {
+            object
+                .iter()
+                .map(|(field, message)| FieldError {
+                    field: field.clone(),
+                    message: message
+                        .as_str()
+                        .map(|s| s.to_string())
+                        .unwrap_or_else(|| message.to_string()),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Network { message } => write!(f, "网络连接失败: {}", message),
+            ApiError::Timeout { message } => write!(f, "请求超时: {}", message),
+            ApiError::Unauthorized { message } => write!(f, "未授权: {}", message),
+            ApiError::Forbidden { message } => write!(f, "无权限: {}", message),
+            ApiError::Validation { message, .. } => write!(f, "参数错误: {}", message),
+            ApiError::Server { code, message } => write!(f, "服务器错误({}): {}", code, message),
+            ApiError::Decode { message } => write!(f, "响应格式错误: {}", message),
+            ApiError::Queued { id, message } => write!(f, "已加入离线队列(#{}): {}", id, message),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl From<serde_json::Error> for ApiError {
+    fn from(e: serde_json::Error) -> Self {
+        ApiError::Decode { message: e.to_string() }
+    }
+}
@@ -0,0 +1,10 @@
+// 原生层向所有窗口广播的事件名称
+
+/// 用户登出
+pub const LOGOUT: &str = "logout";
+/// token自动续期成功，负载为 `SessionEventPayload`
+pub const SESSION_REFRESHED: &str = "session-refreshed";
+/// 会话失效需要重新登录，负载为 `SessionEventPayload`
+pub const SESSION_EXPIRED: &str = "session-expired";
+/// 离线队列或连接状态变化，负载为 `OfflineStatus`
+pub const OFFLINE_STATUS: &str = "offline-status";
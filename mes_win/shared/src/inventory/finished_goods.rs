@@ -0,0 +1,197 @@
+use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use crate::error::FieldError;
+
+/// 成品库存（按SKU）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductStock {
+    pub sku: String,
+    pub product_name: String,
+    pub unit: String,
+    /// 在库数量
+    pub on_hand: f64,
+    /// 是否按序列号管理
+    #[serde(default)]
+    pub serial_tracked: bool,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// 成品库存查询条件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductStockQuery {
+    pub page: u32,
+    pub page_size: u32,
+    /// 按SKU/产品名称模糊查询
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<String>,
+}
+
+impl Default for ProductStockQuery {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            page_size: 20,
+            keyword: None,
+        }
+    }
+}
+
+/// 校验序列号列表：不能为空白或重复
+fn validate_serials(serials: &[String], errors: &mut Vec<FieldError>) {
+    let mut seen = HashSet::new();
+    for serial in serials {
+        if serial.trim().is_empty() {
+            errors.push(FieldError {
+                field: "serials".to_string(),
+                message: "序列号不能为空".to_string(),
+            });
+            return;
+        }
+        if !seen.insert(serial.trim()) {
+            errors.push(FieldError {
+                field: "serials".to_string(),
+                message: format!("序列号 {} 重复", serial),
+            });
+            return;
+        }
+    }
+}
+
+/// 完工入库登记
+///
+/// 按序列号管理的产品需逐件登记序列号，其余产品按批次登记
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionRegistration {
+    pub work_order_id: u64,
+    pub quantity: f64,
+    /// 批次号，为空时由后端生成
+    #[serde(default)]
+    pub lot_no: Option<String>,
+    #[serde(default)]
+    pub serials: Vec<String>,
+    /// 入库库位
+    #[serde(default)]
+    pub location: Option<String>,
+}
+
+impl CompletionRegistration {
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        if self.work_order_id == 0 {
+            errors.push(FieldError {
+                field: "workOrderId".to_string(),
+                message: "请选择工单".to_string(),
+            });
+        }
+        if !self.quantity.is_finite() || self.quantity <= 0.0 {
+            errors.push(FieldError {
+                field: "quantity".to_string(),
+                message: "完工数量必须大于0".to_string(),
+            });
+        }
+        if !self.serials.is_empty() && self.serials.len() as f64 != self.quantity {
+            errors.push(FieldError {
+                field: "serials".to_string(),
+                message: format!("已录入 {} 个序列号，与完工数量不一致", self.serials.len()),
+            });
+        }
+        validate_serials(&self.serials, &mut errors);
+        errors
+    }
+}
+
+/// 发货出库
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Shipment {
+    pub sku: String,
+    pub quantity: f64,
+    pub customer: String,
+    /// 发货单号（如销售订单号）
+    #[serde(default)]
+    pub reference_no: Option<String>,
+    /// 按序列号管理的产品需指定发出的序列号
+    #[serde(default)]
+    pub serials: Vec<String>,
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+impl Shipment {
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        if self.sku.trim().is_empty() {
+            errors.push(FieldError {
+                field: "sku".to_string(),
+                message: "请选择产品".to_string(),
+            });
+        }
+        if self.customer.trim().is_empty() {
+            errors.push(FieldError {
+                field: "customer".to_string(),
+                message: "请输入客户".to_string(),
+            });
+        }
+        if !self.quantity.is_finite() || self.quantity <= 0.0 {
+            errors.push(FieldError {
+                field: "quantity".to_string(),
+                message: "发货数量必须大于0".to_string(),
+            });
+        }
+        if !self.serials.is_empty() && self.serials.len() as f64 != self.quantity {
+            errors.push(FieldError {
+                field: "serials".to_string(),
+                message: format!("已录入 {} 个序列号，与发货数量不一致", self.serials.len()),
+            });
+        }
+        validate_serials(&self.serials, &mut errors);
+        errors
+    }
+}
+
+/// 序列号状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SerialStatus {
+    InStock,
+    Shipped,
+    Scrapped,
+}
+
+impl SerialStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SerialStatus::InStock => "在库",
+            SerialStatus::Shipped => "已发货",
+            SerialStatus::Scrapped => "已报废",
+        }
+    }
+}
+
+/// 序列号档案：记录成品由哪个工单、产线、操作员生产
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SerialRecord {
+    pub serial_no: String,
+    pub sku: String,
+    #[serde(default)]
+    pub product_name: String,
+    #[serde(default)]
+    pub lot_no: Option<String>,
+    pub work_order_id: u64,
+    pub work_order_no: String,
+    #[serde(default)]
+    pub line: Option<String>,
+    #[serde(default)]
+    pub operator: Option<String>,
+    pub produced_at: String,
+    pub status: SerialStatus,
+    #[serde(default)]
+    pub customer: Option<String>,
+    #[serde(default)]
+    pub shipped_at: Option<String>,
+}
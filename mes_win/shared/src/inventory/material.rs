@@ -0,0 +1,247 @@
+use serde::{Deserialize, Serialize};
+use crate::error::FieldError;
+use crate::time::parse_date;
+
+/// 即将过期的提醒天数
+pub const EXPIRY_WARNING_DAYS: i64 = 30;
+
+/// 原材料批次库存
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaterialLot {
+    /// 批次号
+    pub lot_no: String,
+    pub material_code: String,
+    #[serde(default)]
+    pub material_name: String,
+    /// 当前库存数量
+    pub quantity: f64,
+    pub unit: String,
+    /// 库位
+    pub location: String,
+    /// 有效期至（YYYY-MM-DD）
+    #[serde(default)]
+    pub expiry_date: Option<String>,
+    #[serde(default)]
+    pub supplier: Option<String>,
+    #[serde(default)]
+    pub received_at: Option<String>,
+}
+
+/// 批次有效期状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpiryState {
+    /// 未设置有效期或距离过期较远
+    Valid,
+    /// 即将过期
+    Expiring,
+    Expired,
+}
+
+impl MaterialLot {
+    /// 按 `today`（自1970-01-01起的天数）判断有效期状态
+    pub fn expiry_state(&self, today: i64) -> ExpiryState {
+        match self.expiry_date.as_deref().and_then(parse_date) {
+            Some(expiry) if expiry < today => ExpiryState::Expired,
+            Some(expiry) if expiry - today <= EXPIRY_WARNING_DAYS => ExpiryState::Expiring,
+            _ => ExpiryState::Valid,
+        }
+    }
+}
+
+/// 物料库存汇总（含各批次）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaterialStock {
+    pub material_code: String,
+    pub material_name: String,
+    pub unit: String,
+    pub total_quantity: f64,
+    #[serde(default)]
+    pub lots: Vec<MaterialLot>,
+}
+
+/// 物料库存查询条件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaterialStockQuery {
+    pub page: u32,
+    pub page_size: u32,
+    /// 按物料编码/名称/批次号模糊查询
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<String>,
+    /// 是否包含库存为0的批次
+    #[serde(default)]
+    pub include_empty: bool,
+}
+
+impl Default for MaterialStockQuery {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            page_size: 20,
+            keyword: None,
+            include_empty: false,
+        }
+    }
+}
+
+/// 收货（入库）单
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoodsReceipt {
+    pub material_code: String,
+    /// 批次号，为空时由后端生成
+    #[serde(default)]
+    pub lot_no: Option<String>,
+    pub quantity: f64,
+    pub unit: String,
+    pub location: String,
+    #[serde(default)]
+    pub expiry_date: Option<String>,
+    #[serde(default)]
+    pub supplier: Option<String>,
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+impl Default for GoodsReceipt {
+    fn default() -> Self {
+        Self {
+            material_code: String::new(),
+            lot_no: None,
+            quantity: 0.0,
+            unit: "kg".to_string(),
+            location: String::new(),
+            expiry_date: None,
+            supplier: None,
+            remark: None,
+        }
+    }
+}
+
+impl GoodsReceipt {
+    /// 校验收货单
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        let mut push = |field: &str, message: &str| {
+            errors.push(FieldError {
+                field: field.to_string(),
+                message: message.to_string(),
+            });
+        };
+
+        if self.material_code.trim().is_empty() {
+            push("materialCode", "请输入物料编码");
+        }
+        if !self.quantity.is_finite() || self.quantity <= 0.0 {
+            push("quantity", "收货数量必须大于0");
+        }
+        if self.unit.trim().is_empty() {
+            push("unit", "请输入单位");
+        }
+        if self.location.trim().is_empty() {
+            push("location", "请输入库位");
+        }
+        if let Some(expiry) = self.expiry_date.as_deref() {
+            if parse_date(expiry).is_none() {
+                push("expiryDate", "有效期格式应为 YYYY-MM-DD");
+            }
+        }
+
+        errors
+    }
+}
+
+/// 发料到工单
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaterialIssue {
+    pub work_order_id: u64,
+    pub material_code: String,
+    pub lot_no: String,
+    pub quantity: f64,
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+impl MaterialIssue {
+    /// 校验发料单
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        if self.work_order_id == 0 {
+            errors.push(FieldError {
+                field: "workOrderId".to_string(),
+                message: "请选择工单".to_string(),
+            });
+        }
+        if self.lot_no.trim().is_empty() {
+            errors.push(FieldError {
+                field: "lotNo".to_string(),
+                message: "请选择批次".to_string(),
+            });
+        }
+        if !self.quantity.is_finite() || self.quantity <= 0.0 {
+            errors.push(FieldError {
+                field: "quantity".to_string(),
+                message: "发料数量必须大于0".to_string(),
+            });
+        }
+        errors
+    }
+}
+
+/// 批次事务类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LotTransactionKind {
+    /// 收货入库
+    Receipt,
+    /// 发料到工单
+    Issue,
+    /// 退料
+    Return,
+    /// 库存调整
+    Adjustment,
+}
+
+impl LotTransactionKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LotTransactionKind::Receipt => "收货",
+            LotTransactionKind::Issue => "发料",
+            LotTransactionKind::Return => "退料",
+            LotTransactionKind::Adjustment => "调整",
+        }
+    }
+}
+
+/// 批次库存事务
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LotTransaction {
+    pub id: u64,
+    pub kind: LotTransactionKind,
+    pub lot_no: String,
+    pub material_code: String,
+    /// 数量（入库为正，出库为负）
+    pub quantity: f64,
+    pub unit: String,
+    /// 关联工单（发料/退料）
+    #[serde(default)]
+    pub work_order_id: Option<u64>,
+    #[serde(default)]
+    pub work_order_no: Option<String>,
+    #[serde(default)]
+    pub operator: Option<String>,
+    pub occurred_at: String,
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+/// 批次追溯：批次信息及从收货到消耗的全部事务
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LotTrace {
+    pub lot: MaterialLot,
+    pub transactions: Vec<LotTransaction>,
+}
@@ -0,0 +1,4 @@
+// 库存管理相关数据类型
+pub mod material;
+pub mod finished_goods;
+pub mod warehouse;
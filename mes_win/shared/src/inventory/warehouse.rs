@@ -0,0 +1,427 @@
+use serde::{Deserialize, Serialize};
+use crate::error::FieldError;
+
+/// 库位占用率达到该比例时视为接近满仓
+pub const BIN_NEAR_FULL_RATIO: f64 = 0.9;
+
+/// 库位
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bin {
+    pub code: String,
+    #[serde(default)]
+    pub name: String,
+    /// 容量（为空表示不限容量）
+    #[serde(default)]
+    pub capacity: Option<f64>,
+    /// 已占用数量
+    #[serde(default)]
+    pub occupied: f64,
+    /// 冻结的库位不能移入（如盘点中）
+    #[serde(default)]
+    pub blocked: bool,
+}
+
+impl Bin {
+    /// 占用率（0~1，不限容量时为空）
+    pub fn occupancy(&self) -> Option<f64> {
+        self.capacity
+            .filter(|capacity| *capacity > 0.0)
+            .map(|capacity| (self.occupied / capacity).max(0.0))
+    }
+
+    /// 剩余可用容量（不限容量时为空）
+    pub fn available(&self) -> Option<f64> {
+        self.capacity.map(|capacity| (capacity - self.occupied).max(0.0))
+    }
+
+    pub fn occupancy_state(&self) -> OccupancyState {
+        if self.occupied <= 0.0 {
+            return OccupancyState::Empty;
+        }
+        match self.occupancy() {
+            Some(ratio) if ratio >= 1.0 => OccupancyState::Full,
+            Some(ratio) if ratio >= BIN_NEAR_FULL_RATIO => OccupancyState::NearFull,
+            _ => OccupancyState::Partial,
+        }
+    }
+}
+
+/// 库位占用状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OccupancyState {
+    Empty,
+    Partial,
+    NearFull,
+    Full,
+}
+
+impl OccupancyState {
+    pub fn label(&self) -> &'static str {
+        match self {
+            OccupancyState::Empty => "空闲",
+            OccupancyState::Partial => "部分占用",
+            OccupancyState::NearFull => "接近满仓",
+            OccupancyState::Full => "满仓",
+        }
+    }
+
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            OccupancyState::Empty => "empty",
+            OccupancyState::Partial => "partial",
+            OccupancyState::NearFull => "near-full",
+            OccupancyState::Full => "full",
+        }
+    }
+}
+
+/// 库区
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Zone {
+    pub code: String,
+    pub name: String,
+    #[serde(default)]
+    pub bins: Vec<Bin>,
+}
+
+/// 仓库（仓库 → 库区 → 库位）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Warehouse {
+    pub code: String,
+    pub name: String,
+    #[serde(default)]
+    pub zones: Vec<Zone>,
+}
+
+impl Warehouse {
+    /// 按编码查找库位
+    pub fn find_bin(&self, code: &str) -> Option<&Bin> {
+        self.zones.iter().flat_map(|zone| zone.bins.iter()).find(|bin| bin.code == code)
+    }
+}
+
+/// 库位上的库存明细
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinStock {
+    pub bin_code: String,
+    /// 物料编码或成品SKU
+    pub item_code: String,
+    #[serde(default)]
+    pub item_name: String,
+    #[serde(default)]
+    pub lot_no: Option<String>,
+    pub quantity: f64,
+    pub unit: String,
+}
+
+/// 库位间移库
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinTransfer {
+    pub item_code: String,
+    #[serde(default)]
+    pub lot_no: Option<String>,
+    pub from_bin: String,
+    pub to_bin: String,
+    pub quantity: f64,
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+impl BinTransfer {
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        if self.item_code.trim().is_empty() {
+            errors.push(FieldError {
+                field: "itemCode".to_string(),
+                message: "请选择移库物料".to_string(),
+            });
+        }
+        if self.to_bin.trim().is_empty() {
+            errors.push(FieldError {
+                field: "toBin".to_string(),
+                message: "请选择目标库位".to_string(),
+            });
+        } else if self.to_bin == self.from_bin {
+            errors.push(FieldError {
+                field: "toBin".to_string(),
+                message: "目标库位不能与来源库位相同".to_string(),
+            });
+        }
+        if !self.quantity.is_finite() || self.quantity <= 0.0 {
+            errors.push(FieldError {
+                field: "quantity".to_string(),
+                message: "移库数量必须大于0".to_string(),
+            });
+        }
+        errors
+    }
+}
+
+/// 移库记录
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferRecord {
+    pub id: u64,
+    #[serde(flatten)]
+    pub transfer: BinTransfer,
+    pub unit: String,
+    #[serde(default)]
+    pub operator: Option<String>,
+    pub occurred_at: String,
+}
+
+/// 盘点单状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CycleCountStatus {
+    /// 盘点中，可录入实盘数量
+    Counting,
+    /// 已提交，等待差异审批
+    Submitted,
+    /// 差异已批准，库存已调整
+    Approved,
+    /// 差异被驳回，需重新盘点
+    Rejected,
+}
+
+impl CycleCountStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CycleCountStatus::Counting => "盘点中",
+            CycleCountStatus::Submitted => "待审批",
+            CycleCountStatus::Approved => "已批准",
+            CycleCountStatus::Rejected => "已驳回",
+        }
+    }
+
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            CycleCountStatus::Counting => "in-progress",
+            CycleCountStatus::Submitted => "released",
+            CycleCountStatus::Approved => "completed",
+            CycleCountStatus::Rejected => "closed",
+        }
+    }
+
+    /// 是否可以录入实盘数量（驳回后重新盘点）
+    pub fn is_editable(&self) -> bool {
+        matches!(self, CycleCountStatus::Counting | CycleCountStatus::Rejected)
+    }
+}
+
+/// 盘点明细
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleCountLine {
+    pub bin_code: String,
+    pub item_code: String,
+    #[serde(default)]
+    pub item_name: String,
+    #[serde(default)]
+    pub lot_no: Option<String>,
+    pub unit: String,
+    /// 账面数量
+    pub book_quantity: f64,
+    /// 实盘数量（未录入时为空）
+    #[serde(default)]
+    pub counted_quantity: Option<f64>,
+}
+
+impl CycleCountLine {
+    /// 盘点差异（实盘 - 账面）
+    pub fn variance(&self) -> Option<f64> {
+        self.counted_quantity.map(|counted| counted - self.book_quantity)
+    }
+}
+
+/// 盘点单
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleCount {
+    pub id: u64,
+    pub count_no: String,
+    pub warehouse_code: String,
+    #[serde(default)]
+    pub zone_code: Option<String>,
+    pub status: CycleCountStatus,
+    #[serde(default)]
+    pub lines: Vec<CycleCountLine>,
+    #[serde(default)]
+    pub created_by: Option<String>,
+    pub created_at: String,
+    /// 提交实盘数量的用户，审批人不能与其相同
+    #[serde(default)]
+    pub submitted_by: Option<String>,
+    #[serde(default)]
+    pub submitted_at: Option<String>,
+    #[serde(default)]
+    pub reviewed_by: Option<String>,
+    #[serde(default)]
+    pub reviewed_at: Option<String>,
+    #[serde(default)]
+    pub review_remark: Option<String>,
+}
+
+impl CycleCount {
+    /// 有差异的明细数
+    pub fn variance_count(&self) -> usize {
+        self.lines
+            .iter()
+            .filter(|line| line.variance().is_some_and(|v| v.abs() > f64::EPSILON))
+            .count()
+    }
+
+    /// 把提交的实盘数量写入对应明细
+    ///
+    /// 按（库位, 物料, 批次）逐条匹配已有明细，只取实盘数量，其余字段以盘点单为准；
+    /// 条数不一致或有对应不上的明细时说明盘点单已变化，不做任何修改
+    pub fn apply_counts(&mut self, counts: &[CycleCountLine]) -> Vec<FieldError> {
+        if counts.len() != self.lines.len() {
+            return vec![FieldError {
+                field: "lines".to_string(),
+                message: "盘点明细已变化，请刷新后重新录入".to_string(),
+            }];
+        }
+
+        let mut matched = vec![None; self.lines.len()];
+        let mut errors = Vec::new();
+        for (i, count) in counts.iter().enumerate() {
+            let found = self.lines.iter().enumerate().position(|(j, line)| {
+                matched[j].is_none()
+                    && line.bin_code == count.bin_code
+                    && line.item_code == count.item_code
+                    && line.lot_no == count.lot_no
+            });
+            match found {
+                Some(j) => matched[j] = Some(count.counted_quantity),
+                None => errors.push(FieldError {
+                    field: format!("lines[{}]", i),
+                    message: format!("库位 {} 物料 {} 不在盘点单中，请刷新后重新录入", count.bin_code, count.item_code),
+                }),
+            }
+        }
+
+        if errors.is_empty() {
+            for (line, counted) in self.lines.iter_mut().zip(matched) {
+                line.counted_quantity = counted.flatten();
+            }
+        }
+        errors
+    }
+
+    /// 提交前校验：所有明细都须录入有效的实盘数量
+    pub fn validate_counts(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        for (i, line) in self.lines.iter().enumerate() {
+            match line.counted_quantity {
+                None => errors.push(FieldError {
+                    field: format!("lines[{}].countedQuantity", i),
+                    message: format!("库位 {} 物料 {} 未录入实盘数量", line.bin_code, line.item_code),
+                }),
+                Some(counted) if !counted.is_finite() || counted < 0.0 => errors.push(FieldError {
+                    field: format!("lines[{}].countedQuantity", i),
+                    message: format!("库位 {} 物料 {} 实盘数量无效", line.bin_code, line.item_code),
+                }),
+                _ => {}
+            }
+        }
+        errors
+    }
+}
+
+/// 新建盘点单（按仓库或库区生成盘点明细）
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleCountDraft {
+    pub warehouse_code: String,
+    #[serde(default)]
+    pub zone_code: Option<String>,
+}
+
+/// 盘点差异审批
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CountReview {
+    pub approved: bool,
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(bin: &str, item: &str, lot: Option<&str>, counted: Option<f64>) -> CycleCountLine {
+        CycleCountLine {
+            bin_code: bin.to_string(),
+            item_code: item.to_string(),
+            item_name: String::new(),
+            lot_no: lot.map(str::to_string),
+            unit: "PCS".to_string(),
+            book_quantity: 10.0,
+            counted_quantity: counted,
+        }
+    }
+
+    fn count(lines: Vec<CycleCountLine>) -> CycleCount {
+        CycleCount {
+            id: 1,
+            count_no: "CC001".to_string(),
+            warehouse_code: "WH01".to_string(),
+            zone_code: None,
+            status: CycleCountStatus::Counting,
+            lines,
+            created_by: None,
+            created_at: String::new(),
+            submitted_by: None,
+            submitted_at: None,
+            reviewed_by: None,
+            reviewed_at: None,
+            review_remark: None,
+        }
+    }
+
+    #[test]
+    fn apply_counts_matches_by_bin_item_and_lot() {
+        let mut current = count(vec![
+            line("A-01", "M001", Some("L1"), None),
+            line("A-01", "M001", Some("L2"), None),
+        ]);
+        // 顺序不同，且客户端篡改了账面数量
+        let mut tampered = line("A-01", "M001", Some("L2"), Some(7.0));
+        tampered.book_quantity = 7.0;
+        let counts = vec![tampered, line("A-01", "M001", Some("L1"), Some(10.0))];
+
+        assert!(current.apply_counts(&counts).is_empty());
+        assert_eq!(current.lines[0].lot_no.as_deref(), Some("L1"));
+        assert_eq!(current.lines[0].counted_quantity, Some(10.0));
+        assert_eq!(current.lines[1].counted_quantity, Some(7.0));
+        assert_eq!(current.lines[1].book_quantity, 10.0);
+    }
+
+    #[test]
+    fn apply_counts_rejects_unknown_or_duplicate_lines() {
+        let original = count(vec![line("A-01", "M001", None, None), line("A-02", "M001", None, None)]);
+
+        let mut current = original.clone();
+        let errors = current.apply_counts(&[line("A-01", "M001", None, Some(1.0)), line("A-03", "M001", None, Some(2.0))]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "lines[1]");
+        assert_eq!(current, original);
+
+        let mut current = original.clone();
+        let errors = current.apply_counts(&[line("A-01", "M001", None, Some(1.0)), line("A-01", "M001", None, Some(2.0))]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(current, original);
+
+        let mut current = original.clone();
+        let errors = current.apply_counts(&[line("A-01", "M001", None, Some(1.0))]);
+        assert_eq!(errors[0].field, "lines");
+        assert_eq!(current, original);
+    }
+}
@@ -0,0 +1,24 @@
+//! 前端与Tauri后端共用的数据类型
+//!
+//! 所有Tauri命令的参数、返回值和事件负载都在这里定义，
+//! 前后端同时依赖本crate，命令契约在编译期即可检查。
+
+pub mod auth;
+pub mod commands;
+pub mod config;
+pub mod dashboard;
+pub mod error;
+pub mod events;
+pub mod inventory;
+pub mod master_data;
+pub mod offline;
+pub mod permission;
+pub mod production;
+pub mod quality;
+pub mod report;
+pub mod response;
+pub mod search;
+pub mod settings;
+pub mod time;
+pub mod user;
+pub mod window;
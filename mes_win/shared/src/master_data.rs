@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+use crate::permission::Permission;
+
+/// 本机缓存的主数据类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MasterDataKind {
+    /// 物料
+    Material,
+    /// 产品
+    Product,
+    /// 工作中心
+    WorkCenter,
+    /// 用户
+    User,
+}
+
+impl MasterDataKind {
+    pub const ALL: [MasterDataKind; 4] = [
+        MasterDataKind::Material,
+        MasterDataKind::Product,
+        MasterDataKind::WorkCenter,
+        MasterDataKind::User,
+    ];
+
+    /// 本地缓存和增量同步接口使用的类型编码
+    pub fn code(&self) -> &'static str {
+        match self {
+            MasterDataKind::Material => "MATERIAL",
+            MasterDataKind::Product => "PRODUCT",
+            MasterDataKind::WorkCenter => "WORK_CENTER",
+            MasterDataKind::User => "USER",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.code() == code)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MasterDataKind::Material => "物料",
+            MasterDataKind::Product => "产品",
+            MasterDataKind::WorkCenter => "工作中心",
+            MasterDataKind::User => "用户",
+        }
+    }
+
+    /// 读取该类缓存所需的权限（拥有其一即可），与对应的在线查询命令一致
+    pub fn required_permissions(&self) -> &'static [Permission] {
+        match self {
+            MasterDataKind::Material => &[Permission::InventoryView],
+            // 工单表单按产品联想，生产人员同样需要
+            MasterDataKind::Product => &[Permission::InventoryView, Permission::ProductionView],
+            MasterDataKind::WorkCenter => &[Permission::ProductionView],
+            MasterDataKind::User => &[Permission::UserManage],
+        }
+    }
+}
+
+/// 主数据条目
+///
+/// 各类主数据统一为编码 + 名称的查找结构：
+/// 物料/产品的 `spec` 为规格型号，工作中心为所属产线，用户为所属部门
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MasterDataItem {
+    pub kind: MasterDataKind,
+    /// 物料编码、产品SKU、工作中心编码或用户名
+    pub code: String,
+    pub name: String,
+    #[serde(default)]
+    pub spec: Option<String>,
+    #[serde(default)]
+    pub unit: Option<String>,
+    /// 停用的条目保留在缓存中，但不出现在联想结果里
+    #[serde(default = "default_active")]
+    pub active: bool,
+    pub updated_at: String,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+/// 增量同步接口返回的一批变更
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MasterDataChanges {
+    /// 新增或修改的条目
+    #[serde(default)]
+    pub items: Vec<MasterDataItem>,
+    /// 已删除条目的编码
+    #[serde(default)]
+    pub deleted: Vec<String>,
+    /// 本批变更之后的水位，下次从这里继续拉取
+    pub watermark: String,
+    /// 是否还有后续变更
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// 某类主数据的本地缓存状态
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MasterDataStatus {
+    pub kind: MasterDataKind,
+    /// 缓存条目数（含停用）
+    pub count: u32,
+    /// 当前水位，未同步过时为空
+    #[serde(default)]
+    pub watermark: Option<String>,
+    /// 最近一次同步完成的时间（Unix秒）
+    #[serde(default)]
+    pub synced_at: Option<u64>,
+}
+
+/// 联想查询返回条数上限
+pub const MAX_LOOKUP_LIMIT: u32 = 50;
+/// 未指定条数时的默认值
+pub const DEFAULT_LOOKUP_LIMIT: u32 = 10;
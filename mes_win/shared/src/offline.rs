@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+
+/// 可离线提交的写事务类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransactionKind {
+    /// 收货入库
+    GoodsReceipt,
+    /// 工单发料
+    MaterialIssue,
+    /// 库位移库
+    BinTransfer,
+    /// 完工入库（生产报工）
+    Completion,
+    /// 成品发货
+    Shipment,
+    /// 检验结果
+    InspectionResult,
+}
+
+impl TransactionKind {
+    pub const ALL: [TransactionKind; 6] = [
+        TransactionKind::GoodsReceipt,
+        TransactionKind::MaterialIssue,
+        TransactionKind::BinTransfer,
+        TransactionKind::Completion,
+        TransactionKind::Shipment,
+        TransactionKind::InspectionResult,
+    ];
+
+    /// 本地队列中保存的类型编码
+    pub fn code(&self) -> &'static str {
+        match self {
+            TransactionKind::GoodsReceipt => "GOODS_RECEIPT",
+            TransactionKind::MaterialIssue => "MATERIAL_ISSUE",
+            TransactionKind::BinTransfer => "BIN_TRANSFER",
+            TransactionKind::Completion => "COMPLETION",
+            TransactionKind::Shipment => "SHIPMENT",
+            TransactionKind::InspectionResult => "INSPECTION_RESULT",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.code() == code)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TransactionKind::GoodsReceipt => "收货入库",
+            TransactionKind::MaterialIssue => "工单发料",
+            TransactionKind::BinTransfer => "库位移库",
+            TransactionKind::Completion => "完工入库",
+            TransactionKind::Shipment => "成品发货",
+            TransactionKind::InspectionResult => "检验结果",
+        }
+    }
+}
+
+/// 离线事务状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum QueuedStatus {
+    /// 等待重放
+    Pending,
+    /// 重放时被后端拒绝（数据已被他人修改、库存不足等），需要人工处理
+    Conflict,
+}
+
+impl QueuedStatus {
+    pub fn code(&self) -> &'static str {
+        match self {
+            QueuedStatus::Pending => "PENDING",
+            QueuedStatus::Conflict => "CONFLICT",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        [QueuedStatus::Pending, QueuedStatus::Conflict]
+            .into_iter()
+            .find(|status| status.code() == code)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            QueuedStatus::Pending => "待同步",
+            QueuedStatus::Conflict => "冲突",
+        }
+    }
+
+    /// 样式类名后缀
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            QueuedStatus::Pending => "released",
+            QueuedStatus::Conflict => "rejected",
+        }
+    }
+}
+
+/// 离线队列中的写事务
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedTransaction {
+    pub id: u64,
+    pub kind: TransactionKind,
+    /// 操作摘要，如 "物料 M-001 收货 100 kg"
+    pub summary: String,
+    pub status: QueuedStatus,
+    /// 已尝试提交的次数
+    pub attempts: u32,
+    /// 最近一次提交失败的原因（冲突时为后端返回的提示）
+    #[serde(default)]
+    pub last_error: Option<String>,
+    /// 加入队列的时间（Unix秒）
+    pub created_at: u64,
+}
+
+/// 离线状态（`offline-status` 事件负载）
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfflineStatus {
+    /// 最近一次请求后端是否可达
+    pub online: bool,
+    /// 等待重放的事务数
+    pub pending: u32,
+    /// 重放冲突待处理的事务数
+    pub conflicts: u32,
+    /// 最近一次成功同步的时间（Unix秒）
+    #[serde(default)]
+    pub last_sync_at: Option<u64>,
+}
+
+impl OfflineStatus {
+    /// 队列中尚未完成的事务总数
+    pub fn queued(&self) -> u32 {
+        self.pending + self.conflicts
+    }
+}
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+
+/// 超级权限：拥有全部权限
+pub const ALL_PERMISSIONS: &str = "*";
+
+/// 功能权限
+///
+/// 编码格式为 `模块.操作`，后端也可以下发 `模块.*` 表示该模块的全部权限
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Permission {
+    DashboardView,
+    ReportExport,
+    ProductionView,
+    ProductionEdit,
+    InventoryView,
+    InventoryEdit,
+    QualityView,
+    QualityEdit,
+    UserManage,
+    RoleManage,
+    SystemSettings,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 11] = [
+        Permission::DashboardView,
+        Permission::ReportExport,
+        Permission::ProductionView,
+        Permission::ProductionEdit,
+        Permission::InventoryView,
+        Permission::InventoryEdit,
+        Permission::QualityView,
+        Permission::QualityEdit,
+        Permission::UserManage,
+        Permission::RoleManage,
+        Permission::SystemSettings,
+    ];
+
+    /// 与后端约定的权限编码
+    pub fn code(&self) -> &'static str {
+        match self {
+            Permission::DashboardView => "dashboard.view",
+            Permission::ReportExport => "dashboard.export",
+            Permission::ProductionView => "production.view",
+            Permission::ProductionEdit => "production.edit",
+            Permission::InventoryView => "inventory.view",
+            Permission::InventoryEdit => "inventory.edit",
+            Permission::QualityView => "quality.view",
+            Permission::QualityEdit => "quality.edit",
+            Permission::UserManage => "system.users",
+            Permission::RoleManage => "system.roles",
+            Permission::SystemSettings => "system.settings",
+        }
+    }
+
+    /// 根据权限编码解析
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|permission| permission.code() == code)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Permission::DashboardView => "查看仪表板",
+            Permission::ReportExport => "导出报表",
+            Permission::ProductionView => "查看生产数据",
+            Permission::ProductionEdit => "编辑工单、排程和工艺",
+            Permission::InventoryView => "查看库存",
+            Permission::InventoryEdit => "收发料、移库和盘点",
+            Permission::QualityView => "查看质量数据",
+            Permission::QualityEdit => "检验录入、标准和质量问题处理",
+            Permission::UserManage => "用户账户管理",
+            Permission::RoleManage => "角色权限管理",
+            Permission::SystemSettings => "系统参数设置",
+        }
+    }
+
+    /// 所属模块（角色编辑器按模块分组显示）
+    pub fn group(&self) -> &'static str {
+        match self {
+            Permission::DashboardView | Permission::ReportExport => "仪表板",
+            Permission::ProductionView | Permission::ProductionEdit => "生产管理",
+            Permission::InventoryView | Permission::InventoryEdit => "库存管理",
+            Permission::QualityView | Permission::QualityEdit => "质量管理",
+            Permission::UserManage | Permission::RoleManage | Permission::SystemSettings => "系统管理",
+        }
+    }
+}
+
+/// 已授予的权限编码中是否包含指定权限
+pub fn has_permission(granted: &[String], permission: Permission) -> bool {
+    let code = permission.code();
+    granted.iter().any(|grant| {
+        grant == ALL_PERMISSIONS
+            || grant == code
+            || grant
+                .strip_suffix(".*")
+                .is_some_and(|module| code.split('.').next() == Some(module))
+    })
+}
+
+/// 当前登录会话（get_session_info 的返回值）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionInfo {
+    pub user_id: u32,
+    pub username: String,
+    /// 角色名称
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// 权限编码
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    /// 登录时间（Unix时间戳，秒）
+    pub login_time: u64,
+}
+
+impl SessionInfo {
+    /// 是否拥有指定权限
+    pub fn can(&self, permission: Permission) -> bool {
+        has_permission(&self.permissions, permission)
+    }
+}
@@ -0,0 +1,4 @@
+// 生产管理相关数据类型
+pub mod work_order;
+pub mod schedule;
+pub mod routing;
@@ -0,0 +1,369 @@
+use std::collections::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use crate::error::FieldError;
+
+/// 工艺路线状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RoutingStatus {
+    /// 草稿，可编辑
+    Draft,
+    /// 已发布，生产使用中
+    Published,
+    /// 已被新版本替代
+    Obsolete,
+}
+
+impl RoutingStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RoutingStatus::Draft => "草稿",
+            RoutingStatus::Published => "已发布",
+            RoutingStatus::Obsolete => "已失效",
+        }
+    }
+
+    /// 样式类名后缀
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            RoutingStatus::Draft => "draft",
+            RoutingStatus::Published => "completed",
+            RoutingStatus::Obsolete => "closed",
+        }
+    }
+}
+
+/// 工作中心
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkCenter {
+    pub code: String,
+    pub name: String,
+}
+
+/// 工序所需物料
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationMaterial {
+    pub material_code: String,
+    pub quantity: f64,
+    pub unit: String,
+}
+
+/// 工序
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Operation {
+    /// 工序号（如 10、20、30），路线内唯一
+    pub seq: u32,
+    pub name: String,
+    /// 工作中心编码
+    pub work_center: String,
+    /// 标准工时（分钟/件）
+    pub standard_minutes: f64,
+    /// 准备时间（分钟）
+    #[serde(default)]
+    pub setup_minutes: f64,
+    /// 前序工序号，为空表示首道工序
+    #[serde(default)]
+    pub predecessors: Vec<u32>,
+    #[serde(default)]
+    pub materials: Vec<OperationMaterial>,
+    /// 是否为检验点
+    #[serde(default)]
+    pub inspection_point: bool,
+}
+
+impl Operation {
+    /// 新工序，默认接在 `previous` 之后
+    pub fn new(seq: u32, previous: Option<u32>) -> Self {
+        Self {
+            seq,
+            name: String::new(),
+            work_center: String::new(),
+            standard_minutes: 0.0,
+            setup_minutes: 0.0,
+            predecessors: previous.into_iter().collect(),
+            materials: Vec::new(),
+            inspection_point: false,
+        }
+    }
+}
+
+/// 工艺路线（某产品的一个版本）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Routing {
+    /// 为空表示尚未保存
+    #[serde(default)]
+    pub id: Option<u64>,
+    pub product_code: String,
+    #[serde(default)]
+    pub product_name: String,
+    /// 版本号，由后端分配
+    #[serde(default)]
+    pub version: u32,
+    pub status: RoutingStatus,
+    pub operations: Vec<Operation>,
+    #[serde(default)]
+    pub remark: Option<String>,
+    #[serde(default)]
+    pub published_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+impl Default for Routing {
+    fn default() -> Self {
+        Self {
+            id: None,
+            product_code: String::new(),
+            product_name: String::new(),
+            version: 0,
+            status: RoutingStatus::Draft,
+            operations: vec![Operation::new(10, None)],
+            remark: None,
+            published_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+/// 工艺路线列表项
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoutingSummary {
+    pub id: u64,
+    pub product_code: String,
+    #[serde(default)]
+    pub product_name: String,
+    pub version: u32,
+    pub status: RoutingStatus,
+    #[serde(default)]
+    pub operation_count: u32,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+impl Routing {
+    /// 校验工艺路线
+    ///
+    /// 检查必填项、工序号重复、工作中心是否存在、前序引用和环路。
+    /// 字段名使用 `operations[i].field` 形式，`i` 为工序在列表中的下标
+    pub fn validate(&self, work_centers: &[WorkCenter]) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        let mut push = |field: String, message: String| errors.push(FieldError { field, message });
+
+        if self.product_code.trim().is_empty() {
+            push("productCode".to_string(), "请输入产品编码".to_string());
+        }
+        if self.operations.is_empty() {
+            push("operations".to_string(), "工艺路线至少需要一道工序".to_string());
+        }
+
+        let known_centers: HashSet<&str> = work_centers.iter().map(|wc| wc.code.as_str()).collect();
+        let mut seen = HashSet::new();
+
+        for (index, operation) in self.operations.iter().enumerate() {
+            let field = |name: &str| format!("operations[{}].{}", index, name);
+
+            if operation.seq == 0 {
+                push(field("seq"), "工序号必须大于0".to_string());
+            } else if !seen.insert(operation.seq) {
+                push(field("seq"), format!("工序号 {} 重复", operation.seq));
+            }
+            if operation.name.trim().is_empty() {
+                push(field("name"), format!("工序 {} 缺少名称", operation.seq));
+            }
+            if operation.work_center.trim().is_empty() {
+                push(field("workCenter"), format!("工序 {} 未指定工作中心", operation.seq));
+            } else if !known_centers.contains(operation.work_center.as_str()) {
+                push(
+                    field("workCenter"),
+                    format!("工序 {} 的工作中心 {} 不存在", operation.seq, operation.work_center),
+                );
+            }
+            if !operation.standard_minutes.is_finite() || operation.standard_minutes <= 0.0 {
+                push(field("standardMinutes"), format!("工序 {} 的标准工时必须大于0", operation.seq));
+            }
+            if !operation.setup_minutes.is_finite() || operation.setup_minutes < 0.0 {
+                push(field("setupMinutes"), format!("工序 {} 的准备时间不能为负数", operation.seq));
+            }
+            for material in &operation.materials {
+                if material.material_code.trim().is_empty() || !material.quantity.is_finite() || material.quantity <= 0.0 {
+                    push(field("materials"), format!("工序 {} 的物料编码和用量不能为空", operation.seq));
+                    break;
+                }
+            }
+        }
+
+        let all_seqs: HashSet<u32> = self.operations.iter().map(|op| op.seq).collect();
+        for (index, operation) in self.operations.iter().enumerate() {
+            for predecessor in &operation.predecessors {
+                if *predecessor == operation.seq {
+                    push(
+                        format!("operations[{}].predecessors", index),
+                        format!("工序 {} 不能以自身为前序", operation.seq),
+                    );
+                } else if !all_seqs.contains(predecessor) {
+                    push(
+                        format!("operations[{}].predecessors", index),
+                        format!("工序 {} 的前序工序 {} 不存在", operation.seq, predecessor),
+                    );
+                }
+            }
+        }
+
+        if let Some(cycle) = self.find_cycle() {
+            let path: Vec<String> = cycle.iter().map(|seq| seq.to_string()).collect();
+            push("operations".to_string(), format!("工序存在循环依赖：{}", path.join(" → ")));
+        }
+
+        errors
+    }
+
+    /// 查找前序关系中的环路，返回环上的工序号（首尾相同）
+    pub fn find_cycle(&self) -> Option<Vec<u32>> {
+        // 0: 未访问, 1: 访问中, 2: 已完成
+        fn visit(seq: u32, graph: &HashMap<u32, Vec<u32>>, state: &mut HashMap<u32, u8>, stack: &mut Vec<u32>) -> Option<Vec<u32>> {
+            state.insert(seq, 1);
+            stack.push(seq);
+
+            for next in graph.get(&seq).into_iter().flatten() {
+                match state.get(next).copied().unwrap_or(0) {
+                    0 => {
+                        if let Some(cycle) = visit(*next, graph, state, stack) {
+                            return Some(cycle);
+                        }
+                    }
+                    1 => {
+                        let start = stack.iter().position(|s| s == next).unwrap_or(0);
+                        let mut cycle = stack[start..].to_vec();
+                        cycle.push(*next);
+                        return Some(cycle);
+                    }
+                    _ => {}
+                }
+            }
+
+            stack.pop();
+            state.insert(seq, 2);
+            None
+        }
+
+        // 边方向：前序 → 后序
+        let mut graph: HashMap<u32, Vec<u32>> = HashMap::new();
+        for operation in &self.operations {
+            for predecessor in &operation.predecessors {
+                if *predecessor != operation.seq {
+                    graph.entry(*predecessor).or_default().push(operation.seq);
+                }
+            }
+        }
+
+        let mut state = HashMap::new();
+        let mut seqs: Vec<u32> = self.operations.iter().map(|op| op.seq).collect();
+        seqs.sort_unstable();
+        for seq in seqs {
+            if state.get(&seq).copied().unwrap_or(0) == 0 {
+                if let Some(cycle) = visit(seq, &graph, &mut state, &mut Vec::new()) {
+                    return Some(cycle);
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn work_centers() -> Vec<WorkCenter> {
+        ["WC01", "WC02"]
+            .iter()
+            .map(|code| WorkCenter { code: code.to_string(), name: code.to_string() })
+            .collect()
+    }
+
+    fn operation(seq: u32, work_center: &str, predecessors: &[u32]) -> Operation {
+        Operation {
+            name: format!("工序{}", seq),
+            work_center: work_center.to_string(),
+            standard_minutes: 1.5,
+            predecessors: predecessors.to_vec(),
+            ..Operation::new(seq, None)
+        }
+    }
+
+    fn routing(operations: Vec<Operation>) -> Routing {
+        Routing {
+            product_code: "P001".to_string(),
+            operations,
+            ..Routing::default()
+        }
+    }
+
+    fn fields(errors: &[FieldError]) -> Vec<&str> {
+        errors.iter().map(|error| error.field.as_str()).collect()
+    }
+
+    #[test]
+    fn valid_graph_passes() {
+        // 10 → 20、30 并行 → 40 汇合
+        let routing = routing(vec![
+            operation(10, "WC01", &[]),
+            operation(20, "WC02", &[10]),
+            operation(30, "WC02", &[10]),
+            operation(40, "WC01", &[20, 30]),
+        ]);
+        assert!(routing.validate(&work_centers()).is_empty());
+        assert_eq!(routing.find_cycle(), None);
+    }
+
+    #[test]
+    fn cycle_is_reported() {
+        let routing = routing(vec![
+            operation(10, "WC01", &[30]),
+            operation(20, "WC01", &[10]),
+            operation(30, "WC01", &[20]),
+        ]);
+        assert_eq!(routing.find_cycle(), Some(vec![10, 20, 30, 10]));
+
+        let errors = routing.validate(&work_centers());
+        assert_eq!(fields(&errors), vec!["operations"]);
+        assert!(errors[0].message.contains("10 → 20 → 30 → 10"));
+    }
+
+    #[test]
+    fn duplicate_seq_is_reported() {
+        let routing = routing(vec![
+            operation(10, "WC01", &[]),
+            operation(20, "WC01", &[10]),
+            operation(10, "WC02", &[]),
+        ]);
+        let errors = routing.validate(&work_centers());
+        assert_eq!(fields(&errors), vec!["operations[2].seq"]);
+    }
+
+    #[test]
+    fn unknown_work_center_uses_submission_index() {
+        let routing = routing(vec![
+            operation(20, "WC99", &[10]),
+            operation(10, "WC01", &[]),
+        ]);
+        let errors = routing.validate(&work_centers());
+        assert_eq!(fields(&errors), vec!["operations[0].workCenter"]);
+        assert!(errors[0].message.contains("WC99"));
+    }
+
+    #[test]
+    fn bad_predecessors_are_reported() {
+        let routing = routing(vec![
+            operation(10, "WC01", &[10]),
+            operation(20, "WC01", &[15]),
+        ]);
+        let errors = routing.validate(&work_centers());
+        assert_eq!(fields(&errors), vec!["operations[0].predecessors", "operations[1].predecessors"]);
+        assert_eq!(routing.find_cycle(), None);
+    }
+}
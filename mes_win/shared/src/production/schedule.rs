@@ -0,0 +1,293 @@
+use serde::{Deserialize, Serialize};
+use super::work_order::WorkOrderStatus;
+
+use crate::time::parse_minutes;
+
+/// 排程资源（产线或机台）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleResource {
+    pub code: String,
+    pub name: String,
+    /// 可同时执行的工单数
+    #[serde(default = "default_capacity")]
+    pub capacity: u32,
+}
+
+fn default_capacity() -> u32 {
+    1
+}
+
+/// 排程条目（一个工单在某资源上的计划时段）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleItem {
+    pub work_order_id: u64,
+    pub order_no: String,
+    pub product_name: String,
+    /// 资源编码
+    pub resource: String,
+    /// 计划开始时间（ISO 8601）
+    pub start: String,
+    /// 计划完成时间
+    pub end: String,
+    pub status: WorkOrderStatus,
+    /// 是否允许拖拽调整（由Tauri端根据工单状态机填写）
+    #[serde(default)]
+    pub movable: bool,
+}
+
+/// 排程查询时间范围 `[start, end)`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleQuery {
+    pub start: String,
+    pub end: String,
+}
+
+/// 排程看板数据
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ScheduleBoard {
+    pub resources: Vec<ScheduleResource>,
+    pub items: Vec<ScheduleItem>,
+}
+
+/// 重新排程请求
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RescheduleRequest {
+    pub work_order_id: u64,
+    pub resource: String,
+    pub start: String,
+    pub end: String,
+}
+
+/// 排程冲突
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ScheduleConflict {
+    /// 开始/结束时间无效
+    InvalidRange,
+    /// 资源不存在
+    UnknownResource { resource: String },
+    /// 与同一资源上的其他工单时间重叠，且超出资源产能
+    Capacity {
+        resource: String,
+        capacity: u32,
+        /// 重叠的工单号
+        overlapping: Vec<String>,
+    },
+}
+
+impl ScheduleConflict {
+    /// 冲突说明
+    pub fn describe(&self) -> String {
+        match self {
+            ScheduleConflict::InvalidRange => "计划完成时间必须晚于开始时间".to_string(),
+            ScheduleConflict::UnknownResource { resource } => format!("资源 {} 不存在", resource),
+            ScheduleConflict::Capacity { resource, capacity, overlapping } => format!(
+                "资源 {} 产能为 {}，与工单 {} 时间冲突",
+                resource,
+                capacity,
+                overlapping.join("、")
+            ),
+        }
+    }
+}
+
+impl ScheduleBoard {
+    /// 检查条目放到指定资源和时段后是否冲突
+    ///
+    /// 与 `candidate` 同一工单的原条目不参与计算
+    pub fn conflicts_for(&self, candidate: &ScheduleItem) -> Vec<ScheduleConflict> {
+        let (Some(start), Some(end)) = (parse_minutes(&candidate.start), parse_minutes(&candidate.end)) else {
+            return vec![ScheduleConflict::InvalidRange];
+        };
+        if end <= start {
+            return vec![ScheduleConflict::InvalidRange];
+        }
+
+        let Some(resource) = self.resources.iter().find(|r| r.code == candidate.resource) else {
+            return vec![ScheduleConflict::UnknownResource {
+                resource: candidate.resource.clone(),
+            }];
+        };
+
+        // 与候选时段重叠的其他工单
+        let overlapping: Vec<(i64, i64, &ScheduleItem)> = self
+            .items
+            .iter()
+            .filter(|item| item.work_order_id != candidate.work_order_id && item.resource == candidate.resource)
+            .filter_map(|item| {
+                let item_start = parse_minutes(&item.start)?;
+                let item_end = parse_minutes(&item.end)?;
+                (item_start < end && start < item_end).then_some((item_start.max(start), item_end.min(end), item))
+            })
+            .collect();
+
+        if max_concurrency(&overlapping) < resource.capacity.max(1) {
+            return Vec::new();
+        }
+
+        vec![ScheduleConflict::Capacity {
+            resource: resource.name.clone(),
+            capacity: resource.capacity,
+            overlapping: overlapping.iter().map(|(_, _, item)| item.order_no.clone()).collect(),
+        }]
+    }
+
+    /// 当前看板中存在冲突的工单ID
+    pub fn conflicting_orders(&self) -> Vec<u64> {
+        self.items
+            .iter()
+            .filter(|item| !self.conflicts_for(item).is_empty())
+            .map(|item| item.work_order_id)
+            .collect()
+    }
+}
+
+/// 计算一组时段的最大并发数
+fn max_concurrency(intervals: &[(i64, i64, &ScheduleItem)]) -> u32 {
+    let mut events: Vec<(i64, i32)> = intervals
+        .iter()
+        .flat_map(|(start, end, _)| [(*start, 1), (*end, -1)])
+        .collect();
+    // 同一时刻先处理结束，首尾相接不算重叠
+    events.sort();
+
+    let mut current = 0i32;
+    let mut max = 0i32;
+    for (_, delta) in events {
+        current += delta;
+        max = max.max(current);
+    }
+    max as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(work_order_id: u64, resource: &str, start: &str, end: &str) -> ScheduleItem {
+        ScheduleItem {
+            work_order_id,
+            order_no: format!("WO-{}", work_order_id),
+            product_name: String::new(),
+            resource: resource.to_string(),
+            start: format!("2026-10-17T{}", start),
+            end: format!("2026-10-17T{}", end),
+            status: WorkOrderStatus::Released,
+            movable: true,
+        }
+    }
+
+    /// 产线 L1 产能为1，L2 产能为2
+    fn board(items: Vec<ScheduleItem>) -> ScheduleBoard {
+        ScheduleBoard {
+            resources: vec![
+                ScheduleResource { code: "L1".into(), name: "一号线".into(), capacity: 1 },
+                ScheduleResource { code: "L2".into(), name: "二号线".into(), capacity: 2 },
+            ],
+            items,
+        }
+    }
+
+    fn overlapping(conflicts: &[ScheduleConflict]) -> Option<Vec<String>> {
+        match conflicts {
+            [] => Some(Vec::new()),
+            [ScheduleConflict::Capacity { overlapping, .. }] => Some(overlapping.clone()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn conflicts_table() {
+        // (看板上的条目, 候选条目, 预期冲突的工单号)
+        let cases: Vec<(Vec<ScheduleItem>, ScheduleItem, Vec<&str>)> = vec![
+            // 首尾相接不算冲突
+            (vec![item(1, "L1", "08:00", "10:00")], item(9, "L1", "10:00", "12:00"), vec![]),
+            (vec![item(1, "L1", "10:00", "12:00")], item(9, "L1", "08:00", "10:00"), vec![]),
+            (vec![item(1, "L1", "08:00", "10:00")], item(9, "L1", "09:59", "12:00"), vec!["WO-1"]),
+            // 其他资源上的工单不参与计算
+            (vec![item(1, "L2", "08:00", "10:00")], item(9, "L1", "08:00", "10:00"), vec![]),
+            // 产能2：两个重叠允许，三个冲突
+            (vec![item(1, "L2", "08:00", "12:00")], item(9, "L2", "09:00", "11:00"), vec![]),
+            (
+                vec![item(1, "L2", "08:00", "12:00"), item(2, "L2", "09:00", "10:00")],
+                item(9, "L2", "09:30", "11:00"),
+                vec!["WO-1", "WO-2"],
+            ),
+            // 两个工单与候选重叠但彼此不重叠，并发数仍为2
+            (
+                vec![item(1, "L2", "08:00", "10:00"), item(2, "L2", "10:00", "12:00")],
+                item(9, "L2", "09:00", "11:00"),
+                vec![],
+            ),
+            // 再加一个全程工单，09:00~10:00 三者同时
+            (
+                vec![item(1, "L2", "08:00", "10:00"), item(2, "L2", "10:00", "12:00"), item(3, "L2", "08:00", "12:00")],
+                item(9, "L2", "09:00", "11:00"),
+                vec!["WO-1", "WO-2", "WO-3"],
+            ),
+            // 调整中的工单不与自己的原条目冲突
+            (vec![item(9, "L1", "08:00", "10:00")], item(9, "L1", "09:00", "11:00"), vec![]),
+            (
+                vec![item(9, "L1", "08:00", "10:00"), item(1, "L1", "10:30", "12:00")],
+                item(9, "L1", "09:00", "11:00"),
+                vec!["WO-1"],
+            ),
+        ];
+        for (index, (items, candidate, expected)) in cases.into_iter().enumerate() {
+            let conflicts = board(items).conflicts_for(&candidate);
+            assert_eq!(
+                overlapping(&conflicts),
+                Some(expected.iter().map(|s| s.to_string()).collect()),
+                "第 {} 组：{:?}",
+                index + 1,
+                conflicts
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_candidates() {
+        let board = board(Vec::new());
+        let cases = [
+            (item(9, "L1", "10:00", "10:00"), ScheduleConflict::InvalidRange),
+            (item(9, "L1", "11:00", "10:00"), ScheduleConflict::InvalidRange),
+            (
+                ScheduleItem { end: "2026-02-31T10:00".into(), ..item(9, "L1", "08:00", "10:00") },
+                ScheduleConflict::InvalidRange,
+            ),
+            (item(9, "L9", "08:00", "10:00"), ScheduleConflict::UnknownResource { resource: "L9".into() }),
+        ];
+        for (candidate, expected) in cases {
+            assert_eq!(board.conflicts_for(&candidate), vec![expected]);
+        }
+    }
+
+    #[test]
+    fn max_concurrency_counts_overlaps() {
+        let a = item(1, "L1", "08:00", "10:00");
+        let cases: [(&[(i64, i64)], u32); 5] = [
+            (&[], 0),
+            (&[(0, 10)], 1),
+            (&[(0, 10), (10, 20)], 1),
+            (&[(0, 10), (5, 15), (9, 20)], 3),
+            (&[(0, 10), (5, 15), (10, 20)], 2),
+        ];
+        for (index, (intervals, expected)) in cases.into_iter().enumerate() {
+            let intervals: Vec<_> = intervals.iter().map(|(start, end)| (*start, *end, &a)).collect();
+            assert_eq!(max_concurrency(&intervals), expected, "第 {} 组", index + 1);
+        }
+    }
+
+    #[test]
+    fn conflicting_orders_lists_both_sides() {
+        let board = board(vec![
+            item(1, "L1", "08:00", "10:00"),
+            item(2, "L1", "09:00", "11:00"),
+            item(3, "L1", "11:00", "12:00"),
+        ]);
+        assert_eq!(board.conflicting_orders(), vec![1, 2]);
+    }
+}
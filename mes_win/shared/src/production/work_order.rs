@@ -0,0 +1,268 @@
+use serde::{Deserialize, Serialize};
+use crate::error::FieldError;
+
+/// 工单状态
+///
+/// 状态流转规则由Tauri端的工单状态机负责校验
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WorkOrderStatus {
+    /// 新建（未下达）
+    Created,
+    /// 已下达
+    Released,
+    /// 生产中
+    InProgress,
+    /// 已完工
+    Completed,
+    /// 已关闭
+    Closed,
+}
+
+impl WorkOrderStatus {
+    pub const ALL: [WorkOrderStatus; 5] = [
+        WorkOrderStatus::Created,
+        WorkOrderStatus::Released,
+        WorkOrderStatus::InProgress,
+        WorkOrderStatus::Completed,
+        WorkOrderStatus::Closed,
+    ];
+
+    /// 与后端约定的状态编码
+    pub fn code(&self) -> &'static str {
+        match self {
+            WorkOrderStatus::Created => "CREATED",
+            WorkOrderStatus::Released => "RELEASED",
+            WorkOrderStatus::InProgress => "IN_PROGRESS",
+            WorkOrderStatus::Completed => "COMPLETED",
+            WorkOrderStatus::Closed => "CLOSED",
+        }
+    }
+
+    /// 根据状态编码解析
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|status| status.code() == code)
+    }
+
+    /// 显示名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            WorkOrderStatus::Created => "新建",
+            WorkOrderStatus::Released => "已下达",
+            WorkOrderStatus::InProgress => "生产中",
+            WorkOrderStatus::Completed => "已完工",
+            WorkOrderStatus::Closed => "已关闭",
+        }
+    }
+
+    /// 流转到该状态时的操作名称
+    pub fn action_label(&self) -> &'static str {
+        match self {
+            WorkOrderStatus::Created => "退回",
+            WorkOrderStatus::Released => "下达",
+            WorkOrderStatus::InProgress => "开工",
+            WorkOrderStatus::Completed => "完工",
+            WorkOrderStatus::Closed => "关闭",
+        }
+    }
+}
+
+/// 生产工单
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkOrder {
+    pub id: u64,
+    /// 工单号
+    pub order_no: String,
+    pub product_code: String,
+    pub product_name: String,
+    /// 计划数量
+    pub quantity: f64,
+    /// 已完工数量
+    #[serde(default)]
+    pub completed_quantity: f64,
+    pub unit: String,
+    /// 生产线
+    #[serde(default)]
+    pub line: Option<String>,
+    /// 计划开始时间（ISO 8601，如 2024-05-01T08:00）
+    pub planned_start: String,
+    /// 计划完成时间
+    pub planned_end: String,
+    /// 优先级，1最高，5最低
+    pub priority: u8,
+    pub status: WorkOrderStatus,
+    #[serde(default)]
+    pub remark: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// 工单创建/编辑表单
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkOrderDraft {
+    /// 工单号，为空时由后端生成
+    #[serde(default)]
+    pub order_no: Option<String>,
+    pub product_code: String,
+    pub product_name: String,
+    pub quantity: f64,
+    pub unit: String,
+    #[serde(default)]
+    pub line: Option<String>,
+    pub planned_start: String,
+    pub planned_end: String,
+    pub priority: u8,
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+impl Default for WorkOrderDraft {
+    fn default() -> Self {
+        Self {
+            order_no: None,
+            product_code: String::new(),
+            product_name: String::new(),
+            quantity: 0.0,
+            unit: "件".to_string(),
+            line: None,
+            planned_start: String::new(),
+            planned_end: String::new(),
+            priority: 3,
+            remark: None,
+        }
+    }
+}
+
+impl From<&WorkOrder> for WorkOrderDraft {
+    fn from(order: &WorkOrder) -> Self {
+        Self {
+            order_no: Some(order.order_no.clone()),
+            product_code: order.product_code.clone(),
+            product_name: order.product_name.clone(),
+            quantity: order.quantity,
+            unit: order.unit.clone(),
+            line: order.line.clone(),
+            planned_start: order.planned_start.clone(),
+            planned_end: order.planned_end.clone(),
+            priority: order.priority,
+            remark: order.remark.clone(),
+        }
+    }
+}
+
+impl WorkOrderDraft {
+    /// 校验表单，返回所有字段错误
+    ///
+    /// 前端提交前和Tauri命令中使用同一套规则
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        let mut push = |field: &str, message: &str| {
+            errors.push(FieldError {
+                field: field.to_string(),
+                message: message.to_string(),
+            });
+        };
+
+        if self.product_code.trim().is_empty() {
+            push("productCode", "请输入产品编码");
+        }
+        if self.product_name.trim().is_empty() {
+            push("productName", "请输入产品名称");
+        }
+        if !self.quantity.is_finite() || self.quantity <= 0.0 {
+            push("quantity", "计划数量必须大于0");
+        }
+        if self.unit.trim().is_empty() {
+            push("unit", "请输入单位");
+        }
+        if !(1..=5).contains(&self.priority) {
+            push("priority", "优先级必须在1到5之间");
+        }
+
+        let start_valid = is_datetime(&self.planned_start);
+        let end_valid = is_datetime(&self.planned_end);
+        if !start_valid {
+            push("plannedStart", "请选择计划开始时间");
+        }
+        if !end_valid {
+            push("plannedEnd", "请选择计划完成时间");
+        }
+        // 同一格式的ISO时间字符串可直接按字典序比较
+        if start_valid && end_valid && self.planned_end <= self.planned_start {
+            push("plannedEnd", "计划完成时间必须晚于开始时间");
+        }
+
+        errors
+    }
+}
+
+/// 检查是否为 `YYYY-MM-DDTHH:MM[:SS]` 格式的时间
+fn is_datetime(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if bytes.len() != 16 && bytes.len() != 19 {
+        return false;
+    }
+
+    bytes.iter().enumerate().all(|(index, byte)| match index {
+        4 | 7 => *byte == b'-',
+        10 => *byte == b'T',
+        13 | 16 => *byte == b':',
+        _ => byte.is_ascii_digit(),
+    })
+}
+
+/// 工单列表排序字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WorkOrderSortField {
+    OrderNo,
+    PlannedStart,
+    PlannedEnd,
+    Priority,
+    Status,
+    CreatedAt,
+}
+
+/// 工单分页查询条件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkOrderQuery {
+    pub page: u32,
+    pub page_size: u32,
+    /// 按工单号/产品编码/产品名称模糊查询
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<WorkOrderStatus>,
+    pub sort_by: WorkOrderSortField,
+    #[serde(default)]
+    pub descending: bool,
+}
+
+impl Default for WorkOrderQuery {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            page_size: 20,
+            keyword: None,
+            status: None,
+            sort_by: WorkOrderSortField::CreatedAt,
+            descending: true,
+        }
+    }
+}
+
+/// 工单详情（get_work_order 的返回值）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkOrderDetail {
+    pub order: WorkOrder,
+    /// 当前状态下允许流转到的状态
+    pub transitions: Vec<WorkOrderStatus>,
+    /// 当前状态下是否允许编辑
+    pub editable: bool,
+}
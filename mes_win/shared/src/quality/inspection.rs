@@ -0,0 +1,468 @@
+use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use crate::error::FieldError;
+
+/// 检验特性类型
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum CharacteristicKind {
+    /// 计量型：测量值须落在上下限之间（含边界）
+    #[serde(rename_all = "camelCase")]
+    Numeric {
+        nominal: f64,
+        #[serde(default)]
+        lower_limit: Option<f64>,
+        #[serde(default)]
+        upper_limit: Option<f64>,
+        #[serde(default)]
+        unit: String,
+    },
+    /// 合格/不合格判定
+    PassFail,
+    /// 属性型：从选项中选择，只有可接收的选项判为合格
+    #[serde(rename_all = "camelCase")]
+    Attribute {
+        options: Vec<String>,
+        accepted: Vec<String>,
+    },
+}
+
+/// 检验特性
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Characteristic {
+    pub code: String,
+    pub name: String,
+    pub kind: CharacteristicKind,
+    /// 关键特性
+    #[serde(default)]
+    pub critical: bool,
+}
+
+impl Characteristic {
+    /// 判定单个检验值是否合格，类型不匹配时判为不合格
+    pub fn is_conforming(&self, value: &MeasuredValue) -> bool {
+        match (&self.kind, value) {
+            (CharacteristicKind::Numeric { lower_limit, upper_limit, .. }, MeasuredValue::Numeric(v)) => {
+                v.is_finite()
+                    && lower_limit.is_none_or(|lower| *v >= lower)
+                    && upper_limit.is_none_or(|upper| *v <= upper)
+            }
+            (CharacteristicKind::PassFail, MeasuredValue::PassFail(pass)) => *pass,
+            (CharacteristicKind::Attribute { accepted, .. }, MeasuredValue::Attribute(v)) => accepted.contains(v),
+            _ => false,
+        }
+    }
+
+    /// 规格说明（如 "10 ±0.05 mm"）
+    pub fn specification(&self) -> String {
+        match &self.kind {
+            CharacteristicKind::Numeric { nominal, lower_limit, upper_limit, unit } => {
+                let range = match (lower_limit, upper_limit) {
+                    (Some(lower), Some(upper)) => format!("[{}, {}]", lower, upper),
+                    (Some(lower), None) => format!("≥ {}", lower),
+                    (None, Some(upper)) => format!("≤ {}", upper),
+                    (None, None) => "-".to_string(),
+                };
+                format!("{} {} {}", nominal, range, unit).trim_end().to_string()
+            }
+            CharacteristicKind::PassFail => "合格 / 不合格".to_string(),
+            CharacteristicKind::Attribute { accepted, .. } => format!("可接收：{}", accepted.join("、")),
+        }
+    }
+}
+
+/// 检验值
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum MeasuredValue {
+    Numeric(f64),
+    PassFail(bool),
+    Attribute(String),
+}
+
+/// 单个样本的检验记录
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Measurement {
+    pub characteristic_code: String,
+    /// 样本序号（从1开始）
+    pub sample_no: u32,
+    pub value: MeasuredValue,
+}
+
+/// 检验来源
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum InspectionSource {
+    /// 来料检验：按原材料批次
+    #[serde(rename_all = "camelCase")]
+    Receipt { lot_no: String },
+    /// 工序检验：按工单工序
+    #[serde(rename_all = "camelCase")]
+    Operation { work_order_id: u64, operation_seq: u32 },
+}
+
+impl InspectionSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            InspectionSource::Receipt { .. } => "来料检验",
+            InspectionSource::Operation { .. } => "工序检验",
+        }
+    }
+}
+
+/// 检验批状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InspectionStatus {
+    /// 待检验
+    Open,
+    Accepted,
+    Rejected,
+}
+
+impl InspectionStatus {
+    pub const ALL: [InspectionStatus; 3] = [
+        InspectionStatus::Open,
+        InspectionStatus::Accepted,
+        InspectionStatus::Rejected,
+    ];
+
+    /// 与后端约定的状态编码
+    pub fn code(&self) -> &'static str {
+        match self {
+            InspectionStatus::Open => "OPEN",
+            InspectionStatus::Accepted => "ACCEPTED",
+            InspectionStatus::Rejected => "REJECTED",
+        }
+    }
+
+    /// 根据状态编码解析
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|status| status.code() == code)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            InspectionStatus::Open => "待检验",
+            InspectionStatus::Accepted => "接收",
+            InspectionStatus::Rejected => "拒收",
+        }
+    }
+
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            InspectionStatus::Open => "released",
+            InspectionStatus::Accepted => "completed",
+            InspectionStatus::Rejected => "rejected",
+        }
+    }
+}
+
+/// 检验批
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InspectionLot {
+    pub id: u64,
+    pub inspection_no: String,
+    pub source: InspectionSource,
+    /// 被检物料编码或产品SKU
+    pub item_code: String,
+    #[serde(default)]
+    pub item_name: String,
+    /// 来源描述（如批次号、工单号及工序）
+    #[serde(default)]
+    pub source_ref: String,
+    pub quantity: f64,
+    pub sample_size: u32,
+    /// 接收数 Ac：开批时按检验标准的抽样方案确定，不合格品数不超过该值时接收
+    #[serde(default)]
+    pub accept: u32,
+    /// 拒收数 Re：不合格品数达到该值时拒收
+    #[serde(default = "default_reject")]
+    pub reject: u32,
+    /// 生成检验特性所依据的检验标准及版本
+    #[serde(default)]
+    pub plan_id: Option<u64>,
+    #[serde(default)]
+    pub plan_version: Option<u32>,
+    pub characteristics: Vec<Characteristic>,
+    #[serde(default)]
+    pub measurements: Vec<Measurement>,
+    pub status: InspectionStatus,
+    #[serde(default)]
+    pub inspector: Option<String>,
+    pub created_at: String,
+    #[serde(default)]
+    pub decided_at: Option<String>,
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+/// 未记录抽样方案的检验批按零缺陷接收
+fn default_reject() -> u32 {
+    1
+}
+
+impl InspectionLot {
+    /// 查找某特性某样本的检验值
+    pub fn value_of(&self, characteristic_code: &str, sample_no: u32) -> Option<&MeasuredValue> {
+        self.measurements
+            .iter()
+            .find(|m| m.characteristic_code == characteristic_code && m.sample_no == sample_no)
+            .map(|m| &m.value)
+    }
+
+    /// 不合格的检验记录数
+    pub fn nonconforming_count(&self) -> usize {
+        self.measurements
+            .iter()
+            .filter(|m| {
+                self.characteristics
+                    .iter()
+                    .find(|c| c.code == m.characteristic_code)
+                    .is_some_and(|c| !c.is_conforming(&m.value))
+            })
+            .count()
+    }
+
+    /// 不合格品数：有任一特性不合格的样本数，同一样本多项不合格只计一次
+    pub fn defective_count(&self) -> usize {
+        self.measurements
+            .iter()
+            .filter(|m| {
+                self.characteristics
+                    .iter()
+                    .find(|c| c.code == m.characteristic_code)
+                    .is_some_and(|c| !c.is_conforming(&m.value))
+            })
+            .map(|m| m.sample_no)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// 是否所有特性的所有样本都已录入
+    pub fn is_complete(&self) -> bool {
+        self.characteristics.iter().all(|c| {
+            (1..=self.sample_size).all(|sample_no| self.value_of(&c.code, sample_no).is_some())
+        })
+    }
+
+    /// 自动判定：未录完时为空，不合格品数不超过 Ac 时接收，达到 Re 时拒收
+    pub fn decide(&self) -> Option<InspectionStatus> {
+        if !self.is_complete() {
+            return None;
+        }
+        let defective = self.defective_count() as u32;
+        if defective <= self.accept && defective < self.reject {
+            Some(InspectionStatus::Accepted)
+        } else {
+            Some(InspectionStatus::Rejected)
+        }
+    }
+
+    /// 提交前校验：检验值须完整，且与特性类型一致
+    pub fn validate_measurements(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        for characteristic in &self.characteristics {
+            for sample_no in 1..=self.sample_size {
+                let field = format!("{}[{}]", characteristic.code, sample_no);
+                match (&characteristic.kind, self.value_of(&characteristic.code, sample_no)) {
+                    (_, None) => errors.push(FieldError {
+                        field,
+                        message: format!("{} 样本{} 未录入", characteristic.name, sample_no),
+                    }),
+                    (CharacteristicKind::Numeric { .. }, Some(MeasuredValue::Numeric(v))) if !v.is_finite() => {
+                        errors.push(FieldError {
+                            field,
+                            message: format!("{} 样本{} 测量值无效", characteristic.name, sample_no),
+                        })
+                    }
+                    (CharacteristicKind::Numeric { .. }, Some(MeasuredValue::Numeric(_)))
+                    | (CharacteristicKind::PassFail, Some(MeasuredValue::PassFail(_))) => {}
+                    (CharacteristicKind::Attribute { options, .. }, Some(MeasuredValue::Attribute(v)))
+                        if options.contains(v) => {}
+                    _ => errors.push(FieldError {
+                        field,
+                        message: format!("{} 样本{} 检验值类型不符", characteristic.name, sample_no),
+                    }),
+                }
+            }
+        }
+        errors
+    }
+}
+
+/// 检验批查询条件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InspectionLotQuery {
+    pub page: u32,
+    pub page_size: u32,
+    /// 按检验单号/物料/来源模糊查询
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<InspectionStatus>,
+}
+
+impl Default for InspectionLotQuery {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            page_size: 20,
+            keyword: None,
+            status: None,
+        }
+    }
+}
+
+/// 新建检验批
+///
+/// 检验特性和样本量由后端按物料/工序当前发布的检验标准生成
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InspectionLotDraft {
+    pub source: InspectionSource,
+    pub quantity: f64,
+}
+
+impl InspectionLotDraft {
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        match &self.source {
+            InspectionSource::Receipt { lot_no } if lot_no.trim().is_empty() => errors.push(FieldError {
+                field: "lotNo".to_string(),
+                message: "请输入来料批次号".to_string(),
+            }),
+            InspectionSource::Operation { work_order_id: 0, .. } => errors.push(FieldError {
+                field: "workOrderId".to_string(),
+                message: "请选择工单".to_string(),
+            }),
+            InspectionSource::Operation { operation_seq: 0, .. } => errors.push(FieldError {
+                field: "operationSeq".to_string(),
+                message: "请输入工序号".to_string(),
+            }),
+            _ => {}
+        }
+        if !self.quantity.is_finite() || self.quantity <= 0.0 {
+            errors.push(FieldError {
+                field: "quantity".to_string(),
+                message: "送检数量必须大于0".to_string(),
+            });
+        }
+        errors
+    }
+}
+
+/// 提交检验结果
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InspectionRecord {
+    pub measurements: Vec<Measurement>,
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 判为不合格的（特性, 样本）
+    type Failed = &'static [(&'static str, u32)];
+
+    fn pass_fail(code: &str) -> Characteristic {
+        Characteristic {
+            code: code.to_string(),
+            name: code.to_string(),
+            kind: CharacteristicKind::PassFail,
+            critical: false,
+        }
+    }
+
+    /// 两个特性、5个样本、Ac 1 / Re 2 的检验批，`failed` 中的（特性, 样本）判为不合格
+    fn lot(failed: &[(&str, u32)]) -> InspectionLot {
+        let characteristics = vec![pass_fail("APPEARANCE"), pass_fail("FUNCTION")];
+        let measurements = characteristics
+            .iter()
+            .flat_map(|c| {
+                (1..=5).map(move |sample_no| Measurement {
+                    characteristic_code: c.code.clone(),
+                    sample_no,
+                    value: MeasuredValue::PassFail(!failed.contains(&(c.code.as_str(), sample_no))),
+                })
+            })
+            .collect();
+        InspectionLot {
+            id: 1,
+            inspection_no: "IQC-001".to_string(),
+            source: InspectionSource::Receipt { lot_no: "L001".to_string() },
+            item_code: "M-001".to_string(),
+            item_name: String::new(),
+            source_ref: String::new(),
+            quantity: 100.0,
+            sample_size: 5,
+            accept: 1,
+            reject: 2,
+            plan_id: None,
+            plan_version: None,
+            characteristics,
+            measurements,
+            status: InspectionStatus::Open,
+            inspector: None,
+            created_at: "2026-10-01 08:00".to_string(),
+            decided_at: None,
+            remark: None,
+        }
+    }
+
+    #[test]
+    fn decide_compares_defective_units_with_ac_re() {
+        let cases: [(Failed, usize, InspectionStatus); 5] = [
+            (&[], 0, InspectionStatus::Accepted),
+            (&[("APPEARANCE", 3)], 1, InspectionStatus::Accepted),
+            (&[("APPEARANCE", 1), ("FUNCTION", 4)], 2, InspectionStatus::Rejected),
+            (&[("APPEARANCE", 1), ("APPEARANCE", 2), ("FUNCTION", 3)], 3, InspectionStatus::Rejected),
+            // 同一样本多项不合格只算一件不合格品
+            (&[("APPEARANCE", 2), ("FUNCTION", 2)], 1, InspectionStatus::Accepted),
+        ];
+        for (failed, defective, expected) in cases {
+            let lot = lot(failed);
+            assert_eq!(lot.defective_count(), defective, "不合格品数 {:?}", failed);
+            assert_eq!(lot.decide(), Some(expected), "判定 {:?}", failed);
+        }
+    }
+
+    #[test]
+    fn nonconforming_count_still_counts_measurements() {
+        let lot = lot(&[("APPEARANCE", 2), ("FUNCTION", 2)]);
+        assert_eq!(lot.nonconforming_count(), 2);
+        assert_eq!(lot.defective_count(), 1);
+    }
+
+    #[test]
+    fn zero_acceptance_rejects_on_first_defective() {
+        let mut lot = lot(&[("FUNCTION", 5)]);
+        lot.accept = 0;
+        lot.reject = 1;
+        assert_eq!(lot.decide(), Some(InspectionStatus::Rejected));
+    }
+
+    #[test]
+    fn incomplete_lot_is_not_decided() {
+        let mut lot = lot(&[]);
+        lot.measurements.pop();
+        assert_eq!(lot.decide(), None);
+    }
+
+    #[test]
+    fn missing_ac_re_defaults_to_zero_acceptance() {
+        let json = serde_json::to_value(lot(&[])).unwrap();
+        let mut object = json.as_object().unwrap().clone();
+        object.remove("accept");
+        object.remove("reject");
+        let lot: InspectionLot = serde_json::from_value(serde_json::Value::Object(object)).unwrap();
+        assert_eq!((lot.accept, lot.reject), (0, 1));
+    }
+}
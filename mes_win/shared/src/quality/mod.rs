@@ -0,0 +1,5 @@
+// 质量管理相关数据类型
+pub mod inspection;
+pub mod plan;
+pub mod ncr;
+pub mod spc;
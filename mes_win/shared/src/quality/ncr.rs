@@ -0,0 +1,342 @@
+use serde::{Deserialize, Serialize};
+use crate::error::FieldError;
+
+/// 附件大小上限（字节）
+pub const MAX_ATTACHMENT_BYTES: usize = 10 * 1024 * 1024;
+
+/// 不合格报告（NCR）状态，按处理流程先后排序
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NcrStatus {
+    Open,
+    Investigating,
+    Action,
+    Verified,
+    Closed,
+}
+
+impl NcrStatus {
+    pub const ALL: [NcrStatus; 5] = [
+        NcrStatus::Open,
+        NcrStatus::Investigating,
+        NcrStatus::Action,
+        NcrStatus::Verified,
+        NcrStatus::Closed,
+    ];
+
+    /// 与后端约定的状态编码
+    pub fn code(&self) -> &'static str {
+        match self {
+            NcrStatus::Open => "OPEN",
+            NcrStatus::Investigating => "INVESTIGATING",
+            NcrStatus::Action => "ACTION",
+            NcrStatus::Verified => "VERIFIED",
+            NcrStatus::Closed => "CLOSED",
+        }
+    }
+
+    /// 根据状态编码解析
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|status| status.code() == code)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            NcrStatus::Open => "待处理",
+            NcrStatus::Investigating => "调查中",
+            NcrStatus::Action => "整改中",
+            NcrStatus::Verified => "已验证",
+            NcrStatus::Closed => "已关闭",
+        }
+    }
+
+    /// 流转到该状态的操作名称
+    pub fn action_label(&self) -> &'static str {
+        match self {
+            NcrStatus::Open => "退回待处理",
+            NcrStatus::Investigating => "开始调查",
+            NcrStatus::Action => "实施整改",
+            NcrStatus::Verified => "验证有效",
+            NcrStatus::Closed => "关闭",
+        }
+    }
+
+    /// 样式类名后缀
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            NcrStatus::Open => "rejected",
+            NcrStatus::Investigating => "in-progress",
+            NcrStatus::Action => "released",
+            NcrStatus::Verified => "completed",
+            NcrStatus::Closed => "closed",
+        }
+    }
+}
+
+/// 严重程度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Severity {
+    Minor,
+    #[default]
+    Major,
+    Critical,
+}
+
+impl Severity {
+    pub const ALL: [Severity; 3] = [Severity::Minor, Severity::Major, Severity::Critical];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Severity::Minor => "MINOR",
+            Severity::Major => "MAJOR",
+            Severity::Critical => "CRITICAL",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|severity| severity.code() == code)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Minor => "轻微",
+            Severity::Major => "严重",
+            Severity::Critical => "致命",
+        }
+    }
+}
+
+/// 8D报告
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EightD {
+    /// D1 成立小组
+    pub team: String,
+    /// D2 问题描述
+    pub description: String,
+    /// D3 临时遏制措施
+    pub containment: String,
+    /// D4 根本原因
+    pub root_cause: String,
+    /// D5 纠正措施
+    pub corrective_action: String,
+    /// D6 措施实施与效果验证
+    pub verification: String,
+    /// D7 预防再发生
+    pub prevention: String,
+    /// D8 总结
+    pub closure: String,
+}
+
+impl EightD {
+    /// 各步骤标题，与 `steps()` 顺序一致
+    pub const TITLES: [&'static str; 8] = [
+        "D1 成立小组",
+        "D2 问题描述",
+        "D3 临时遏制措施",
+        "D4 根本原因分析",
+        "D5 纠正措施",
+        "D6 实施与效果验证",
+        "D7 预防再发生",
+        "D8 总结",
+    ];
+
+    /// 按D1~D8顺序返回各步骤内容
+    pub fn steps(&self) -> [&str; 8] {
+        [
+            &self.team,
+            &self.description,
+            &self.containment,
+            &self.root_cause,
+            &self.corrective_action,
+            &self.verification,
+            &self.prevention,
+            &self.closure,
+        ]
+    }
+
+    /// 按步骤序号（0~7）修改内容
+    pub fn set_step(&mut self, index: usize, value: String) {
+        let slot = match index {
+            0 => &mut self.team,
+            1 => &mut self.description,
+            2 => &mut self.containment,
+            3 => &mut self.root_cause,
+            4 => &mut self.corrective_action,
+            5 => &mut self.verification,
+            6 => &mut self.prevention,
+            7 => &mut self.closure,
+            _ => return,
+        };
+        *slot = value;
+    }
+}
+
+/// 附件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attachment {
+    pub id: u64,
+    pub file_name: String,
+    #[serde(default)]
+    pub content_type: String,
+    pub size: u64,
+    #[serde(default)]
+    pub uploaded_by: Option<String>,
+    pub uploaded_at: String,
+}
+
+/// 上传附件（内容为原始字节）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentUpload {
+    pub file_name: String,
+    #[serde(default)]
+    pub content_type: String,
+    pub content: Vec<u8>,
+}
+
+/// 不合格报告
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ncr {
+    pub id: u64,
+    pub ncr_no: String,
+    pub title: String,
+    /// 来源检验批
+    #[serde(default)]
+    pub inspection_id: Option<u64>,
+    #[serde(default)]
+    pub inspection_no: Option<String>,
+    pub item_code: String,
+    #[serde(default)]
+    pub item_name: String,
+    /// 不合格数量
+    pub quantity: f64,
+    pub severity: Severity,
+    /// 责任人
+    #[serde(default)]
+    pub owner: Option<String>,
+    pub status: NcrStatus,
+    #[serde(default)]
+    pub report: EightD,
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+    #[serde(default)]
+    pub created_by: Option<String>,
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// 审计记录
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    /// 操作说明（如 "状态变更"、"上传附件"、"修改8D报告"）
+    pub action: String,
+    #[serde(default)]
+    pub from_status: Option<NcrStatus>,
+    #[serde(default)]
+    pub to_status: Option<NcrStatus>,
+    #[serde(default)]
+    pub comment: Option<String>,
+    pub operator: String,
+    pub occurred_at: String,
+}
+
+/// 不合格报告详情：包含当前可执行的状态流转和审计记录
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NcrDetail {
+    pub ncr: Ncr,
+    pub transitions: Vec<NcrStatus>,
+    pub editable: bool,
+    #[serde(default)]
+    pub history: Vec<AuditEntry>,
+}
+
+/// 不合格报告查询条件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NcrQuery {
+    pub page: u32,
+    pub page_size: u32,
+    /// 按编号/标题/物料模糊查询
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<NcrStatus>,
+}
+
+impl Default for NcrQuery {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            page_size: 20,
+            keyword: None,
+            status: None,
+        }
+    }
+}
+
+/// 新建不合格报告
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NcrDraft {
+    pub title: String,
+    pub item_code: String,
+    pub quantity: f64,
+    pub severity: Severity,
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// 问题描述（作为8D报告的D2）
+    #[serde(default)]
+    pub description: String,
+}
+
+impl NcrDraft {
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        if self.title.trim().is_empty() {
+            errors.push(FieldError {
+                field: "title".to_string(),
+                message: "请输入问题标题".to_string(),
+            });
+        }
+        if self.item_code.trim().is_empty() {
+            errors.push(FieldError {
+                field: "itemCode".to_string(),
+                message: "请输入物料/产品编码".to_string(),
+            });
+        }
+        if !self.quantity.is_finite() || self.quantity <= 0.0 {
+            errors.push(FieldError {
+                field: "quantity".to_string(),
+                message: "不合格数量必须大于0".to_string(),
+            });
+        }
+        errors
+    }
+}
+
+/// 修改不合格报告（责任人、严重程度、8D报告）
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NcrUpdate {
+    #[serde(default)]
+    pub owner: Option<String>,
+    pub severity: Severity,
+    pub report: EightD,
+}
+
+impl From<&Ncr> for NcrUpdate {
+    fn from(ncr: &Ncr) -> Self {
+        Self {
+            owner: ncr.owner.clone(),
+            severity: ncr.severity,
+            report: ncr.report.clone(),
+        }
+    }
+}
@@ -0,0 +1,516 @@
+use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use crate::error::FieldError;
+use super::inspection::{Characteristic, CharacteristicKind};
+
+/// 检验标准状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PlanStatus {
+    /// 草稿，可编辑
+    Draft,
+    /// 已发布，新建检验批使用该版本
+    Published,
+    /// 已被新版本替代
+    Obsolete,
+}
+
+impl PlanStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PlanStatus::Draft => "草稿",
+            PlanStatus::Published => "已发布",
+            PlanStatus::Obsolete => "已失效",
+        }
+    }
+
+    /// 样式类名后缀
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            PlanStatus::Draft => "draft",
+            PlanStatus::Published => "completed",
+            PlanStatus::Obsolete => "closed",
+        }
+    }
+}
+
+/// 一般检验水平（GB/T 2828.1）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InspectionLevel {
+    I,
+    II,
+    III,
+}
+
+impl InspectionLevel {
+    pub const ALL: [InspectionLevel; 3] = [InspectionLevel::I, InspectionLevel::II, InspectionLevel::III];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            InspectionLevel::I => "I",
+            InspectionLevel::II => "II",
+            InspectionLevel::III => "III",
+        }
+    }
+}
+
+/// 批量上限与各检验水平的样本量字码（I、II、III）
+const CODE_LETTERS: [(f64, [char; 3]); 15] = [
+    (8.0, ['A', 'A', 'B']),
+    (15.0, ['A', 'B', 'C']),
+    (25.0, ['B', 'C', 'D']),
+    (50.0, ['C', 'D', 'E']),
+    (90.0, ['C', 'E', 'F']),
+    (150.0, ['D', 'F', 'G']),
+    (280.0, ['E', 'G', 'H']),
+    (500.0, ['F', 'H', 'J']),
+    (1200.0, ['G', 'J', 'K']),
+    (3200.0, ['H', 'K', 'L']),
+    (10000.0, ['J', 'L', 'M']),
+    (35000.0, ['K', 'M', 'N']),
+    (150000.0, ['L', 'N', 'P']),
+    (500000.0, ['M', 'P', 'Q']),
+    (f64::INFINITY, ['N', 'Q', 'R']),
+];
+
+/// 样本量字码对应的样本量
+const SAMPLE_SIZES: [(char, u32); 16] = [
+    ('A', 2), ('B', 3), ('C', 5), ('D', 8), ('E', 13), ('F', 20), ('G', 32), ('H', 50),
+    ('J', 80), ('K', 125), ('L', 200), ('M', 315), ('N', 500), ('P', 800), ('Q', 1250), ('R', 2000),
+];
+
+/// 表2-A 的AQL优选值（AQL ≤ 10 为不合格品百分数，以上为每百单位不合格数）
+pub const AQL_VALUES: [f64; 26] = [
+    0.010, 0.015, 0.025, 0.040, 0.065, 0.10, 0.15, 0.25, 0.40, 0.65, 1.0, 1.5, 2.5, 4.0, 6.5,
+    10.0, 15.0, 25.0, 40.0, 65.0, 100.0, 150.0, 250.0, 400.0, 650.0, 1000.0,
+];
+
+/// 表2-A 中出现的接收数/拒收数组合
+const AC_RE: [(u32, u32); 11] = [
+    (0, 1), (1, 2), (2, 3), (3, 4), (5, 6), (7, 8), (10, 11), (14, 15), (21, 22), (30, 31), (44, 45),
+];
+
+/// 不合格品百分数（AQL ≤ 10）允许的最大接收数，更大的方案在表中为向上箭头
+const MAX_PERCENT_ACCEPT: u32 = 21;
+
+/// 表2-A 的一个单元格
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TableCell {
+    Plan(u32, u32),
+    /// 使用箭头上方第一个抽样方案
+    Up,
+    /// 使用箭头下方第一个抽样方案
+    Down,
+}
+
+/// GB/T 2828.1 表2-A（正常检验一次抽样方案）
+///
+/// 表中方案沿对角线排列：字码每增大一级或AQL每提高一档，方案向后移动一格，
+/// 依次为 ↓…、0/1、↑、↓、1/2、2/3、3/4、5/6、7/8、10/11、14/15、21/22、30/31、44/45、↑…
+fn table_cell(row: usize, column: usize) -> TableCell {
+    let step = row + column;
+    let cell = match step {
+        0..=13 => TableCell::Down,
+        14 => TableCell::Plan(0, 1),
+        15 => TableCell::Up,
+        16 => TableCell::Down,
+        _ => AC_RE
+            .get(step - 16)
+            .map(|(ac, re)| TableCell::Plan(*ac, *re))
+            .unwrap_or(TableCell::Up),
+    };
+    match cell {
+        TableCell::Plan(ac, _) if AQL_VALUES[column] <= 10.0 && ac > MAX_PERCENT_ACCEPT => TableCell::Up,
+        cell => cell,
+    }
+}
+
+/// AQL对应的表2-A列号，非优选值返回 `None`
+fn aql_column(aql: f64) -> Option<usize> {
+    AQL_VALUES.iter().position(|value| (value - aql).abs() <= value * 1e-6)
+}
+
+/// 按AQL确定的一次抽样方案
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptancePlan {
+    /// 按箭头调整后的样本量字码
+    pub code_letter: char,
+    pub sample_size: u32,
+    /// 接收数 Ac：不合格数不超过该值时接收
+    pub accept: u32,
+    /// 拒收数 Re：不合格数达到该值时拒收
+    pub reject: u32,
+}
+
+/// 抽样规则
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SamplingRule {
+    /// 固定样本量
+    #[serde(rename_all = "camelCase")]
+    Fixed { sample_size: u32 },
+    /// 按比例抽样，不少于最小样本量
+    #[serde(rename_all = "camelCase")]
+    Percentage { percent: f64, minimum: u32 },
+    /// 按AQL抽样：批量和检验水平确定样本量字码
+    #[serde(rename_all = "camelCase")]
+    Aql { level: InspectionLevel, aql: f64 },
+}
+
+impl Default for SamplingRule {
+    fn default() -> Self {
+        SamplingRule::Aql {
+            level: InspectionLevel::II,
+            aql: 1.0,
+        }
+    }
+}
+
+impl SamplingRule {
+    /// 按AQL抽样时的样本量字码
+    pub fn code_letter(&self, lot_quantity: f64) -> Option<char> {
+        let SamplingRule::Aql { level, .. } = self else {
+            return None;
+        };
+        let column = match level {
+            InspectionLevel::I => 0,
+            InspectionLevel::II => 1,
+            InspectionLevel::III => 2,
+        };
+        CODE_LETTERS
+            .iter()
+            .find(|(max, _)| lot_quantity <= *max)
+            .map(|(_, letters)| letters[column])
+    }
+
+    /// 按AQL抽样时的一次抽样方案（正常检验）
+    ///
+    /// 从批量对应的字码出发，遇到箭头时沿箭头方向换用相邻字码的方案；
+    /// AQL不是优选值时返回 `None`
+    pub fn acceptance(&self, lot_quantity: f64) -> Option<AcceptancePlan> {
+        let SamplingRule::Aql { aql, .. } = self else {
+            return None;
+        };
+        let column = aql_column(*aql)?;
+        let letter = self.code_letter(lot_quantity)?;
+        let mut row = SAMPLE_SIZES.iter().position(|(code, _)| *code == letter)?;
+        let last = SAMPLE_SIZES.len() - 1;
+
+        // 对角线方案每行不同，最多移动一整列即可找到方案
+        for _ in 0..SAMPLE_SIZES.len() {
+            match table_cell(row, column) {
+                TableCell::Plan(accept, reject) => {
+                    let (code_letter, sample_size) = SAMPLE_SIZES[row];
+                    return Some(AcceptancePlan { code_letter, sample_size, accept, reject });
+                }
+                TableCell::Up if row > 0 => row -= 1,
+                TableCell::Down if row < last => row += 1,
+                // 表的首行、末行没有可指向的方案，改取另一方向
+                TableCell::Up => row += 1,
+                TableCell::Down => row -= 1,
+            }
+        }
+        None
+    }
+
+    /// 接收数和拒收数：按AQL查表，固定/比例抽样及查不到方案时为零缺陷接收（Ac 0 / Re 1）
+    pub fn accept_reject(&self, lot_quantity: f64) -> (u32, u32) {
+        self.acceptance(lot_quantity)
+            .map(|plan| (plan.accept, plan.reject))
+            .unwrap_or((0, 1))
+    }
+
+    /// 计算样本量（不超过批量，至少为1）
+    pub fn sample_size(&self, lot_quantity: f64) -> u32 {
+        let size = match self {
+            SamplingRule::Fixed { sample_size } => *sample_size,
+            SamplingRule::Percentage { percent, minimum } => {
+                ((lot_quantity * percent / 100.0).ceil() as u32).max(*minimum)
+            }
+            SamplingRule::Aql { .. } => self
+                .acceptance(lot_quantity)
+                .map(|plan| plan.sample_size)
+                .unwrap_or(1),
+        };
+        let lot_size = lot_quantity.ceil().max(1.0) as u32;
+        size.clamp(1, lot_size)
+    }
+
+    /// 规则说明
+    pub fn describe(&self) -> String {
+        match self {
+            SamplingRule::Fixed { sample_size } => format!("固定抽样 {} 件", sample_size),
+            SamplingRule::Percentage { percent, minimum } => format!("按 {}% 抽样，至少 {} 件", percent, minimum),
+            SamplingRule::Aql { level, aql } => format!("AQL {}，一般检验水平 {}", aql, level.label()),
+        }
+    }
+
+    fn validate(&self, errors: &mut Vec<FieldError>) {
+        let invalid = match self {
+            SamplingRule::Fixed { sample_size } => (*sample_size == 0).then_some("固定样本量必须大于0"),
+            SamplingRule::Percentage { percent, .. } => {
+                (!percent.is_finite() || *percent <= 0.0 || *percent > 100.0).then_some("抽样比例须在 0~100% 之间")
+            }
+            SamplingRule::Aql { aql, .. } => aql_column(*aql).is_none().then_some("AQL须为标准优选值（如 0.65、1.0、1.5、2.5）"),
+        };
+        if let Some(message) = invalid {
+            errors.push(FieldError {
+                field: "sampling".to_string(),
+                message: message.to_string(),
+            });
+        }
+    }
+}
+
+/// 检验标准（检验计划）
+///
+/// 按物料/产品定义；指定工序号时为该产品工艺路线上对应工序的工序检验，否则为来料检验
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InspectionPlan {
+    /// 新建时为空，保存后由后端分配
+    #[serde(default)]
+    pub id: Option<u64>,
+    /// 物料编码或产品SKU
+    pub item_code: String,
+    #[serde(default)]
+    pub item_name: String,
+    /// 工艺路线工序号
+    #[serde(default)]
+    pub operation_seq: Option<u32>,
+    pub version: u32,
+    pub status: PlanStatus,
+    pub sampling: SamplingRule,
+    pub characteristics: Vec<Characteristic>,
+    #[serde(default)]
+    pub remark: Option<String>,
+    #[serde(default)]
+    pub published_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+impl Default for InspectionPlan {
+    fn default() -> Self {
+        Self {
+            id: None,
+            item_code: String::new(),
+            item_name: String::new(),
+            operation_seq: None,
+            version: 1,
+            status: PlanStatus::Draft,
+            sampling: SamplingRule::default(),
+            characteristics: Vec::new(),
+            remark: None,
+            published_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+/// 检验标准列表项
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InspectionPlanSummary {
+    pub id: u64,
+    pub item_code: String,
+    #[serde(default)]
+    pub item_name: String,
+    #[serde(default)]
+    pub operation_seq: Option<u32>,
+    pub version: u32,
+    pub status: PlanStatus,
+    #[serde(default)]
+    pub characteristic_count: u32,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+impl InspectionPlan {
+    /// 校验检验标准
+    ///
+    /// 特性相关错误的字段名形如 `characteristics[0].upperLimit`
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        let mut push = |field: String, message: String| errors.push(FieldError { field, message });
+
+        if self.item_code.trim().is_empty() {
+            push("itemCode".to_string(), "请输入物料/产品编码".to_string());
+        }
+        if self.operation_seq == Some(0) {
+            push("operationSeq".to_string(), "工序号必须大于0".to_string());
+        }
+        if self.characteristics.is_empty() {
+            push("characteristics".to_string(), "至少需要一个检验特性".to_string());
+        }
+
+        let mut codes = HashSet::new();
+        for (i, characteristic) in self.characteristics.iter().enumerate() {
+            let field = |name: &str| format!("characteristics[{}].{}", i, name);
+            let code = characteristic.code.trim();
+            if code.is_empty() {
+                push(field("code"), format!("第{}个特性缺少编码", i + 1));
+            } else if !codes.insert(code) {
+                push(field("code"), format!("特性编码 {} 重复", code));
+            }
+            if characteristic.name.trim().is_empty() {
+                push(field("name"), format!("特性 {} 缺少名称", code));
+            }
+
+            match &characteristic.kind {
+                CharacteristicKind::Numeric { nominal, lower_limit, upper_limit, .. } => {
+                    if lower_limit.is_none() && upper_limit.is_none() {
+                        push(field("lowerLimit"), format!("特性 {} 至少需要上限或下限", code));
+                    }
+                    if lower_limit.is_some_and(|lower| lower > *nominal) {
+                        push(field("lowerLimit"), format!("特性 {} 下限不能大于标准值", code));
+                    }
+                    if upper_limit.is_some_and(|upper| upper < *nominal) {
+                        push(field("upperLimit"), format!("特性 {} 上限不能小于标准值", code));
+                    }
+                }
+                CharacteristicKind::PassFail => {}
+                CharacteristicKind::Attribute { options, accepted } => {
+                    if options.is_empty() {
+                        push(field("options"), format!("特性 {} 缺少可选项", code));
+                    } else if accepted.is_empty() || accepted.iter().any(|a| !options.contains(a)) {
+                        push(field("accepted"), format!("特性 {} 的可接收项须从可选项中选择", code));
+                    }
+                }
+            }
+        }
+
+        self.sampling.validate(&mut errors);
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aql(level: InspectionLevel, aql: f64) -> SamplingRule {
+        SamplingRule::Aql { level, aql }
+    }
+
+    fn plan_for(lot_quantity: f64, rule: &SamplingRule) -> (char, u32, u32, u32) {
+        let plan = rule.acceptance(lot_quantity).unwrap();
+        (plan.code_letter, plan.sample_size, plan.accept, plan.reject)
+    }
+
+    #[test]
+    fn code_letters_follow_table_1() {
+        let cases = [
+            (2.0, ['A', 'A', 'B']),
+            (8.0, ['A', 'A', 'B']),
+            (9.0, ['A', 'B', 'C']),
+            (150.0, ['D', 'F', 'G']),
+            (151.0, ['E', 'G', 'H']),
+            (1200.0, ['G', 'J', 'K']),
+            (1201.0, ['H', 'K', 'L']),
+            (10000.0, ['J', 'L', 'M']),
+            (600000.0, ['N', 'Q', 'R']),
+        ];
+        for (lot, letters) in cases {
+            for (level, letter) in InspectionLevel::ALL.into_iter().zip(letters) {
+                assert_eq!(aql(level, 1.0).code_letter(lot), Some(letter), "批量 {} 水平 {}", lot, level.label());
+            }
+        }
+        assert_eq!(SamplingRule::Fixed { sample_size: 5 }.code_letter(100.0), None);
+    }
+
+    #[test]
+    fn lot_1200_level_ii_uses_letter_j() {
+        let rule = aql(InspectionLevel::II, 1.0);
+        assert_eq!(rule.code_letter(1200.0), Some('J'));
+        assert_eq!(rule.sample_size(1200.0), 80);
+        assert_eq!(plan_for(1200.0, &rule), ('J', 80, 2, 3));
+    }
+
+    #[test]
+    fn aql_1_0_column() {
+        // 起始字码 → (调整后字码, 样本量, Ac, Re)
+        let expected = [
+            ('A', ('E', 13, 0, 1)),
+            ('D', ('E', 13, 0, 1)),
+            ('E', ('E', 13, 0, 1)),
+            ('F', ('E', 13, 0, 1)),
+            ('G', ('H', 50, 1, 2)),
+            ('H', ('H', 50, 1, 2)),
+            ('J', ('J', 80, 2, 3)),
+            ('K', ('K', 125, 3, 4)),
+            ('L', ('L', 200, 5, 6)),
+            ('M', ('M', 315, 7, 8)),
+            ('N', ('N', 500, 10, 11)),
+            ('P', ('P', 800, 14, 15)),
+            ('Q', ('Q', 1250, 21, 22)),
+            ('R', ('Q', 1250, 21, 22)),
+        ];
+        let column = aql_column(1.0).unwrap();
+        for (letter, (code_letter, sample_size, accept, reject)) in expected {
+            let mut row = SAMPLE_SIZES.iter().position(|(code, _)| *code == letter).unwrap();
+            let plan = loop {
+                match table_cell(row, column) {
+                    TableCell::Plan(ac, re) => break (SAMPLE_SIZES[row].0, SAMPLE_SIZES[row].1, ac, re),
+                    TableCell::Up => row -= 1,
+                    TableCell::Down => row += 1,
+                }
+            };
+            assert_eq!(plan, (code_letter, sample_size, accept, reject), "字码 {}", letter);
+        }
+    }
+
+    #[test]
+    fn acceptance_follows_arrows_through_lots() {
+        // 批量 50000、水平 II → N；AQL 0.10 → 1/2，AQL 0.065 → 向下到 P 的 1/2
+        assert_eq!(plan_for(50000.0, &aql(InspectionLevel::II, 1.0)), ('N', 500, 10, 11));
+        assert_eq!(plan_for(50000.0, &aql(InspectionLevel::II, 0.10)), ('N', 500, 1, 2));
+        assert_eq!(plan_for(50000.0, &aql(InspectionLevel::II, 0.065)), ('P', 800, 1, 2));
+        // 批量 500、水平 II → H；AQL 0.40 → 向上到 G 的 0/1，AQL 0.65 → 向下到 J 的 1/2
+        assert_eq!(plan_for(500.0, &aql(InspectionLevel::II, 2.5)), ('H', 50, 3, 4));
+        assert_eq!(plan_for(500.0, &aql(InspectionLevel::II, 0.40)), ('G', 32, 0, 1));
+        assert_eq!(plan_for(500.0, &aql(InspectionLevel::II, 0.65)), ('J', 80, 1, 2));
+        // 每百单位不合格数：A 列 1000 为 30/31，B 列 1000 为 44/45
+        assert_eq!(plan_for(2.0, &aql(InspectionLevel::II, 1000.0)), ('A', 2, 30, 31));
+        assert_eq!(plan_for(3.0, &aql(InspectionLevel::III, 1000.0)), ('B', 3, 44, 45));
+    }
+
+    #[test]
+    fn sample_size_is_capped_by_lot() {
+        // 批量 10、水平 II → B，AQL 1.0 向下到 E（13件），超过批量时全检
+        let rule = aql(InspectionLevel::II, 1.0);
+        assert_eq!(rule.acceptance(10.0).unwrap().sample_size, 13);
+        assert_eq!(rule.sample_size(10.0), 10);
+    }
+
+    #[test]
+    fn non_preferred_aql_is_rejected() {
+        assert!(aql(InspectionLevel::II, 1.2).acceptance(1200.0).is_none());
+
+        let mut errors = Vec::new();
+        aql(InspectionLevel::II, 1.2).validate(&mut errors);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "sampling");
+
+        let mut errors = Vec::new();
+        aql(InspectionLevel::II, 0.65).validate(&mut errors);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn fixed_and_percentage_sample_sizes() {
+        assert_eq!(SamplingRule::Fixed { sample_size: 20 }.sample_size(10.0), 10);
+        assert_eq!(SamplingRule::Percentage { percent: 10.0, minimum: 5 }.sample_size(1000.0), 100);
+        assert_eq!(SamplingRule::Percentage { percent: 10.0, minimum: 5 }.sample_size(20.0), 5);
+        assert!(SamplingRule::Fixed { sample_size: 20 }.acceptance(100.0).is_none());
+    }
+
+    #[test]
+    fn accept_reject_defaults_to_zero_defects() {
+        assert_eq!(aql(InspectionLevel::II, 1.0).accept_reject(1200.0), (2, 3));
+        assert_eq!(SamplingRule::Fixed { sample_size: 5 }.accept_reject(100.0), (0, 1));
+        assert_eq!(SamplingRule::Percentage { percent: 10.0, minimum: 5 }.accept_reject(100.0), (0, 1));
+    }
+}
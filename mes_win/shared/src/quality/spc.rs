@@ -0,0 +1,924 @@
+use serde::{Deserialize, Serialize};
+use super::inspection::{Characteristic, CharacteristicKind};
+
+/// X̄-R 图支持的最大子组容量
+pub const MAX_SUBGROUP_SIZE: usize = 25;
+
+/// 判异所需的最少点数
+pub const MIN_POINTS: usize = 2;
+
+/// 极差系数 d2、d3（子组容量 2~25）
+const D2_D3: [(f64, f64); 24] = [
+    (1.128, 0.853),
+    (1.693, 0.888),
+    (2.059, 0.880),
+    (2.326, 0.864),
+    (2.534, 0.848),
+    (2.704, 0.833),
+    (2.847, 0.820),
+    (2.970, 0.808),
+    (3.078, 0.797),
+    (3.173, 0.787),
+    (3.258, 0.778),
+    (3.336, 0.770),
+    (3.407, 0.763),
+    (3.472, 0.756),
+    (3.532, 0.750),
+    (3.588, 0.744),
+    (3.640, 0.739),
+    (3.689, 0.734),
+    (3.735, 0.729),
+    (3.778, 0.724),
+    (3.819, 0.720),
+    (3.858, 0.716),
+    (3.895, 0.712),
+    (3.931, 0.708),
+];
+
+fn d2_d3(n: usize) -> Option<(f64, f64)> {
+    n.checked_sub(2).and_then(|index| D2_D3.get(index)).copied()
+}
+
+/// 标准差无偏系数 c4 = sqrt(2/(n-1))·Γ(n/2)/Γ((n-1)/2)
+///
+/// Γ比值按 r(n+1) = (n-1)/2 / r(n) 递推，r(2) = 1/sqrt(π)
+fn c4(n: usize) -> f64 {
+    if n < 2 {
+        return 1.0;
+    }
+    let mut ratio = 1.0 / std::f64::consts::PI.sqrt();
+    for k in 2..n {
+        ratio = (k as f64 - 1.0) / 2.0 / ratio;
+    }
+    (2.0 / (n as f64 - 1.0)).sqrt() * ratio
+}
+
+/// 控制图类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ChartType {
+    /// 均值-极差图
+    XbarR,
+    /// 均值-标准差图
+    XbarS,
+    /// 单值-移动极差图
+    Individuals,
+    /// 不合格品率图
+    P,
+    /// 不合格品数图
+    Np,
+}
+
+impl ChartType {
+    pub const ALL: [ChartType; 5] = [
+        ChartType::XbarR,
+        ChartType::XbarS,
+        ChartType::Individuals,
+        ChartType::P,
+        ChartType::Np,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            ChartType::XbarR => "XBAR_R",
+            ChartType::XbarS => "XBAR_S",
+            ChartType::Individuals => "INDIVIDUALS",
+            ChartType::P => "P",
+            ChartType::Np => "NP",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|chart| chart.code() == code)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChartType::XbarR => "X̄-R 均值极差图",
+            ChartType::XbarS => "X̄-S 均值标准差图",
+            ChartType::Individuals => "I-MR 单值移动极差图",
+            ChartType::P => "p 不合格品率图",
+            ChartType::Np => "np 不合格品数图",
+        }
+    }
+
+    /// 计量型控制图（需要测量值）
+    pub fn is_variable(&self) -> bool {
+        matches!(self, ChartType::XbarR | ChartType::XbarS | ChartType::Individuals)
+    }
+
+    /// 使用子组容量参数
+    pub fn uses_subgroups(&self) -> bool {
+        matches!(self, ChartType::XbarR | ChartType::XbarS)
+    }
+}
+
+/// 判异规则集
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RuleSet {
+    /// 西电规则（4条）
+    WesternElectric,
+    /// Nelson规则（8条）
+    Nelson,
+}
+
+impl RuleSet {
+    pub const ALL: [RuleSet; 2] = [RuleSet::WesternElectric, RuleSet::Nelson];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            RuleSet::WesternElectric => "WESTERN_ELECTRIC",
+            RuleSet::Nelson => "NELSON",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|rules| rules.code() == code)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RuleSet::WesternElectric => "西电规则",
+            RuleSet::Nelson => "Nelson规则",
+        }
+    }
+
+    /// 规则集包含的规则
+    pub fn rules(&self) -> &'static [Rule] {
+        match self {
+            RuleSet::WesternElectric => &[
+                Rule::BeyondLimits,
+                Rule::TwoOfThree,
+                Rule::FourOfFive,
+                Rule::SameSide(8),
+            ],
+            RuleSet::Nelson => &[
+                Rule::BeyondLimits,
+                Rule::SameSide(9),
+                Rule::Trend,
+                Rule::Alternating,
+                Rule::TwoOfThree,
+                Rule::FourOfFive,
+                Rule::Stratification,
+                Rule::Mixture,
+            ],
+        }
+    }
+}
+
+/// 判异规则
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "run", rename_all = "camelCase")]
+pub enum Rule {
+    /// 1点落在3σ控制限外
+    BeyondLimits,
+    /// 连续n点落在中心线同一侧
+    SameSide(usize),
+    /// 连续6点递增或递减
+    Trend,
+    /// 连续14点上下交替
+    Alternating,
+    /// 连续3点中有2点落在同侧2σ外
+    TwoOfThree,
+    /// 连续5点中有4点落在同侧1σ外
+    FourOfFive,
+    /// 连续15点落在1σ内
+    Stratification,
+    /// 连续8点落在1σ外，且两侧都有
+    Mixture,
+}
+
+impl Rule {
+    pub fn description(&self) -> String {
+        match self {
+            Rule::BeyondLimits => "1点落在控制限外".to_string(),
+            Rule::SameSide(run) => format!("连续{}点落在中心线同一侧", run),
+            Rule::Trend => "连续6点递增或递减".to_string(),
+            Rule::Alternating => "连续14点上下交替".to_string(),
+            Rule::TwoOfThree => "连续3点中有2点落在同侧2σ外".to_string(),
+            Rule::FourOfFive => "连续5点中有4点落在同侧1σ外".to_string(),
+            Rule::Stratification => "连续15点落在中心线两侧1σ内".to_string(),
+            Rule::Mixture => "连续8点落在中心线两侧1σ外".to_string(),
+        }
+    }
+}
+
+/// 控制限
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Limits {
+    pub center: f64,
+    pub upper: f64,
+    pub lower: f64,
+}
+
+impl Limits {
+    /// 上侧1σ宽度，下限被截断为0时仍按上侧计算
+    fn sigma(&self) -> f64 {
+        (self.upper - self.center) / 3.0
+    }
+}
+
+/// 控制图中的一条数据序列及其逐点控制限（p图控制限随样本量变化）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChartSeries {
+    pub title: String,
+    pub points: Vec<f64>,
+    pub limits: Vec<Limits>,
+}
+
+impl ChartSeries {
+    fn new(title: &str, points: Vec<f64>, limits: Vec<Limits>) -> Self {
+        Self {
+            title: title.to_string(),
+            points,
+            limits,
+        }
+    }
+
+    fn constant(title: &str, points: Vec<f64>, limits: Limits) -> Self {
+        let limits = vec![limits; points.len()];
+        Self::new(title, points, limits)
+    }
+
+    pub fn center(&self) -> f64 {
+        self.limits.first().map(|limits| limits.center).unwrap_or(0.0)
+    }
+}
+
+/// 判异结果
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Violation {
+    /// 是否为下方的辅助图（R、S、MR）
+    pub secondary: bool,
+    /// 触发规则的点序号
+    pub index: usize,
+    pub rule: Rule,
+}
+
+/// 过程能力
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Capability {
+    pub mean: f64,
+    /// 组内标准差估计
+    pub sigma: f64,
+    /// 需同时有上下规格限
+    pub cp: Option<f64>,
+    pub cpk: Option<f64>,
+}
+
+impl Capability {
+    /// 按Cpk评价过程能力
+    pub fn rating(&self) -> &'static str {
+        match self.cpk {
+            Some(cpk) if cpk >= 1.67 => "充分",
+            Some(cpk) if cpk >= 1.33 => "满足",
+            Some(cpk) if cpk >= 1.0 => "不足",
+            Some(_) => "严重不足",
+            None => "-",
+        }
+    }
+}
+
+/// 控制图计算结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ControlChart {
+    pub chart_type: ChartType,
+    /// 每个点对应的样本标签
+    pub labels: Vec<String>,
+    pub primary: ChartSeries,
+    pub secondary: Option<ChartSeries>,
+    pub violations: Vec<Violation>,
+    pub capability: Option<Capability>,
+}
+
+/// 一个检验批的样本数据
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpcSample {
+    pub inspection_no: String,
+    pub inspected_at: String,
+    /// 计量型特性的测量值（按样本序号）
+    #[serde(default)]
+    pub values: Vec<f64>,
+    /// 检验样本数
+    pub inspected: u32,
+    /// 不合格样本数
+    pub defective: u32,
+}
+
+/// SPC数据查询条件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpcQuery {
+    pub item_code: String,
+    /// 为空时取第一个计量型特性
+    #[serde(default)]
+    pub characteristic: Option<String>,
+    /// 最多取最近的检验批数
+    pub limit: u32,
+}
+
+impl Default for SpcQuery {
+    fn default() -> Self {
+        Self {
+            item_code: String::new(),
+            characteristic: None,
+            limit: 50,
+        }
+    }
+}
+
+/// SPC数据集：按检验时间先后排列
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpcDataset {
+    pub item_code: String,
+    #[serde(default)]
+    pub item_name: String,
+    /// 该物料检验过的全部特性
+    pub characteristics: Vec<Characteristic>,
+    /// 当前分析的特性
+    #[serde(default)]
+    pub characteristic: Option<Characteristic>,
+    pub samples: Vec<SpcSample>,
+}
+
+impl SpcDataset {
+    /// 当前特性的规格限（下限, 上限）
+    pub fn spec_limits(&self) -> (Option<f64>, Option<f64>) {
+        match self.characteristic.as_ref().map(|c| &c.kind) {
+            Some(CharacteristicKind::Numeric { lower_limit, upper_limit, .. }) => (*lower_limit, *upper_limit),
+            _ => (None, None),
+        }
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+}
+
+fn range(values: &[f64]) -> f64 {
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    max - min
+}
+
+fn std_dev(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let avg = mean(values);
+    let sum: f64 = values.iter().map(|v| (v - avg).powi(2)).sum();
+    (sum / (values.len() as f64 - 1.0)).sqrt()
+}
+
+/// 计算控制图
+///
+/// 均值图每个检验批取前 `subgroup_size` 个测量值组成子组，测量值不足的批次跳过；
+/// 单值图按时间顺序展开全部测量值；p/np图按批次的不合格样本数计算，
+/// np图按平均样本量计算控制限。
+pub fn build_chart(
+    chart_type: ChartType,
+    samples: &[SpcSample],
+    subgroup_size: usize,
+    spec: (Option<f64>, Option<f64>),
+    rules: RuleSet,
+) -> Result<ControlChart, String> {
+    let (labels, primary, secondary, sigma) = match chart_type {
+        ChartType::XbarR | ChartType::XbarS => subgroup_chart(chart_type, samples, subgroup_size)?,
+        ChartType::Individuals => individuals_chart(samples)?,
+        ChartType::P | ChartType::Np => attribute_chart(chart_type, samples)?,
+    };
+
+    let mut violations: Vec<Violation> = detect(&primary, rules)
+        .into_iter()
+        .map(|(index, rule)| Violation { secondary: false, index, rule })
+        .collect();
+    // 离散度图只判控制限外的点
+    if let Some(series) = &secondary {
+        violations.extend(
+            detect(series, RuleSet::WesternElectric)
+                .into_iter()
+                .filter(|(_, rule)| *rule == Rule::BeyondLimits)
+                .map(|(index, rule)| Violation { secondary: true, index, rule }),
+        );
+    }
+
+    let capability = sigma.map(|sigma| capability(primary.center(), sigma, spec));
+
+    Ok(ControlChart {
+        chart_type,
+        labels,
+        primary,
+        secondary,
+        violations,
+        capability,
+    })
+}
+
+type ChartParts = (Vec<String>, ChartSeries, Option<ChartSeries>, Option<f64>);
+
+fn subgroup_chart(chart_type: ChartType, samples: &[SpcSample], n: usize) -> Result<ChartParts, String> {
+    if !(2..=MAX_SUBGROUP_SIZE).contains(&n) {
+        return Err(format!("子组容量须在 2 到 {} 之间", MAX_SUBGROUP_SIZE));
+    }
+    let subgroups: Vec<(&SpcSample, &[f64])> = samples
+        .iter()
+        .filter(|sample| sample.values.len() >= n)
+        .map(|sample| (sample, &sample.values[..n]))
+        .collect();
+    if subgroups.len() < MIN_POINTS {
+        return Err(format!("测量值不少于 {} 个的检验批不足 {} 批，无法绘制均值图", n, MIN_POINTS));
+    }
+
+    let labels = subgroups.iter().map(|(sample, _)| sample.inspection_no.clone()).collect();
+    let means: Vec<f64> = subgroups.iter().map(|(_, values)| mean(values)).collect();
+    let grand_mean = mean(&means);
+    let (d2, d3) = d2_d3(n).unwrap_or((1.0, 0.0));
+
+    let (dispersion, title, sigma, spread_limits): (Vec<f64>, &str, f64, Limits) = if chart_type == ChartType::XbarR {
+        let ranges: Vec<f64> = subgroups.iter().map(|(_, values)| range(values)).collect();
+        let r_bar = mean(&ranges);
+        let limits = Limits {
+            center: r_bar,
+            upper: r_bar * (1.0 + 3.0 * d3 / d2),
+            lower: (r_bar * (1.0 - 3.0 * d3 / d2)).max(0.0),
+        };
+        (ranges, "R", r_bar / d2, limits)
+    } else {
+        let deviations: Vec<f64> = subgroups.iter().map(|(_, values)| std_dev(values)).collect();
+        let s_bar = mean(&deviations);
+        let c4 = c4(n);
+        let width = 3.0 * (1.0 - c4 * c4).sqrt() / c4;
+        let limits = Limits {
+            center: s_bar,
+            upper: s_bar * (1.0 + width),
+            lower: (s_bar * (1.0 - width)).max(0.0),
+        };
+        (deviations, "S", s_bar / c4, limits)
+    };
+
+    let half_width = 3.0 * sigma / (n as f64).sqrt();
+    let mean_limits = Limits {
+        center: grand_mean,
+        upper: grand_mean + half_width,
+        lower: grand_mean - half_width,
+    };
+
+    Ok((
+        labels,
+        ChartSeries::constant("X̄", means, mean_limits),
+        Some(ChartSeries::constant(title, dispersion, spread_limits)),
+        Some(sigma),
+    ))
+}
+
+fn individuals_chart(samples: &[SpcSample]) -> Result<ChartParts, String> {
+    let mut labels = Vec::new();
+    let mut values = Vec::new();
+    for sample in samples {
+        for (index, value) in sample.values.iter().enumerate() {
+            labels.push(format!("{}#{}", sample.inspection_no, index + 1));
+            values.push(*value);
+        }
+    }
+    if values.len() < MIN_POINTS {
+        return Err(format!("测量值不足 {} 个，无法绘制单值图", MIN_POINTS));
+    }
+
+    // 移动极差序列首点无值，按0占位以与单值图对齐
+    let moving_ranges: Vec<f64> = std::iter::once(0.0)
+        .chain(values.windows(2).map(|pair| (pair[1] - pair[0]).abs()))
+        .collect();
+    let mr_bar = mean(&moving_ranges[1..]);
+    let (d2, d3) = d2_d3(2).unwrap_or((1.128, 0.853));
+    let sigma = mr_bar / d2;
+    let center = mean(&values);
+
+    let value_limits = Limits {
+        center,
+        upper: center + 3.0 * sigma,
+        lower: center - 3.0 * sigma,
+    };
+    let range_limits = Limits {
+        center: mr_bar,
+        upper: mr_bar * (1.0 + 3.0 * d3 / d2),
+        lower: 0.0,
+    };
+
+    Ok((
+        labels,
+        ChartSeries::constant("X", values, value_limits),
+        Some(ChartSeries::constant("MR", moving_ranges, range_limits)),
+        Some(sigma),
+    ))
+}
+
+fn attribute_chart(chart_type: ChartType, samples: &[SpcSample]) -> Result<ChartParts, String> {
+    let samples: Vec<&SpcSample> = samples.iter().filter(|sample| sample.inspected > 0).collect();
+    if samples.len() < MIN_POINTS {
+        return Err(format!("有效检验批不足 {} 批，无法绘制计数型控制图", MIN_POINTS));
+    }
+
+    let labels = samples.iter().map(|sample| sample.inspection_no.clone()).collect();
+    let inspected: f64 = samples.iter().map(|sample| sample.inspected as f64).sum();
+    let defective: f64 = samples.iter().map(|sample| sample.defective as f64).sum();
+    let p_bar = defective / inspected;
+    let limits_for = |n: f64, scale: f64| {
+        let width = 3.0 * (p_bar * (1.0 - p_bar) / n).sqrt();
+        Limits {
+            center: p_bar * scale,
+            upper: ((p_bar + width).min(1.0)) * scale,
+            lower: (p_bar - width).max(0.0) * scale,
+        }
+    };
+
+    let series = if chart_type == ChartType::P {
+        let points = samples
+            .iter()
+            .map(|sample| sample.defective as f64 / sample.inspected as f64)
+            .collect();
+        let limits = samples.iter().map(|sample| limits_for(sample.inspected as f64, 1.0)).collect();
+        ChartSeries::new("p", points, limits)
+    } else {
+        let n_bar = inspected / samples.len() as f64;
+        let points = samples.iter().map(|sample| sample.defective as f64).collect();
+        ChartSeries::constant("np", points, limits_for(n_bar, n_bar))
+    };
+
+    Ok((labels, series, None, None))
+}
+
+/// 过程能力：Cp = (USL-LSL)/6σ，Cpk = min(USL-μ, μ-LSL)/3σ
+fn capability(mean: f64, sigma: f64, (lower, upper): (Option<f64>, Option<f64>)) -> Capability {
+    let valid = sigma > 0.0 && sigma.is_finite();
+    let cp = match (lower, upper) {
+        (Some(lower), Some(upper)) if valid => Some((upper - lower) / (6.0 * sigma)),
+        _ => None,
+    };
+    let cpk = if valid {
+        [
+            upper.map(|upper| (upper - mean) / (3.0 * sigma)),
+            lower.map(|lower| (mean - lower) / (3.0 * sigma)),
+        ]
+        .into_iter()
+        .flatten()
+        .reduce(f64::min)
+    } else {
+        None
+    };
+    Capability { mean, sigma, cp, cpk }
+}
+
+/// 按规则集判异，返回（点序号, 规则），同一点可触发多条规则
+pub fn detect(series: &ChartSeries, rules: RuleSet) -> Vec<(usize, Rule)> {
+    // 各点到中心线的距离，以σ为单位
+    let zones: Vec<Option<f64>> = series
+        .points
+        .iter()
+        .zip(&series.limits)
+        .map(|(point, limits)| {
+            let sigma = limits.sigma();
+            (sigma > 0.0).then(|| (point - limits.center) / sigma)
+        })
+        .collect();
+
+    let mut found = Vec::new();
+    for rule in rules.rules() {
+        for index in 0..series.points.len() {
+            if matches_rule(*rule, index, &series.points, &series.limits, &zones) {
+                found.push((index, *rule));
+            }
+        }
+    }
+    found.sort_by_key(|(index, _)| *index);
+    found
+}
+
+/// 以 `index` 为结尾的连续点是否满足规则
+fn matches_rule(rule: Rule, index: usize, points: &[f64], limits: &[Limits], zones: &[Option<f64>]) -> bool {
+    let window = |len: usize| -> Option<Vec<f64>> {
+        if index + 1 < len {
+            return None;
+        }
+        zones[index + 1 - len..=index].iter().copied().collect()
+    };
+    let same_side_beyond = |len: usize, count: usize, sigmas: f64| {
+        let Some(current) = zones[index] else {
+            return false;
+        };
+        if current.abs() <= sigmas {
+            return false;
+        }
+        window(len).is_some_and(|zones| {
+            zones.iter().filter(|z| z.abs() > sigmas && z.signum() == current.signum()).count() >= count
+        })
+    };
+
+    match rule {
+        Rule::BeyondLimits => points[index] > limits[index].upper || points[index] < limits[index].lower,
+        Rule::SameSide(run) => window(run).is_some_and(|zones| {
+            zones.iter().all(|z| *z > 0.0) || zones.iter().all(|z| *z < 0.0)
+        }),
+        Rule::Trend => {
+            index >= 5 && {
+                let run = &points[index - 5..=index];
+                run.windows(2).all(|pair| pair[1] > pair[0]) || run.windows(2).all(|pair| pair[1] < pair[0])
+            }
+        }
+        Rule::Alternating => {
+            index >= 13 && {
+                let diffs: Vec<f64> = points[index - 13..=index].windows(2).map(|pair| pair[1] - pair[0]).collect();
+                diffs.iter().all(|d| *d != 0.0) && diffs.windows(2).all(|pair| pair[0].signum() != pair[1].signum())
+            }
+        }
+        Rule::TwoOfThree => same_side_beyond(3, 2, 2.0),
+        Rule::FourOfFive => same_side_beyond(5, 4, 1.0),
+        Rule::Stratification => window(15).is_some_and(|zones| zones.iter().all(|z| z.abs() < 1.0)),
+        Rule::Mixture => window(8).is_some_and(|zones| {
+            zones.iter().all(|z| z.abs() > 1.0) && zones.iter().any(|z| *z > 0.0) && zones.iter().any(|z| *z < 0.0)
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "期望 {}，实际 {}",
+            expected,
+            actual
+        );
+    }
+
+    fn measured(no: &str, values: &[f64]) -> SpcSample {
+        SpcSample {
+            inspection_no: no.to_string(),
+            inspected_at: String::new(),
+            values: values.to_vec(),
+            inspected: values.len() as u32,
+            defective: 0,
+        }
+    }
+
+    fn counted(no: &str, inspected: u32, defective: u32) -> SpcSample {
+        SpcSample {
+            inspection_no: no.to_string(),
+            inspected_at: String::new(),
+            values: Vec::new(),
+            inspected,
+            defective,
+        }
+    }
+
+    /// 中心线0、σ=1的序列上触发的规则
+    fn triggered(points: &[f64], rules: RuleSet) -> Vec<Rule> {
+        let limits = Limits { center: 0.0, upper: 3.0, lower: -3.0 };
+        let series = ChartSeries::constant("X", points.to_vec(), limits);
+        detect(&series, rules).into_iter().map(|(_, rule)| rule).collect()
+    }
+
+    fn alternating(len: usize, amplitude: f64) -> Vec<f64> {
+        (0..len).map(|i| if i % 2 == 0 { amplitude } else { -amplitude }).collect()
+    }
+
+    #[test]
+    fn range_constants_match_table() {
+        assert_eq!(d2_d3(1), None);
+        assert_eq!(d2_d3(2), Some((1.128, 0.853)));
+        assert_eq!(d2_d3(5), Some((2.326, 0.864)));
+        assert_eq!(d2_d3(10), Some((3.078, 0.797)));
+        assert_eq!(d2_d3(25), Some((3.931, 0.708)));
+        assert_eq!(d2_d3(26), None);
+    }
+
+    #[test]
+    fn c4_matches_table() {
+        assert_close(c4(2), 0.7979, 1e-4);
+        assert_close(c4(3), 0.8862, 1e-4);
+        assert_close(c4(5), 0.9400, 1e-4);
+        assert_close(c4(10), 0.9727, 1e-4);
+        assert_close(c4(25), 0.9896, 1e-4);
+    }
+
+    #[test]
+    fn xbar_r_limits() {
+        // 子组均值 3、4，极差均为 4；n=5 时 A2=0.577、D3=0、D4=2.114
+        let samples = [
+            measured("A", &[1.0, 2.0, 3.0, 4.0, 5.0]),
+            measured("B", &[2.0, 3.0, 4.0, 5.0, 6.0, 9.0]),
+            measured("C", &[1.0, 2.0]),
+        ];
+        let chart = build_chart(ChartType::XbarR, &samples, 5, (None, None), RuleSet::WesternElectric).unwrap();
+
+        assert_eq!(chart.labels, vec!["A", "B"]);
+        assert_eq!(chart.primary.points, vec![3.0, 4.0]);
+        let limits = chart.primary.limits[0];
+        assert_close(limits.center, 3.5, 1e-9);
+        assert_close(limits.upper, 3.5 + 0.577 * 4.0, 1e-2);
+        assert_close(limits.lower, 3.5 - 0.577 * 4.0, 1e-2);
+
+        let ranges = chart.secondary.unwrap();
+        assert_eq!(ranges.points, vec![4.0, 4.0]);
+        let limits = ranges.limits[0];
+        assert_close(limits.center, 4.0, 1e-9);
+        assert_close(limits.upper, 2.114 * 4.0, 1e-2);
+        assert_eq!(limits.lower, 0.0);
+        assert_close(chart.capability.unwrap().sigma, 4.0 / 2.326, 1e-9);
+    }
+
+    #[test]
+    fn xbar_s_limits() {
+        // 两组标准差均为 sqrt(2.5)；n=5 时 A3=1.427、B3=0、B4=2.089
+        let samples = [
+            measured("A", &[1.0, 2.0, 3.0, 4.0, 5.0]),
+            measured("B", &[2.0, 3.0, 4.0, 5.0, 6.0]),
+        ];
+        let chart = build_chart(ChartType::XbarS, &samples, 5, (None, None), RuleSet::WesternElectric).unwrap();
+        let s_bar = 2.5f64.sqrt();
+
+        let limits = chart.primary.limits[0];
+        assert_close(limits.center, 3.5, 1e-9);
+        assert_close(limits.upper, 3.5 + 1.427 * s_bar, 1e-2);
+        assert_close(limits.lower, 3.5 - 1.427 * s_bar, 1e-2);
+
+        let deviations = chart.secondary.unwrap();
+        assert_close(deviations.points[0], s_bar, 1e-9);
+        let limits = deviations.limits[0];
+        assert_close(limits.center, s_bar, 1e-9);
+        assert_close(limits.upper, 2.089 * s_bar, 1e-2);
+        assert_eq!(limits.lower, 0.0);
+    }
+
+    #[test]
+    fn subgroup_size_out_of_range() {
+        let samples = [measured("A", &[1.0, 2.0]), measured("B", &[1.0, 2.0])];
+        assert!(build_chart(ChartType::XbarR, &samples, 1, (None, None), RuleSet::WesternElectric).is_err());
+        assert!(build_chart(ChartType::XbarR, &samples, 26, (None, None), RuleSet::WesternElectric).is_err());
+        assert!(build_chart(ChartType::XbarR, &samples, 3, (None, None), RuleSet::WesternElectric).is_err());
+    }
+
+    #[test]
+    fn individuals_limits() {
+        // 移动极差 2、1、2，均值 5/3；E2=2.66、D4=3.267
+        let samples = [measured("A", &[10.0, 12.0]), measured("B", &[11.0, 13.0])];
+        let chart = build_chart(ChartType::Individuals, &samples, 5, (None, None), RuleSet::WesternElectric).unwrap();
+        let mr_bar = 5.0 / 3.0;
+
+        assert_eq!(chart.labels, vec!["A#1", "A#2", "B#1", "B#2"]);
+        let limits = chart.primary.limits[0];
+        assert_close(limits.center, 11.5, 1e-9);
+        assert_close(limits.upper, 11.5 + 2.66 * mr_bar, 1e-2);
+        assert_close(limits.lower, 11.5 - 2.66 * mr_bar, 1e-2);
+
+        let moving = chart.secondary.unwrap();
+        assert_eq!(moving.points, vec![0.0, 2.0, 1.0, 2.0]);
+        let limits = moving.limits[0];
+        assert_close(limits.center, mr_bar, 1e-9);
+        assert_close(limits.upper, 3.267 * mr_bar, 1e-2);
+        assert_eq!(limits.lower, 0.0);
+    }
+
+    #[test]
+    fn p_limits_vary_with_sample_size() {
+        // p̄ = 12/400 = 0.03，未检验的批次跳过
+        let samples = [
+            counted("A", 100, 2),
+            counted("B", 100, 4),
+            counted("C", 0, 0),
+            counted("D", 200, 6),
+        ];
+        let chart = build_chart(ChartType::P, &samples, 5, (None, None), RuleSet::WesternElectric).unwrap();
+
+        assert_eq!(chart.labels, vec!["A", "B", "D"]);
+        assert_eq!(chart.primary.points, vec![0.02, 0.04, 0.03]);
+        assert!(chart.secondary.is_none());
+        assert!(chart.capability.is_none());
+
+        let small = chart.primary.limits[0];
+        assert_close(small.center, 0.03, 1e-9);
+        assert_close(small.upper, 0.03 + 3.0 * (0.03 * 0.97 / 100.0f64).sqrt(), 1e-9);
+        assert_close(small.upper, 0.0812, 1e-4);
+        assert_eq!(small.lower, 0.0);
+
+        let large = chart.primary.limits[2];
+        assert_close(large.upper, 0.0662, 1e-4);
+    }
+
+    #[test]
+    fn np_limits_use_average_sample_size() {
+        // n̄p̄ = 4，UCL = 4 + 3·sqrt(4×0.92)
+        let samples = [counted("A", 50, 5), counted("B", 50, 3), counted("C", 50, 4)];
+        let chart = build_chart(ChartType::Np, &samples, 5, (None, None), RuleSet::WesternElectric).unwrap();
+
+        assert_eq!(chart.primary.points, vec![5.0, 3.0, 4.0]);
+        let limits = chart.primary.limits[0];
+        assert_close(limits.center, 4.0, 1e-9);
+        assert_close(limits.upper, 9.755, 1e-3);
+        assert_eq!(limits.lower, 0.0);
+    }
+
+    #[test]
+    fn beyond_limits() {
+        assert!(triggered(&[0.0, 3.5, 0.0], RuleSet::WesternElectric).contains(&Rule::BeyondLimits));
+        assert!(triggered(&[0.0, -3.5, 0.0], RuleSet::WesternElectric).contains(&Rule::BeyondLimits));
+        assert!(!triggered(&[0.0, 2.9, -2.9], RuleSet::WesternElectric).contains(&Rule::BeyondLimits));
+    }
+
+    #[test]
+    fn same_side_western_electric() {
+        assert!(triggered(&[0.5; 8], RuleSet::WesternElectric).contains(&Rule::SameSide(8)));
+        let mut broken = vec![0.5; 7];
+        broken.push(-0.5);
+        assert!(!triggered(&broken, RuleSet::WesternElectric).contains(&Rule::SameSide(8)));
+    }
+
+    #[test]
+    fn same_side_nelson() {
+        assert!(triggered(&[-0.5; 9], RuleSet::Nelson).contains(&Rule::SameSide(9)));
+        assert!(!triggered(&[-0.5; 8], RuleSet::Nelson).contains(&Rule::SameSide(9)));
+    }
+
+    #[test]
+    fn trend() {
+        let rising = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+        let falling = [1.0, 0.8, 0.6, 0.4, 0.2, 0.0];
+        let broken = [0.0, 0.2, 0.4, 0.6, 0.8, 0.7];
+        assert!(triggered(&rising, RuleSet::Nelson).contains(&Rule::Trend));
+        assert!(triggered(&falling, RuleSet::Nelson).contains(&Rule::Trend));
+        assert!(!triggered(&broken, RuleSet::Nelson).contains(&Rule::Trend));
+    }
+
+    #[test]
+    fn alternating_run() {
+        assert!(triggered(&alternating(14, 0.5), RuleSet::Nelson).contains(&Rule::Alternating));
+        assert!(!triggered(&alternating(13, 0.5), RuleSet::Nelson).contains(&Rule::Alternating));
+    }
+
+    #[test]
+    fn two_of_three() {
+        assert!(triggered(&[0.0, 2.5, 0.0, 2.5], RuleSet::WesternElectric).contains(&Rule::TwoOfThree));
+        assert!(!triggered(&[2.5, 0.0, 0.0, 2.5], RuleSet::WesternElectric).contains(&Rule::TwoOfThree));
+        assert!(!triggered(&[0.0, 2.5, 0.0, -2.5], RuleSet::WesternElectric).contains(&Rule::TwoOfThree));
+    }
+
+    #[test]
+    fn four_of_five() {
+        assert!(triggered(&[1.5, 1.5, 0.0, 1.5, 1.5], RuleSet::WesternElectric).contains(&Rule::FourOfFive));
+        assert!(!triggered(&[1.5, 1.5, 0.0, 0.0, 1.5], RuleSet::WesternElectric).contains(&Rule::FourOfFive));
+    }
+
+    #[test]
+    fn stratification() {
+        let hugging = alternating(15, 0.5);
+        assert!(triggered(&hugging, RuleSet::Nelson).contains(&Rule::Stratification));
+        let mut broken = hugging;
+        broken[7] = 1.5;
+        assert!(!triggered(&broken, RuleSet::Nelson).contains(&Rule::Stratification));
+    }
+
+    #[test]
+    fn mixture() {
+        assert!(triggered(&alternating(8, 1.5), RuleSet::Nelson).contains(&Rule::Mixture));
+        assert!(!triggered(&[1.5; 8], RuleSet::Nelson).contains(&Rule::Mixture));
+    }
+
+    #[test]
+    fn in_control_series_has_no_violations() {
+        let points = [0.3, -0.4, 0.8, -1.1, 0.2, 0.6, -0.7, 1.2, -0.2, 0.1];
+        assert!(triggered(&points, RuleSet::Nelson).is_empty());
+    }
+
+    #[test]
+    fn capability_two_sided() {
+        // Cp = 4/3，Cpk = min(2.5, 1.5)/1.5 = 1.0
+        let result = capability(10.0, 0.5, (Some(8.5), Some(12.5)));
+        assert_close(result.cp.unwrap(), 4.0 / 3.0, 1e-9);
+        assert_close(result.cpk.unwrap(), 1.0, 1e-9);
+        assert_eq!(result.rating(), "不足");
+
+        let centered = capability(10.0, 0.4, (Some(8.0), Some(12.0)));
+        assert_close(centered.cp.unwrap(), 5.0 / 3.0, 1e-9);
+        assert_close(centered.cpk.unwrap(), 5.0 / 3.0, 1e-9);
+    }
+
+    #[test]
+    fn capability_one_sided_and_degenerate() {
+        let upper_only = capability(10.0, 0.5, (None, Some(12.5)));
+        assert_eq!(upper_only.cp, None);
+        assert_close(upper_only.cpk.unwrap(), 2.5 / 1.5, 1e-9);
+
+        let no_spread = capability(10.0, 0.0, (Some(8.5), Some(12.5)));
+        assert_eq!(no_spread.cp, None);
+        assert_eq!(no_spread.cpk, None);
+        assert_eq!(no_spread.rating(), "-");
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use crate::error::FieldError;
+use crate::time::parse_date;
+
+/// 报表类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReportKind {
+    /// 生产日报
+    DailyProduction,
+    /// 报废统计
+    Scrap,
+    /// 库存价值
+    InventoryValuation,
+    /// 检验汇总
+    InspectionSummary,
+}
+
+impl ReportKind {
+    pub const ALL: [ReportKind; 4] = [
+        ReportKind::DailyProduction,
+        ReportKind::Scrap,
+        ReportKind::InventoryValuation,
+        ReportKind::InspectionSummary,
+    ];
+
+    /// 与后端约定的报表编码（用于接口路径）
+    pub fn code(&self) -> &'static str {
+        match self {
+            ReportKind::DailyProduction => "daily-production",
+            ReportKind::Scrap => "scrap",
+            ReportKind::InventoryValuation => "inventory-valuation",
+            ReportKind::InspectionSummary => "inspection-summary",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.code() == code)
+    }
+}
+
+/// 报表参数类型
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ParamKind {
+    /// `YYYY-MM-DD`
+    Date,
+    Text,
+    Select { options: Vec<String> },
+}
+
+/// 报表参数定义
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportParam {
+    pub key: String,
+    pub label: String,
+    pub kind: ParamKind,
+    pub required: bool,
+}
+
+/// 报表列的数据类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ColumnKind {
+    Text,
+    Number,
+    /// 0~1 的比率，显示为百分比
+    Percent,
+    /// 金额，保留2位小数
+    Money,
+}
+
+/// 报表列定义
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportColumn {
+    /// 后端返回记录中的字段名
+    pub key: String,
+    pub title: String,
+    pub kind: ColumnKind,
+    /// 合计行是否汇总该列
+    #[serde(default)]
+    pub summable: bool,
+}
+
+/// 报表定义
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportDefinition {
+    pub kind: ReportKind,
+    pub title: String,
+    pub description: String,
+    pub params: Vec<ReportParam>,
+    pub columns: Vec<ReportColumn>,
+}
+
+impl ReportDefinition {
+    /// 校验参数：必填项、日期格式及起止日期先后
+    pub fn validate(&self, params: &BTreeMap<String, String>) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        let value = |key: &str| params.get(key).map(|v| v.trim()).filter(|v| !v.is_empty());
+
+        for param in &self.params {
+            let Some(text) = value(&param.key) else {
+                if param.required {
+                    errors.push(FieldError {
+                        field: param.key.clone(),
+                        message: format!("请填写{}", param.label),
+                    });
+                }
+                continue;
+            };
+            match &param.kind {
+                ParamKind::Date if parse_date(text).is_none() => errors.push(FieldError {
+                    field: param.key.clone(),
+                    message: format!("{}格式应为 YYYY-MM-DD", param.label),
+                }),
+                ParamKind::Select { options } if !options.iter().any(|option| option == text) => {
+                    errors.push(FieldError {
+                        field: param.key.clone(),
+                        message: format!("{}的取值无效", param.label),
+                    })
+                }
+                _ => {}
+            }
+        }
+
+        if let (Some(from), Some(to)) = (value("dateFrom").and_then(parse_date), value("dateTo").and_then(parse_date)) {
+            if from > to {
+                errors.push(FieldError {
+                    field: "dateTo".to_string(),
+                    message: "结束日期不能早于开始日期".to_string(),
+                });
+            }
+        }
+        errors
+    }
+}
+
+/// 报表运行请求
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportRequest {
+    pub kind: ReportKind,
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+}
+
+/// 单元格
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Cell {
+    Number(f64),
+    Text(String),
+    Empty,
+}
+
+impl Cell {
+    /// 按列类型格式化显示
+    pub fn display(&self, kind: ColumnKind) -> String {
+        match (self, kind) {
+            (Cell::Number(value), ColumnKind::Percent) => format!("{:.1}%", value * 100.0),
+            (Cell::Number(value), ColumnKind::Money) => format!("{:.2}", value),
+            (Cell::Number(value), _) => {
+                if value.fract() == 0.0 {
+                    format!("{:.0}", value)
+                } else {
+                    let text = format!("{:.3}", value);
+                    text.trim_end_matches('0').trim_end_matches('.').to_string()
+                }
+            }
+            (Cell::Text(text), _) => text.clone(),
+            (Cell::Empty, _) => String::new(),
+        }
+    }
+}
+
+/// 报表运行结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportTable {
+    pub kind: ReportKind,
+    pub title: String,
+    /// 参数说明（如 "日期：2024-05-01；产线：L1"）
+    pub subtitle: String,
+    pub columns: Vec<ReportColumn>,
+    pub rows: Vec<Vec<Cell>>,
+    /// 合计行，没有可汇总的列时为空
+    #[serde(default)]
+    pub totals: Option<Vec<Cell>>,
+    pub generated_at: String,
+}
+
+/// 导出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExportFormat {
+    Csv,
+    Xlsx,
+    Pdf,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 3] = [ExportFormat::Csv, ExportFormat::Xlsx, ExportFormat::Pdf];
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Xlsx => "xlsx",
+            ExportFormat::Pdf => "pdf",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Xlsx => "Excel",
+            ExportFormat::Pdf => "PDF",
+        }
+    }
+}
+
+/// 导出结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportResult {
+    /// 导出文件的完整路径
+    pub path: String,
+    pub rows: usize,
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+// 通用API响应结构
+//
+// 后端所有接口统一使用该结构；部分接口不返回 `success`，此时根据 `code` 判断
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiResponse<T> {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    pub code: u32,
+    pub message: String,
+    pub data: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+}
+
+impl<T> ApiResponse<T> {
+    /// 后端是否返回成功
+    pub fn is_success(&self) -> bool {
+        self.success
+            .unwrap_or(self.code == 0 || (200..300).contains(&self.code))
+    }
+}
+
+// 分页查询结果
+//
+// `page` 从1开始计数
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageData<T> {
+    #[serde(default = "Vec::new")]
+    pub items: Vec<T>,
+    pub total: u64,
+    pub page: u32,
+    pub page_size: u32,
+}
+
+impl<T> PageData<T> {
+    /// 总页数（至少为1）
+    pub fn total_pages(&self) -> u32 {
+        if self.page_size == 0 {
+            return 1;
+        }
+        let pages = self.total.div_ceil(self.page_size as u64);
+        pages.max(1) as u32
+    }
+}
+
+impl<T> Default for PageData<T> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            total: 0,
+            page: 1,
+            page_size: 20,
+        }
+    }
+}
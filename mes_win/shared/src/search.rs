@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+/// 搜索结果的跳转目标
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SearchTarget {
+    /// 功能菜单（由前端按菜单表匹配，`code` 为二级菜单编码）
+    Menu { code: String },
+    /// 工单详情
+    WorkOrder { id: u64 },
+    /// 物料库存（按物料编码筛选）
+    Material { code: String },
+    /// 批次追溯
+    Lot {
+        #[serde(rename = "lotNo")]
+        lot_no: String,
+    },
+    /// 序列号追溯
+    Serial {
+        #[serde(rename = "serialNo")]
+        serial_no: String,
+    },
+    /// 不合格报告详情
+    Ncr { id: u64 },
+}
+
+impl SearchTarget {
+    /// 结果分类名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            SearchTarget::Menu { .. } => "功能",
+            SearchTarget::WorkOrder { .. } => "工单",
+            SearchTarget::Material { .. } => "物料",
+            SearchTarget::Lot { .. } => "批次",
+            SearchTarget::Serial { .. } => "序列号",
+            SearchTarget::Ncr { .. } => "质量问题",
+        }
+    }
+}
+
+/// 一条搜索结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchHit {
+    pub target: SearchTarget,
+    pub title: String,
+    #[serde(default)]
+    pub subtitle: Option<String>,
+}
+
+/// 全局搜索结果
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResults {
+    pub hits: Vec<SearchHit>,
+    /// 部分后端查询超时或失败，结果只包含本地缓存和已返回的部分
+    #[serde(default)]
+    pub partial: bool,
+}
+
+/// 每类结果的条数上限
+pub const SEARCH_LIMIT_PER_KIND: u32 = 5;
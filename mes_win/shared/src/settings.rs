@@ -0,0 +1,315 @@
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use crate::error::FieldError;
+
+/// 主窗口尺寸下限
+pub const MIN_WINDOW_SIZE: (u32, u32) = (1024, 680);
+/// 主窗口尺寸上限
+pub const MAX_WINDOW_SIZE: (u32, u32) = (3840, 2160);
+
+/// 界面语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    #[serde(rename = "zh-CN")]
+    ZhCn,
+    #[serde(rename = "en-US")]
+    EnUs,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::ZhCn, Language::EnUs];
+
+    /// BCP 47 语言标记，同时用作 `<html lang>`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::ZhCn => "zh-CN",
+            Language::EnUs => "en-US",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|language| language.code() == code)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Language::ZhCn => "简体中文",
+            Language::EnUs => "English",
+        }
+    }
+}
+
+/// 界面主题
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    Dark,
+    Light,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 2] = [Theme::Dark, Theme::Light];
+
+    /// 与 index.html 中 localStorage 保存的取值一致
+    pub fn code(&self) -> &'static str {
+        match self {
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|theme| theme.code() == code)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Theme::Dark => "深色",
+            Theme::Light => "浅色",
+        }
+    }
+}
+
+/// 原生层日志级别，按详细程度递增
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 4] = [LogLevel::Error, LogLevel::Warn, LogLevel::Info, LogLevel::Debug];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|level| level.code() == code)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "错误",
+            LogLevel::Warn => "警告",
+            LogLevel::Info => "信息",
+            LogLevel::Debug => "调试（记录每个接口请求）",
+        }
+    }
+}
+
+/// 本机客户端设置（应用配置目录下的 settings.json）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LocalSettings {
+    pub language: Language,
+    pub theme: Theme,
+    /// 登录后主窗口的默认宽度
+    pub window_width: u32,
+    /// 登录后主窗口的默认高度
+    pub window_height: u32,
+    /// 后端档案标识，实际保存在 backend.json
+    pub backend_profile: String,
+    pub log_level: LogLevel,
+}
+
+impl Default for LocalSettings {
+    fn default() -> Self {
+        Self {
+            language: Language::ZhCn,
+            theme: Theme::Dark,
+            window_width: 1200,
+            window_height: 800,
+            backend_profile: String::new(),
+            log_level: LogLevel::Info,
+        }
+    }
+}
+
+impl LocalSettings {
+    /// 校验窗口尺寸范围和后端档案（档案是否存在由原生层检查）
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        let mut push = |field: &str, message: String| {
+            errors.push(FieldError {
+                field: field.to_string(),
+                message,
+            });
+        };
+
+        let (min_width, min_height) = MIN_WINDOW_SIZE;
+        let (max_width, max_height) = MAX_WINDOW_SIZE;
+        if !(min_width..=max_width).contains(&self.window_width) {
+            push("windowWidth", format!("窗口宽度应在 {} 到 {} 之间", min_width, max_width));
+        }
+        if !(min_height..=max_height).contains(&self.window_height) {
+            push("windowHeight", format!("窗口高度应在 {} 到 {} 之间", min_height, max_height));
+        }
+        if self.backend_profile.trim().is_empty() {
+            push("backendProfile", "请选择后端档案".to_string());
+        }
+
+        errors
+    }
+}
+
+/// 工厂参数的取值类型
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SettingKind {
+    Integer {
+        #[serde(default)]
+        min: Option<i64>,
+        #[serde(default)]
+        max: Option<i64>,
+    },
+    Decimal {
+        #[serde(default)]
+        min: Option<f64>,
+        #[serde(default)]
+        max: Option<f64>,
+    },
+    Text {
+        #[serde(default, rename = "maxLength")]
+        max_length: Option<usize>,
+    },
+    Boolean,
+    /// `HH:MM`，如班次开始时间
+    Time,
+    Select { options: Vec<String> },
+}
+
+/// 工厂参数定义，由后端下发，前端据此生成表单
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingField {
+    pub key: String,
+    pub label: String,
+    /// 分组名称，如 "班次日历" / "计量单位" / "编号规则"
+    pub group: String,
+    pub kind: SettingKind,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub unit: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl SettingField {
+    /// 检查单个取值的类型和范围，不合法时返回提示
+    pub fn check(&self, value: &str) -> Option<String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return self.required.then(|| format!("请填写{}", self.label));
+        }
+
+        match &self.kind {
+            SettingKind::Integer { min, max } => match value.parse::<i64>() {
+                Err(_) => Some(format!("{}必须是整数", self.label)),
+                Ok(number) => range_problem(&self.label, number, *min, *max),
+            },
+            SettingKind::Decimal { min, max } => match value.parse::<f64>() {
+                Ok(number) if number.is_finite() => range_problem(&self.label, number, *min, *max),
+                _ => Some(format!("{}必须是数字", self.label)),
+            },
+            SettingKind::Text { max_length: Some(max) } if value.chars().count() > *max => {
+                Some(format!("{}不能超过{}个字符", self.label, max))
+            }
+            SettingKind::Text { .. } => None,
+            SettingKind::Boolean if value != "true" && value != "false" => {
+                Some(format!("{}的取值无效", self.label))
+            }
+            SettingKind::Boolean => None,
+            SettingKind::Time if parse_time(value).is_none() => Some(format!("{}格式应为 HH:MM", self.label)),
+            SettingKind::Time => None,
+            SettingKind::Select { options } if !options.iter().any(|option| option == value) => {
+                Some(format!("{}的取值无效", self.label))
+            }
+            SettingKind::Select { .. } => None,
+        }
+    }
+}
+
+fn range_problem<T: PartialOrd + std::fmt::Display + Copy>(
+    label: &str,
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+) -> Option<String> {
+    match (min, max) {
+        (Some(min), Some(max)) if value < min || value > max => Some(format!("{}应在 {} 到 {} 之间", label, min, max)),
+        (Some(min), None) if value < min => Some(format!("{}不能小于 {}", label, min)),
+        (None, Some(max)) if value > max => Some(format!("{}不能大于 {}", label, max)),
+        _ => None,
+    }
+}
+
+/// 将 `HH:MM` 解析为当天的分钟数
+pub fn parse_time(value: &str) -> Option<u32> {
+    let (hour, minute) = value.split_once(':')?;
+    if hour.len() != 2 || minute.len() != 2 {
+        return None;
+    }
+    let (hour, minute) = (hour.parse::<u32>().ok()?, minute.parse::<u32>().ok()?);
+    (hour < 24 && minute < 60).then_some(hour * 60 + minute)
+}
+
+/// 工厂参数（get_plant_settings / save_plant_settings 的返回值）
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlantSettings {
+    pub schema: Vec<SettingField>,
+    /// 参数键 -> 取值，统一以字符串传递
+    pub values: BTreeMap<String, String>,
+    #[serde(default)]
+    pub updated_by: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+impl PlantSettings {
+    /// 按定义顺序分组（保持后端下发的顺序）
+    pub fn groups(&self) -> Vec<(&str, Vec<&SettingField>)> {
+        let mut groups: Vec<(&str, Vec<&SettingField>)> = Vec::new();
+        for field in &self.schema {
+            match groups.iter_mut().find(|(group, _)| *group == field.group) {
+                Some((_, fields)) => fields.push(field),
+                None => groups.push((&field.group, vec![field])),
+            }
+        }
+        groups
+    }
+}
+
+/// 按参数定义校验全部取值，未定义的参数键同样视为错误
+pub fn validate_plant_values(schema: &[SettingField], values: &BTreeMap<String, String>) -> Vec<FieldError> {
+    let mut errors: Vec<FieldError> = schema
+        .iter()
+        .filter_map(|field| {
+            let value = values.get(&field.key).map(String::as_str).unwrap_or_default();
+            field.check(value).map(|message| FieldError {
+                field: field.key.clone(),
+                message,
+            })
+        })
+        .collect();
+
+    for key in values.keys() {
+        if !schema.iter().any(|field| field.key == *key) {
+            errors.push(FieldError {
+                field: key.clone(),
+                message: format!("未定义的参数: {}", key),
+            });
+        }
+    }
+    errors
+}
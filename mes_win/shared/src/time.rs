@@ -0,0 +1,127 @@
+// 日期时间工具
+//
+// 前后端统一使用本地时间的ISO字符串（`YYYY-MM-DDTHH:MM[:SS]`）传递时间，
+// 计算时转换为自1970-01-01起的分钟数，不依赖时区库
+
+/// 将 `YYYY-MM-DDTHH:MM[:SS]` 解析为自1970-01-01起的分钟数
+pub fn parse_minutes(value: &str) -> Option<i64> {
+    let number = |range: std::ops::Range<usize>| value.get(range)?.parse::<i64>().ok();
+
+    if value.len() < 16 || value.as_bytes()[10] != b'T' {
+        return None;
+    }
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute) = (number(11..13)?, number(14..16)?);
+    if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) || hour > 23 || minute > 59 {
+        return None;
+    }
+
+    Some(days_from_civil(year, month, day) * 1440 + hour * 60 + minute)
+}
+
+/// 将分钟数格式化为 `YYYY-MM-DDTHH:MM`
+pub fn format_minutes(minutes: i64) -> String {
+    let days = minutes.div_euclid(1440);
+    let rest = minutes.rem_euclid(1440);
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}", year, month, day, rest / 60, rest % 60)
+}
+
+/// 将 `YYYY-MM-DD` 解析为自1970-01-01起的天数
+pub fn parse_date(value: &str) -> Option<i64> {
+    parse_minutes(&format!("{}T00:00", value.get(0..10)?)).map(|minutes| minutes / 1440)
+}
+
+/// 将天数格式化为 `YYYY-MM-DD`
+pub fn format_date(days: i64) -> String {
+    format_minutes(days * 1440)[..10].to_string()
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// 月份天数（月份须为 1~12）
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// 公历日期与天数互转（Howard Hinnant 算法）
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_formats_round_trip() {
+        assert_eq!(parse_minutes("1970-01-01T00:00"), Some(0));
+        assert_eq!(parse_minutes("1970-01-02T01:30:45"), Some(1440 + 90));
+        for value in ["2024-02-29T23:59", "2026-12-31T08:05", "1999-03-01T00:00"] {
+            assert_eq!(format_minutes(parse_minutes(value).unwrap()), value);
+        }
+        assert_eq!(parse_date("2026-10-17"), parse_minutes("2026-10-17T00:00").map(|m| m / 1440));
+        assert_eq!(format_date(parse_date("2026-10-17").unwrap()), "2026-10-17");
+    }
+
+    #[test]
+    fn rejects_impossible_dates() {
+        let cases = [
+            ("2026-02-28T00:00", true),
+            ("2026-02-29T00:00", false),
+            ("2026-02-31T00:00", false),
+            ("2024-02-29T00:00", true),
+            ("2024-02-30T00:00", false),
+            // 整百年须能被400整除才是闰年
+            ("1900-02-29T00:00", false),
+            ("2000-02-29T00:00", true),
+            ("2026-04-30T00:00", true),
+            ("2026-04-31T00:00", false),
+            ("2026-06-31T00:00", false),
+            ("2026-09-31T00:00", false),
+            ("2026-11-31T00:00", false),
+            ("2026-01-31T00:00", true),
+            ("2026-12-31T00:00", true),
+            ("2026-00-10T00:00", false),
+            ("2026-13-01T00:00", false),
+            ("2026-05-00T00:00", false),
+        ];
+        for (value, valid) in cases {
+            assert_eq!(parse_minutes(value).is_some(), valid, "{}", value);
+        }
+        assert_eq!(parse_date("2026-02-31"), None);
+    }
+
+    #[test]
+    fn rejects_malformed_values() {
+        for value in ["", "2026-10-17", "2026-10-17 08:00", "2026-10-17T24:00", "2026-10-17T08:60", "2026-1x-17T08:00"] {
+            assert_eq!(parse_minutes(value), None, "{:?}", value);
+        }
+    }
+}
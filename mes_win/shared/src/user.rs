@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+use crate::error::FieldError;
+use crate::permission::{Permission, ALL_PERMISSIONS};
+
+/// 密码最小长度
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// 用户账户状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum UserStatus {
+    Active,
+    Disabled,
+    /// 连续登录失败被锁定
+    Locked,
+}
+
+impl UserStatus {
+    pub const ALL: [UserStatus; 3] = [UserStatus::Active, UserStatus::Disabled, UserStatus::Locked];
+
+    /// 与后端约定的状态编码
+    pub fn code(&self) -> &'static str {
+        match self {
+            UserStatus::Active => "ACTIVE",
+            UserStatus::Disabled => "DISABLED",
+            UserStatus::Locked => "LOCKED",
+        }
+    }
+
+    /// 根据状态编码解析
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|status| status.code() == code)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            UserStatus::Active => "正常",
+            UserStatus::Disabled => "已停用",
+            UserStatus::Locked => "已锁定",
+        }
+    }
+
+    /// 样式类名后缀
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            UserStatus::Active => "completed",
+            UserStatus::Disabled => "closed",
+            UserStatus::Locked => "rejected",
+        }
+    }
+}
+
+/// 角色
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Role {
+    pub code: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// 权限编码
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    /// 系统内置角色，不允许删除
+    #[serde(default)]
+    pub builtin: bool,
+}
+
+impl Role {
+    /// 校验角色编码、名称和权限编码
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        let mut push = |field: &str, message: &str| {
+            errors.push(FieldError {
+                field: field.to_string(),
+                message: message.to_string(),
+            });
+        };
+
+        let code = self.code.trim();
+        if code.is_empty() {
+            push("code", "请输入角色编码");
+        } else if !code.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            push("code", "角色编码只能包含字母、数字和下划线");
+        }
+        if self.name.trim().is_empty() {
+            push("name", "请输入角色名称");
+        }
+        if self.permissions.is_empty() {
+            push("permissions", "请至少选择一项权限");
+        } else if self.permissions.iter().any(|code| {
+            code != ALL_PERMISSIONS && !code.ends_with(".*") && Permission::from_code(code).is_none()
+        }) {
+            push("permissions", "包含无法识别的权限编码");
+        }
+
+        errors
+    }
+}
+
+/// 用户账户
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserAccount {
+    pub id: u64,
+    pub username: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub phone: Option<String>,
+    pub status: UserStatus,
+    /// 角色编码
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub last_login_at: Option<String>,
+    pub created_at: String,
+}
+
+/// 用户分页查询条件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserQuery {
+    pub page: u32,
+    pub page_size: u32,
+    /// 按用户名/姓名模糊查询
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<UserStatus>,
+}
+
+impl Default for UserQuery {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            page_size: 20,
+            keyword: None,
+            status: None,
+        }
+    }
+}
+
+/// 新建用户
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDraft {
+    pub username: String,
+    pub display_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    /// 初始密码，首次登录须修改
+    pub password: String,
+    pub roles: Vec<String>,
+}
+
+impl UserDraft {
+    /// 校验必填项和格式，返回所有字段错误
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        let mut push = |field: &str, message: &str| {
+            errors.push(FieldError {
+                field: field.to_string(),
+                message: message.to_string(),
+            });
+        };
+
+        let username = self.username.trim();
+        if username.is_empty() {
+            push("username", "请输入用户名");
+        } else if !(3..=32).contains(&username.len())
+            || !username.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+        {
+            push("username", "用户名为3到32位字母、数字或 _ . -");
+        }
+        if self.display_name.trim().is_empty() {
+            push("displayName", "请输入姓名");
+        }
+        if let Some(email) = self.email.as_deref().map(str::trim).filter(|e| !e.is_empty()) {
+            if !email.contains('@') || email.starts_with('@') || email.ends_with('@') {
+                push("email", "邮箱格式不正确");
+            }
+        }
+        if let Some(message) = password_problem(&self.password) {
+            push("password", message);
+        }
+        if self.roles.is_empty() {
+            push("roles", "请至少分配一个角色");
+        }
+
+        errors
+    }
+}
+
+/// 密码强度检查：至少8位且同时包含字母和数字，不满足时返回提示
+pub fn password_problem(password: &str) -> Option<&'static str> {
+    if password.chars().count() < MIN_PASSWORD_LENGTH {
+        Some("密码长度不能少于8位")
+    } else if !password.chars().any(|c| c.is_ascii_alphabetic())
+        || !password.chars().any(|c| c.is_ascii_digit())
+    {
+        Some("密码必须同时包含字母和数字")
+    } else {
+        None
+    }
+}
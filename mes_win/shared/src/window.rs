@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+// 窗口大小参数
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowSizeArgs {
+    pub width: f64,
+    pub height: f64,
+}
@@ -1,6 +1,10 @@
-use crate::auth::types::{ApiResponse, LoginRequest};
+use crate::api::error::{from_reqwest, ApiError};
+use crate::auth::types::{ApiResponse, LoginData, LoginRequest};
 use crate::auth::store::{UserSession, USER_STORE};
-use crate::auth::client::AUTH_CLIENT;
+use crate::auth::client::{AuthenticatedClient, AUTH_CLIENT};
+use crate::master_data;
+use mes_win_shared::events;
+use mes_win_shared::permission::SessionInfo;
 use tauri::{Manager, Emitter};
 
 /// 登录API调用
@@ -10,156 +14,109 @@ use tauri::{Manager, Emitter};
 /// * `password` - 密码
 /// 
 /// # 返回值
-/// * `Ok(ApiResponse)` - 登录成功或失败的API响应
-/// * `Err(String)` - 网络错误或其他系统错误
-pub async fn login_api(username: String, password: String) -> Result<ApiResponse, String> {
+/// * `Ok(ApiResponse)` - 登录成功的API响应
+/// * `Err(ApiError)` - 用户名密码错误、网络错误或其他系统错误
+pub async fn login_api(username: String, password: String) -> Result<ApiResponse<LoginData>, ApiError> {
     let login_request = LoginRequest { username, password };
     
-    println!("发起登录请求: {:?}", login_request);
+    println!("发起登录请求: {:?}", login_request.username);
     
     // 使用认证客户端的原始客户端进行登录请求（不需要认证头）
-    match AUTH_CLIENT
+    let response = AUTH_CLIENT
         .raw_client()
-        .post("http://127.0.0.1:8080/api/auth/login")
+        .post(AUTH_CLIENT.resolve_url("/api/auth/login"))
         .json(&login_request)
         .send()
         .await
-    {
-        Ok(response) => {
-            println!("响应状态: {}", response.status());
-            
-            // 不管是成功还是失败，都尝试解析为 ApiResponse
-            match response.json::<ApiResponse>().await {
-                Ok(api_response) => {
-                    println!("API 响应: {:?}", api_response);
-                    Ok(api_response)
-                }
-                Err(e) => {
-                    println!("解析响应失败: {:?}", e);
-                    Err("响应格式错误".to_string())
-                }
-            }
-        }
-        Err(e) => {
+        .map_err(|e| {
             println!("请求失败: {:?}", e);
-            Err(format!("网络连接失败: {}", e))
-        }
+            from_reqwest(e)
+        })?;
+
+    println!("响应状态: {}", response.status());
+    
+    let api_response = AuthenticatedClient::read_envelope::<LoginData>(response).await?;
+    if api_response.data.is_none() {
+        return Err(ApiError::Decode {
+            message: "登录响应缺少用户数据".to_string(),
+        });
     }
+    Ok(api_response)
 }
 
 /// Tauri命令：登录
 #[tauri::command]
-pub async fn login(username: String, password: String) -> Result<ApiResponse, String> {
-    match login_api(username, password).await {
-        Ok(api_response) => {
-            // 如果登录成功，保存用户会话信息
-            if api_response.success {
-                if let Some(login_data) = &api_response.data {
-                    let session = UserSession::from_login_data(login_data.clone());
-                    USER_STORE.set_current_session(session);
-                    println!("用户登录成功，会话信息已保存");
-                }
-            }
-            Ok(api_response)
-        }
-        Err(e) => Err(e)
+pub async fn login(username: String, password: String) -> Result<ApiResponse<LoginData>, ApiError> {
+    if username.trim().is_empty() {
+        return Err(ApiError::invalid_field("username", "用户名不能为空"));
+    }
+    if password.is_empty() {
+        return Err(ApiError::invalid_field("password", "密码不能为空"));
+    }
+
+    let api_response = login_api(username, password).await?;
+
+    // 登录成功，保存用户会话信息
+    if let Some(login_data) = &api_response.data {
+        let session = UserSession::from_login_data(login_data.clone());
+        USER_STORE.set_current_session(session);
+        println!("用户登录成功，会话信息已保存");
+
+        // 登录后立即拉取主数据变更
+        master_data::sync::wake();
     }
+    Ok(api_response)
 }
 
 /// Tauri命令：获取当前用户信息
 #[tauri::command]
-pub async fn get_current_user() -> Result<Option<(String, u32)>, String> {
+pub async fn get_current_user() -> Result<Option<(String, u32)>, ApiError> {
     Ok(USER_STORE.get_current_user_info())
 }
 
+/// Tauri命令：获取当前会话的角色和权限
+#[tauri::command]
+pub async fn get_session_info() -> Result<Option<SessionInfo>, ApiError> {
+    Ok(USER_STORE.get_session_info())
+}
+
 /// Tauri命令：获取当前认证Token
 #[tauri::command]
-pub async fn get_current_token() -> Result<Option<String>, String> {
+pub async fn get_current_token() -> Result<Option<String>, ApiError> {
     Ok(USER_STORE.get_current_auth_header())
 }
 
 /// Tauri命令：检查用户是否已登录
 #[tauri::command]
-pub async fn is_logged_in() -> Result<bool, String> {
-    Ok(USER_STORE.get_valid_current_session().is_some())
+pub async fn is_logged_in() -> Result<bool, ApiError> {
+    Ok(USER_STORE.has_active_session())
 }
 
 /// 登出API调用
 /// 
 /// # 返回值
-/// * `Ok(ApiResponse)` - 登出成功或失败的API响应
-/// * `Err(String)` - 网络错误或其他系统错误
-pub async fn logout_api() -> Result<ApiResponse, String> {
+/// * `Ok(())` - 后端登出成功
+/// * `Err(ApiError)` - 后端返回失败、网络错误或响应无法解析
+pub async fn logout_api() -> Result<(), ApiError> {
     println!("发起登出请求");
-    
-    // 使用认证客户端的POST方法（会自动添加Authorization头）
-    match AUTH_CLIENT
-        .post("http://localhost:8080/api/auth/logout")
-    {
-        Ok(request_builder) => {
-            match request_builder
-                .header("Content-Type", "application/json")
-                .send()
-                .await
-            {
-                Ok(response) => {
-                    println!("登出响应状态: {}", response.status());
-                    
-                    // 首先获取响应文本
-                    match response.text().await {
-                        Ok(response_text) => {
-                            println!("登出响应内容: {}", response_text);
-                            
-                            // 尝试解析为 ApiResponse
-                            match serde_json::from_str::<ApiResponse>(&response_text) {
-                                Ok(api_response) => {
-                                    println!("登出API响应解析成功: {:?}", api_response);
-                                    Ok(api_response)
-                                }
-                                Err(e) => {
-                                    println!("解析登出响应失败: {:?}", e);
-                                    println!("原始响应: {}", response_text);
-                                    // 返回一个默认的成功响应
-                                    Ok(ApiResponse {
-                                        success: true,
-                                        code: 200,
-                                        message: "登出成功".to_string(),
-                                        data: None,
-                                    })
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            println!("读取响应文本失败: {:?}", e);
-                            Err("无法读取响应内容".to_string())
-                        }
-                    }
-                }
-                Err(e) => {
-                    println!("登出请求失败: {:?}", e);
-                    Err(format!("登出请求失败: {}", e))
-                }
-            }
-        }
-        Err(e) => {
-            println!("构建登出请求失败: {}", e);
-            Err(e)
-        }
-    }
+    AUTH_CLIENT
+        .post_json("/api/auth/logout", &serde_json::json!({}))
+        .await
 }
 
 /// Tauri命令：注销用户
 #[tauri::command]
-pub async fn logout(app: tauri::AppHandle) -> Result<String, String> {
+pub async fn logout(app: tauri::AppHandle) -> Result<String, ApiError> {
     println!("开始登出流程");
     
     // 先尝试调用后端API登出
-    let logout_result: Result<ApiResponse, String> = logout_api().await;
+    let logout_result = logout_api().await;
     
     // 无论后端API调用是否成功，都清理本地会话
     // 这样即使网络问题导致API调用失败，用户也能在本地登出
     USER_STORE.clear_current_session();
-    println!("本地会话已清理");
+    master_data::sync::clear_cache().await;
+    println!("本地会话及主数据缓存已清理");
     
     // 关闭个人中心窗口（如果存在）
     if let Some(profile_window) = app.get_webview_window("profile") {
@@ -171,22 +128,16 @@ pub async fn logout(app: tauri::AppHandle) -> Result<String, String> {
     }
     
     // 通知所有窗口登出事件
-    if let Err(e) = app.emit("logout", ()) {
+    if let Err(e) = app.emit(events::LOGOUT, ()) {
         println!("发送登出事件失败: {}", e);
     } else {
         println!("登出事件已发送");
     }
     
     match logout_result {
-        Ok(api_response) => {
-            if api_response.success {
-                println!("登出成功");
-                Ok("登出成功".to_string())
-            } else {
-                println!("后端登出失败: {}", api_response.message);
-                // 即使后端返回失败，本地会话已清理，仍然返回成功
-                Ok("本地登出成功".to_string())
-            }
+        Ok(_) => {
+            println!("登出成功");
+            Ok("登出成功".to_string())
         }
         Err(e) => {
             println!("登出API调用失败: {}", e);
@@ -0,0 +1,31 @@
+use crate::api::error::ApiError;
+use crate::config::profile::BackendProfiles;
+use crate::config::store::CONFIG_STORE;
+use crate::auth::store::USER_STORE;
+
+/// Tauri命令：获取后端服务器档案列表
+#[tauri::command]
+pub async fn list_backend_profiles() -> Result<BackendProfiles, ApiError> {
+    Ok(CONFIG_STORE.list_profiles())
+}
+
+/// Tauri命令：切换后端服务器档案
+///
+/// 切换到其他服务器后，原服务器签发的token不再有效，因此同时清理本地会话
+#[tauri::command]
+pub async fn switch_backend_profile(name: String) -> Result<BackendProfiles, ApiError> {
+    let previous = CONFIG_STORE.list_profiles();
+    if !previous.profiles.iter().any(|profile| profile.name == name) {
+        return Err(ApiError::invalid_field("name", format!("后端档案不存在: {}", name)));
+    }
+
+    let profiles = CONFIG_STORE
+        .switch_profile(&name)
+        .map_err(|message| ApiError::Server { code: 500, message })?;
+
+    if previous.active != profiles.active {
+        USER_STORE.clear_current_session();
+    }
+
+    Ok(profiles)
+}
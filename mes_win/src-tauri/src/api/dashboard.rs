@@ -0,0 +1,65 @@
+use mes_win_shared::dashboard::{DashboardKpis, DashboardLayout, OeeFactors};
+use mes_win_shared::permission::Permission;
+use crate::api::error::{check_fields, ApiError};
+use crate::auth::client::AUTH_CLIENT;
+use crate::auth::guard::require;
+use crate::auth::store::USER_STORE;
+use crate::config::layout::LAYOUT_STORE;
+use crate::config::store::CONFIG_STORE;
+
+/// 布局按后端档案和用户区分，不同服务器上的同名用户互不影响
+fn layout_key() -> Result<String, ApiError> {
+    let (username, _) = USER_STORE.get_current_user_info().ok_or_else(ApiError::unauthorized)?;
+    let profile = CONFIG_STORE
+        .active_profile()
+        .map(|profile| profile.name)
+        .unwrap_or_default();
+    Ok(format!("{}/{}", profile, username))
+}
+
+fn ratio(value: f64) -> f64 {
+    if value.is_finite() {
+        value.clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Tauri命令：获取仪表板指标
+///
+/// 比率类指标统一规整到0~1，低库存按缺口比例从大到小排列
+#[tauri::command]
+pub async fn get_dashboard_kpis() -> Result<DashboardKpis, ApiError> {
+    require(Permission::DashboardView)?;
+
+    let mut kpis: DashboardKpis = AUTH_CLIENT.get_json("/api/dashboard/kpis").await?;
+
+    kpis.oee = kpis.oee.map(|factors| OeeFactors {
+        availability: ratio(factors.availability),
+        performance: ratio(factors.performance),
+        quality: ratio(factors.quality),
+    });
+    kpis.first_pass_yield = kpis.first_pass_yield.map(ratio);
+    kpis.low_stock.sort_by(|a, b| {
+        let shortage = |on_hand: f64, safety: f64| if safety > 0.0 { on_hand / safety } else { 1.0 };
+        shortage(a.on_hand, a.safety_stock).total_cmp(&shortage(b.on_hand, b.safety_stock))
+    });
+    Ok(kpis)
+}
+
+/// Tauri命令：获取当前用户的仪表板布局
+#[tauri::command]
+pub async fn get_dashboard_layout() -> Result<DashboardLayout, ApiError> {
+    Ok(LAYOUT_STORE.get(&layout_key()?))
+}
+
+/// Tauri命令：保存当前用户的仪表板布局
+#[tauri::command]
+pub async fn save_dashboard_layout(layout: DashboardLayout) -> Result<DashboardLayout, ApiError> {
+    check_fields(layout.validate())?;
+
+    LAYOUT_STORE
+        .save(&layout_key()?, layout.clone())
+        .map_err(|message| ApiError::Server { code: 500, message })?;
+    Ok(layout)
+}
@@ -0,0 +1,39 @@
+use mes_win_shared::error::FieldError;
+use crate::auth::refresh::RefreshError;
+
+// 错误类型定义在共享crate中，前后端共用
+pub use mes_win_shared::error::ApiError;
+
+/// 将HTTP客户端错误转换为 `ApiError`
+pub fn from_reqwest(e: reqwest::Error) -> ApiError {
+    if e.is_timeout() {
+        ApiError::Timeout { message: e.to_string() }
+    } else if e.is_decode() {
+        ApiError::Decode { message: e.to_string() }
+    } else if let Some(status) = e.status() {
+        ApiError::from_status(status.as_u16())
+    } else {
+        ApiError::Network { message: e.to_string() }
+    }
+}
+
+/// 表单校验结果转换为 `ApiError::Validation`（以第一条错误作为提示信息）
+pub fn check_fields(fields: Vec<FieldError>) -> Result<(), ApiError> {
+    if fields.is_empty() {
+        return Ok(());
+    }
+
+    Err(ApiError::Validation {
+        message: fields[0].message.clone(),
+        fields,
+    })
+}
+
+impl From<RefreshError> for ApiError {
+    fn from(e: RefreshError) -> Self {
+        match e {
+            RefreshError::Unavailable(_) => ApiError::Network { message: e.to_string() },
+            _ => ApiError::Unauthorized { message: e.to_string() },
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use mes_win_shared::inventory::finished_goods::{
+    CompletionRegistration, ProductStock, ProductStockQuery, SerialRecord, Shipment,
+};
+use mes_win_shared::production::work_order::WorkOrder;
+use mes_win_shared::response::PageData;
+use mes_win_shared::offline::TransactionKind;
+use mes_win_shared::permission::Permission;
+use crate::api::error::{check_fields, ApiError};
+use crate::auth::client::{path_segment, AUTH_CLIENT};
+use crate::auth::guard::require;
+use crate::offline::sync::{post_or_queue, precheck};
+use crate::production::work_order as state_machine;
+
+async fn fetch_stock(sku: &str) -> Result<ProductStock, ApiError> {
+    AUTH_CLIENT.get_json(&format!("/api/products/stock/{}", path_segment(sku))).await
+}
+
+/// 去掉序列号首尾空白
+fn normalize_serials(serials: Vec<String>) -> Vec<String> {
+    serials.into_iter().map(|serial| serial.trim().to_string()).collect()
+}
+
+/// Tauri命令：分页查询成品库存
+#[tauri::command]
+pub async fn list_product_stock(query: ProductStockQuery) -> Result<PageData<ProductStock>, ApiError> {
+    require(Permission::InventoryView)?;
+
+    let mut query = query;
+    query.page = query.page.max(1);
+    query.page_size = query.page_size.clamp(1, 200);
+    query.keyword = query
+        .keyword
+        .map(|keyword| keyword.trim().to_string())
+        .filter(|keyword| !keyword.is_empty());
+
+    AUTH_CLIENT.get_json_with_query("/api/products/stock", &query).await
+}
+
+/// Tauri命令：完工入库登记
+///
+/// 工单须处于生产中，登记数量不能超过工单剩余未完工数量；后端不可达时转入离线队列
+#[tauri::command]
+pub async fn register_completion(registration: CompletionRegistration) -> Result<ProductStock, ApiError> {
+    require(Permission::InventoryEdit)?;
+
+    let mut registration = registration;
+    registration.serials = normalize_serials(registration.serials);
+    check_fields(registration.validate())?;
+    precheck(check_completion(&registration)).await?;
+
+    let summary = format!("工单 #{} 完工入库 {}", registration.work_order_id, registration.quantity);
+    let stock: ProductStock =
+        post_or_queue(TransactionKind::Completion, summary, "/api/products/completions", &registration).await?;
+    println!("工单 #{} 完工入库 {} {}", registration.work_order_id, registration.quantity, stock.unit);
+    Ok(stock)
+}
+
+/// 完工登记前检查工单状态和剩余数量
+async fn check_completion(registration: &CompletionRegistration) -> Result<(), ApiError> {
+    let order: WorkOrder = AUTH_CLIENT
+        .get_json(&format!("/api/work-orders/{}", registration.work_order_id))
+        .await?;
+    if !state_machine::can_report_output(order.status) {
+        return Err(ApiError::invalid_field(
+            "workOrderId",
+            format!("工单 {} 为「{}」状态，不能登记完工", order.order_no, order.status.label()),
+        ));
+    }
+
+    let remaining = order.quantity - order.completed_quantity;
+    if registration.quantity > remaining {
+        return Err(ApiError::invalid_field(
+            "quantity",
+            format!("超出工单剩余数量（剩余 {} {}）", remaining, order.unit),
+        ));
+    }
+    Ok(())
+}
+
+/// Tauri命令：发货出库，扣减成品库存
+///
+/// 后端不可达时转入离线队列
+#[tauri::command]
+pub async fn ship_products(shipment: Shipment) -> Result<ProductStock, ApiError> {
+    require(Permission::InventoryEdit)?;
+
+    let mut shipment = shipment;
+    shipment.serials = normalize_serials(shipment.serials);
+    check_fields(shipment.validate())?;
+    precheck(check_shipment(&shipment)).await?;
+
+    let summary = format!("{} 发货 {}，客户 {}", shipment.sku, shipment.quantity, shipment.customer);
+    let stock: ProductStock =
+        post_or_queue(TransactionKind::Shipment, summary, "/api/products/shipments", &shipment).await?;
+    println!("{} 发货 {} {}，客户 {}", stock.sku, shipment.quantity, stock.unit, shipment.customer);
+    Ok(stock)
+}
+
+/// 发货前检查库存和序列号
+async fn check_shipment(shipment: &Shipment) -> Result<(), ApiError> {
+    let stock = fetch_stock(&shipment.sku).await?;
+    if shipment.quantity > stock.on_hand {
+        return Err(ApiError::invalid_field(
+            "quantity",
+            format!("{} 库存不足（在库 {} {}）", stock.sku, stock.on_hand, stock.unit),
+        ));
+    }
+    if stock.serial_tracked && shipment.serials.is_empty() {
+        return Err(ApiError::invalid_field("serials", format!("{} 按序列号管理，请录入发货序列号", stock.sku)));
+    }
+    Ok(())
+}
+
+/// Tauri命令：按序列号查询生产档案
+#[tauri::command]
+pub async fn find_serial(serial: String) -> Result<SerialRecord, ApiError> {
+    require(Permission::InventoryView)?;
+
+    let serial = serial.trim();
+    if serial.is_empty() {
+        return Err(ApiError::invalid_field("serial", "请输入序列号"));
+    }
+
+    AUTH_CLIENT.get_json(&format!("/api/products/serials/{}", path_segment(serial))).await
+}
@@ -0,0 +1,142 @@
+use serde::Serialize;
+use mes_win_shared::production::routing::RoutingStatus;
+use mes_win_shared::quality::plan::{InspectionPlan, InspectionPlanSummary, PlanStatus};
+use mes_win_shared::permission::Permission;
+use crate::api::error::{check_fields, ApiError};
+use crate::api::routings::{get_routing, list_routings};
+use crate::auth::client::AUTH_CLIENT;
+use crate::auth::guard::require;
+
+/// 检验标准查询参数
+#[derive(Debug, Serialize)]
+struct PlanQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keyword: Option<String>,
+}
+
+/// 只有草稿状态的检验标准允许修改
+fn ensure_draft(plan: &InspectionPlan) -> Result<(), ApiError> {
+    if plan.status == PlanStatus::Draft {
+        return Ok(());
+    }
+
+    Err(ApiError::invalid_field(
+        "status",
+        format!(
+            "{} 第{}版{}，不能修改，请先创建新版本",
+            plan.item_code,
+            plan.version,
+            plan.status.label()
+        ),
+    ))
+}
+
+/// 校验检验标准；工序检验须对应产品已发布工艺路线上的工序
+async fn validate_plan(plan: &InspectionPlan) -> Result<(), ApiError> {
+    check_fields(plan.validate())?;
+
+    let Some(seq) = plan.operation_seq else {
+        return Ok(());
+    };
+    let published = list_routings(Some(plan.item_code.clone()))
+        .await?
+        .into_iter()
+        .find(|routing| routing.product_code == plan.item_code && routing.status == RoutingStatus::Published);
+    let Some(summary) = published else {
+        return Err(ApiError::invalid_field(
+            "operationSeq",
+            format!("产品 {} 没有已发布的工艺路线", plan.item_code),
+        ));
+    };
+    let routing = get_routing(summary.id).await?;
+    if !routing.operations.iter().any(|operation| operation.seq == seq) {
+        return Err(ApiError::invalid_field(
+            "operationSeq",
+            format!("工艺路线第{}版中不存在工序 {}", routing.version, seq),
+        ));
+    }
+    Ok(())
+}
+
+pub(crate) async fn fetch_plan(id: u64) -> Result<InspectionPlan, ApiError> {
+    AUTH_CLIENT.get_json(&format!("/api/inspection-plans/{}", id)).await
+}
+
+/// Tauri命令：查询检验标准（按物料/产品编码或名称）
+#[tauri::command]
+pub async fn list_inspection_plans(keyword: Option<String>) -> Result<Vec<InspectionPlanSummary>, ApiError> {
+    require(Permission::QualityView)?;
+
+    let query = PlanQuery {
+        keyword: keyword
+            .map(|keyword| keyword.trim().to_string())
+            .filter(|keyword| !keyword.is_empty()),
+    };
+    AUTH_CLIENT.get_json_with_query("/api/inspection-plans", &query).await
+}
+
+/// Tauri命令：获取检验标准详情
+#[tauri::command]
+pub async fn get_inspection_plan(id: u64) -> Result<InspectionPlan, ApiError> {
+    require(Permission::QualityView)?;
+
+    fetch_plan(id).await
+}
+
+/// Tauri命令：保存检验标准草稿
+#[tauri::command]
+pub async fn save_inspection_plan(plan: InspectionPlan) -> Result<InspectionPlan, ApiError> {
+    require(Permission::QualityEdit)?;
+
+    let mut plan = plan;
+    plan.item_code = plan.item_code.trim().to_string();
+    for characteristic in plan.characteristics.iter_mut() {
+        characteristic.code = characteristic.code.trim().to_string();
+    }
+    validate_plan(&plan).await?;
+
+    match plan.id {
+        Some(id) => {
+            let current = fetch_plan(id).await?;
+            ensure_draft(&current)?;
+            AUTH_CLIENT.put_json(&format!("/api/inspection-plans/{}", id), &plan).await
+        }
+        None => {
+            plan.status = PlanStatus::Draft;
+            AUTH_CLIENT.post_json("/api/inspection-plans", &plan).await
+        }
+    }
+}
+
+/// Tauri命令：发布检验标准
+///
+/// 发布后新建的检验批使用该版本，已有检验记录仍引用当时的版本
+#[tauri::command]
+pub async fn publish_inspection_plan(id: u64) -> Result<InspectionPlan, ApiError> {
+    require(Permission::QualityEdit)?;
+
+    let current = fetch_plan(id).await?;
+    ensure_draft(&current)?;
+    validate_plan(&current).await?;
+
+    let plan: InspectionPlan = AUTH_CLIENT
+        .post_json(&format!("/api/inspection-plans/{}/publish", id), &serde_json::json!({}))
+        .await?;
+    println!("检验标准已发布: {} 第{}版", plan.item_code, plan.version);
+    Ok(plan)
+}
+
+/// Tauri命令：基于已有版本创建新的草稿版本
+#[tauri::command]
+pub async fn new_inspection_plan_version(id: u64) -> Result<InspectionPlan, ApiError> {
+    require(Permission::QualityEdit)?;
+
+    let current = fetch_plan(id).await?;
+    if current.status == PlanStatus::Draft {
+        return Err(ApiError::invalid_field("status", "草稿版本可直接编辑，无需创建新版本"));
+    }
+
+    AUTH_CLIENT
+        .post_json(&format!("/api/inspection-plans/{}/versions", id), &serde_json::json!({}))
+        .await
+}
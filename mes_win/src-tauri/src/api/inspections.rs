@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use serde::Serialize;
+use mes_win_shared::inventory::material::MaterialLot;
+use mes_win_shared::production::work_order::WorkOrder;
+use mes_win_shared::quality::inspection::{
+    InspectionLot, InspectionLotDraft, InspectionLotQuery, InspectionRecord, InspectionSource,
+    InspectionStatus, Measurement,
+};
+use mes_win_shared::response::PageData;
+use mes_win_shared::offline::TransactionKind;
+use mes_win_shared::permission::Permission;
+use crate::api::error::{check_fields, ApiError};
+use crate::api::inspection_plans::fetch_plan;
+use crate::auth::client::{path_segment, AUTH_CLIENT};
+use crate::auth::guard::require;
+use crate::offline::queue::{OfflineQueue, Owner, OFFLINE_QUEUE};
+use crate::offline::sync::{current_owner, post_or_queue};
+use crate::production::work_order as state_machine;
+
+/// 检验结果提交内容（含自动判定结果）
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InspectionResult<'a> {
+    measurements: &'a [Measurement],
+    remark: Option<&'a str>,
+    result: InspectionStatus,
+}
+
+/// 检验批的接收数/拒收数
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LotAcceptance {
+    accept: u32,
+    reject: u32,
+}
+
+// 最近打开过的检验批详情，离线时据此校验和判定检验结果
+lazy_static::lazy_static! {
+    static ref OPENED_LOTS: Mutex<HashMap<u64, InspectionLot>> = Mutex::new(HashMap::new());
+}
+
+fn cached_inspection_lot(id: u64) -> Option<InspectionLot> {
+    OPENED_LOTS.lock().ok().and_then(|lots| lots.get(&id).cloned())
+}
+
+fn cache_inspection_lot(lot: &InspectionLot) {
+    if let Ok(mut lots) = OPENED_LOTS.lock() {
+        lots.insert(lot.id, lot.clone());
+    }
+}
+
+async fn fetch_inspection_lot(id: u64) -> Result<InspectionLot, ApiError> {
+    let lot: InspectionLot = AUTH_CLIENT.get_json(&format!("/api/inspections/{}", id)).await?;
+    cache_inspection_lot(&lot);
+    Ok(lot)
+}
+
+fn result_path(id: u64) -> String {
+    format!("/api/inspections/{}/results", id)
+}
+
+/// 检验结果已在离线队列中等待重放时不允许再次提交
+///
+/// 以队列为准判断：重放前后端返回的检验批仍为待检状态，不能据此判断是否已提交
+fn check_not_queued(queue: &OfflineQueue, owner: Option<&Owner>, path: &str, lot: &InspectionLot) -> Result<(), ApiError> {
+    if owner.is_some_and(|owner| queue.has_queued(owner, TransactionKind::InspectionResult, path)) {
+        return Err(ApiError::invalid_field(
+            "status",
+            format!("检验批 {} 的检验结果已离线提交，等待同步，不能重复提交", lot.inspection_no),
+        ));
+    }
+    Ok(())
+}
+
+/// Tauri命令：分页查询检验批
+#[tauri::command]
+pub async fn list_inspection_lots(query: InspectionLotQuery) -> Result<PageData<InspectionLot>, ApiError> {
+    require(Permission::QualityView)?;
+
+    let mut query = query;
+    query.page = query.page.max(1);
+    query.page_size = query.page_size.clamp(1, 200);
+    query.keyword = query
+        .keyword
+        .map(|keyword| keyword.trim().to_string())
+        .filter(|keyword| !keyword.is_empty());
+
+    AUTH_CLIENT.get_json_with_query("/api/inspections", &query).await
+}
+
+/// Tauri命令：新建检验批
+///
+/// 来料检验须对应已入库的批次；工序检验的工单须处于生产中；
+/// 创建后按检验标准的抽样方案记录接收数/拒收数
+#[tauri::command]
+pub async fn open_inspection_lot(draft: InspectionLotDraft) -> Result<InspectionLot, ApiError> {
+    require(Permission::QualityEdit)?;
+
+    check_fields(draft.validate())?;
+
+    match &draft.source {
+        InspectionSource::Receipt { lot_no } => {
+            let _: MaterialLot = AUTH_CLIENT
+                .get_json(&format!("/api/materials/lots/{}", path_segment(lot_no.trim())))
+                .await?;
+        }
+        InspectionSource::Operation { work_order_id, .. } => {
+            let order: WorkOrder = AUTH_CLIENT
+                .get_json(&format!("/api/work-orders/{}", work_order_id))
+                .await?;
+            if !state_machine::can_report_output(order.status) {
+                return Err(ApiError::invalid_field(
+                    "workOrderId",
+                    format!("工单 {} 为「{}」状态，不能进行工序检验", order.order_no, order.status.label()),
+                ));
+            }
+        }
+    }
+
+    let lot: InspectionLot = AUTH_CLIENT.post_json("/api/inspections", &draft).await?;
+    let Some(plan_id) = lot.plan_id else {
+        return Ok(lot);
+    };
+
+    // 按生成该检验批的检验标准记录 Ac/Re，之后的判定不再受标准改版影响
+    let plan = fetch_plan(plan_id).await?;
+    let (accept, reject) = plan.sampling.accept_reject(lot.quantity);
+    let lot: InspectionLot = AUTH_CLIENT
+        .put_json(&format!("/api/inspections/{}/acceptance", lot.id), &LotAcceptance { accept, reject })
+        .await?;
+    cache_inspection_lot(&lot);
+    Ok(lot)
+}
+
+/// Tauri命令：获取检验批详情
+#[tauri::command]
+pub async fn get_inspection_lot(id: u64) -> Result<InspectionLot, ApiError> {
+    require(Permission::QualityView)?;
+
+    fetch_inspection_lot(id).await
+}
+
+/// Tauri命令：提交检验结果
+///
+/// 所有特性的所有样本须录入完整，按检验值自动判定接收或拒收；
+/// 后端不可达时按打开检验批时缓存的详情判定，结果转入离线队列
+#[tauri::command]
+pub async fn submit_inspection(id: u64, record: InspectionRecord) -> Result<InspectionLot, ApiError> {
+    require(Permission::QualityEdit)?;
+
+    let path = result_path(id);
+    let current = match fetch_inspection_lot(id).await {
+        Ok(lot) => lot,
+        Err(e) if e.is_unreachable() => cached_inspection_lot(id).ok_or(e)?,
+        Err(e) => return Err(e),
+    };
+    check_not_queued(&OFFLINE_QUEUE, current_owner().as_ref(), &path, &current)?;
+    if current.status != InspectionStatus::Open {
+        return Err(ApiError::invalid_field(
+            "status",
+            format!("检验批 {} 已判定为「{}」，不能重复提交", current.inspection_no, current.status.label()),
+        ));
+    }
+
+    let lot = InspectionLot {
+        measurements: record.measurements,
+        ..current
+    };
+    check_fields(lot.validate_measurements())?;
+    let Some(result) = lot.decide() else {
+        return Err(ApiError::invalid_field("measurements", "检验值未录入完整"));
+    };
+
+    let summary = format!("检验批 {} 判定为{}", lot.inspection_no, result.label());
+    let submitted: InspectionLot = match post_or_queue(
+        TransactionKind::InspectionResult,
+        summary,
+        &path,
+        &InspectionResult {
+            measurements: &lot.measurements,
+            remark: record.remark.as_deref().map(str::trim).filter(|remark| !remark.is_empty()),
+            result,
+        },
+    )
+    .await
+    {
+        Err(e @ ApiError::Queued { .. }) => {
+            cache_inspection_lot(&InspectionLot { status: result, ..lot });
+            return Err(e);
+        }
+        submitted => submitted?,
+    };
+    cache_inspection_lot(&submitted);
+    println!(
+        "检验批 {} 判定为{}，不合格品 {} 件",
+        submitted.inspection_no,
+        result.label(),
+        lot.defective_count()
+    );
+    Ok(submitted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open_lot(id: u64) -> InspectionLot {
+        InspectionLot {
+            id,
+            inspection_no: format!("IQC-{}", id),
+            source: InspectionSource::Receipt { lot_no: "L001".into() },
+            item_code: "M-001".into(),
+            item_name: String::new(),
+            source_ref: String::new(),
+            quantity: 100.0,
+            sample_size: 5,
+            accept: 0,
+            reject: 1,
+            plan_id: None,
+            plan_version: None,
+            characteristics: Vec::new(),
+            measurements: Vec::new(),
+            status: InspectionStatus::Open,
+            inspector: None,
+            created_at: "2026-10-01 08:00".into(),
+            decided_at: None,
+            remark: None,
+        }
+    }
+
+    fn owner(username: &str) -> Owner {
+        Owner { profile: "default".into(), username: username.into() }
+    }
+
+    /// 模拟离线时的一次提交：通过重复提交检查后入队
+    fn submit(queue: &OfflineQueue, owner: &Owner, lot: &InspectionLot) -> Result<u64, ApiError> {
+        let path = result_path(lot.id);
+        check_not_queued(queue, Some(owner), &path, lot)?;
+        Ok(queue
+            .enqueue(owner, TransactionKind::InspectionResult, "检验", &path, &serde_json::json!({}), &format!("key-{}", path))
+            .unwrap())
+    }
+
+    #[test]
+    fn retries_after_offline_submission_are_rejected() {
+        let queue = OfflineQueue::in_memory();
+        let inspector = owner("qc01");
+        // 重放前后端返回的检验批始终是待检状态
+        let lot = open_lot(7);
+
+        assert!(submit(&queue, &inspector, &lot).is_ok(), "首次提交应入队");
+        for attempt in 1..=2 {
+            assert!(
+                matches!(submit(&queue, &inspector, &lot), Err(ApiError::Validation { .. })),
+                "第 {} 次重试应被拒绝",
+                attempt
+            );
+        }
+        assert_eq!(queue.pending(&inspector).unwrap().len(), 1, "队列中只能有一条检验结果");
+    }
+
+    #[test]
+    fn queued_result_is_scoped_to_lot_and_owner() {
+        let queue = OfflineQueue::in_memory();
+        let inspector = owner("qc01");
+        submit(&queue, &inspector, &open_lot(7)).unwrap();
+
+        assert!(submit(&queue, &inspector, &open_lot(8)).is_ok(), "其他检验批不受影响");
+        assert!(submit(&queue, &owner("qc02"), &open_lot(7)).is_ok(), "其他用户的队列互不影响");
+    }
+
+    #[test]
+    fn lot_can_be_submitted_again_after_discard() {
+        let queue = OfflineQueue::in_memory();
+        let inspector = owner("qc01");
+        let id = submit(&queue, &inspector, &open_lot(7)).unwrap();
+
+        assert!(queue.discard(&inspector, id).unwrap());
+        assert!(submit(&queue, &inspector, &open_lot(7)).is_ok(), "放弃后可重新提交");
+    }
+}
@@ -0,0 +1,199 @@
+use serde::Serialize;
+use mes_win_shared::inventory::warehouse::{
+    Bin, BinStock, BinTransfer, CountReview, CycleCount, CycleCountDraft, CycleCountLine,
+    CycleCountStatus, TransferRecord, Warehouse,
+};
+use mes_win_shared::offline::TransactionKind;
+use mes_win_shared::permission::Permission;
+use crate::api::error::{check_fields, ApiError};
+use crate::auth::client::{path_segment, AUTH_CLIENT};
+use crate::auth::guard::require;
+use crate::auth::store::USER_STORE;
+use crate::offline::sync::{post_or_queue, precheck};
+
+/// 盘点单查询参数
+#[derive(Debug, Serialize)]
+struct CycleCountQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warehouse: Option<String>,
+}
+
+/// 盘点结果提交内容
+#[derive(Debug, Serialize)]
+struct CountSubmission<'a> {
+    lines: &'a [CycleCountLine],
+}
+
+async fn fetch_bin(code: &str) -> Result<Bin, ApiError> {
+    AUTH_CLIENT.get_json(&format!("/api/warehouse/bins/{}", path_segment(code))).await
+}
+
+async fn fetch_cycle_count(id: u64) -> Result<CycleCount, ApiError> {
+    AUTH_CLIENT.get_json(&format!("/api/cycle-counts/{}", id)).await
+}
+
+/// Tauri命令：获取仓库结构（仓库 → 库区 → 库位，含占用情况）
+#[tauri::command]
+pub async fn list_warehouses() -> Result<Vec<Warehouse>, ApiError> {
+    require(Permission::InventoryView)?;
+
+    AUTH_CLIENT.get_json("/api/warehouses").await
+}
+
+/// Tauri命令：查询库位上的库存明细
+#[tauri::command]
+pub async fn list_bin_stock(bin: String) -> Result<Vec<BinStock>, ApiError> {
+    require(Permission::InventoryView)?;
+
+    AUTH_CLIENT
+        .get_json(&format!("/api/warehouse/bins/{}/stock", path_segment(bin.trim())))
+        .await
+}
+
+/// Tauri命令：库位间移库
+///
+/// 来源库位须有足够库存，目标库位不能被冻结且剩余容量充足；后端不可达时转入离线队列
+#[tauri::command]
+pub async fn transfer_stock(transfer: BinTransfer) -> Result<TransferRecord, ApiError> {
+    require(Permission::InventoryEdit)?;
+
+    check_fields(transfer.validate())?;
+    precheck(check_transfer(&transfer)).await?;
+
+    let summary = format!(
+        "物料 {} 从库位 {} 移至 {}，数量 {}",
+        transfer.item_code, transfer.from_bin, transfer.to_bin, transfer.quantity
+    );
+    // 转入离线队列时由 post_or_queue 记录入队日志，这里只记录已提交的移库
+    let record: TransferRecord =
+        post_or_queue(TransactionKind::BinTransfer, summary.clone(), "/api/warehouse/transfers", &transfer).await?;
+    println!("移库已提交：{}", summary);
+    Ok(record)
+}
+
+/// 移库前检查来源库位库存和目标库位状态、容量
+async fn check_transfer(transfer: &BinTransfer) -> Result<(), ApiError> {
+    let stock = list_bin_stock(transfer.from_bin.clone()).await?;
+    let available: f64 = stock
+        .iter()
+        .filter(|s| s.item_code == transfer.item_code && s.lot_no == transfer.lot_no)
+        .map(|s| s.quantity)
+        .sum();
+    if transfer.quantity > available {
+        return Err(ApiError::invalid_field(
+            "quantity",
+            format!("库位 {} 库存不足（可移 {}）", transfer.from_bin, available),
+        ));
+    }
+
+    let target = fetch_bin(&transfer.to_bin).await?;
+    if target.blocked {
+        return Err(ApiError::invalid_field("toBin", format!("库位 {} 已冻结，不能移入", target.code)));
+    }
+    if let Some(room) = target.available() {
+        if transfer.quantity > room {
+            return Err(ApiError::invalid_field(
+                "toBin",
+                format!("库位 {} 剩余容量不足（剩余 {}）", target.code, room),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Tauri命令：查询盘点单（可按仓库过滤）
+#[tauri::command]
+pub async fn list_cycle_counts(warehouse: Option<String>) -> Result<Vec<CycleCount>, ApiError> {
+    require(Permission::InventoryView)?;
+
+    let query = CycleCountQuery {
+        warehouse: warehouse
+            .map(|warehouse| warehouse.trim().to_string())
+            .filter(|warehouse| !warehouse.is_empty()),
+    };
+    AUTH_CLIENT.get_json_with_query("/api/cycle-counts", &query).await
+}
+
+/// Tauri命令：新建盘点单
+///
+/// 后端按仓库/库区生成盘点明细，并冻结相关库位
+#[tauri::command]
+pub async fn start_cycle_count(draft: CycleCountDraft) -> Result<CycleCount, ApiError> {
+    require(Permission::InventoryEdit)?;
+
+    if draft.warehouse_code.trim().is_empty() {
+        return Err(ApiError::invalid_field("warehouseCode", "请选择仓库"));
+    }
+
+    AUTH_CLIENT.post_json("/api/cycle-counts", &draft).await
+}
+
+/// Tauri命令：获取盘点单详情
+#[tauri::command]
+pub async fn get_cycle_count(id: u64) -> Result<CycleCount, ApiError> {
+    require(Permission::InventoryView)?;
+
+    fetch_cycle_count(id).await
+}
+
+/// Tauri命令：提交实盘数量
+///
+/// 只有盘点中或已驳回的盘点单可以提交，所有明细须录入实盘数量；
+/// 明细按（库位, 物料, 批次）对应到盘点单，只提交实盘数量
+#[tauri::command]
+pub async fn submit_cycle_count(id: u64, lines: Vec<CycleCountLine>) -> Result<CycleCount, ApiError> {
+    require(Permission::InventoryEdit)?;
+
+    let current = fetch_cycle_count(id).await?;
+    if !current.status.is_editable() {
+        return Err(ApiError::invalid_field(
+            "status",
+            format!("盘点单 {} 为「{}」状态，不能提交", current.count_no, current.status.label()),
+        ));
+    }
+
+    let mut submitted = current;
+    check_fields(submitted.apply_counts(&lines))?;
+    check_fields(submitted.validate_counts())?;
+
+    AUTH_CLIENT
+        .post_json(
+            &format!("/api/cycle-counts/{}/submit", id),
+            &CountSubmission { lines: &submitted.lines },
+        )
+        .await
+}
+
+/// Tauri命令：审批盘点差异
+///
+/// 审批人须与提交人不同；批准后按差异调整库存并解冻库位；驳回须填写原因，驳回后可重新录入实盘数量再提交
+#[tauri::command]
+pub async fn review_cycle_count(id: u64, review: CountReview) -> Result<CycleCount, ApiError> {
+    require(Permission::InventoryEdit)?;
+
+    let current = fetch_cycle_count(id).await?;
+    if current.status != CycleCountStatus::Submitted {
+        return Err(ApiError::invalid_field(
+            "status",
+            format!("盘点单 {} 为「{}」状态，不能审批", current.count_no, current.status.label()),
+        ));
+    }
+    let (username, _) = USER_STORE.get_current_user_info().ok_or_else(ApiError::unauthorized)?;
+    if current.submitted_by.as_deref() == Some(username.as_str()) {
+        return Err(ApiError::forbidden("不能审批自己提交的盘点结果，请由其他人审批"));
+    }
+    if !review.approved && review.remark.as_deref().is_none_or(|remark| remark.trim().is_empty()) {
+        return Err(ApiError::invalid_field("remark", "驳回时请填写原因"));
+    }
+
+    let reviewed: CycleCount = AUTH_CLIENT
+        .post_json(&format!("/api/cycle-counts/{}/review", id), &review)
+        .await?;
+    println!(
+        "盘点单 {} {}，差异 {} 项",
+        reviewed.count_no,
+        reviewed.status.label(),
+        reviewed.variance_count()
+    );
+    Ok(reviewed)
+}
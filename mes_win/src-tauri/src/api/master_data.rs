@@ -0,0 +1,55 @@
+use mes_win_shared::master_data::{
+    MasterDataItem, MasterDataKind, MasterDataStatus, DEFAULT_LOOKUP_LIMIT, MAX_LOOKUP_LIMIT,
+};
+use crate::api::error::ApiError;
+use crate::auth::guard::{has_any, require_any};
+use crate::auth::store::USER_STORE;
+use crate::master_data::store::MASTER_DATA_STORE;
+use crate::master_data::sync::{current_profile, sync_all};
+
+fn require_login() -> Result<(), ApiError> {
+    USER_STORE.get_current_user_info().map(|_| ()).ok_or_else(ApiError::unauthorized)
+}
+
+/// Tauri命令：获取本地主数据缓存状态
+#[tauri::command]
+pub async fn get_master_data_status() -> Result<Vec<MasterDataStatus>, ApiError> {
+    require_login()?;
+
+    readable_status()
+}
+
+/// Tauri命令：立即同步主数据（`full` 为 true 时全量重建）
+#[tauri::command]
+pub async fn sync_master_data(full: bool) -> Result<Vec<MasterDataStatus>, ApiError> {
+    require_login()?;
+
+    sync_all(full).await?;
+    readable_status()
+}
+
+/// 当前用户有权读取的各类缓存状态
+fn readable_status() -> Result<Vec<MasterDataStatus>, ApiError> {
+    let statuses = MASTER_DATA_STORE
+        .status(&current_profile())
+        .map_err(|message| ApiError::Server { code: 500, message })?;
+    Ok(statuses
+        .into_iter()
+        .filter(|status| has_any(status.kind.required_permissions()))
+        .collect())
+}
+
+/// Tauri命令：在本地缓存中联想查询主数据（不访问后端）
+#[tauri::command]
+pub async fn lookup_master_data(
+    kind: MasterDataKind,
+    keyword: String,
+    limit: Option<u32>,
+) -> Result<Vec<MasterDataItem>, ApiError> {
+    require_any(kind.required_permissions())?;
+
+    let limit = limit.unwrap_or(DEFAULT_LOOKUP_LIMIT).clamp(1, MAX_LOOKUP_LIMIT);
+    MASTER_DATA_STORE
+        .lookup(&current_profile(), kind, &keyword, limit)
+        .map_err(|message| ApiError::Server { code: 500, message })
+}
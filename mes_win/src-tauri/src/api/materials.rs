@@ -0,0 +1,123 @@
+use mes_win_shared::inventory::material::{
+    ExpiryState, GoodsReceipt, LotTrace, LotTransaction, MaterialIssue, MaterialLot, MaterialStock,
+    MaterialStockQuery,
+};
+use mes_win_shared::production::work_order::WorkOrder;
+use mes_win_shared::response::PageData;
+use mes_win_shared::offline::TransactionKind;
+use mes_win_shared::permission::Permission;
+use crate::api::error::{check_fields, ApiError};
+use crate::auth::client::{path_segment, AUTH_CLIENT};
+use crate::auth::guard::require;
+use crate::auth::store::now_secs;
+use crate::offline::sync::{post_or_queue, precheck};
+use crate::production::work_order as state_machine;
+
+/// 今天（自1970-01-01起的天数）
+fn today() -> i64 {
+    (now_secs() / 86_400) as i64
+}
+
+async fn fetch_lot(lot_no: &str) -> Result<MaterialLot, ApiError> {
+    AUTH_CLIENT.get_json(&format!("/api/materials/lots/{}", path_segment(lot_no))).await
+}
+
+/// Tauri命令：分页查询原材料库存（按物料汇总，含批次明细）
+#[tauri::command]
+pub async fn list_material_stock(query: MaterialStockQuery) -> Result<PageData<MaterialStock>, ApiError> {
+    require(Permission::InventoryView)?;
+
+    let mut query = query;
+    query.page = query.page.max(1);
+    query.page_size = query.page_size.clamp(1, 200);
+    query.keyword = query
+        .keyword
+        .map(|keyword| keyword.trim().to_string())
+        .filter(|keyword| !keyword.is_empty());
+
+    AUTH_CLIENT.get_json_with_query("/api/materials/stock", &query).await
+}
+
+/// Tauri命令：原材料收货入库
+///
+/// 未指定批次号时由后端生成，返回入库后的批次；后端不可达时转入离线队列
+#[tauri::command]
+pub async fn receive_material(receipt: GoodsReceipt) -> Result<MaterialLot, ApiError> {
+    require(Permission::InventoryEdit)?;
+
+    check_fields(receipt.validate())?;
+
+    let summary = format!("物料 {} 收货 {} {}", receipt.material_code, receipt.quantity, receipt.unit);
+    let lot: MaterialLot =
+        post_or_queue(TransactionKind::GoodsReceipt, summary, "/api/materials/receipts", &receipt).await?;
+    println!("物料 {} 收货入库，批次 {}，数量 {}", lot.material_code, lot.lot_no, receipt.quantity);
+    Ok(lot)
+}
+
+/// Tauri命令：按批次发料到工单
+///
+/// 工单须处于已下达或生产中状态，批次须属于该物料、未过期且库存充足；
+/// 离线时跳过这些检查，由重放时后端校验
+#[tauri::command]
+pub async fn issue_material(issue: MaterialIssue) -> Result<LotTransaction, ApiError> {
+    require(Permission::InventoryEdit)?;
+
+    check_fields(issue.validate())?;
+    precheck(check_issue(&issue)).await?;
+
+    let summary = format!("批次 {} 发料 {} 到工单 #{}", issue.lot_no, issue.quantity, issue.work_order_id);
+    let transaction: LotTransaction =
+        post_or_queue(TransactionKind::MaterialIssue, summary, "/api/materials/issues", &issue).await?;
+    println!("批次 {} 发料 {} 到工单 #{}", issue.lot_no, issue.quantity, issue.work_order_id);
+    Ok(transaction)
+}
+
+/// 发料前检查工单状态和批次
+async fn check_issue(issue: &MaterialIssue) -> Result<(), ApiError> {
+    let order: WorkOrder = AUTH_CLIENT
+        .get_json(&format!("/api/work-orders/{}", issue.work_order_id))
+        .await?;
+    if !state_machine::can_consume_material(order.status) {
+        return Err(ApiError::invalid_field(
+            "workOrderId",
+            format!("工单 {} 为「{}」状态，不能发料", order.order_no, order.status.label()),
+        ));
+    }
+
+    let lot = fetch_lot(&issue.lot_no).await?;
+    if lot.material_code != issue.material_code {
+        return Err(ApiError::invalid_field(
+            "lotNo",
+            format!("批次 {} 不属于物料 {}", lot.lot_no, issue.material_code),
+        ));
+    }
+    if lot.expiry_state(today()) == ExpiryState::Expired {
+        return Err(ApiError::invalid_field("lotNo", format!("批次 {} 已过期，不能发料", lot.lot_no)));
+    }
+    if issue.quantity > lot.quantity {
+        return Err(ApiError::invalid_field(
+            "quantity",
+            format!("批次 {} 库存不足（剩余 {} {}）", lot.lot_no, lot.quantity, lot.unit),
+        ));
+    }
+    Ok(())
+}
+
+/// Tauri命令：批次追溯
+///
+/// 返回批次信息及从收货到工单消耗的全部事务（按时间排序）
+#[tauri::command]
+pub async fn trace_lot(lot: String) -> Result<LotTrace, ApiError> {
+    require(Permission::InventoryView)?;
+
+    let lot_no = lot.trim();
+    if lot_no.is_empty() {
+        return Err(ApiError::invalid_field("lot", "请输入批次号"));
+    }
+
+    let mut trace: LotTrace = AUTH_CLIENT
+        .get_json(&format!("/api/materials/lots/{}/trace", path_segment(lot_no)))
+        .await?;
+    trace.transactions.sort_by(|a, b| a.occurred_at.cmp(&b.occurred_at));
+    Ok(trace)
+}
@@ -1 +1,20 @@
+pub mod error;
 pub mod auth;
+pub mod config;
+pub mod dashboard;
+pub mod work_orders;
+pub mod schedule;
+pub mod routings;
+pub mod materials;
+pub mod finished_goods;
+pub mod inventory;
+pub mod inspections;
+pub mod inspection_plans;
+pub mod ncrs;
+pub mod spc;
+pub mod reports;
+pub mod users;
+pub mod settings;
+pub mod offline;
+pub mod master_data;
+pub mod search;
@@ -0,0 +1,238 @@
+use serde::Serialize;
+use mes_win_shared::quality::inspection::{InspectionLot, InspectionStatus};
+use mes_win_shared::quality::ncr::{
+    AttachmentUpload, AuditEntry, Ncr, NcrDetail, NcrDraft, NcrQuery, NcrStatus, NcrUpdate, Severity,
+    MAX_ATTACHMENT_BYTES,
+};
+use mes_win_shared::response::PageData;
+use mes_win_shared::permission::Permission;
+use crate::api::error::{check_fields, ApiError};
+use crate::auth::client::AUTH_CLIENT;
+use crate::auth::guard::require;
+use crate::quality::ncr as state_machine;
+
+/// 新建请求体：可关联来源检验批
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateNcrRequest<'a> {
+    #[serde(flatten)]
+    draft: &'a NcrDraft,
+    inspection_id: Option<u64>,
+}
+
+/// 状态变更请求体
+#[derive(Debug, Serialize)]
+struct StatusChangeRequest {
+    status: NcrStatus,
+    comment: Option<String>,
+}
+
+/// 附件上传的查询参数
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AttachmentQuery<'a> {
+    file_name: &'a str,
+}
+
+async fn fetch_ncr(id: u64) -> Result<Ncr, ApiError> {
+    AUTH_CLIENT.get_json(&format!("/api/ncrs/{}", id)).await
+}
+
+/// 组装详情：附带状态机计算出的可执行操作和审计记录
+async fn to_detail(ncr: Ncr) -> Result<NcrDetail, ApiError> {
+    let mut history: Vec<AuditEntry> = AUTH_CLIENT
+        .get_json(&format!("/api/ncrs/{}/history", ncr.id))
+        .await?;
+    history.sort_by(|a, b| b.occurred_at.cmp(&a.occurred_at));
+
+    Ok(NcrDetail {
+        transitions: state_machine::transitions(ncr.status).to_vec(),
+        editable: state_machine::is_editable(ncr.status),
+        history,
+        ncr,
+    })
+}
+
+async fn create(draft: &NcrDraft, inspection_id: Option<u64>) -> Result<NcrDetail, ApiError> {
+    check_fields(draft.validate())?;
+
+    let ncr: Ncr = AUTH_CLIENT
+        .post_json("/api/ncrs", &CreateNcrRequest { draft, inspection_id })
+        .await?;
+    println!("已创建不合格报告 {}", ncr.ncr_no);
+    to_detail(ncr).await
+}
+
+/// Tauri命令：分页查询不合格报告
+#[tauri::command]
+pub async fn list_ncrs(query: NcrQuery) -> Result<PageData<Ncr>, ApiError> {
+    require(Permission::QualityView)?;
+
+    let mut query = query;
+    query.page = query.page.max(1);
+    query.page_size = query.page_size.clamp(1, 200);
+    query.keyword = query
+        .keyword
+        .map(|keyword| keyword.trim().to_string())
+        .filter(|keyword| !keyword.is_empty());
+
+    AUTH_CLIENT.get_json_with_query("/api/ncrs", &query).await
+}
+
+/// Tauri命令：获取不合格报告详情
+#[tauri::command]
+pub async fn get_ncr(id: u64) -> Result<NcrDetail, ApiError> {
+    require(Permission::QualityView)?;
+
+    to_detail(fetch_ncr(id).await?).await
+}
+
+/// Tauri命令：新建不合格报告
+#[tauri::command]
+pub async fn create_ncr(draft: NcrDraft) -> Result<NcrDetail, ApiError> {
+    require(Permission::QualityEdit)?;
+
+    create(&draft, None).await
+}
+
+/// Tauri命令：由拒收的检验批创建不合格报告
+///
+/// 以不合格特性生成问题描述，不合格数量取送检数量
+#[tauri::command]
+pub async fn create_ncr_from_inspection(inspection: u64) -> Result<NcrDetail, ApiError> {
+    require(Permission::QualityEdit)?;
+
+    let lot: InspectionLot = AUTH_CLIENT
+        .get_json(&format!("/api/inspections/{}", inspection))
+        .await?;
+    if lot.status != InspectionStatus::Rejected {
+        return Err(ApiError::invalid_field(
+            "inspection",
+            format!("检验批 {} 为「{}」，只有拒收的检验批可以创建不合格报告", lot.inspection_no, lot.status.label()),
+        ));
+    }
+
+    let failed: Vec<_> = lot
+        .characteristics
+        .iter()
+        .filter(|characteristic| {
+            lot.measurements.iter().any(|m| {
+                m.characteristic_code == characteristic.code && !characteristic.is_conforming(&m.value)
+            })
+        })
+        .collect();
+    // 关键特性不合格时直接定为严重
+    let severity = if failed.iter().any(|characteristic| characteristic.critical) {
+        Severity::Critical
+    } else {
+        Severity::default()
+    };
+    let failed: Vec<String> = failed
+        .iter()
+        .map(|characteristic| format!("{}（{}）", characteristic.name, characteristic.specification()))
+        .collect();
+    let draft = NcrDraft {
+        title: format!("{} {} 检验不合格", lot.item_code, lot.source.label()),
+        item_code: lot.item_code.clone(),
+        quantity: lot.quantity,
+        severity,
+        owner: None,
+        description: format!(
+            "检验批 {}（{}）拒收，不合格特性：{}",
+            lot.inspection_no,
+            lot.source_ref,
+            failed.join("；")
+        ),
+    };
+    create(&draft, Some(lot.id)).await
+}
+
+/// Tauri命令：修改责任人、严重程度和8D报告
+#[tauri::command]
+pub async fn update_ncr(id: u64, update: NcrUpdate) -> Result<NcrDetail, ApiError> {
+    require(Permission::QualityEdit)?;
+
+    let current = fetch_ncr(id).await?;
+    if !state_machine::is_editable(current.status) {
+        return Err(ApiError::invalid_field(
+            "status",
+            format!("不合格报告 {} 已关闭，不能修改", current.ncr_no),
+        ));
+    }
+
+    let update = NcrUpdate {
+        owner: update.owner.map(|owner| owner.trim().to_string()).filter(|owner| !owner.is_empty()),
+        severity: update.severity,
+        report: update.report,
+    };
+    let ncr: Ncr = AUTH_CLIENT.put_json(&format!("/api/ncrs/{}", id), &update).await?;
+    to_detail(ncr).await
+}
+
+/// Tauri命令：变更不合格报告状态
+///
+/// 由状态机校验流转是否合法及所需8D步骤是否完成，变更记录写入审计记录
+#[tauri::command]
+pub async fn transition_ncr(id: u64, target: NcrStatus, comment: Option<String>) -> Result<NcrDetail, ApiError> {
+    require(Permission::QualityEdit)?;
+
+    let current = fetch_ncr(id).await?;
+    state_machine::check_transition(&current, target)?;
+
+    let ncr: Ncr = AUTH_CLIENT
+        .post_json(
+            &format!("/api/ncrs/{}/status", id),
+            &StatusChangeRequest {
+                status: target,
+                comment: comment.map(|comment| comment.trim().to_string()).filter(|comment| !comment.is_empty()),
+            },
+        )
+        .await?;
+    println!(
+        "不合格报告 {} 状态变更: {} -> {}",
+        ncr.ncr_no,
+        current.status.label(),
+        ncr.status.label()
+    );
+    to_detail(ncr).await
+}
+
+/// Tauri命令：上传附件（照片、检测报告等）
+#[tauri::command]
+pub async fn upload_ncr_attachment(id: u64, attachment: AttachmentUpload) -> Result<NcrDetail, ApiError> {
+    require(Permission::QualityEdit)?;
+
+    let file_name = attachment.file_name.trim();
+    if file_name.is_empty() {
+        return Err(ApiError::invalid_field("fileName", "附件缺少文件名"));
+    }
+    if attachment.content.is_empty() || attachment.content.len() > MAX_ATTACHMENT_BYTES {
+        return Err(ApiError::invalid_field(
+            "content",
+            format!("附件大小须在 1 字节到 {} MB 之间", MAX_ATTACHMENT_BYTES / 1024 / 1024),
+        ));
+    }
+
+    let current = fetch_ncr(id).await?;
+    if !state_machine::is_editable(current.status) {
+        return Err(ApiError::invalid_field(
+            "status",
+            format!("不合格报告 {} 已关闭，不能上传附件", current.ncr_no),
+        ));
+    }
+
+    let content_type = if attachment.content_type.is_empty() {
+        "application/octet-stream"
+    } else {
+        attachment.content_type.as_str()
+    };
+    let ncr: Ncr = AUTH_CLIENT
+        .post_bytes(
+            &format!("/api/ncrs/{}/attachments", id),
+            &AttachmentQuery { file_name },
+            content_type,
+            &attachment.content,
+        )
+        .await?;
+    to_detail(ncr).await
+}
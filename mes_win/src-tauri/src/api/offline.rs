@@ -0,0 +1,63 @@
+use mes_win_shared::offline::{OfflineStatus, QueuedTransaction};
+use crate::api::error::ApiError;
+use crate::offline::queue::OFFLINE_QUEUE;
+use crate::offline::sync::{current_owner, current_status, notify_status, replay};
+
+/// Tauri命令：获取离线状态（连接状态和队列数量）
+#[tauri::command]
+pub async fn get_offline_status() -> Result<OfflineStatus, ApiError> {
+    Ok(current_status())
+}
+
+/// Tauri命令：列出当前用户离线队列中的事务
+#[tauri::command]
+pub async fn list_offline_transactions() -> Result<Vec<QueuedTransaction>, ApiError> {
+    let owner = current_owner().ok_or_else(ApiError::unauthorized)?;
+    OFFLINE_QUEUE
+        .list(&owner)
+        .map_err(|message| ApiError::Server { code: 500, message })
+}
+
+/// Tauri命令：立即重放离线队列
+#[tauri::command]
+pub async fn sync_offline_queue() -> Result<OfflineStatus, ApiError> {
+    current_owner().ok_or_else(ApiError::unauthorized)?;
+
+    replay().await;
+    notify_status();
+    Ok(current_status())
+}
+
+/// Tauri命令：冲突事务重新排队并立即重放
+#[tauri::command]
+pub async fn retry_offline_transaction(id: u64) -> Result<OfflineStatus, ApiError> {
+    let owner = current_owner().ok_or_else(ApiError::unauthorized)?;
+
+    let found = OFFLINE_QUEUE
+        .retry(&owner, id)
+        .map_err(|message| ApiError::Server { code: 500, message })?;
+    if !found {
+        return Err(ApiError::invalid_field("id", format!("离线事务 #{} 不存在或不是冲突状态", id)));
+    }
+
+    replay().await;
+    notify_status();
+    Ok(current_status())
+}
+
+/// Tauri命令：放弃离线事务（不再提交）
+#[tauri::command]
+pub async fn discard_offline_transaction(id: u64) -> Result<OfflineStatus, ApiError> {
+    let owner = current_owner().ok_or_else(ApiError::unauthorized)?;
+
+    let found = OFFLINE_QUEUE
+        .discard(&owner, id)
+        .map_err(|message| ApiError::Server { code: 500, message })?;
+    if !found {
+        return Err(ApiError::invalid_field("id", format!("离线事务 #{} 不存在", id)));
+    }
+
+    println!("离线事务 #{} 已放弃", id);
+    notify_status();
+    Ok(current_status())
+}
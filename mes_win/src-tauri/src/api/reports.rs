@@ -0,0 +1,128 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use serde_json::{Map, Value};
+use tauri::Manager;
+use mes_win_shared::report::{ExportFormat, ExportResult, ReportDefinition, ReportRequest, ReportTable};
+use mes_win_shared::time::format_minutes;
+use mes_win_shared::permission::Permission;
+use crate::api::error::{check_fields, ApiError};
+use crate::auth::client::AUTH_CLIENT;
+use crate::auth::guard::require;
+use crate::auth::store::now_secs;
+use crate::report;
+
+lazy_static::lazy_static! {
+    // 本次运行中导出的文件，只允许在文件管理器中显示这些文件
+    static ref EXPORTED_FILES: Mutex<HashSet<PathBuf>> = Mutex::new(HashSet::new());
+}
+
+fn io_error(context: &str, e: std::io::Error) -> ApiError {
+    ApiError::Server {
+        code: 500,
+        message: format!("{}: {}", context, e),
+    }
+}
+
+/// 校验参数后向后端查询报表数据并组装表格
+async fn run(request: &ReportRequest) -> Result<ReportTable, ApiError> {
+    let definition = report::definition(request.kind);
+    check_fields(definition.validate(&request.params))?;
+
+    let params: BTreeMap<String, String> = request
+        .params
+        .iter()
+        .map(|(key, value)| (key.clone(), value.trim().to_string()))
+        .filter(|(_, value)| !value.is_empty())
+        .collect();
+    let records: Vec<Map<String, Value>> = AUTH_CLIENT
+        .get_json_with_query(&format!("/api/reports/{}", request.kind.code()), &params)
+        .await?;
+
+    let generated_at = format_minutes((now_secs() / 60) as i64).replace('T', " ");
+    Ok(report::build_table(&definition, &params, &records, generated_at))
+}
+
+/// 导出文件路径：报表名_时间.扩展名，重名时追加序号
+fn export_path(folder: &Path, table: &ReportTable, format: ExportFormat) -> PathBuf {
+    let stamp: String = table.generated_at.chars().filter(|c| c.is_ascii_digit()).collect();
+    let base = format!("{}_{}", table.title, stamp);
+    let mut path = folder.join(format!("{}.{}", base, format.extension()));
+    let mut index = 1;
+    while path.exists() {
+        index += 1;
+        path = folder.join(format!("{}({}).{}", base, index, format.extension()));
+    }
+    path
+}
+
+/// Tauri命令：获取报表定义（参数和列）
+#[tauri::command]
+pub async fn list_report_definitions() -> Result<Vec<ReportDefinition>, ApiError> {
+    Ok(report::definitions())
+}
+
+/// Tauri命令：运行报表，返回预览表格
+#[tauri::command]
+pub async fn run_report(request: ReportRequest) -> Result<ReportTable, ApiError> {
+    require(Permission::DashboardView)?;
+
+    run(&request).await
+}
+
+/// Tauri命令：运行报表并导出到指定文件夹
+#[tauri::command]
+pub async fn export_report(request: ReportRequest, format: ExportFormat, folder: String) -> Result<ExportResult, ApiError> {
+    require(Permission::ReportExport)?;
+
+    let folder = PathBuf::from(folder.trim());
+    if folder.as_os_str().is_empty() || !folder.is_dir() {
+        return Err(ApiError::invalid_field("folder", "导出文件夹不存在，请重新选择"));
+    }
+
+    let table = run(&request).await?;
+    let content = match format {
+        ExportFormat::Csv => report::csv::write(&table),
+        ExportFormat::Xlsx => report::xlsx::write(&table),
+        ExportFormat::Pdf => report::pdf::write(&table),
+    };
+
+    let path = export_path(&folder, &table, format);
+    std::fs::write(&path, content).map_err(|e| io_error("写入导出文件失败", e))?;
+    println!("报表已导出: {}", path.display());
+    if let Ok(mut exported) = EXPORTED_FILES.lock() {
+        exported.insert(path.clone());
+    }
+
+    Ok(ExportResult {
+        path: path.display().to_string(),
+        rows: table.rows.len(),
+    })
+}
+
+/// Tauri命令：默认导出文件夹（文档目录，取不到时用下载目录）
+#[tauri::command]
+pub async fn get_default_export_folder(app: tauri::AppHandle) -> Result<String, ApiError> {
+    let folder = app
+        .path()
+        .document_dir()
+        .or_else(|_| app.path().download_dir())
+        .map_err(|e| ApiError::Server { code: 500, message: e.to_string() })?;
+    Ok(folder.display().to_string())
+}
+
+/// Tauri命令：在文件管理器中显示导出的文件
+///
+/// 只接受 `export_report` 返回的路径，不能用来打开任意位置
+#[tauri::command]
+pub async fn reveal_export(path: String) -> Result<(), ApiError> {
+    require(Permission::ReportExport)?;
+
+    let path = PathBuf::from(path);
+    let exported = EXPORTED_FILES.lock().map(|exported| exported.contains(&path)).unwrap_or(false);
+    if !exported {
+        return Err(ApiError::forbidden("只能显示本次导出的报表文件"));
+    }
+    tauri_plugin_opener::reveal_item_in_dir(&path)
+        .map_err(|e| ApiError::Server { code: 500, message: e.to_string() })
+}
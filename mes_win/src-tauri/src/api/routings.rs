@@ -0,0 +1,126 @@
+use serde::Serialize;
+use mes_win_shared::production::routing::{Routing, RoutingStatus, RoutingSummary, WorkCenter};
+use mes_win_shared::permission::Permission;
+use crate::api::error::{check_fields, ApiError};
+use crate::auth::client::AUTH_CLIENT;
+use crate::auth::guard::require;
+
+/// 工艺路线查询参数
+#[derive(Debug, Serialize)]
+struct RoutingQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keyword: Option<String>,
+}
+
+/// 只有草稿状态的工艺路线允许修改
+fn ensure_draft(routing: &Routing) -> Result<(), ApiError> {
+    if routing.status == RoutingStatus::Draft {
+        return Ok(());
+    }
+
+    Err(ApiError::invalid_field(
+        "status",
+        format!(
+            "{} 第{}版{}，不能修改，请先创建新版本",
+            routing.product_code,
+            routing.version,
+            routing.status.label()
+        ),
+    ))
+}
+
+/// 按工作中心主数据校验工艺路线
+async fn validate_routing(routing: &Routing) -> Result<(), ApiError> {
+    let work_centers = list_work_centers().await?;
+    check_fields(routing.validate(&work_centers))
+}
+
+async fn fetch_routing(id: u64) -> Result<Routing, ApiError> {
+    AUTH_CLIENT.get_json(&format!("/api/routings/{}", id)).await
+}
+
+/// Tauri命令：获取工作中心列表
+#[tauri::command]
+pub async fn list_work_centers() -> Result<Vec<WorkCenter>, ApiError> {
+    require(Permission::ProductionView)?;
+
+    AUTH_CLIENT.get_json("/api/work-centers").await
+}
+
+/// Tauri命令：查询工艺路线（按产品编码/名称）
+#[tauri::command]
+pub async fn list_routings(keyword: Option<String>) -> Result<Vec<RoutingSummary>, ApiError> {
+    require(Permission::ProductionView)?;
+
+    let query = RoutingQuery {
+        keyword: keyword
+            .map(|keyword| keyword.trim().to_string())
+            .filter(|keyword| !keyword.is_empty()),
+    };
+    AUTH_CLIENT.get_json_with_query("/api/routings", &query).await
+}
+
+/// Tauri命令：获取工艺路线详情
+#[tauri::command]
+pub async fn get_routing(id: u64) -> Result<Routing, ApiError> {
+    require(Permission::ProductionView)?;
+
+    fetch_routing(id).await
+}
+
+/// Tauri命令：保存工艺路线草稿
+///
+/// 按提交顺序校验工作中心、前序引用和环路（错误字段序号与界面一致），通过后按工序号排序提交
+#[tauri::command]
+pub async fn save_routing(routing: Routing) -> Result<Routing, ApiError> {
+    require(Permission::ProductionEdit)?;
+
+    validate_routing(&routing).await?;
+    let mut routing = routing;
+    routing.operations.sort_by_key(|operation| operation.seq);
+
+    match routing.id {
+        Some(id) => {
+            let current = fetch_routing(id).await?;
+            ensure_draft(&current)?;
+            AUTH_CLIENT.put_json(&format!("/api/routings/{}", id), &routing).await
+        }
+        None => {
+            routing.status = RoutingStatus::Draft;
+            AUTH_CLIENT.post_json("/api/routings", &routing).await
+        }
+    }
+}
+
+/// Tauri命令：发布工艺路线
+///
+/// 发布后该版本生效，同一产品之前发布的版本由后端置为失效
+#[tauri::command]
+pub async fn publish_routing(id: u64) -> Result<Routing, ApiError> {
+    require(Permission::ProductionEdit)?;
+
+    let current = fetch_routing(id).await?;
+    ensure_draft(&current)?;
+    validate_routing(&current).await?;
+
+    let routing: Routing = AUTH_CLIENT
+        .post_json(&format!("/api/routings/{}/publish", id), &serde_json::json!({}))
+        .await?;
+    println!("工艺路线已发布: {} 第{}版", routing.product_code, routing.version);
+    Ok(routing)
+}
+
+/// Tauri命令：基于已有版本创建新的草稿版本
+#[tauri::command]
+pub async fn new_routing_version(id: u64) -> Result<Routing, ApiError> {
+    require(Permission::ProductionEdit)?;
+
+    let current = fetch_routing(id).await?;
+    if current.status == RoutingStatus::Draft {
+        return Err(ApiError::invalid_field("status", "草稿版本可直接编辑，无需创建新版本"));
+    }
+
+    AUTH_CLIENT
+        .post_json(&format!("/api/routings/{}/versions", id), &serde_json::json!({}))
+        .await
+}
@@ -0,0 +1,56 @@
+use mes_win_shared::production::schedule::{RescheduleRequest, ScheduleBoard, ScheduleItem, ScheduleQuery};
+use mes_win_shared::production::work_order::WorkOrder;
+use mes_win_shared::time::parse_minutes;
+use mes_win_shared::permission::Permission;
+use crate::api::error::ApiError;
+use crate::auth::client::AUTH_CLIENT;
+use crate::auth::guard::require;
+use crate::production::work_order as state_machine;
+
+/// 标记条目是否允许拖拽调整
+fn mark_movable(item: &mut ScheduleItem) {
+    item.movable = state_machine::is_editable(item.status);
+}
+
+/// Tauri命令：获取时间范围内的排程看板
+#[tauri::command]
+pub async fn get_schedule(query: ScheduleQuery) -> Result<ScheduleBoard, ApiError> {
+    require(Permission::ProductionView)?;
+
+    match (parse_minutes(&query.start), parse_minutes(&query.end)) {
+        (Some(start), Some(end)) if start < end => {}
+        _ => return Err(ApiError::invalid_field("end", "排程时间范围无效")),
+    }
+
+    let mut board: ScheduleBoard = AUTH_CLIENT.get_json_with_query("/api/schedule", &query).await?;
+    board.items.iter_mut().for_each(mark_movable);
+    Ok(board)
+}
+
+/// Tauri命令：调整工单的排程资源和时段
+///
+/// 冲突由前端在提交前检测，这里只校验时间范围和工单状态，最终以后端结果为准
+#[tauri::command]
+pub async fn reschedule(request: RescheduleRequest) -> Result<ScheduleItem, ApiError> {
+    require(Permission::ProductionEdit)?;
+
+    match (parse_minutes(&request.start), parse_minutes(&request.end)) {
+        (Some(start), Some(end)) if start < end => {}
+        _ => return Err(ApiError::invalid_field("end", "计划完成时间必须晚于开始时间")),
+    }
+
+    let order: WorkOrder = AUTH_CLIENT
+        .get_json(&format!("/api/work-orders/{}", request.work_order_id))
+        .await?;
+    if !state_machine::is_editable(order.status) {
+        return Err(ApiError::invalid_field(
+            "status",
+            format!("「{}」状态的工单不能调整排程", order.status.label()),
+        ));
+    }
+
+    let mut item: ScheduleItem = AUTH_CLIENT.post_json("/api/schedule/reschedule", &request).await?;
+    mark_movable(&mut item);
+    println!("工单 {} 已排程到 {} {} ~ {}", item.order_no, item.resource, item.start, item.end);
+    Ok(item)
+}
@@ -0,0 +1,167 @@
+use std::future::Future;
+use std::time::Duration;
+use mes_win_shared::inventory::material::MaterialStockQuery;
+use mes_win_shared::master_data::MasterDataKind;
+use mes_win_shared::permission::{has_permission, Permission};
+use mes_win_shared::production::work_order::WorkOrderQuery;
+use mes_win_shared::quality::ncr::NcrQuery;
+use mes_win_shared::search::{SearchHit, SearchResults, SearchTarget, SEARCH_LIMIT_PER_KIND};
+use crate::api::error::ApiError;
+use crate::api::finished_goods::find_serial;
+use crate::api::materials::list_material_stock;
+use crate::api::ncrs::list_ncrs;
+use crate::api::work_orders::list_work_orders;
+use crate::auth::store::USER_STORE;
+use crate::master_data::store::MASTER_DATA_STORE;
+use crate::master_data::sync::current_profile;
+
+/// 单个后端查询的等待上限，超时的部分不等待，只返回已有结果
+const BACKEND_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Tauri命令：全局搜索
+///
+/// 物料从本地主数据缓存查询；工单、批次、序列号和质量问题并发查询后端，
+/// 只查询当前用户有权限的类别，某类查询超时或失败时标记为部分结果
+#[tauri::command]
+pub async fn search(keyword: String) -> Result<SearchResults, ApiError> {
+    let session = USER_STORE
+        .get_current_session()
+        .filter(|_| USER_STORE.has_active_session())
+        .ok_or_else(ApiError::unauthorized)?;
+    let can = |permission| has_permission(&session.permissions, permission);
+
+    let keyword = keyword.trim().to_string();
+    if keyword.is_empty() {
+        return Ok(SearchResults::default());
+    }
+
+    let (orders, stock, serial, ncrs) = tokio::join!(
+        backend(can(Permission::ProductionView), search_work_orders(&keyword)),
+        backend(can(Permission::InventoryView), search_lots(&keyword)),
+        backend(can(Permission::InventoryView), search_serial(&keyword)),
+        backend(can(Permission::QualityView), search_ncrs(&keyword)),
+    );
+
+    let mut results = SearchResults::default();
+    let mut collect = |found: Result<Vec<SearchHit>, ApiError>| match found {
+        Ok(hits) => results.hits.extend(hits),
+        Err(e) => {
+            println!("全局搜索部分查询失败: {}", e);
+            results.partial = true;
+        }
+    };
+    collect(orders);
+    if can(Permission::InventoryView) {
+        collect(search_materials(&keyword));
+    }
+    collect(stock);
+    collect(serial);
+    collect(ncrs);
+
+    Ok(results)
+}
+
+/// 有权限时执行后端查询（带超时），无权限时返回空结果
+async fn backend<F>(enabled: bool, query: F) -> Result<Vec<SearchHit>, ApiError>
+where
+    F: Future<Output = Result<Vec<SearchHit>, ApiError>>,
+{
+    if !enabled {
+        return Ok(Vec::new());
+    }
+    tokio::time::timeout(BACKEND_TIMEOUT, query)
+        .await
+        .unwrap_or_else(|_| Err(ApiError::Timeout { message: "搜索请求超时".to_string() }))
+}
+
+/// 物料：本地主数据缓存
+fn search_materials(keyword: &str) -> Result<Vec<SearchHit>, ApiError> {
+    let items = MASTER_DATA_STORE
+        .lookup(&current_profile(), MasterDataKind::Material, keyword, SEARCH_LIMIT_PER_KIND)
+        .map_err(|message| ApiError::Server { code: 500, message })?;
+
+    Ok(items
+        .into_iter()
+        .map(|item| SearchHit {
+            title: format!("{} {}", item.code, item.name),
+            subtitle: item.spec.filter(|spec| !spec.is_empty()),
+            target: SearchTarget::Material { code: item.code },
+        })
+        .collect())
+}
+
+async fn search_work_orders(keyword: &str) -> Result<Vec<SearchHit>, ApiError> {
+    let page = list_work_orders(WorkOrderQuery {
+        page_size: SEARCH_LIMIT_PER_KIND,
+        keyword: Some(keyword.to_string()),
+        ..WorkOrderQuery::default()
+    })
+    .await?;
+
+    Ok(page
+        .items
+        .into_iter()
+        .map(|order| SearchHit {
+            title: order.order_no,
+            subtitle: Some(format!("{} {} · {}", order.product_code, order.product_name, order.status.label())),
+            target: SearchTarget::WorkOrder { id: order.id },
+        })
+        .collect())
+}
+
+/// 批次：物料库存查询同时匹配批次号，只取批次号包含关键字的批次
+async fn search_lots(keyword: &str) -> Result<Vec<SearchHit>, ApiError> {
+    let page = list_material_stock(MaterialStockQuery {
+        page_size: SEARCH_LIMIT_PER_KIND,
+        keyword: Some(keyword.to_string()),
+        include_empty: true,
+        ..MaterialStockQuery::default()
+    })
+    .await?;
+
+    let needle = keyword.to_lowercase();
+    Ok(page
+        .items
+        .into_iter()
+        .flat_map(|stock| stock.lots)
+        .filter(|lot| lot.lot_no.to_lowercase().contains(&needle))
+        .take(SEARCH_LIMIT_PER_KIND as usize)
+        .map(|lot| SearchHit {
+            title: lot.lot_no.clone(),
+            subtitle: Some(format!("{} {} · {}", lot.material_code, lot.material_name, lot.location)),
+            target: SearchTarget::Lot { lot_no: lot.lot_no },
+        })
+        .collect())
+}
+
+/// 序列号：按完整序列号精确查找，不存在时没有结果
+async fn search_serial(keyword: &str) -> Result<Vec<SearchHit>, ApiError> {
+    match find_serial(keyword.to_string()).await {
+        Ok(record) => Ok(vec![SearchHit {
+            title: record.serial_no.clone(),
+            subtitle: Some(format!("{} {} · 工单 {}", record.sku, record.product_name, record.work_order_no)),
+            target: SearchTarget::Serial { serial_no: record.serial_no },
+        }]),
+        Err(ApiError::Server { code: 404, .. }) | Err(ApiError::Validation { .. }) => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn search_ncrs(keyword: &str) -> Result<Vec<SearchHit>, ApiError> {
+    let page = list_ncrs(NcrQuery {
+        page_size: SEARCH_LIMIT_PER_KIND,
+        keyword: Some(keyword.to_string()),
+        ..NcrQuery::default()
+    })
+    .await?;
+
+    Ok(page
+        .items
+        .into_iter()
+        .map(|ncr| SearchHit {
+            title: format!("{} {}", ncr.ncr_no, ncr.title),
+            subtitle: Some(format!("{} · {}", ncr.item_code, ncr.status.label())),
+            target: SearchTarget::Ncr { id: ncr.id },
+        })
+        .collect())
+}
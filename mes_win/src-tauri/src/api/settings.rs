@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+use serde::Serialize;
+use mes_win_shared::events;
+use mes_win_shared::permission::Permission;
+use mes_win_shared::settings::{validate_plant_values, LocalSettings, PlantSettings, SettingKind};
+use tauri::Emitter;
+use crate::api::error::{check_fields, ApiError};
+use crate::auth::client::AUTH_CLIENT;
+use crate::auth::guard::require;
+use crate::auth::store::USER_STORE;
+use crate::config::settings::SETTINGS_STORE;
+use crate::config::store::CONFIG_STORE;
+
+/// 工厂参数保存请求体
+#[derive(Debug, Serialize)]
+struct PlantSettingsUpdate {
+    values: BTreeMap<String, String>,
+}
+
+/// Tauri命令：获取本机客户端设置
+///
+/// 登录前也可调用（启动时按设置调整主题和窗口大小）
+#[tauri::command]
+pub async fn get_local_settings() -> Result<LocalSettings, ApiError> {
+    let mut settings = SETTINGS_STORE.get();
+    settings.backend_profile = CONFIG_STORE.list_profiles().active;
+    Ok(settings)
+}
+
+/// Tauri命令：保存本机客户端设置
+///
+/// 切换后端档案时与 `switch_backend_profile` 一致地清理本地会话，并通知各窗口回到登录界面
+#[tauri::command]
+pub async fn save_local_settings(app: tauri::AppHandle, settings: LocalSettings) -> Result<LocalSettings, ApiError> {
+    check_fields(settings.validate())?;
+
+    let profiles = CONFIG_STORE.list_profiles();
+    if !profiles.profiles.iter().any(|profile| profile.name == settings.backend_profile) {
+        return Err(ApiError::invalid_field(
+            "backendProfile",
+            format!("后端档案不存在: {}", settings.backend_profile),
+        ));
+    }
+
+    SETTINGS_STORE
+        .save(settings.clone())
+        .map_err(|message| ApiError::Server { code: 500, message })?;
+
+    if profiles.active != settings.backend_profile {
+        CONFIG_STORE
+            .switch_profile(&settings.backend_profile)
+            .map_err(|message| ApiError::Server { code: 500, message })?;
+        USER_STORE.clear_current_session();
+        if let Err(e) = app.emit(events::LOGOUT, ()) {
+            println!("发送登出事件失败: {}", e);
+        }
+    }
+
+    println!("客户端设置已保存");
+    Ok(settings)
+}
+
+/// Tauri命令：获取工厂参数（定义和当前取值）
+#[tauri::command]
+pub async fn get_plant_settings() -> Result<PlantSettings, ApiError> {
+    require(Permission::SystemSettings)?;
+
+    AUTH_CLIENT.get_json("/api/settings/plant").await
+}
+
+/// Tauri命令：保存工厂参数
+///
+/// 按后端下发的最新参数定义校验类型和范围，数值和开关统一规整后再提交
+#[tauri::command]
+pub async fn save_plant_settings(values: BTreeMap<String, String>) -> Result<PlantSettings, ApiError> {
+    require(Permission::SystemSettings)?;
+
+    let current: PlantSettings = AUTH_CLIENT.get_json("/api/settings/plant").await?;
+    let values: BTreeMap<String, String> = values
+        .into_iter()
+        .map(|(key, value)| (key, value.trim().to_string()))
+        .collect();
+    check_fields(validate_plant_values(&current.schema, &values))?;
+
+    let values = values
+        .into_iter()
+        .map(|(key, value)| {
+            let kind = current.schema.iter().find(|field| field.key == key).map(|field| &field.kind);
+            let value = match kind {
+                Some(SettingKind::Integer { .. }) if !value.is_empty() => {
+                    value.parse::<i64>().map(|n| n.to_string()).unwrap_or(value)
+                }
+                Some(SettingKind::Decimal { .. }) if !value.is_empty() => {
+                    value.parse::<f64>().map(|n| n.to_string()).unwrap_or(value)
+                }
+                _ => value,
+            };
+            (key, value)
+        })
+        .collect();
+
+    let saved: PlantSettings = AUTH_CLIENT
+        .put_json("/api/settings/plant", &PlantSettingsUpdate { values })
+        .await?;
+    println!("工厂参数已保存");
+    Ok(saved)
+}
@@ -0,0 +1,114 @@
+use mes_win_shared::quality::inspection::{
+    Characteristic, CharacteristicKind, InspectionLot, InspectionLotQuery, InspectionStatus, MeasuredValue,
+};
+use mes_win_shared::quality::spc::{SpcDataset, SpcQuery, SpcSample};
+use mes_win_shared::response::PageData;
+use mes_win_shared::permission::Permission;
+use crate::api::error::ApiError;
+use crate::auth::client::AUTH_CLIENT;
+use crate::auth::guard::require;
+
+/// 由检验批的测量记录生成单个特性的样本数据
+fn to_sample(lot: &InspectionLot, code: &str) -> Option<SpcSample> {
+    let characteristic = lot.characteristics.iter().find(|c| c.code == code)?;
+    let mut measurements: Vec<_> = lot
+        .measurements
+        .iter()
+        .filter(|m| m.characteristic_code == code)
+        .collect();
+    if measurements.is_empty() {
+        return None;
+    }
+    measurements.sort_by_key(|m| m.sample_no);
+
+    let values = measurements
+        .iter()
+        .filter_map(|m| match m.value {
+            MeasuredValue::Numeric(value) if value.is_finite() => Some(value),
+            _ => None,
+        })
+        .collect();
+    let defective = measurements
+        .iter()
+        .filter(|m| !characteristic.is_conforming(&m.value))
+        .count();
+
+    Some(SpcSample {
+        inspection_no: lot.inspection_no.clone(),
+        inspected_at: lot.decided_at.clone().unwrap_or_else(|| lot.created_at.clone()),
+        values,
+        inspected: measurements.len() as u32,
+        defective: defective as u32,
+    })
+}
+
+/// Tauri命令：获取物料某一检验特性的SPC数据
+///
+/// 取最近已判定的检验批，按检验时间先后排列；控制图在前端计算
+#[tauri::command]
+pub async fn get_spc_dataset(query: SpcQuery) -> Result<SpcDataset, ApiError> {
+    require(Permission::QualityView)?;
+
+    let item_code = query.item_code.trim().to_string();
+    if item_code.is_empty() {
+        return Err(ApiError::invalid_field("itemCode", "请输入物料/产品编码"));
+    }
+
+    let lots_query = InspectionLotQuery {
+        page: 1,
+        page_size: query.limit.clamp(10, 200),
+        keyword: Some(item_code.clone()),
+        status: None,
+    };
+    let page: PageData<InspectionLot> = AUTH_CLIENT.get_json_with_query("/api/inspections", &lots_query).await?;
+    let mut lots: Vec<InspectionLot> = page
+        .items
+        .into_iter()
+        .filter(|lot| lot.item_code == item_code && lot.status != InspectionStatus::Open)
+        .collect();
+    lots.sort_by(|a, b| {
+        let a = a.decided_at.as_deref().unwrap_or(&a.created_at);
+        let b = b.decided_at.as_deref().unwrap_or(&b.created_at);
+        a.cmp(b)
+    });
+
+    // 以最新的检验标准为准汇总特性
+    let mut characteristics: Vec<Characteristic> = Vec::new();
+    for lot in lots.iter().rev() {
+        for characteristic in &lot.characteristics {
+            if !characteristics.iter().any(|c| c.code == characteristic.code) {
+                characteristics.push(characteristic.clone());
+            }
+        }
+    }
+
+    let characteristic = match query.characteristic.as_deref().filter(|code| !code.is_empty()) {
+        Some(code) => Some(
+            characteristics
+                .iter()
+                .find(|c| c.code == code)
+                .cloned()
+                .ok_or_else(|| {
+                    ApiError::invalid_field("characteristic", format!("物料 {} 没有检验特性 {}", item_code, code))
+                })?,
+        ),
+        None => characteristics
+            .iter()
+            .find(|c| matches!(c.kind, CharacteristicKind::Numeric { .. }))
+            .or(characteristics.first())
+            .cloned(),
+    };
+
+    let samples = characteristic
+        .as_ref()
+        .map(|c| lots.iter().filter_map(|lot| to_sample(lot, &c.code)).collect())
+        .unwrap_or_default();
+
+    Ok(SpcDataset {
+        item_name: lots.first().map(|lot| lot.item_name.clone()).unwrap_or_default(),
+        item_code,
+        characteristics,
+        characteristic,
+        samples,
+    })
+}
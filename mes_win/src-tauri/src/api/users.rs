@@ -0,0 +1,181 @@
+use serde::Serialize;
+use mes_win_shared::response::PageData;
+use mes_win_shared::user::{password_problem, Role, UserAccount, UserDraft, UserQuery};
+use mes_win_shared::permission::Permission;
+use crate::api::error::{check_fields, ApiError};
+use crate::auth::client::{path_segment, AUTH_CLIENT};
+use crate::auth::guard::{require, require_any};
+use crate::auth::store::USER_STORE;
+
+/// 重置密码请求体
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PasswordResetRequest {
+    password: String,
+    /// 重置后下次登录必须修改密码
+    must_change: bool,
+}
+
+/// 角色分配请求体
+#[derive(Debug, Serialize)]
+struct RoleAssignment {
+    roles: Vec<String>,
+}
+
+/// 不允许对当前登录的账户执行的操作（停用自己、修改自己的角色）
+fn reject_self(id: u64, field: &str, message: &str) -> Result<(), ApiError> {
+    match USER_STORE.get_current_user_info() {
+        Some((_, user_id)) if user_id as u64 == id => Err(ApiError::invalid_field(field, message)),
+        _ => Ok(()),
+    }
+}
+
+/// Tauri命令：分页查询用户
+#[tauri::command]
+pub async fn list_users(query: UserQuery) -> Result<PageData<UserAccount>, ApiError> {
+    require(Permission::UserManage)?;
+
+    let mut query = query;
+    query.page = query.page.max(1);
+    query.page_size = query.page_size.clamp(1, 200);
+    query.keyword = query
+        .keyword
+        .map(|keyword| keyword.trim().to_string())
+        .filter(|keyword| !keyword.is_empty());
+
+    AUTH_CLIENT.get_json_with_query("/api/users", &query).await
+}
+
+/// Tauri命令：获取全部角色
+#[tauri::command]
+pub async fn list_roles() -> Result<Vec<Role>, ApiError> {
+    require_any(&[Permission::UserManage, Permission::RoleManage])?;
+
+    AUTH_CLIENT.get_json("/api/roles").await
+}
+
+/// Tauri命令：新建用户
+#[tauri::command]
+pub async fn create_user(draft: UserDraft) -> Result<UserAccount, ApiError> {
+    require(Permission::UserManage)?;
+
+    check_fields(draft.validate())?;
+
+    let draft = UserDraft {
+        username: draft.username.trim().to_string(),
+        display_name: draft.display_name.trim().to_string(),
+        email: draft.email.map(|e| e.trim().to_string()).filter(|e| !e.is_empty()),
+        phone: draft.phone.map(|p| p.trim().to_string()).filter(|p| !p.is_empty()),
+        ..draft
+    };
+    let user: UserAccount = AUTH_CLIENT.post_json("/api/users", &draft).await?;
+    println!("用户创建成功: {}", user.username);
+    Ok(user)
+}
+
+/// Tauri命令：停用/启用用户
+#[tauri::command]
+pub async fn set_user_enabled(id: u64, enabled: bool) -> Result<UserAccount, ApiError> {
+    require(Permission::UserManage)?;
+
+    if !enabled {
+        reject_self(id, "status", "不能停用当前登录的账户")?;
+    }
+
+    let action = if enabled { "enable" } else { "disable" };
+    AUTH_CLIENT
+        .post_json(&format!("/api/users/{}/{}", id, action), &())
+        .await
+}
+
+/// Tauri命令：解锁因登录失败被锁定的用户
+#[tauri::command]
+pub async fn unlock_user(id: u64) -> Result<UserAccount, ApiError> {
+    require(Permission::UserManage)?;
+
+    AUTH_CLIENT
+        .post_json(&format!("/api/users/{}/unlock", id), &())
+        .await
+}
+
+/// Tauri命令：重置用户密码
+#[tauri::command]
+pub async fn reset_user_password(id: u64, password: String) -> Result<(), ApiError> {
+    require(Permission::UserManage)?;
+
+    if let Some(message) = password_problem(&password) {
+        return Err(ApiError::invalid_field("password", message));
+    }
+
+    let request = PasswordResetRequest {
+        password,
+        must_change: true,
+    };
+    AUTH_CLIENT
+        .post_json(&format!("/api/users/{}/password", id), &request)
+        .await
+}
+
+/// Tauri命令：分配用户角色
+#[tauri::command]
+pub async fn assign_user_roles(id: u64, roles: Vec<String>) -> Result<UserAccount, ApiError> {
+    require(Permission::UserManage)?;
+
+    if roles.is_empty() {
+        return Err(ApiError::invalid_field("roles", "请至少分配一个角色"));
+    }
+    reject_self(id, "roles", "不能修改当前登录账户的角色")?;
+
+    AUTH_CLIENT
+        .put_json(&format!("/api/users/{}/roles", id), &RoleAssignment { roles })
+        .await
+}
+
+/// Tauri命令：新建角色
+#[tauri::command]
+pub async fn create_role(role: Role) -> Result<Role, ApiError> {
+    require(Permission::RoleManage)?;
+
+    check_fields(role.validate())?;
+    let role = Role {
+        code: role.code.trim().to_string(),
+        name: role.name.trim().to_string(),
+        builtin: false,
+        ..role
+    };
+    let created: Role = AUTH_CLIENT.post_json("/api/roles", &role).await?;
+    println!("角色创建成功: {}", created.code);
+    Ok(created)
+}
+
+/// Tauri命令：修改角色名称、说明和权限
+#[tauri::command]
+pub async fn update_role(role: Role) -> Result<Role, ApiError> {
+    require(Permission::RoleManage)?;
+
+    check_fields(role.validate())?;
+    let role = Role {
+        name: role.name.trim().to_string(),
+        ..role
+    };
+    AUTH_CLIENT
+        .put_json(&format!("/api/roles/{}", path_segment(&role.code)), &role)
+        .await
+}
+
+/// Tauri命令：删除角色
+///
+/// 内置角色不允许删除；仍有用户使用的角色由后端拒绝
+#[tauri::command]
+pub async fn delete_role(code: String) -> Result<(), ApiError> {
+    require(Permission::RoleManage)?;
+
+    let roles: Vec<Role> = AUTH_CLIENT.get_json("/api/roles").await?;
+    if roles.iter().any(|role| role.code == code && role.builtin) {
+        return Err(ApiError::invalid_field("code", "系统内置角色不允许删除"));
+    }
+
+    AUTH_CLIENT
+        .delete_json(&format!("/api/roles/{}", path_segment(&code)))
+        .await
+}
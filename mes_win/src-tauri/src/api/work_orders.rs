@@ -0,0 +1,114 @@
+use serde::Serialize;
+use mes_win_shared::production::work_order::{
+    WorkOrder, WorkOrderDetail, WorkOrderDraft, WorkOrderQuery, WorkOrderStatus,
+};
+use mes_win_shared::response::PageData;
+use mes_win_shared::permission::Permission;
+use crate::api::error::{check_fields, ApiError};
+use crate::auth::client::AUTH_CLIENT;
+use crate::auth::guard::require;
+use crate::production::work_order as state_machine;
+
+/// 工单状态变更请求体
+#[derive(Debug, Serialize)]
+struct StatusChangeRequest {
+    status: WorkOrderStatus,
+}
+
+/// 组装工单详情（附带状态机计算出的可执行操作）
+fn to_detail(order: WorkOrder) -> WorkOrderDetail {
+    WorkOrderDetail {
+        transitions: state_machine::transitions(order.status).to_vec(),
+        editable: state_machine::is_editable(order.status),
+        order,
+    }
+}
+
+/// 查询单个工单
+async fn fetch_work_order(id: u64) -> Result<WorkOrder, ApiError> {
+    AUTH_CLIENT.get_json(&format!("/api/work-orders/{}", id)).await
+}
+
+/// Tauri命令：分页查询工单
+#[tauri::command]
+pub async fn list_work_orders(query: WorkOrderQuery) -> Result<PageData<WorkOrder>, ApiError> {
+    require(Permission::ProductionView)?;
+
+    let mut query = query;
+    query.page = query.page.max(1);
+    query.page_size = query.page_size.clamp(1, 200);
+    query.keyword = query
+        .keyword
+        .map(|keyword| keyword.trim().to_string())
+        .filter(|keyword| !keyword.is_empty());
+
+    AUTH_CLIENT.get_json_with_query("/api/work-orders", &query).await
+}
+
+/// Tauri命令：获取工单详情
+#[tauri::command]
+pub async fn get_work_order(id: u64) -> Result<WorkOrderDetail, ApiError> {
+    require(Permission::ProductionView)?;
+
+    fetch_work_order(id).await.map(to_detail)
+}
+
+/// Tauri命令：创建工单
+#[tauri::command]
+pub async fn create_work_order(draft: WorkOrderDraft) -> Result<WorkOrderDetail, ApiError> {
+    require(Permission::ProductionEdit)?;
+
+    check_fields(draft.validate())?;
+
+    let order: WorkOrder = AUTH_CLIENT.post_json("/api/work-orders", &draft).await?;
+    println!("工单创建成功: {}", order.order_no);
+    Ok(to_detail(order))
+}
+
+/// Tauri命令：编辑工单
+///
+/// 仅新建和已下达状态的工单允许编辑
+#[tauri::command]
+pub async fn update_work_order(id: u64, draft: WorkOrderDraft) -> Result<WorkOrderDetail, ApiError> {
+    require(Permission::ProductionEdit)?;
+
+    check_fields(draft.validate())?;
+
+    let current = fetch_work_order(id).await?;
+    if !state_machine::is_editable(current.status) {
+        return Err(ApiError::invalid_field(
+            "status",
+            format!("「{}」状态的工单不允许编辑", current.status.label()),
+        ));
+    }
+
+    let order: WorkOrder = AUTH_CLIENT
+        .put_json(&format!("/api/work-orders/{}", id), &draft)
+        .await?;
+    Ok(to_detail(order))
+}
+
+/// Tauri命令：变更工单状态
+///
+/// 先按状态机校验，再提交后端
+#[tauri::command]
+pub async fn transition_work_order(id: u64, target: WorkOrderStatus) -> Result<WorkOrderDetail, ApiError> {
+    require(Permission::ProductionEdit)?;
+
+    let current = fetch_work_order(id).await?;
+    state_machine::check_transition(current.status, target)?;
+
+    let order: WorkOrder = AUTH_CLIENT
+        .post_json(
+            &format!("/api/work-orders/{}/status", id),
+            &StatusChangeRequest { status: target },
+        )
+        .await?;
+    println!(
+        "工单 {} 状态变更: {} -> {}",
+        order.order_no,
+        current.status.label(),
+        order.status.label()
+    );
+    Ok(to_detail(order))
+}
@@ -1,5 +1,22 @@
-use reqwest::{Client, RequestBuilder};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use super::refresh::refresh_session;
 use super::store::USER_STORE;
+use super::types::ApiResponse;
+use crate::api::error::{from_reqwest, ApiError};
+use crate::config::settings::SETTINGS_STORE;
+use crate::config::store::CONFIG_STORE;
+use mes_win_shared::settings::LogLevel;
+
+/// URL路径段中需要转义的字符（RFC 3986 非保留字符之外的全部字符）
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'_').remove(b'.').remove(b'~');
+
+/// 转义用户输入的编码（库位、批次号等），使其可以安全地作为一个URL路径段
+pub fn path_segment(value: &str) -> String {
+    utf8_percent_encode(value, PATH_SEGMENT).to_string()
+}
 
 /// 获取带有认证头的HTTP客户端
 pub struct AuthenticatedClient {
@@ -18,16 +35,33 @@ impl AuthenticatedClient {
     pub fn raw_client(&self) -> &Client {
         &self.client
     }
+
+    /// 将API路径解析为完整URL
+    ///
+    /// 以 http:// 或 https:// 开头的地址原样返回，
+    /// 相对路径（如 `/api/auth/login`）基于当前后端档案的根地址拼接
+    pub fn resolve_url(&self, path: &str) -> String {
+        if path.starts_with("http://") || path.starts_with("https://") {
+            return path.to_string();
+        }
+
+        let base_url = CONFIG_STORE.active_base_url();
+        format!(
+            "{}/{}",
+            base_url.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
     
     /// 获取带认证头的GET请求构建器
     #[allow(dead_code)]
-    pub fn get(&self, url: &str) -> Result<RequestBuilder, String> {
-        let mut request = self.client.get(url);
+    pub fn get(&self, url: &str) -> Result<RequestBuilder, ApiError> {
+        let mut request = self.client.get(self.resolve_url(url));
         
         if let Some(auth_header) = USER_STORE.get_current_auth_header() {
             request = request.header("Authorization", auth_header);
         } else {
-            return Err("用户未登录或token已过期".to_string());
+            return Err(ApiError::unauthorized());
         }
         
         Ok(request)
@@ -35,13 +69,13 @@ impl AuthenticatedClient {
     
     /// 获取带认证头的POST请求构建器
     #[allow(dead_code)]
-    pub fn post(&self, url: &str) -> Result<RequestBuilder, String> {
-        let mut request = self.client.post(url);
+    pub fn post(&self, url: &str) -> Result<RequestBuilder, ApiError> {
+        let mut request = self.client.post(self.resolve_url(url));
         
         if let Some(auth_header) = USER_STORE.get_current_auth_header() {
             request = request.header("Authorization", auth_header);
         } else {
-            return Err("用户未登录或token已过期".to_string());
+            return Err(ApiError::unauthorized());
         }
         
         Ok(request)
@@ -49,13 +83,13 @@ impl AuthenticatedClient {
     
     /// 获取带认证头的PUT请求构建器
     #[allow(dead_code)]
-    pub fn put(&self, url: &str) -> Result<RequestBuilder, String> {
-        let mut request = self.client.put(url);
+    pub fn put(&self, url: &str) -> Result<RequestBuilder, ApiError> {
+        let mut request = self.client.put(self.resolve_url(url));
         
         if let Some(auth_header) = USER_STORE.get_current_auth_header() {
             request = request.header("Authorization", auth_header);
         } else {
-            return Err("用户未登录或token已过期".to_string());
+            return Err(ApiError::unauthorized());
         }
         
         Ok(request)
@@ -63,17 +97,179 @@ impl AuthenticatedClient {
     
     /// 获取带认证头的DELETE请求构建器
     #[allow(dead_code)]
-    pub fn delete(&self, url: &str) -> Result<RequestBuilder, String> {
-        let mut request = self.client.delete(url);
+    pub fn delete(&self, url: &str) -> Result<RequestBuilder, ApiError> {
+        let mut request = self.client.delete(self.resolve_url(url));
         
         if let Some(auth_header) = USER_STORE.get_current_auth_header() {
             request = request.header("Authorization", auth_header);
         } else {
-            return Err("用户未登录或token已过期".to_string());
+            return Err(ApiError::unauthorized());
         }
         
         Ok(request)
     }
+
+    /// 发送认证请求，token过期或返回401时自动刷新token并重试一次
+    ///
+    /// `build` 用于构建请求（刷新后需要使用新的认证头重新构建）
+    pub async fn send_with_refresh<F>(&self, build: F) -> Result<Response, ApiError>
+    where
+        F: Fn(&Self) -> Result<RequestBuilder, ApiError>,
+    {
+        // access token已过期但可刷新时，先刷新再发起请求
+        if USER_STORE.get_current_auth_header().is_none() && USER_STORE.has_active_session() {
+            refresh_session().await?;
+        }
+
+        let response = build(self)?.send().await.map_err(from_reqwest)?;
+        if SETTINGS_STORE.log_enabled(LogLevel::Debug) {
+            println!("请求 {} 返回 {}", response.url(), response.status());
+        }
+
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+
+        let can_refresh = USER_STORE
+            .get_current_session()
+            .is_some_and(|session| session.is_refreshable());
+        if !can_refresh {
+            return Ok(response);
+        }
+
+        println!("请求返回401，刷新token后重试");
+        refresh_session().await?;
+
+        build(self)?.send().await.map_err(from_reqwest)
+    }
+
+    /// 解析后端统一响应结构，后端返回失败时转换为对应的 `ApiError`
+    pub async fn read_envelope<T: DeserializeOwned>(response: Response) -> Result<ApiResponse<T>, ApiError> {
+        let status = response.status();
+        let response_text = response.text().await.map_err(from_reqwest)?;
+
+        let envelope = match serde_json::from_str::<ApiResponse<serde_json::Value>>(&response_text) {
+            Ok(envelope) => envelope,
+            Err(e) => {
+                println!("解析响应失败: {:?}, 原始响应: {}", e, response_text);
+                return Err(if status.is_success() {
+                    ApiError::from(e)
+                } else {
+                    ApiError::from_status(status.as_u16())
+                });
+            }
+        };
+
+        if !status.is_success() || !envelope.is_success() {
+            let code = if envelope.is_success() { status.as_u16() as u32 } else { envelope.code };
+            return Err(ApiError::from_response(status.as_u16(), code, envelope.message, envelope.data.as_ref()));
+        }
+
+        let data = match envelope.data {
+            Some(data) if !data.is_null() => Some(serde_json::from_value::<T>(data)?),
+            _ => None,
+        };
+
+        Ok(ApiResponse {
+            success: Some(true),
+            code: envelope.code,
+            message: envelope.message,
+            data,
+            timestamp: envelope.timestamp,
+        })
+    }
+
+    /// 发送认证请求并返回响应中的 `data`
+    ///
+    /// 对于不返回数据的接口，`T` 可以使用 `()` 或 `Option<_>`
+    async fn request_json<T, F>(&self, build: F) -> Result<T, ApiError>
+    where
+        T: DeserializeOwned,
+        F: Fn(&Self) -> Result<RequestBuilder, ApiError>,
+    {
+        let response = self.send_with_refresh(build).await?;
+        let envelope = Self::read_envelope::<T>(response).await?;
+
+        match envelope.data {
+            Some(data) => Ok(data),
+            None => serde_json::from_value::<T>(serde_json::Value::Null).map_err(|_| ApiError::Decode {
+                message: "响应缺少data字段".to_string(),
+            }),
+        }
+    }
+
+    /// 发送认证GET请求并解析响应数据
+    pub async fn get_json<T: DeserializeOwned>(&self, path: &str) -> Result<T, ApiError> {
+        self.request_json(|client| client.get(path)).await
+    }
+
+    /// 发送认证GET请求（附带查询参数）并解析响应数据
+    pub async fn get_json_with_query<Q, T>(&self, path: &str, query: &Q) -> Result<T, ApiError>
+    where
+        Q: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        self.request_json(|client| client.get(path).map(|request| request.query(query)))
+            .await
+    }
+
+    /// 发送认证POST请求（JSON请求体）并解析响应数据
+    pub async fn post_json<B, T>(&self, path: &str, body: &B) -> Result<T, ApiError>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        self.request_json(|client| client.post(path).map(|request| request.json(body)))
+            .await
+    }
+
+    /// 发送认证PUT请求（JSON请求体）并解析响应数据
+    pub async fn put_json<B, T>(&self, path: &str, body: &B) -> Result<T, ApiError>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        self.request_json(|client| client.put(path).map(|request| request.json(body)))
+            .await
+    }
+
+    /// 发送带幂等键的认证POST请求并解析响应数据
+    ///
+    /// 离线队列重放时使用，后端按 `Idempotency-Key` 去重
+    pub async fn post_json_with_key<B, T>(&self, path: &str, idempotency_key: &str, body: &B) -> Result<T, ApiError>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        self.request_json(|client| {
+            client
+                .post(path)
+                .map(|request| request.header("Idempotency-Key", idempotency_key).json(body))
+        })
+        .await
+    }
+
+    /// 发送认证POST请求（原始字节请求体，如文件上传）并解析响应数据
+    pub async fn post_bytes<Q, T>(&self, path: &str, query: &Q, content_type: &str, body: &[u8]) -> Result<T, ApiError>
+    where
+        Q: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        self.request_json(|client| {
+            client.post(path).map(|request| {
+                request
+                    .query(query)
+                    .header(reqwest::header::CONTENT_TYPE, content_type)
+                    .body(body.to_vec())
+            })
+        })
+        .await
+    }
+
+    /// 发送认证DELETE请求并解析响应数据
+    pub async fn delete_json<T: DeserializeOwned>(&self, path: &str) -> Result<T, ApiError> {
+        self.request_json(|client| client.delete(path)).await
+    }
 }
 
 impl Default for AuthenticatedClient {
@@ -0,0 +1,43 @@
+// 命令权限检查
+//
+// 需要授权的Tauri命令在调用后端之前先检查当前会话的权限，
+// 界面上隐藏的菜单只是体验优化，真正的限制在这里
+use mes_win_shared::permission::{has_permission, Permission};
+use crate::api::error::ApiError;
+use super::store::USER_STORE;
+
+/// 要求当前用户拥有指定权限，未登录返回Unauthorized，权限不足返回Forbidden
+pub fn require(permission: Permission) -> Result<(), ApiError> {
+    require_any(&[permission])
+}
+
+/// 当前用户是否拥有其中任一权限（只做判断，用于筛选可见的数据）
+pub fn has_any(permissions: &[Permission]) -> bool {
+    USER_STORE
+        .get_current_session()
+        .filter(|_| USER_STORE.has_active_session())
+        .is_some_and(|session| {
+            permissions
+                .iter()
+                .any(|permission| has_permission(&session.permissions, *permission))
+        })
+}
+
+/// 要求当前用户拥有其中任一权限
+pub fn require_any(permissions: &[Permission]) -> Result<(), ApiError> {
+    let session = USER_STORE
+        .get_current_session()
+        .filter(|_| USER_STORE.has_active_session())
+        .ok_or_else(ApiError::unauthorized)?;
+
+    if permissions
+        .iter()
+        .any(|permission| has_permission(&session.permissions, *permission))
+    {
+        return Ok(());
+    }
+
+    let labels: Vec<&str> = permissions.iter().map(|permission| permission.label()).collect();
+    println!("用户 {} 缺少权限: {}", session.username, labels.join(" / "));
+    Err(ApiError::forbidden(format!("没有「{}」权限", labels.join("」或「"))))
+}
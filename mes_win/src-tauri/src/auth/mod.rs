@@ -1,3 +1,6 @@
 pub mod types;
 pub mod store;
 pub mod client;
+pub mod vault;
+pub mod refresh;
+pub mod guard;
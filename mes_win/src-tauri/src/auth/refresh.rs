@@ -0,0 +1,170 @@
+use std::fmt;
+use std::sync::OnceLock;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use super::client::AUTH_CLIENT;
+use super::store::{UserSession, USER_STORE};
+use super::types::{ApiResponse, LoginData, RefreshRequest, SessionEventPayload};
+use mes_win_shared::events::{SESSION_EXPIRED, SESSION_REFRESHED};
+
+/// 没有可刷新会话时的轮询间隔（秒）
+const IDLE_CHECK_INTERVAL_SECS: u64 = 30;
+/// 刷新因网络原因失败后的重试间隔（秒）
+const RETRY_INTERVAL_SECS: u64 = 15;
+
+// 用于向所有窗口广播会话事件
+static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+
+// 防止多个请求同时触发刷新
+lazy_static::lazy_static! {
+    static ref REFRESH_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::new(());
+}
+
+/// 刷新失败原因
+#[derive(Debug)]
+pub enum RefreshError {
+    /// 当前没有可刷新的会话
+    NoSession,
+    /// 后端拒绝刷新（刷新token无效或已过期），会话已清除
+    Rejected(String),
+    /// 网络或后端暂时不可用，可稍后重试
+    Unavailable(String),
+}
+
+impl fmt::Display for RefreshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RefreshError::NoSession => write!(f, "用户未登录或登录已失效"),
+            RefreshError::Rejected(message) => write!(f, "登录已失效: {}", message),
+            RefreshError::Unavailable(message) => write!(f, "刷新登录状态失败: {}", message),
+        }
+    }
+}
+
+/// 启动后台刷新任务
+///
+/// 在access token有效期的80%处自动续期，刷新被拒绝时清除会话并通知所有窗口
+pub fn start_refresh_task(app: AppHandle) {
+    let _ = APP_HANDLE.set(app);
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let wait_secs = match USER_STORE.get_current_session() {
+                Some(session) if session.is_refreshable() => session.seconds_until_refresh(),
+                _ => IDLE_CHECK_INTERVAL_SECS,
+            };
+
+            if wait_secs > 0 {
+                tokio::time::sleep(Duration::from_secs(wait_secs.min(IDLE_CHECK_INTERVAL_SECS))).await;
+                continue;
+            }
+
+            match refresh_session().await {
+                Ok(_) => {}
+                Err(RefreshError::Unavailable(e)) => {
+                    println!("后台刷新token失败，稍后重试: {}", e);
+                    tokio::time::sleep(Duration::from_secs(RETRY_INTERVAL_SECS)).await;
+                }
+                Err(e) => println!("后台刷新token失败: {}", e),
+            }
+        }
+    });
+}
+
+/// 使用刷新token续期当前会话
+///
+/// 并发调用时只会向后端发起一次刷新请求
+pub async fn refresh_session() -> Result<UserSession, RefreshError> {
+    let token_before = USER_STORE
+        .get_current_session()
+        .map(|session| session.access_token);
+
+    let _guard = REFRESH_LOCK.lock().await;
+
+    let session = USER_STORE.get_current_session().ok_or(RefreshError::NoSession)?;
+
+    // 等待锁期间其他请求已完成刷新
+    if token_before.as_ref() != Some(&session.access_token) && !session.is_token_expired() {
+        return Ok(session);
+    }
+
+    let refresh_token = match session.refresh_token.clone() {
+        Some(token) if session.is_refreshable() => token,
+        _ => {
+            expire_session();
+            return Err(RefreshError::NoSession);
+        }
+    };
+
+    println!("发起token刷新请求: {}", session.username);
+
+    let response = AUTH_CLIENT
+        .raw_client()
+        .post(AUTH_CLIENT.resolve_url("/api/auth/refresh"))
+        .json(&RefreshRequest { refresh_token })
+        .send()
+        .await
+        .map_err(|e| RefreshError::Unavailable(e.to_string()))?;
+
+    let status = response.status();
+    if status.is_server_error() {
+        return Err(RefreshError::Unavailable(format!("服务器错误: {}", status)));
+    }
+    // 4xx 表示刷新token已失效或被拒绝，响应体可能不是JSON，重试也不会成功
+    if status.is_client_error() {
+        let message = response
+            .json::<ApiResponse<LoginData>>()
+            .await
+            .map(|api_response| api_response.message)
+            .unwrap_or_else(|_| format!("刷新请求被拒绝: {}", status));
+        println!("后端拒绝刷新token: {}", message);
+        expire_session();
+        return Err(RefreshError::Rejected(message));
+    }
+
+    let api_response = response
+        .json::<ApiResponse<LoginData>>()
+        .await
+        .map_err(|e| RefreshError::Unavailable(format!("响应格式错误: {}", e)))?;
+
+    match api_response.data {
+        Some(login_data) if api_response.is_success() => {
+            let mut refreshed = session;
+            refreshed.apply_refresh(login_data);
+            USER_STORE.set_current_session(refreshed.clone());
+            println!("token刷新成功: {}", refreshed.username);
+
+            emit_session_event(SESSION_REFRESHED, &refreshed);
+            Ok(refreshed)
+        }
+        _ => {
+            println!("后端拒绝刷新token: {}", api_response.message);
+            expire_session();
+            Err(RefreshError::Rejected(api_response.message))
+        }
+    }
+}
+
+/// 清除会话并通知所有窗口需要重新登录
+fn expire_session() {
+    if let Some(session) = USER_STORE.get_current_session() {
+        USER_STORE.clear_current_session();
+        emit_session_event(SESSION_EXPIRED, &session);
+    }
+}
+
+/// 向所有窗口广播会话事件
+fn emit_session_event(event: &str, session: &UserSession) {
+    let Some(app) = APP_HANDLE.get() else {
+        return;
+    };
+
+    let payload = SessionEventPayload {
+        username: session.username.clone(),
+        expires_in: session.expires_in,
+    };
+
+    if let Err(e) = app.emit(event, payload) {
+        println!("发送{}事件失败: {}", event, e);
+    }
+}
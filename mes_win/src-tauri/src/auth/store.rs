@@ -1,7 +1,10 @@
 use std::sync::{Arc, Mutex};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
+use mes_win_shared::permission::SessionInfo;
 use super::types::LoginData;
+use super::vault::SessionVault;
 
 /// 用户会话信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,16 +14,39 @@ pub struct UserSession {
     pub access_token: String,
     pub token_type: String,
     pub expires_in: u32,
-    pub login_time: u64, // Unix时间戳
+    pub login_time: u64, // 登录时间，Unix时间戳
+    #[serde(default)]
+    pub refreshed_at: Option<u64>, // 最近一次刷新token的Unix时间戳
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    #[serde(default)]
+    pub refresh_expires_at: Option<u64>, // 刷新token过期的Unix时间戳
+    #[serde(default)]
+    pub roles: Vec<String>, // 角色名称
+    #[serde(default)]
+    pub permissions: Vec<String>, // 权限编码
+    #[serde(default)]
+    pub version: u32, // 会话格式版本，旧版本保存的会话没有该字段
+}
+
+/// 当前会话格式版本（1: 会话中包含角色和权限）
+pub const SESSION_VERSION: u32 = 1;
+
+/// 在access token有效期的该比例处提前刷新
+const REFRESH_RATIO_PERCENT: u64 = 80;
+
+/// 当前Unix时间戳（秒）
+pub fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
 }
 
 impl UserSession {
     /// 从登录数据创建用户会话
     pub fn from_login_data(login_data: LoginData) -> Self {
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        let now = now_secs();
             
         Self {
             user_id: login_data.user_id,
@@ -29,17 +55,60 @@ impl UserSession {
             token_type: login_data.token_type,
             expires_in: login_data.expires_in,
             login_time: now,
+            refreshed_at: None,
+            refresh_expires_at: login_data
+                .refresh_expires_in
+                .map(|secs| now + secs as u64),
+            refresh_token: login_data.refresh_token,
+            roles: login_data.roles,
+            permissions: login_data.permissions,
+            version: SESSION_VERSION,
+        }
+    }
+
+    /// 使用刷新接口返回的数据更新会话
+    ///
+    /// 后端未轮换刷新token时保留原有的刷新token；角色和权限总是以刷新响应为准，
+    /// 权限被全部收回时同样生效
+    pub fn apply_refresh(&mut self, login_data: LoginData) {
+        let now = now_secs();
+
+        self.access_token = login_data.access_token;
+        self.token_type = login_data.token_type;
+        self.expires_in = login_data.expires_in;
+        self.refreshed_at = Some(now);
+        if let Some(refresh_token) = login_data.refresh_token {
+            self.refresh_token = Some(refresh_token);
+            self.refresh_expires_at = login_data
+                .refresh_expires_in
+                .map(|secs| now + secs as u64);
         }
+        self.roles = login_data.roles;
+        self.permissions = login_data.permissions;
     }
     
+    /// 当前access token的签发时间（登录或最近一次刷新）
+    fn token_issued_at(&self) -> u64 {
+        self.refreshed_at.unwrap_or(self.login_time)
+    }
+
     /// 检查token是否过期
     pub fn is_token_expired(&self) -> bool {
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-            
-        now > (self.login_time + self.expires_in as u64)
+        now_secs() > (self.token_issued_at() + self.expires_in as u64)
+    }
+
+    /// 检查是否可以通过刷新token续期
+    pub fn is_refreshable(&self) -> bool {
+        self.refresh_token.is_some()
+            && self
+                .refresh_expires_at
+                .is_none_or(|expires_at| now_secs() < expires_at)
+    }
+
+    /// 距离应当刷新token还有多少秒（已到期返回0）
+    pub fn seconds_until_refresh(&self) -> u64 {
+        let refresh_at = self.token_issued_at() + self.expires_in as u64 * REFRESH_RATIO_PERCENT / 100;
+        refresh_at.saturating_sub(now_secs())
     }
     
     /// 获取Authorization header值
@@ -53,6 +122,8 @@ pub struct UserStore {
     current_session: Arc<Mutex<Option<UserSession>>>,
     // 可以扩展为支持多用户会话
     sessions: Arc<Mutex<HashMap<u32, UserSession>>>,
+    // 会话持久化存储（应用启动时初始化）
+    vault: Arc<Mutex<Option<SessionVault>>>,
 }
 
 impl UserStore {
@@ -61,6 +132,45 @@ impl UserStore {
         Self {
             current_session: Arc::new(Mutex::new(None)),
             sessions: Arc::new(Mutex::new(HashMap::new())),
+            vault: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// 启用会话持久化，并恢复上次保存的会话
+    ///
+    /// 已过期或无法解密的会话会被丢弃
+    pub fn restore(&self, data_dir: PathBuf) {
+        let vault = SessionVault::new(data_dir);
+
+        match vault.load() {
+            Ok(Some(session)) => {
+                if session.is_token_expired() && !session.is_refreshable() {
+                    println!("已保存的会话已过期，丢弃: {}", session.username);
+                    vault.clear();
+                } else if session.version < SESSION_VERSION {
+                    // 旧版本保存的会话没有权限信息，需要重新登录获取
+                    println!("已保存的会话缺少权限信息，丢弃: {}", session.username);
+                    vault.clear();
+                } else {
+                    let username = session.username.clone();
+                    if let Ok(mut current) = self.current_session.lock() {
+                        *current = Some(session.clone());
+                    }
+                    if let Ok(mut sessions) = self.sessions.lock() {
+                        sessions.insert(session.user_id, session);
+                    }
+                    println!("已恢复用户会话: {}", username);
+                }
+            }
+            Ok(None) => {}
+            Err(e) => {
+                println!("恢复会话失败，丢弃: {}", e);
+                vault.clear();
+            }
+        }
+
+        if let Ok(mut current_vault) = self.vault.lock() {
+            *current_vault = Some(vault);
         }
     }
     
@@ -74,6 +184,15 @@ impl UserStore {
             *current = Some(session.clone());
         }
         
+        // 持久化到磁盘
+        if let Ok(vault) = self.vault.lock() {
+            if let Some(vault) = vault.as_ref() {
+                if let Err(e) = vault.save(&session) {
+                    println!("保存会话到磁盘失败: {}", e);
+                }
+            }
+        }
+        
         // 存储到会话映射
         if let Ok(mut sessions) = self.sessions.lock() {
             sessions.insert(user_id, session);
@@ -96,6 +215,10 @@ impl UserStore {
         if let Some(session) = self.get_current_session() {
             if !session.is_token_expired() {
                 Some(session)
+            } else if session.is_refreshable() {
+                // 保留会话，由刷新任务或下一次请求续期
+                println!("Token已过期，等待刷新");
+                None
             } else {
                 println!("Token已过期，需要重新登录");
                 self.clear_current_session();
@@ -106,6 +229,14 @@ impl UserStore {
         }
     }
     
+    /// 检查当前会话是否仍然可用（token有效或可刷新）
+    pub fn has_active_session(&self) -> bool {
+        self.get_valid_current_session().is_some()
+            || self
+                .get_current_session()
+                .is_some_and(|session| session.is_refreshable())
+    }
+
     /// 清除当前用户会话
     pub fn clear_current_session(&self) {
         if let Ok(mut current) = self.current_session.lock() {
@@ -113,14 +244,37 @@ impl UserStore {
                 println!("用户会话已清除: {}", session.username);
             }
         }
+
+        if let Ok(vault) = self.vault.lock() {
+            if let Some(vault) = vault.as_ref() {
+                vault.clear();
+            }
+        }
     }
     
     /// 获取当前用户信息
     pub fn get_current_user_info(&self) -> Option<(String, u32)> {
-        self.get_valid_current_session()
+        if !self.has_active_session() {
+            return None;
+        }
+        self.get_current_session()
             .map(|session| (session.username, session.user_id))
     }
     
+    /// 获取当前会话的角色和权限
+    pub fn get_session_info(&self) -> Option<SessionInfo> {
+        if !self.has_active_session() {
+            return None;
+        }
+        self.get_current_session().map(|session| SessionInfo {
+            user_id: session.user_id,
+            username: session.username,
+            roles: session.roles,
+            permissions: session.permissions,
+            login_time: session.login_time,
+        })
+    }
+
     /// 获取当前认证头
     pub fn get_current_auth_header(&self) -> Option<String> {
         self.get_valid_current_session()
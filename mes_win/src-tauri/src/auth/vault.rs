@@ -0,0 +1,107 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use super::store::UserSession;
+
+/// 本机密钥文件名
+const KEY_FILE_NAME: &str = "session.key";
+/// 加密会话文件名
+const SESSION_FILE_NAME: &str = "session.dat";
+/// AES-GCM nonce 长度
+const NONCE_LEN: usize = 12;
+
+/// 会话加密存储
+///
+/// 会话以 AES-256-GCM 加密后写入应用数据目录，密钥保存在同目录下的本机密钥文件中。
+/// 文件格式：nonce(12字节) + 密文
+pub struct SessionVault {
+    dir: PathBuf,
+}
+
+impl SessionVault {
+    /// 创建会话存储（dir 为应用数据目录）
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// 保存会话
+    pub fn save(&self, session: &UserSession) -> Result<(), String> {
+        let cipher = self.cipher()?;
+        let plaintext = serde_json::to_vec(session).map_err(|e| e.to_string())?;
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext.as_ref())
+            .map_err(|e| format!("会话加密失败: {}", e))?;
+
+        let mut content = nonce.to_vec();
+        content.extend_from_slice(&ciphertext);
+        write_private(&self.dir.join(SESSION_FILE_NAME), &content)
+    }
+
+    /// 读取会话，文件不存在时返回 `Ok(None)`
+    pub fn load(&self) -> Result<Option<UserSession>, String> {
+        let path = self.dir.join(SESSION_FILE_NAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read(&path).map_err(|e| e.to_string())?;
+        if content.len() <= NONCE_LEN {
+            return Err("会话文件已损坏".to_string());
+        }
+
+        let cipher = self.cipher()?;
+        let (nonce, ciphertext) = content.split_at(NONCE_LEN);
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| "会话解密失败，可能密钥已变更".to_string())?;
+
+        serde_json::from_slice::<UserSession>(&plaintext)
+            .map(Some)
+            .map_err(|e| e.to_string())
+    }
+
+    /// 删除已保存的会话
+    pub fn clear(&self) {
+        let path = self.dir.join(SESSION_FILE_NAME);
+        if path.exists() {
+            if let Err(e) = fs::remove_file(&path) {
+                println!("删除会话文件失败: {}", e);
+            }
+        }
+    }
+
+    /// 读取本机密钥，不存在时生成新密钥
+    fn cipher(&self) -> Result<Aes256Gcm, String> {
+        let key_path = self.dir.join(KEY_FILE_NAME);
+
+        let key_bytes = match fs::read(&key_path) {
+            Ok(bytes) if bytes.len() == 32 => bytes,
+            _ => {
+                println!("生成新的本机会话密钥");
+                let key = Aes256Gcm::generate_key(OsRng);
+                write_private(&key_path, key.as_slice())?;
+                key.to_vec()
+            }
+        };
+
+        Ok(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key_bytes)))
+    }
+}
+
+/// 写入仅当前用户可读写的文件
+fn write_private(path: &Path, content: &[u8]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    fs::write(path, content).map_err(|e| e.to_string())?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600)).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use mes_win_shared::dashboard::DashboardLayout;
+
+/// 布局文件名
+const LAYOUT_FILE_NAME: &str = "dashboard.json";
+
+/// 仪表板布局存储：按 "后端档案/用户名" 区分，保存在应用配置目录
+pub struct LayoutStore {
+    layouts: Mutex<HashMap<String, DashboardLayout>>,
+    path: Mutex<Option<PathBuf>>,
+}
+
+impl LayoutStore {
+    pub fn new() -> Self {
+        Self {
+            layouts: Mutex::new(HashMap::new()),
+            path: Mutex::new(None),
+        }
+    }
+
+    /// 从应用配置目录加载，文件不存在或格式错误时从空白开始
+    pub fn load(&self, config_dir: PathBuf) {
+        let path = config_dir.join(LAYOUT_FILE_NAME);
+        let layouts = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| match serde_json::from_str(&content) {
+                Ok(layouts) => Some(layouts),
+                Err(e) => {
+                    println!("解析仪表板布局文件失败，使用默认布局: {}", e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        if let Ok(mut current) = self.layouts.lock() {
+            *current = layouts;
+        }
+        if let Ok(mut current) = self.path.lock() {
+            *current = Some(path);
+        }
+    }
+
+    /// 获取用户布局，未保存过时返回默认布局
+    pub fn get(&self, key: &str) -> DashboardLayout {
+        self.layouts
+            .lock()
+            .ok()
+            .and_then(|layouts| layouts.get(key).cloned())
+            .unwrap_or_default()
+    }
+
+    /// 保存用户布局并写入磁盘
+    pub fn save(&self, key: &str, layout: DashboardLayout) -> Result<(), String> {
+        let mut layouts = self.layouts.lock().map_err(|e| e.to_string())?;
+        layouts.insert(key.to_string(), layout);
+
+        if let Some(path) = self.path.lock().map_err(|e| e.to_string())?.as_ref() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+            }
+            let content = serde_json::to_string_pretty(&*layouts).map_err(|e| e.to_string())?;
+            fs::write(path, content).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for LayoutStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// 全局布局存储实例
+lazy_static::lazy_static! {
+    pub static ref LAYOUT_STORE: LayoutStore = LayoutStore::new();
+}
@@ -0,0 +1,4 @@
+pub mod profile;
+pub mod store;
+pub mod layout;
+pub mod settings;
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+// 档案数据类型定义在共享crate中，前后端共用
+pub use mes_win_shared::config::{BackendProfiles, ServerProfile};
+
+/// 后端配置文件内容（backend.json）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackendConfig {
+    /// 当前启用的档案标识
+    #[serde(rename = "activeProfile")]
+    pub active_profile: String,
+    /// 所有可用的服务器档案
+    pub profiles: Vec<ServerProfile>,
+}
+
+impl BackendConfig {
+    /// 根据标识查找档案
+    pub fn find_profile(&self, name: &str) -> Option<&ServerProfile> {
+        self.profiles.iter().find(|profile| profile.name == name)
+    }
+
+    /// 获取当前启用的档案，若配置的档案不存在则回退到第一个
+    pub fn active(&self) -> Option<&ServerProfile> {
+        self.find_profile(&self.active_profile)
+            .or_else(|| self.profiles.first())
+    }
+}
+
+impl Default for BackendConfig {
+    fn default() -> Self {
+        Self {
+            active_profile: "dev".to_string(),
+            profiles: vec![
+                ServerProfile::new("dev", "开发环境", "http://127.0.0.1:8080"),
+                ServerProfile::new("test", "测试环境", "http://mes-test.local:8080"),
+                ServerProfile::new("plant-A", "A厂区", "http://mes-plant-a.local:8080"),
+                ServerProfile::new("plant-B", "B厂区", "http://mes-plant-b.local:8080"),
+            ],
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use mes_win_shared::settings::{LocalSettings, LogLevel};
+
+/// 设置文件名
+const SETTINGS_FILE_NAME: &str = "settings.json";
+
+/// 本机客户端设置存储，保存在应用配置目录
+///
+/// 后端档案由 `CONFIG_STORE` 维护（backend.json），这里保存的值仅作记录
+pub struct SettingsStore {
+    settings: Mutex<LocalSettings>,
+    path: Mutex<Option<PathBuf>>,
+}
+
+impl SettingsStore {
+    pub fn new() -> Self {
+        Self {
+            settings: Mutex::new(LocalSettings::default()),
+            path: Mutex::new(None),
+        }
+    }
+
+    /// 从应用配置目录加载，文件不存在或格式错误时使用默认设置
+    pub fn load(&self, config_dir: PathBuf) {
+        let path = config_dir.join(SETTINGS_FILE_NAME);
+        let settings = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| match serde_json::from_str(&content) {
+                Ok(settings) => Some(settings),
+                Err(e) => {
+                    println!("解析客户端设置文件失败，使用默认设置: {}", e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        if let Ok(mut current) = self.settings.lock() {
+            *current = settings;
+        }
+        if let Ok(mut current) = self.path.lock() {
+            *current = Some(path);
+        }
+    }
+
+    /// 获取当前设置
+    pub fn get(&self) -> LocalSettings {
+        self.settings
+            .lock()
+            .map(|settings| settings.clone())
+            .unwrap_or_default()
+    }
+
+    /// 保存设置并写入磁盘
+    pub fn save(&self, settings: LocalSettings) -> Result<(), String> {
+        let mut current = self.settings.lock().map_err(|e| e.to_string())?;
+        *current = settings;
+
+        if let Some(path) = self.path.lock().map_err(|e| e.to_string())?.as_ref() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+            }
+            let content = serde_json::to_string_pretty(&*current).map_err(|e| e.to_string())?;
+            fs::write(path, content).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    /// 指定级别的日志是否需要输出
+    pub fn log_enabled(&self, level: LogLevel) -> bool {
+        self.settings
+            .lock()
+            .map(|settings| level <= settings.log_level)
+            .unwrap_or(true)
+    }
+}
+
+impl Default for SettingsStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// 全局客户端设置实例
+lazy_static::lazy_static! {
+    pub static ref SETTINGS_STORE: SettingsStore = SettingsStore::new();
+}
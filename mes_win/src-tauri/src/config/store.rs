@@ -0,0 +1,162 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use super::profile::{BackendConfig, BackendProfiles, ServerProfile};
+
+/// 配置文件名
+const CONFIG_FILE_NAME: &str = "backend.json";
+/// 环境变量：指定启用的档案标识
+const ENV_PROFILE: &str = "MES_BACKEND_PROFILE";
+/// 环境变量：直接指定后端根地址（优先级最高，不写入配置文件）
+const ENV_BASE_URL: &str = "MES_BACKEND_URL";
+
+/// 全局后端配置管理器
+pub struct ConfigStore {
+    config: Arc<Mutex<BackendConfig>>,
+    config_path: Arc<Mutex<Option<PathBuf>>>,
+    base_url_override: Arc<Mutex<Option<String>>>,
+}
+
+impl ConfigStore {
+    /// 创建新的配置存储实例（使用内置默认档案）
+    pub fn new() -> Self {
+        Self {
+            config: Arc::new(Mutex::new(BackendConfig::default())),
+            config_path: Arc::new(Mutex::new(None)),
+            base_url_override: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// 从应用配置目录加载配置文件，并应用环境变量覆盖
+    ///
+    /// 配置文件不存在或格式错误时使用默认档案并写回磁盘
+    pub fn load(&self, config_dir: PathBuf) {
+        let path = config_dir.join(CONFIG_FILE_NAME);
+
+        let mut config = match fs::read_to_string(&path) {
+            Ok(content) => match serde_json::from_str::<BackendConfig>(&content) {
+                Ok(config) => config,
+                Err(e) => {
+                    println!("解析后端配置文件失败，使用默认配置: {}", e);
+                    BackendConfig::default()
+                }
+            },
+            Err(_) => {
+                println!("未找到后端配置文件，创建默认配置: {}", path.display());
+                let config = BackendConfig::default();
+                if let Err(e) = Self::write_config(&path, &config) {
+                    println!("写入默认后端配置失败: {}", e);
+                }
+                config
+            }
+        };
+
+        if let Ok(profile_name) = std::env::var(ENV_PROFILE) {
+            if config.find_profile(&profile_name).is_some() {
+                println!("使用环境变量指定的后端档案: {}", profile_name);
+                config.active_profile = profile_name;
+            } else {
+                println!("环境变量指定的后端档案不存在: {}", profile_name);
+            }
+        }
+
+        if let Ok(base_url) = std::env::var(ENV_BASE_URL) {
+            if !base_url.trim().is_empty() {
+                println!("使用环境变量指定的后端地址: {}", base_url);
+                if let Ok(mut url_override) = self.base_url_override.lock() {
+                    *url_override = Some(base_url.trim().to_string());
+                }
+            }
+        }
+
+        if let Ok(mut current) = self.config.lock() {
+            *current = config;
+        }
+        if let Ok(mut config_path) = self.config_path.lock() {
+            *config_path = Some(path);
+        }
+    }
+
+    /// 获取当前启用的后端根地址
+    pub fn active_base_url(&self) -> String {
+        if let Ok(url_override) = self.base_url_override.lock() {
+            if let Some(base_url) = url_override.as_ref() {
+                return base_url.clone();
+            }
+        }
+
+        self.active_profile()
+            .map(|profile| profile.base_url)
+            .unwrap_or_else(|| "http://127.0.0.1:8080".to_string())
+    }
+
+    /// 获取当前启用的档案
+    pub fn active_profile(&self) -> Option<ServerProfile> {
+        self.config
+            .lock()
+            .ok()
+            .and_then(|config| config.active().cloned())
+    }
+
+    /// 获取档案列表
+    pub fn list_profiles(&self) -> BackendProfiles {
+        match self.config.lock() {
+            Ok(config) => BackendProfiles {
+                active: config
+                    .active()
+                    .map(|profile| profile.name.clone())
+                    .unwrap_or_default(),
+                profiles: config.profiles.clone(),
+            },
+            Err(_) => BackendProfiles {
+                active: String::new(),
+                profiles: Vec::new(),
+            },
+        }
+    }
+
+    /// 切换当前档案并写入配置文件
+    pub fn switch_profile(&self, name: &str) -> Result<BackendProfiles, String> {
+        {
+            let mut config = self.config.lock().map_err(|e| e.to_string())?;
+            if config.find_profile(name).is_none() {
+                return Err(format!("后端档案不存在: {}", name));
+            }
+            config.active_profile = name.to_string();
+
+            if let Ok(config_path) = self.config_path.lock() {
+                if let Some(path) = config_path.as_ref() {
+                    Self::write_config(path, &config)?;
+                }
+            }
+        }
+
+        // 手动切换档案后不再使用环境变量指定的地址
+        if let Ok(mut url_override) = self.base_url_override.lock() {
+            *url_override = None;
+        }
+
+        println!("后端档案已切换: {}", name);
+        Ok(self.list_profiles())
+    }
+
+    /// 将配置写入磁盘
+    fn write_config(path: &Path, config: &BackendConfig) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let content = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+        fs::write(path, content).map_err(|e| e.to_string())
+    }
+}
+
+impl Default for ConfigStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// 全局配置存储实例
+lazy_static::lazy_static! {
+    pub static ref CONFIG_STORE: ConfigStore = ConfigStore::new();
+}
@@ -2,11 +2,68 @@
 
 // 导入模块
 mod auth;
+mod config;
 mod windows;
 mod api;
+mod production;
+mod quality;
+mod report;
+mod offline;
+mod master_data;
 
 // 使用新模块结构的功能
-use api::auth::{login, get_current_user, get_current_token, is_logged_in, logout};
+use api::auth::{login, get_current_user, get_session_info, get_current_token, is_logged_in, logout};
+use api::config::{list_backend_profiles, switch_backend_profile};
+use api::dashboard::{get_dashboard_kpis, get_dashboard_layout, save_dashboard_layout};
+use api::finished_goods::{list_product_stock, register_completion, ship_products, find_serial};
+use api::inventory::{
+    list_warehouses, list_bin_stock, transfer_stock, list_cycle_counts, start_cycle_count,
+    get_cycle_count, submit_cycle_count, review_cycle_count,
+};
+use api::inspections::{list_inspection_lots, open_inspection_lot, get_inspection_lot, submit_inspection};
+use api::inspection_plans::{
+    list_inspection_plans, get_inspection_plan, save_inspection_plan, publish_inspection_plan,
+    new_inspection_plan_version,
+};
+use api::ncrs::{
+    list_ncrs, get_ncr, create_ncr, create_ncr_from_inspection, update_ncr, transition_ncr,
+    upload_ncr_attachment,
+};
+use api::master_data::{
+    get_master_data_status, sync_master_data, lookup_master_data,
+};
+use api::materials::{list_material_stock, receive_material, issue_material, trace_lot};
+use api::offline::{
+    get_offline_status, list_offline_transactions, sync_offline_queue, retry_offline_transaction,
+    discard_offline_transaction,
+};
+use api::reports::{
+    list_report_definitions, run_report, export_report, get_default_export_folder, reveal_export,
+};
+use api::routings::{
+    list_work_centers, list_routings, get_routing, save_routing, publish_routing, new_routing_version,
+};
+use api::schedule::{get_schedule, reschedule};
+use api::search::search;
+use api::settings::{get_local_settings, save_local_settings, get_plant_settings, save_plant_settings};
+use api::spc::get_spc_dataset;
+use api::users::{
+    list_users, list_roles, create_user, set_user_enabled, unlock_user, reset_user_password,
+    assign_user_roles, create_role, update_role, delete_role,
+};
+use api::work_orders::{
+    list_work_orders, get_work_order, create_work_order, update_work_order, transition_work_order,
+};
+use config::layout::LAYOUT_STORE;
+use config::settings::SETTINGS_STORE;
+use config::store::CONFIG_STORE;
+use auth::store::USER_STORE;
+use auth::refresh::start_refresh_task;
+use master_data::store::MASTER_DATA_STORE;
+use master_data::sync::start_master_data_task;
+use offline::queue::OFFLINE_QUEUE;
+use offline::sync::start_sync_task;
+use tauri::Manager;
 use windows::manager::{close, minimize, resize_window, toggle_maximize, open_profile_window};
 
 #[tauri::command]
@@ -18,10 +75,43 @@ fn greet(name: &str) -> String {
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_dialog::init())
+        .setup(|app| {
+            // 加载后端服务器配置（应用配置目录下的 backend.json）
+            let config_dir = app.path().app_config_dir()?;
+            CONFIG_STORE.load(config_dir.clone());
+
+            // 加载各用户的仪表板布局（应用配置目录下的 dashboard.json）
+            LAYOUT_STORE.load(config_dir.clone());
+
+            // 加载本机客户端设置（应用配置目录下的 settings.json）
+            SETTINGS_STORE.load(config_dir);
+
+            // 恢复加密保存的用户会话（应用数据目录下的 session.dat）
+            let data_dir = app.path().app_data_dir()?;
+            USER_STORE.restore(data_dir.clone());
+
+            // 打开离线事务队列（应用数据目录下的 offline.db）
+            OFFLINE_QUEUE.open(data_dir.clone());
+
+            // 打开主数据缓存（应用数据目录下的 master-data.db）
+            MASTER_DATA_STORE.open(data_dir);
+
+            // 启动token自动刷新任务
+            start_refresh_task(app.handle().clone());
+
+            // 启动离线事务重放任务
+            start_sync_task(app.handle().clone());
+
+            // 启动主数据增量同步任务
+            start_master_data_task();
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             greet, 
             login, 
             get_current_user, 
+            get_session_info,
             get_current_token, 
             is_logged_in, 
             logout,
@@ -29,7 +119,86 @@ pub fn run() {
             close,
             resize_window,
             toggle_maximize,
-            open_profile_window
+            open_profile_window,
+            list_backend_profiles,
+            switch_backend_profile,
+            get_dashboard_kpis,
+            get_dashboard_layout,
+            save_dashboard_layout,
+            list_work_orders,
+            get_work_order,
+            create_work_order,
+            update_work_order,
+            transition_work_order,
+            get_schedule,
+            reschedule,
+            list_work_centers,
+            list_routings,
+            get_routing,
+            save_routing,
+            publish_routing,
+            new_routing_version,
+            list_material_stock,
+            receive_material,
+            issue_material,
+            trace_lot,
+            list_product_stock,
+            register_completion,
+            ship_products,
+            find_serial,
+            list_warehouses,
+            list_bin_stock,
+            transfer_stock,
+            list_cycle_counts,
+            start_cycle_count,
+            get_cycle_count,
+            submit_cycle_count,
+            review_cycle_count,
+            list_inspection_lots,
+            open_inspection_lot,
+            get_inspection_lot,
+            submit_inspection,
+            list_inspection_plans,
+            get_inspection_plan,
+            save_inspection_plan,
+            publish_inspection_plan,
+            new_inspection_plan_version,
+            list_ncrs,
+            get_ncr,
+            create_ncr,
+            create_ncr_from_inspection,
+            update_ncr,
+            transition_ncr,
+            upload_ncr_attachment,
+            get_spc_dataset,
+            list_report_definitions,
+            run_report,
+            export_report,
+            get_default_export_folder,
+            reveal_export,
+            list_users,
+            list_roles,
+            create_user,
+            set_user_enabled,
+            unlock_user,
+            reset_user_password,
+            assign_user_roles,
+            create_role,
+            update_role,
+            delete_role,
+            get_local_settings,
+            save_local_settings,
+            get_plant_settings,
+            save_plant_settings,
+            get_offline_status,
+            list_offline_transactions,
+            sync_offline_queue,
+            retry_offline_transaction,
+            discard_offline_transaction,
+            get_master_data_status,
+            sync_master_data,
+            lookup_master_data,
+            search
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,99 @@
+use rusqlite::Connection;
+
+/// 按版本号排列的建表/升级脚本
+///
+/// 已发布的脚本不能再修改，结构调整只能追加新版本；
+/// 当前版本记录在数据库的 `PRAGMA user_version` 中
+const MIGRATIONS: &[(u32, &str)] = &[(
+    1,
+    "
+    CREATE TABLE master_data (
+        profile    TEXT    NOT NULL,
+        kind       TEXT    NOT NULL,
+        code       TEXT    NOT NULL,
+        name       TEXT    NOT NULL,
+        spec       TEXT,
+        unit       TEXT,
+        active     INTEGER NOT NULL DEFAULT 1,
+        updated_at TEXT    NOT NULL,
+        PRIMARY KEY (profile, kind, code)
+    );
+    CREATE INDEX idx_master_data_name ON master_data (profile, kind, name);
+    CREATE TABLE sync_state (
+        profile   TEXT    NOT NULL,
+        kind      TEXT    NOT NULL,
+        watermark TEXT    NOT NULL,
+        synced_at INTEGER NOT NULL,
+        PRIMARY KEY (profile, kind)
+    );
+    ",
+)];
+
+/// 将数据库升级到最新版本，返回升级后的版本号
+///
+/// 每个版本在独立事务中执行，失败时回滚该版本并停止
+pub fn migrate(connection: &mut Connection) -> rusqlite::Result<u32> {
+    let current: u32 = connection.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    let mut version = current;
+
+    for (target, script) in MIGRATIONS.iter().filter(|(target, _)| *target > current) {
+        let transaction = connection.transaction()?;
+        transaction.execute_batch(script)?;
+        transaction.pragma_update(None, "user_version", target)?;
+        transaction.commit()?;
+
+        println!("主数据缓存已升级到版本 {}", target);
+        version = *target;
+    }
+
+    Ok(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_version(connection: &Connection) -> u32 {
+        connection.query_row("PRAGMA user_version", [], |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn versions_are_increasing() {
+        assert!(MIGRATIONS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(MIGRATIONS[0].0, 1);
+    }
+
+    #[test]
+    fn migrates_new_database_to_latest() {
+        let mut connection = Connection::open_in_memory().unwrap();
+        let latest = MIGRATIONS.last().unwrap().0;
+
+        assert_eq!(migrate(&mut connection).unwrap(), latest);
+        assert_eq!(user_version(&connection), latest);
+        let tables: u32 = connection
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name IN ('master_data', 'sync_state')",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(tables, 2);
+
+        // 再次执行不重复建表
+        assert_eq!(migrate(&mut connection).unwrap(), latest);
+    }
+
+    #[test]
+    fn failed_migration_rolls_back_version() {
+        let mut connection = Connection::open_in_memory().unwrap();
+        // 已存在同名表时版本1的脚本失败，版本号保持不变
+        connection.execute_batch("CREATE TABLE master_data (code TEXT)").unwrap();
+
+        assert!(migrate(&mut connection).is_err());
+        assert_eq!(user_version(&connection), 0);
+        let sync_state: u32 = connection
+            .query_row("SELECT COUNT(*) FROM sqlite_master WHERE name = 'sync_state'", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(sync_state, 0, "失败版本的脚本整体回滚");
+    }
+}
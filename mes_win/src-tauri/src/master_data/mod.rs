@@ -0,0 +1,3 @@
+pub mod migrations;
+pub mod store;
+pub mod sync;
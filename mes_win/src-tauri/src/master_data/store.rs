@@ -0,0 +1,344 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use mes_win_shared::master_data::{MasterDataChanges, MasterDataItem, MasterDataKind, MasterDataStatus};
+use super::migrations::migrate;
+use crate::auth::store::now_secs;
+
+/// 缓存数据库文件名
+const CACHE_FILE_NAME: &str = "master-data.db";
+
+const ITEM_COLUMNS: &str = "kind, code, name, spec, unit, active, updated_at";
+
+/// 主数据本地缓存（应用数据目录下的 master-data.db）
+///
+/// 按后端档案分开保存，切换档案后查询的是对应环境的数据
+pub struct MasterDataStore {
+    connection: Mutex<Option<Connection>>,
+}
+
+impl MasterDataStore {
+    pub fn new() -> Self {
+        Self {
+            connection: Mutex::new(None),
+        }
+    }
+
+    /// 打开（必要时创建并升级）缓存数据库，失败时联想查询不可用
+    pub fn open(&self, data_dir: PathBuf) {
+        let path = data_dir.join(CACHE_FILE_NAME);
+        let opened = fs::create_dir_all(&data_dir)
+            .map_err(|e| e.to_string())
+            .and_then(|_| Connection::open(&path).map_err(|e| e.to_string()))
+            .and_then(|mut connection| {
+                migrate(&mut connection).map_err(|e| e.to_string())?;
+                Ok(connection)
+            });
+
+        match opened {
+            Ok(connection) => {
+                if let Ok(mut current) = self.connection.lock() {
+                    *current = Some(connection);
+                }
+            }
+            Err(e) => println!("打开主数据缓存失败，联想查询不可用: {}", e),
+        }
+    }
+
+    /// 内存中的缓存（单元测试用）
+    #[cfg(test)]
+    pub fn in_memory() -> Self {
+        let store = Self::new();
+        let mut connection = Connection::open_in_memory().unwrap();
+        migrate(&mut connection).unwrap();
+        *store.connection.lock().unwrap() = Some(connection);
+        store
+    }
+
+    /// 在数据库连接上执行操作
+    fn with_connection<T>(&self, f: impl FnOnce(&mut Connection) -> rusqlite::Result<T>) -> Result<T, String> {
+        let mut guard = self.connection.lock().map_err(|e| e.to_string())?;
+        let connection = guard.as_mut().ok_or_else(|| "主数据缓存不可用".to_string())?;
+        f(connection).map_err(|e| e.to_string())
+    }
+
+    /// 当前水位，未同步过时为 `None`
+    pub fn watermark(&self, profile: &str, kind: MasterDataKind) -> Result<Option<String>, String> {
+        self.with_connection(|connection| {
+            connection
+                .query_row(
+                    "SELECT watermark FROM sync_state WHERE profile = ?1 AND kind = ?2",
+                    params![profile, kind.code()],
+                    |row| row.get(0),
+                )
+                .optional()
+        })
+    }
+
+    /// 写入一批增量变更并推进水位（同一事务内完成）
+    pub fn apply(&self, profile: &str, kind: MasterDataKind, changes: &MasterDataChanges) -> Result<(), String> {
+        self.with_connection(|connection| {
+            let transaction = connection.transaction()?;
+            write_changes(&transaction, profile, kind, changes)?;
+            transaction.commit()
+        })
+    }
+
+    /// 以全量数据替换某类主数据（全量重建时使用）
+    pub fn replace(&self, profile: &str, kind: MasterDataKind, changes: &MasterDataChanges) -> Result<(), String> {
+        self.with_connection(|connection| {
+            let transaction = connection.transaction()?;
+            transaction.execute(
+                "DELETE FROM master_data WHERE profile = ?1 AND kind = ?2",
+                params![profile, kind.code()],
+            )?;
+            write_changes(&transaction, profile, kind, changes)?;
+            transaction.commit()
+        })
+    }
+
+    /// 清除某档案下的全部缓存和水位，下次同步重新全量拉取
+    pub fn clear(&self, profile: &str) -> Result<(), String> {
+        self.with_connection(|connection| {
+            let transaction = connection.transaction()?;
+            transaction.execute("DELETE FROM master_data WHERE profile = ?1", params![profile])?;
+            transaction.execute("DELETE FROM sync_state WHERE profile = ?1", params![profile])?;
+            transaction.commit()
+        })
+    }
+
+    /// 各类主数据的缓存状态
+    pub fn status(&self, profile: &str) -> Result<Vec<MasterDataStatus>, String> {
+        self.with_connection(|connection| {
+            MasterDataKind::ALL
+                .into_iter()
+                .map(|kind| {
+                    let count = connection.query_row(
+                        "SELECT COUNT(*) FROM master_data WHERE profile = ?1 AND kind = ?2",
+                        params![profile, kind.code()],
+                        |row| row.get(0),
+                    )?;
+                    let state = connection
+                        .query_row(
+                            "SELECT watermark, synced_at FROM sync_state WHERE profile = ?1 AND kind = ?2",
+                            params![profile, kind.code()],
+                            |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as u64)),
+                        )
+                        .optional()?;
+                    Ok(MasterDataStatus {
+                        kind,
+                        count,
+                        watermark: state.as_ref().map(|(watermark, _)| watermark.clone()),
+                        synced_at: state.map(|(_, synced_at)| synced_at),
+                    })
+                })
+                .collect()
+        })
+    }
+
+    /// 按编码、名称或规格模糊查找启用的条目
+    ///
+    /// 编码完全匹配的排最前，其次是编码前缀、名称前缀匹配，其余按编码排序
+    pub fn lookup(&self, profile: &str, kind: MasterDataKind, keyword: &str, limit: u32) -> Result<Vec<MasterDataItem>, String> {
+        let escaped = escape_like(keyword.trim());
+        let contains = format!("%{}%", escaped);
+        let prefix = format!("{}%", escaped);
+
+        self.with_connection(|connection| {
+            let mut statement = connection.prepare(&format!(
+                "SELECT {} FROM master_data
+                 WHERE profile = ?1 AND kind = ?2 AND active = 1
+                   AND (code LIKE ?3 ESCAPE '\\' OR name LIKE ?3 ESCAPE '\\' OR spec LIKE ?3 ESCAPE '\\')
+                 ORDER BY CASE
+                     WHEN code = ?4 COLLATE NOCASE THEN 0
+                     WHEN code LIKE ?5 ESCAPE '\\' THEN 1
+                     WHEN name LIKE ?5 ESCAPE '\\' THEN 2
+                     ELSE 3
+                 END, code
+                 LIMIT ?6",
+                ITEM_COLUMNS
+            ))?;
+            let rows = statement.query_map(
+                params![profile, kind.code(), contains, keyword.trim(), prefix, limit],
+                read_item,
+            )?;
+            rows.collect()
+        })
+    }
+}
+
+impl Default for MasterDataStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 写入新增/修改、删除的条目并记录水位
+fn write_changes(connection: &Connection, profile: &str, kind: MasterDataKind, changes: &MasterDataChanges) -> rusqlite::Result<()> {
+    let mut upsert = connection.prepare(
+        "INSERT INTO master_data (profile, kind, code, name, spec, unit, active, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+         ON CONFLICT (profile, kind, code) DO UPDATE SET
+             name = excluded.name, spec = excluded.spec, unit = excluded.unit,
+             active = excluded.active, updated_at = excluded.updated_at",
+    )?;
+    for item in changes.items.iter().filter(|item| item.kind == kind) {
+        upsert.execute(params![
+            profile,
+            kind.code(),
+            item.code,
+            item.name,
+            item.spec,
+            item.unit,
+            item.active,
+            item.updated_at,
+        ])?;
+    }
+
+    let mut delete = connection.prepare("DELETE FROM master_data WHERE profile = ?1 AND kind = ?2 AND code = ?3")?;
+    for code in &changes.deleted {
+        delete.execute(params![profile, kind.code(), code])?;
+    }
+
+    connection.execute(
+        "INSERT INTO sync_state (profile, kind, watermark, synced_at) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT (profile, kind) DO UPDATE SET watermark = excluded.watermark, synced_at = excluded.synced_at",
+        params![profile, kind.code(), changes.watermark, now_secs() as i64],
+    )?;
+    Ok(())
+}
+
+fn read_item(row: &Row) -> rusqlite::Result<MasterDataItem> {
+    let kind: String = row.get(0)?;
+    Ok(MasterDataItem {
+        kind: MasterDataKind::from_code(&kind).unwrap_or(MasterDataKind::Material),
+        code: row.get(1)?,
+        name: row.get(2)?,
+        spec: row.get(3)?,
+        unit: row.get(4)?,
+        active: row.get(5)?,
+        updated_at: row.get(6)?,
+    })
+}
+
+/// 转义 LIKE 通配符，关键字中的 `%`、`_` 按字面匹配
+fn escape_like(keyword: &str) -> String {
+    let mut escaped = String::with_capacity(keyword.len());
+    for c in keyword.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+// 全局主数据缓存实例
+lazy_static::lazy_static! {
+    pub static ref MASTER_DATA_STORE: MasterDataStore = MasterDataStore::new();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(kind: MasterDataKind, code: &str, name: &str, spec: Option<&str>, active: bool) -> MasterDataItem {
+        MasterDataItem {
+            kind,
+            code: code.to_string(),
+            name: name.to_string(),
+            spec: spec.map(str::to_string),
+            unit: None,
+            active,
+            updated_at: "2026-10-01T08:00".to_string(),
+        }
+    }
+
+    fn changes(items: Vec<MasterDataItem>, deleted: &[&str], watermark: &str) -> MasterDataChanges {
+        MasterDataChanges {
+            items,
+            deleted: deleted.iter().map(|code| code.to_string()).collect(),
+            watermark: watermark.to_string(),
+            has_more: false,
+        }
+    }
+
+    fn codes(items: Vec<MasterDataItem>) -> Vec<String> {
+        items.into_iter().map(|item| item.code).collect()
+    }
+
+    #[test]
+    fn escape_like_escapes_wildcards() {
+        assert_eq!(escape_like("M-001"), "M-001");
+        assert_eq!(escape_like("50%"), "50\\%");
+        assert_eq!(escape_like("A_B"), "A\\_B");
+        assert_eq!(escape_like("C:\\tmp"), "C:\\\\tmp");
+    }
+
+    #[test]
+    fn lookup_orders_exact_then_prefix_then_name() {
+        use MasterDataKind::Material;
+        let store = MasterDataStore::in_memory();
+        let items = vec![
+            item(Material, "XM-100", "M100 托架", None, true),
+            item(Material, "AB-9", "支架", Some("适配 M100"), true),
+            item(Material, "M100-B", "螺栓", None, true),
+            item(Material, "M100", "螺母", None, true),
+            item(Material, "M100-A", "垫片", None, true),
+            item(Material, "M100-X", "停用件", None, false),
+        ];
+        store.apply("default", Material, &changes(items, &[], "w1")).unwrap();
+
+        let found = store.lookup("default", Material, " m100 ", 10).unwrap();
+        assert_eq!(codes(found), ["M100", "M100-A", "M100-B", "XM-100", "AB-9"]);
+        assert_eq!(codes(store.lookup("default", Material, "m100", 2).unwrap()), ["M100", "M100-A"]);
+    }
+
+    #[test]
+    fn lookup_treats_wildcards_literally() {
+        use MasterDataKind::Material;
+        let store = MasterDataStore::in_memory();
+        let items = vec![
+            item(Material, "A_1", "下划线", None, true),
+            item(Material, "AB1", "字母", None, true),
+            item(Material, "P-50", "含量 50%", None, true),
+            item(Material, "P-500", "含量 500", None, true),
+        ];
+        store.apply("default", Material, &changes(items, &[], "w1")).unwrap();
+
+        assert_eq!(codes(store.lookup("default", Material, "A_", 10).unwrap()), ["A_1"]);
+        assert_eq!(codes(store.lookup("default", Material, "50%", 10).unwrap()), ["P-50"]);
+    }
+
+    #[test]
+    fn cache_is_scoped_by_profile_and_kind() {
+        let store = MasterDataStore::in_memory();
+        let material = item(MasterDataKind::Material, "M1", "钢板", None, true);
+        store.apply("default", MasterDataKind::Material, &changes(vec![material.clone()], &[], "w1")).unwrap();
+        store.apply("test", MasterDataKind::Material, &changes(vec![material], &[], "t1")).unwrap();
+
+        assert!(store.lookup("default", MasterDataKind::Product, "M1", 10).unwrap().is_empty());
+        assert_eq!(store.watermark("default", MasterDataKind::Material).unwrap().as_deref(), Some("w1"));
+
+        store.clear("default").unwrap();
+        assert!(store.lookup("default", MasterDataKind::Material, "M1", 10).unwrap().is_empty());
+        assert_eq!(store.watermark("default", MasterDataKind::Material).unwrap(), None);
+        assert_eq!(codes(store.lookup("test", MasterDataKind::Material, "M1", 10).unwrap()), ["M1"]);
+    }
+
+    #[test]
+    fn apply_deletes_and_replace_drops_stale_rows() {
+        use MasterDataKind::Material;
+        let store = MasterDataStore::in_memory();
+        let initial = vec![item(Material, "M1", "钢板", None, true), item(Material, "M2", "螺栓", None, true)];
+        store.apply("default", Material, &changes(initial, &[], "w1")).unwrap();
+
+        store.apply("default", Material, &changes(Vec::new(), &["M1"], "w2")).unwrap();
+        assert_eq!(codes(store.lookup("default", Material, "M", 10).unwrap()), ["M2"]);
+
+        store.replace("default", Material, &changes(vec![item(Material, "M3", "垫片", None, true)], &[], "w3")).unwrap();
+        assert_eq!(codes(store.lookup("default", Material, "M", 10).unwrap()), ["M3"]);
+        assert_eq!(store.watermark("default", Material).unwrap().as_deref(), Some("w3"));
+    }
+}
@@ -0,0 +1,172 @@
+use std::time::Duration;
+use tokio::sync::Notify;
+use mes_win_shared::master_data::{MasterDataChanges, MasterDataKind};
+use super::store::MASTER_DATA_STORE;
+use crate::api::error::ApiError;
+use crate::auth::client::AUTH_CLIENT;
+use crate::auth::guard::has_any;
+use crate::auth::store::USER_STORE;
+use crate::config::store::CONFIG_STORE;
+
+/// 后台增量同步间隔（秒）
+const SYNC_INTERVAL_SECS: u64 = 600;
+/// 每次拉取的变更条数
+const PAGE_SIZE: u32 = 500;
+
+lazy_static::lazy_static! {
+    // 登录成功后唤醒后台任务立即同步
+    static ref SYNC_WAKE: Notify = Notify::new();
+    // 同一时间只允许一个同步过程，避免水位被并发覆盖
+    static ref SYNC_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::new(());
+}
+
+/// 当前后端档案名称（缓存按档案分开保存）
+pub fn current_profile() -> String {
+    CONFIG_STORE.active_profile().map(|profile| profile.name).unwrap_or_default()
+}
+
+/// 启动后台同步任务：登录后及之后定时拉取主数据变更
+pub fn start_master_data_task() {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            if USER_STORE.get_current_user_info().is_some() {
+                if let Err(e) = sync_all(false).await {
+                    println!("主数据同步失败，继续使用本地缓存: {}", e);
+                }
+            }
+            let _ = tokio::time::timeout(Duration::from_secs(SYNC_INTERVAL_SECS), SYNC_WAKE.notified()).await;
+        }
+    });
+}
+
+/// 唤醒后台任务立即同步
+pub fn wake() {
+    SYNC_WAKE.notify_one();
+}
+
+/// 同步全部主数据
+///
+/// `full` 为 true 时忽略水位重新拉取全量数据并替换本地缓存；
+/// 当前用户或后端不允许读取的类别跳过，其余错误中止同步，已写入的批次保留
+pub async fn sync_all(full: bool) -> Result<(), ApiError> {
+    let _guard = SYNC_LOCK.lock().await;
+    let profile = current_profile();
+
+    for kind in MasterDataKind::ALL {
+        if !has_any(kind.required_permissions()) {
+            continue;
+        }
+        match sync_kind(&profile, kind, full).await {
+            Ok(changed) if changed > 0 => println!("{}主数据已同步 {} 条变更", kind.label(), changed),
+            Ok(_) => {}
+            Err(ApiError::Forbidden { .. }) => println!("没有{}主数据的读取权限，跳过同步", kind.label()),
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
+
+/// 从水位开始分批拉取某类主数据的变更，返回变更条数
+async fn sync_kind(profile: &str, kind: MasterDataKind, full: bool) -> Result<usize, ApiError> {
+    let mut watermark = if full {
+        None
+    } else {
+        MASTER_DATA_STORE.watermark(profile, kind).map_err(store_error)?
+    };
+    // 全量重建时先汇总全部批次，拉取完成后一次替换，中途失败不影响现有缓存
+    let mut collected = MasterDataChanges::default();
+    let mut changed = 0;
+
+    loop {
+        let changes = fetch_changes(kind, watermark.as_deref()).await?;
+        changed += changes.items.len() + changes.deleted.len();
+        watermark = Some(changes.watermark.clone());
+
+        let has_more = changes.has_more;
+        if full {
+            merge_batch(&mut collected, changes);
+        } else {
+            MASTER_DATA_STORE.apply(profile, kind, &changes).map_err(store_error)?;
+        }
+        if !has_more {
+            break;
+        }
+    }
+
+    if full {
+        MASTER_DATA_STORE.replace(profile, kind, &collected).map_err(store_error)?;
+    }
+    Ok(changed)
+}
+
+/// 全量重建时合并一批变更：同一编码以最后一次修改为准，批次中删除的编码从结果中去掉
+fn merge_batch(collected: &mut MasterDataChanges, changes: MasterDataChanges) {
+    for item in changes.items {
+        collected.items.retain(|existing| existing.code != item.code);
+        collected.items.push(item);
+    }
+    collected.items.retain(|item| !changes.deleted.contains(&item.code));
+    collected.watermark = changes.watermark;
+}
+
+/// 清除当前档案的主数据缓存（退出登录时调用，缓存内容按用户权限拉取）
+pub async fn clear_cache() {
+    let _guard = SYNC_LOCK.lock().await;
+    if let Err(e) = MASTER_DATA_STORE.clear(&current_profile()) {
+        println!("清除主数据缓存失败: {}", e);
+    }
+}
+
+/// 拉取水位之后的一批变更，未指定水位时从头拉取
+async fn fetch_changes(kind: MasterDataKind, since: Option<&str>) -> Result<MasterDataChanges, ApiError> {
+    let mut query = vec![("kind", kind.code().to_string()), ("limit", PAGE_SIZE.to_string())];
+    if let Some(since) = since {
+        query.push(("since", since.to_string()));
+    }
+    AUTH_CLIENT.get_json_with_query("/api/master-data/changes", &query).await
+}
+
+fn store_error(message: String) -> ApiError {
+    ApiError::Server { code: 500, message }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mes_win_shared::master_data::MasterDataItem;
+
+    fn item(code: &str, name: &str) -> MasterDataItem {
+        MasterDataItem {
+            kind: MasterDataKind::Material,
+            code: code.to_string(),
+            name: name.to_string(),
+            spec: None,
+            unit: None,
+            active: true,
+            updated_at: "2026-10-01T08:00".to_string(),
+        }
+    }
+
+    fn batch(items: Vec<MasterDataItem>, deleted: &[&str], watermark: &str) -> MasterDataChanges {
+        MasterDataChanges {
+            items,
+            deleted: deleted.iter().map(|code| code.to_string()).collect(),
+            watermark: watermark.to_string(),
+            has_more: true,
+        }
+    }
+
+    #[test]
+    fn full_sync_merges_batches_and_drops_deleted() {
+        let mut collected = MasterDataChanges::default();
+        merge_batch(&mut collected, batch(vec![item("M1", "钢板"), item("M2", "螺栓"), item("M3", "垫片")], &[], "w1"));
+        merge_batch(&mut collected, batch(vec![item("M1", "冷轧钢板")], &["M2"], "w2"));
+        // 删除后重新创建的编码保留
+        merge_batch(&mut collected, batch(vec![item("M2", "六角螺栓")], &["M3"], "w3"));
+
+        let items: Vec<(&str, &str)> = collected.items.iter().map(|i| (i.code.as_str(), i.name.as_str())).collect();
+        assert_eq!(items, [("M1", "冷轧钢板"), ("M2", "六角螺栓")]);
+        assert!(collected.deleted.is_empty());
+        assert_eq!(collected.watermark, "w3");
+    }
+}
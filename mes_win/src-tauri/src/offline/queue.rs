@@ -0,0 +1,408 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use rusqlite::{params, Connection, OptionalExtension};
+use mes_win_shared::offline::{OfflineStatus, QueuedStatus, QueuedTransaction, TransactionKind};
+use crate::auth::store::now_secs;
+
+/// 队列数据库文件名
+const QUEUE_FILE_NAME: &str = "offline.db";
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS offline_queue (
+        id              INTEGER PRIMARY KEY AUTOINCREMENT,
+        profile         TEXT    NOT NULL,
+        username        TEXT    NOT NULL,
+        kind            TEXT    NOT NULL,
+        summary         TEXT    NOT NULL,
+        path            TEXT    NOT NULL,
+        body            TEXT    NOT NULL,
+        idempotency_key TEXT    NOT NULL,
+        status          TEXT    NOT NULL DEFAULT 'PENDING',
+        attempts        INTEGER NOT NULL DEFAULT 0,
+        last_error      TEXT,
+        created_at      INTEGER NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS idx_offline_queue_owner ON offline_queue (profile, username, status, id);
+";
+
+/// 队列归属：事务只在提交它的后端档案和用户下重放
+#[derive(Debug, Clone, PartialEq)]
+pub struct Owner {
+    pub profile: String,
+    pub username: String,
+}
+
+/// 待重放的事务（含请求内容）
+#[derive(Debug, Clone)]
+pub struct StoredTransaction {
+    pub id: u64,
+    pub path: String,
+    pub body: serde_json::Value,
+    /// 幂等键，首次提交和每次重放都携带，避免超时后重复入账
+    pub idempotency_key: String,
+}
+
+/// 离线写事务队列（应用数据目录下的 offline.db）
+pub struct OfflineQueue {
+    connection: Mutex<Option<Connection>>,
+    online: AtomicBool,
+    last_sync_at: AtomicU64,
+}
+
+impl OfflineQueue {
+    pub fn new() -> Self {
+        Self {
+            connection: Mutex::new(None),
+            online: AtomicBool::new(true),
+            last_sync_at: AtomicU64::new(0),
+        }
+    }
+
+    /// 打开（必要时创建）队列数据库，失败时离线模式不可用，写操作直接返回错误
+    pub fn open(&self, data_dir: PathBuf) {
+        let path = data_dir.join(QUEUE_FILE_NAME);
+        let opened = fs::create_dir_all(&data_dir)
+            .map_err(|e| e.to_string())
+            .and_then(|_| Connection::open(&path).map_err(|e| e.to_string()))
+            .and_then(|connection| {
+                connection.execute_batch(SCHEMA).map_err(|e| e.to_string())?;
+                Ok(connection)
+            });
+
+        match opened {
+            Ok(connection) => {
+                if let Ok(mut current) = self.connection.lock() {
+                    *current = Some(connection);
+                }
+            }
+            Err(e) => println!("打开离线队列数据库失败，离线模式不可用: {}", e),
+        }
+    }
+
+    /// 内存中的队列（单元测试用）
+    #[cfg(test)]
+    pub fn in_memory() -> Self {
+        let queue = Self::new();
+        let connection = Connection::open_in_memory().unwrap();
+        connection.execute_batch(SCHEMA).unwrap();
+        *queue.connection.lock().unwrap() = Some(connection);
+        queue
+    }
+
+    /// 在数据库连接上执行操作
+    fn with_connection<T>(&self, f: impl FnOnce(&Connection) -> rusqlite::Result<T>) -> Result<T, String> {
+        let guard = self.connection.lock().map_err(|e| e.to_string())?;
+        let connection = guard.as_ref().ok_or_else(|| "离线队列不可用".to_string())?;
+        f(connection).map_err(|e| e.to_string())
+    }
+
+    /// 写入一条待重放事务，返回队列编号
+    pub fn enqueue(
+        &self,
+        owner: &Owner,
+        kind: TransactionKind,
+        summary: &str,
+        path: &str,
+        body: &serde_json::Value,
+        idempotency_key: &str,
+    ) -> Result<u64, String> {
+        self.with_connection(|connection| {
+            connection.execute(
+                "INSERT INTO offline_queue (profile, username, kind, summary, path, body, idempotency_key, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    owner.profile,
+                    owner.username,
+                    kind.code(),
+                    summary,
+                    path,
+                    body.to_string(),
+                    idempotency_key,
+                    now_secs() as i64,
+                ],
+            )?;
+            Ok(connection.last_insert_rowid() as u64)
+        })
+    }
+
+    /// 是否有等待重放的事务（有则新的写操作也须排队，保证提交顺序）
+    pub fn has_pending(&self, owner: &Owner) -> bool {
+        self.with_connection(|connection| {
+            connection
+                .query_row(
+                    "SELECT 1 FROM offline_queue WHERE profile = ?1 AND username = ?2 AND status = 'PENDING' LIMIT 1",
+                    params![owner.profile, owner.username],
+                    |_| Ok(()),
+                )
+                .optional()
+        })
+        .map(|found| found.is_some())
+        .unwrap_or(false)
+    }
+
+    /// 是否已有提交到同一地址的同类事务（待重放或冲突）
+    pub fn has_queued(&self, owner: &Owner, kind: TransactionKind, path: &str) -> bool {
+        self.with_connection(|connection| {
+            connection
+                .query_row(
+                    "SELECT 1 FROM offline_queue WHERE profile = ?1 AND username = ?2 AND kind = ?3 AND path = ?4 LIMIT 1",
+                    params![owner.profile, owner.username, kind.code(), path],
+                    |_| Ok(()),
+                )
+                .optional()
+        })
+        .map(|found| found.is_some())
+        .unwrap_or(false)
+    }
+
+    /// 按入队顺序取出待重放事务
+    pub fn pending(&self, owner: &Owner) -> Result<Vec<StoredTransaction>, String> {
+        self.with_connection(|connection| {
+            let mut statement = connection.prepare(
+                "SELECT id, path, body, idempotency_key FROM offline_queue
+                 WHERE profile = ?1 AND username = ?2 AND status = 'PENDING' ORDER BY id",
+            )?;
+            let rows = statement.query_map(params![owner.profile, owner.username], |row| {
+                let body: String = row.get(2)?;
+                Ok(StoredTransaction {
+                    id: row.get::<_, i64>(0)? as u64,
+                    path: row.get(1)?,
+                    body: serde_json::from_str(&body).unwrap_or(serde_json::Value::Null),
+                    idempotency_key: row.get(3)?,
+                })
+            })?;
+            rows.collect()
+        })
+    }
+
+    /// 当前用户的全部未完成事务
+    pub fn list(&self, owner: &Owner) -> Result<Vec<QueuedTransaction>, String> {
+        self.with_connection(|connection| {
+            let mut statement = connection.prepare(
+                "SELECT id, kind, summary, status, attempts, last_error, created_at FROM offline_queue
+                 WHERE profile = ?1 AND username = ?2 ORDER BY id",
+            )?;
+            let rows = statement.query_map(params![owner.profile, owner.username], |row| {
+                let kind: String = row.get(1)?;
+                let status: String = row.get(3)?;
+                Ok(QueuedTransaction {
+                    id: row.get::<_, i64>(0)? as u64,
+                    kind: TransactionKind::from_code(&kind).unwrap_or(TransactionKind::GoodsReceipt),
+                    summary: row.get(2)?,
+                    status: QueuedStatus::from_code(&status).unwrap_or(QueuedStatus::Pending),
+                    attempts: row.get(4)?,
+                    last_error: row.get(5)?,
+                    created_at: row.get::<_, i64>(6)? as u64,
+                })
+            })?;
+            rows.collect()
+        })
+    }
+
+    /// 重放成功后移出队列
+    pub fn remove(&self, id: u64) -> Result<(), String> {
+        self.with_connection(|connection| {
+            connection.execute("DELETE FROM offline_queue WHERE id = ?1", params![id as i64])?;
+            Ok(())
+        })
+    }
+
+    /// 记录一次暂时性失败（网络、服务器错误），保持待重放
+    pub fn record_failure(&self, id: u64, message: &str) -> Result<(), String> {
+        self.with_connection(|connection| {
+            connection.execute(
+                "UPDATE offline_queue SET attempts = attempts + 1, last_error = ?2 WHERE id = ?1",
+                params![id as i64, message],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// 标记为冲突，等待用户重试或放弃
+    pub fn mark_conflict(&self, id: u64, message: &str) -> Result<(), String> {
+        self.with_connection(|connection| {
+            connection.execute(
+                "UPDATE offline_queue SET status = 'CONFLICT', attempts = attempts + 1, last_error = ?2 WHERE id = ?1",
+                params![id as i64, message],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// 冲突事务重新排队，返回是否找到该事务
+    pub fn retry(&self, owner: &Owner, id: u64) -> Result<bool, String> {
+        self.with_connection(|connection| {
+            let changed = connection.execute(
+                "UPDATE offline_queue SET status = 'PENDING'
+                 WHERE id = ?1 AND profile = ?2 AND username = ?3 AND status = 'CONFLICT'",
+                params![id as i64, owner.profile, owner.username],
+            )?;
+            Ok(changed > 0)
+        })
+    }
+
+    /// 放弃事务，返回是否找到该事务
+    pub fn discard(&self, owner: &Owner, id: u64) -> Result<bool, String> {
+        self.with_connection(|connection| {
+            let changed = connection.execute(
+                "DELETE FROM offline_queue WHERE id = ?1 AND profile = ?2 AND username = ?3",
+                params![id as i64, owner.profile, owner.username],
+            )?;
+            Ok(changed > 0)
+        })
+    }
+
+    /// 当前用户的队列状态
+    pub fn status(&self, owner: Option<&Owner>) -> OfflineStatus {
+        let (pending, conflicts) = owner
+            .and_then(|owner| {
+                self.with_connection(|connection| {
+                    connection.query_row(
+                        "SELECT COALESCE(SUM(status = 'PENDING'), 0), COALESCE(SUM(status = 'CONFLICT'), 0)
+                         FROM offline_queue WHERE profile = ?1 AND username = ?2",
+                        params![owner.profile, owner.username],
+                        |row| Ok((row.get::<_, u32>(0)?, row.get::<_, u32>(1)?)),
+                    )
+                })
+                .ok()
+            })
+            .unwrap_or_default();
+
+        let last_sync_at = self.last_sync_at.load(Ordering::Relaxed);
+        OfflineStatus {
+            online: self.is_online(),
+            pending,
+            conflicts,
+            last_sync_at: (last_sync_at > 0).then_some(last_sync_at),
+        }
+    }
+
+    pub fn is_online(&self) -> bool {
+        self.online.load(Ordering::Relaxed)
+    }
+
+    /// 更新连接状态，返回是否发生变化
+    pub fn set_online(&self, online: bool) -> bool {
+        self.online.swap(online, Ordering::Relaxed) != online
+    }
+
+    pub fn mark_synced(&self) {
+        self.last_sync_at.store(now_secs(), Ordering::Relaxed);
+    }
+}
+
+impl Default for OfflineQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// 全局离线队列实例
+lazy_static::lazy_static! {
+    pub static ref OFFLINE_QUEUE: OfflineQueue = OfflineQueue::new();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn owner(profile: &str, username: &str) -> Owner {
+        Owner { profile: profile.into(), username: username.into() }
+    }
+
+    fn enqueue(queue: &OfflineQueue, owner: &Owner, key: &str) -> u64 {
+        queue
+            .enqueue(owner, TransactionKind::BinTransfer, key, &format!("/api/{}", key), &json!({ "key": key }), key)
+            .unwrap()
+    }
+
+    fn pending_keys(queue: &OfflineQueue, owner: &Owner) -> Vec<String> {
+        queue.pending(owner).unwrap().into_iter().map(|t| t.idempotency_key).collect()
+    }
+
+    #[test]
+    fn replays_in_fifo_order() {
+        let queue = OfflineQueue::in_memory();
+        let user = owner("default", "wh01");
+        for key in ["k1", "k2", "k3"] {
+            enqueue(&queue, &user, key);
+        }
+
+        let pending = queue.pending(&user).unwrap();
+        assert_eq!(pending.iter().map(|t| t.idempotency_key.as_str()).collect::<Vec<_>>(), ["k1", "k2", "k3"]);
+        assert_eq!(pending[1].path, "/api/k2");
+        assert_eq!(pending[1].body, json!({ "key": "k2" }));
+
+        // 队首重放成功后，下一次从第二条开始
+        queue.remove(pending[0].id).unwrap();
+        assert_eq!(pending_keys(&queue, &user), ["k2", "k3"]);
+    }
+
+    #[test]
+    fn scoped_by_profile_and_user() {
+        let queue = OfflineQueue::in_memory();
+        let user = owner("default", "wh01");
+        let other_user = owner("default", "wh02");
+        let other_profile = owner("test", "wh01");
+        let id = enqueue(&queue, &user, "k1");
+        enqueue(&queue, &other_profile, "k2");
+
+        assert!(queue.has_pending(&user));
+        assert!(!queue.has_pending(&other_user));
+        assert_eq!(pending_keys(&queue, &other_profile), ["k2"]);
+        assert!(queue.list(&other_user).unwrap().is_empty());
+        assert_eq!(queue.status(Some(&user)).pending, 1);
+        assert_eq!(queue.status(None).pending, 0);
+
+        // 其他用户不能重试或放弃不属于自己的事务
+        queue.mark_conflict(id, "库存不足").unwrap();
+        assert!(!queue.retry(&other_user, id).unwrap());
+        assert!(!queue.discard(&other_profile, id).unwrap());
+        assert_eq!(queue.list(&user).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn retries_reuse_idempotency_key() {
+        let queue = OfflineQueue::in_memory();
+        let user = owner("default", "wh01");
+        let id = enqueue(&queue, &user, "k1");
+
+        queue.record_failure(id, "连接超时").unwrap();
+        assert_eq!(pending_keys(&queue, &user), ["k1"], "暂时性失败后保持原幂等键");
+
+        queue.mark_conflict(id, "库存不足").unwrap();
+        assert!(queue.retry(&user, id).unwrap());
+        assert_eq!(pending_keys(&queue, &user), ["k1"], "冲突重试后保持原幂等键");
+        assert_eq!(queue.list(&user).unwrap()[0].attempts, 2);
+    }
+
+    #[test]
+    fn conflict_retry_and_discard_change_status() {
+        let queue = OfflineQueue::in_memory();
+        let user = owner("default", "wh01");
+        let first = enqueue(&queue, &user, "k1");
+        let second = enqueue(&queue, &user, "k2");
+
+        queue.mark_conflict(first, "库存不足").unwrap();
+        let listed = queue.list(&user).unwrap();
+        assert_eq!(listed[0].status, QueuedStatus::Conflict);
+        assert_eq!(listed[0].last_error.as_deref(), Some("库存不足"));
+        assert_eq!(listed[1].status, QueuedStatus::Pending);
+        assert_eq!(pending_keys(&queue, &user), ["k2"], "冲突事务不参与重放");
+        let status = queue.status(Some(&user));
+        assert_eq!((status.pending, status.conflicts), (1, 1));
+
+        // 只有冲突事务可以重试
+        assert!(!queue.retry(&user, second).unwrap());
+        assert!(queue.retry(&user, first).unwrap());
+        assert_eq!(queue.list(&user).unwrap()[0].status, QueuedStatus::Pending);
+        assert_eq!(pending_keys(&queue, &user), ["k1", "k2"], "重试后按原顺序重放");
+
+        assert!(queue.discard(&user, first).unwrap());
+        assert!(!queue.discard(&user, first).unwrap());
+        assert_eq!(pending_keys(&queue, &user), ["k2"]);
+    }
+}
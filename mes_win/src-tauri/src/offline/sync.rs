@@ -0,0 +1,207 @@
+use std::future::Future;
+use std::sync::OnceLock;
+use std::time::Duration;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::Notify;
+use mes_win_shared::events;
+use mes_win_shared::offline::{OfflineStatus, TransactionKind};
+use super::queue::{Owner, OFFLINE_QUEUE};
+use crate::api::error::ApiError;
+use crate::auth::client::AUTH_CLIENT;
+use crate::auth::store::{now_secs, USER_STORE};
+use crate::config::store::CONFIG_STORE;
+
+/// 后台重放间隔（秒）
+const SYNC_INTERVAL_SECS: u64 = 30;
+
+// 用于向所有窗口广播离线状态
+static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+
+lazy_static::lazy_static! {
+    // 有新事务入队或用户手动同步时唤醒后台任务
+    static ref SYNC_WAKE: Notify = Notify::new();
+    // 同一时间只允许一个重放过程，保证提交顺序
+    static ref SYNC_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::new(());
+}
+
+/// 当前登录用户对应的队列归属
+pub fn current_owner() -> Option<Owner> {
+    let (username, _) = USER_STORE.get_current_user_info()?;
+    let profile = CONFIG_STORE.active_profile().map(|profile| profile.name).unwrap_or_default();
+    Some(Owner { profile, username })
+}
+
+/// 当前用户的离线状态
+pub fn current_status() -> OfflineStatus {
+    OFFLINE_QUEUE.status(current_owner().as_ref())
+}
+
+/// 向所有窗口广播离线状态
+pub fn notify_status() {
+    let Some(app) = APP_HANDLE.get() else {
+        return;
+    };
+    if let Err(e) = app.emit(events::OFFLINE_STATUS, current_status()) {
+        println!("发送离线状态事件失败: {}", e);
+    }
+}
+
+/// 启动后台重放任务：定时或被唤醒时按入队顺序提交离线事务
+pub fn start_sync_task(app: AppHandle) {
+    let _ = APP_HANDLE.set(app);
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let _ = tokio::time::timeout(Duration::from_secs(SYNC_INTERVAL_SECS), SYNC_WAKE.notified()).await;
+            if current_owner().is_some_and(|owner| OFFLINE_QUEUE.has_pending(&owner)) {
+                replay().await;
+            }
+            notify_status();
+        }
+    });
+}
+
+/// 唤醒后台任务立即重放
+pub fn wake() {
+    SYNC_WAKE.notify_one();
+}
+
+/// 服务端预检查（工单状态、库存等）
+///
+/// 有待重放事务时服务端数据尚未包含这些操作，后端不可达时也无法检查，
+/// 两种情况都跳过预检查，由重放时后端校验，不通过则报告为冲突
+pub async fn precheck<F>(check: F) -> Result<(), ApiError>
+where
+    F: Future<Output = Result<(), ApiError>>,
+{
+    if current_owner().is_some_and(|owner| OFFLINE_QUEUE.has_pending(&owner)) {
+        return Ok(());
+    }
+    match check.await {
+        Err(e) if e.is_unreachable() => {
+            if OFFLINE_QUEUE.set_online(false) {
+                notify_status();
+            }
+            Ok(())
+        }
+        other => other,
+    }
+}
+
+/// 提交写事务，后端不可达或已有待重放事务时转入离线队列
+///
+/// 入队后返回 `ApiError::Queued`，前端按已受理处理
+pub async fn post_or_queue<B, T>(kind: TransactionKind, summary: String, path: &str, body: &B) -> Result<T, ApiError>
+where
+    B: Serialize + ?Sized,
+    T: DeserializeOwned,
+{
+    let owner = current_owner().ok_or_else(ApiError::unauthorized)?;
+    let body = serde_json::to_value(body)?;
+    let idempotency_key = format!("{}-{}-{}", owner.username, now_secs(), rand_suffix());
+
+    if !OFFLINE_QUEUE.has_pending(&owner) {
+        match AUTH_CLIENT.post_json_with_key(path, &idempotency_key, &body).await {
+            Err(e) if e.is_unreachable() => {
+                println!("后端不可达，{}转入离线队列: {}", kind.label(), e);
+                OFFLINE_QUEUE.set_online(false);
+            }
+            result => {
+                if OFFLINE_QUEUE.set_online(true) {
+                    notify_status();
+                }
+                return result;
+            }
+        }
+    }
+
+    let id = OFFLINE_QUEUE
+        .enqueue(&owner, kind, &summary, path, &body, &idempotency_key)
+        .map_err(|message| ApiError::Server { code: 500, message })?;
+    println!("离线事务已入队 #{}: {}", id, summary);
+    notify_status();
+    wake();
+
+    Err(ApiError::Queued {
+        id,
+        message: format!("网络不可用，{}已保存到离线队列，恢复连接后自动提交", kind.label()),
+    })
+}
+
+/// 按入队顺序重放当前用户的待提交事务，返回成功提交的数量
+///
+/// - 网络不可达、未登录或服务器内部错误：停止重放，保留剩余事务稍后重试
+/// - 后端拒绝（校验失败、无权限、状态冲突等）：标记为冲突，继续后续事务
+pub async fn replay() -> usize {
+    let _guard = SYNC_LOCK.lock().await;
+    let Some(owner) = current_owner() else {
+        return 0;
+    };
+    let transactions = match OFFLINE_QUEUE.pending(&owner) {
+        Ok(transactions) => transactions,
+        Err(e) => {
+            println!("读取离线队列失败: {}", e);
+            return 0;
+        }
+    };
+
+    let mut synced = 0;
+    for transaction in transactions {
+        let result: Result<serde_json::Value, ApiError> = AUTH_CLIENT
+            .post_json_with_key(&transaction.path, &transaction.idempotency_key, &transaction.body)
+            .await;
+
+        let recorded = match result {
+            Ok(_) => {
+                synced += 1;
+                OFFLINE_QUEUE.set_online(true);
+                OFFLINE_QUEUE.remove(transaction.id)
+            }
+            Err(e) if e.is_unreachable() => {
+                OFFLINE_QUEUE.set_online(false);
+                let _ = OFFLINE_QUEUE.record_failure(transaction.id, &e.user_message());
+                break;
+            }
+            Err(e @ ApiError::Unauthorized { .. }) => {
+                let _ = OFFLINE_QUEUE.record_failure(transaction.id, &e.user_message());
+                break;
+            }
+            Err(e @ ApiError::Server { code, .. }) if code >= 500 => {
+                OFFLINE_QUEUE.set_online(true);
+                let _ = OFFLINE_QUEUE.record_failure(transaction.id, &e.user_message());
+                break;
+            }
+            Err(e) => {
+                println!("离线事务 #{} 重放冲突: {}", transaction.id, e);
+                OFFLINE_QUEUE.set_online(true);
+                OFFLINE_QUEUE.mark_conflict(transaction.id, &e.user_message())
+            }
+        };
+        if let Err(e) = recorded {
+            println!("更新离线队列失败: {}", e);
+            break;
+        }
+    }
+
+    if synced > 0 {
+        OFFLINE_QUEUE.mark_synced();
+        println!("离线事务已同步 {} 条", synced);
+    }
+    synced
+}
+
+/// 幂等键的随机部分（同一秒内多次提交时区分）
+fn rand_suffix() -> u32 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(nanos);
+    hasher.finish() as u32
+}
@@ -0,0 +1,106 @@
+use mes_win_shared::production::work_order::WorkOrderStatus;
+use crate::api::error::ApiError;
+
+/// 工单状态机
+///
+/// 新建 → 已下达 → 生产中 → 已完工 → 已关闭；
+/// 未开工的工单可以退回或直接关闭，生产中的工单可以强制关闭
+pub fn transitions(from: WorkOrderStatus) -> &'static [WorkOrderStatus] {
+    use WorkOrderStatus::*;
+
+    match from {
+        Created => &[Released, Closed],
+        Released => &[InProgress, Created, Closed],
+        InProgress => &[Completed, Closed],
+        Completed => &[Closed],
+        Closed => &[],
+    }
+}
+
+/// 是否允许从 `from` 流转到 `to`
+pub fn can_transition(from: WorkOrderStatus, to: WorkOrderStatus) -> bool {
+    transitions(from).contains(&to)
+}
+
+/// 校验状态流转，不允许时返回校验错误
+pub fn check_transition(from: WorkOrderStatus, to: WorkOrderStatus) -> Result<(), ApiError> {
+    if can_transition(from, to) {
+        Ok(())
+    } else {
+        Err(ApiError::invalid_field(
+            "status",
+            format!("工单状态不能从「{}」变更为「{}」", from.label(), to.label()),
+        ))
+    }
+}
+
+/// 工单内容是否允许编辑（开工后计划信息不可再修改）
+pub fn is_editable(status: WorkOrderStatus) -> bool {
+    matches!(status, WorkOrderStatus::Created | WorkOrderStatus::Released)
+}
+
+/// 工单当前状态是否允许领用物料（下达后、完工前）
+pub fn can_consume_material(status: WorkOrderStatus) -> bool {
+    matches!(status, WorkOrderStatus::Released | WorkOrderStatus::InProgress)
+}
+
+/// 工单当前状态是否允许报工入库（仅生产中）
+pub fn can_report_output(status: WorkOrderStatus) -> bool {
+    status == WorkOrderStatus::InProgress
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use WorkOrderStatus::*;
+
+    /// 允许的全部流转
+    const ALLOWED: [(WorkOrderStatus, WorkOrderStatus); 8] = [
+        (Created, Released),
+        (Created, Closed),
+        (Released, InProgress),
+        (Released, Created),
+        (Released, Closed),
+        (InProgress, Completed),
+        (InProgress, Closed),
+        (Completed, Closed),
+    ];
+
+    #[test]
+    fn allowed_transitions() {
+        for (from, to) in ALLOWED {
+            assert!(can_transition(from, to), "{:?} → {:?} 应允许", from, to);
+            assert!(check_transition(from, to).is_ok());
+        }
+    }
+
+    #[test]
+    fn every_other_transition_is_rejected() {
+        for from in WorkOrderStatus::ALL {
+            for to in WorkOrderStatus::ALL {
+                if !ALLOWED.contains(&(from, to)) {
+                    assert!(!can_transition(from, to), "{:?} → {:?} 应拒绝", from, to);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rejected_transitions_report_status_field() {
+        let rejected = [
+            (Created, InProgress),
+            (Created, Completed),
+            (Released, Completed),
+            (InProgress, Released),
+            (Completed, InProgress),
+            (Closed, Created),
+            (Closed, Closed),
+        ];
+        for (from, to) in rejected {
+            match check_transition(from, to) {
+                Err(ApiError::Validation { fields, .. }) => assert_eq!(fields[0].field, "status"),
+                other => panic!("{:?} → {:?} 应返回校验错误，实际 {:?}", from, to, other),
+            }
+        }
+    }
+}
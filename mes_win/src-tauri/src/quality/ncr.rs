@@ -0,0 +1,175 @@
+use mes_win_shared::quality::ncr::{Ncr, NcrStatus};
+use crate::api::error::ApiError;
+
+/// 不合格报告状态机
+///
+/// 待处理 → 调查中 → 整改中 → 已验证 → 已关闭；
+/// 根本原因不成立时可从整改退回调查，验证不通过时退回整改
+pub fn transitions(from: NcrStatus) -> &'static [NcrStatus] {
+    use NcrStatus::*;
+
+    match from {
+        Open => &[Investigating],
+        Investigating => &[Action, Open],
+        Action => &[Verified, Investigating],
+        Verified => &[Closed, Action],
+        Closed => &[],
+    }
+}
+
+/// 是否允许从 `from` 流转到 `to`
+pub fn can_transition(from: NcrStatus, to: NcrStatus) -> bool {
+    transitions(from).contains(&to)
+}
+
+/// 校验状态流转，同时检查进入目标状态前须完成的8D步骤
+pub fn check_transition(ncr: &Ncr, to: NcrStatus) -> Result<(), ApiError> {
+    if !can_transition(ncr.status, to) {
+        return Err(ApiError::invalid_field(
+            "status",
+            format!("问题状态不能从「{}」变更为「{}」", ncr.status.label(), to.label()),
+        ));
+    }
+
+    let report = &ncr.report;
+    let missing = match to {
+        NcrStatus::Investigating if ncr.owner.as_deref().is_none_or(|owner| owner.trim().is_empty()) => {
+            Some(("owner", "请先指定责任人"))
+        }
+        NcrStatus::Action if report.root_cause.trim().is_empty() => Some(("report.rootCause", "请先填写D4根本原因")),
+        NcrStatus::Verified if report.corrective_action.trim().is_empty() => {
+            Some(("report.correctiveAction", "请先填写D5纠正措施"))
+        }
+        NcrStatus::Closed if report.verification.trim().is_empty() => {
+            Some(("report.verification", "请先填写D6实施与效果验证"))
+        }
+        _ => None,
+    };
+    match missing {
+        Some((field, message)) => Err(ApiError::invalid_field(field, message)),
+        None => Ok(()),
+    }
+}
+
+/// 报告内容是否允许编辑（关闭后只读）
+pub fn is_editable(status: NcrStatus) -> bool {
+    status != NcrStatus::Closed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mes_win_shared::quality::ncr::{EightD, Severity};
+    use NcrStatus::*;
+
+    /// 允许的全部流转（含退回）
+    const ALLOWED: [(NcrStatus, NcrStatus); 7] = [
+        (Open, Investigating),
+        (Investigating, Action),
+        (Investigating, Open),
+        (Action, Verified),
+        (Action, Investigating),
+        (Verified, Closed),
+        (Verified, Action),
+    ];
+
+    /// 处于 `status`、8D步骤均已填写的问题
+    fn ncr(status: NcrStatus) -> Ncr {
+        Ncr {
+            id: 1,
+            ncr_no: "NCR-001".to_string(),
+            title: "外径超差".to_string(),
+            inspection_id: None,
+            inspection_no: None,
+            item_code: "M-001".to_string(),
+            item_name: String::new(),
+            quantity: 3.0,
+            severity: Severity::Major,
+            owner: Some("qe01".to_string()),
+            status,
+            report: EightD {
+                root_cause: "刀具磨损".to_string(),
+                corrective_action: "更换刀具并缩短换刀周期".to_string(),
+                verification: "连续三批抽检合格".to_string(),
+                ..EightD::default()
+            },
+            attachments: Vec::new(),
+            created_by: None,
+            created_at: "2026-10-01 08:00".to_string(),
+            updated_at: None,
+        }
+    }
+
+    /// （当前状态, 目标状态, 清空某步骤内容, 预期的错误字段）
+    type StepCase = (NcrStatus, NcrStatus, fn(&mut Ncr), &'static str);
+
+    fn error_field(result: Result<(), ApiError>) -> Option<String> {
+        match result {
+            Ok(()) => None,
+            Err(ApiError::Validation { fields, .. }) => fields.first().map(|field| field.field.clone()),
+            Err(other) => panic!("应返回校验错误，实际 {:?}", other),
+        }
+    }
+
+    #[test]
+    fn allowed_transitions() {
+        for (from, to) in ALLOWED {
+            assert!(can_transition(from, to), "{:?} → {:?} 应允许", from, to);
+            assert!(check_transition(&ncr(from), to).is_ok(), "{:?} → {:?}", from, to);
+        }
+    }
+
+    #[test]
+    fn every_other_transition_is_rejected() {
+        for from in NcrStatus::ALL {
+            for to in NcrStatus::ALL {
+                if !ALLOWED.contains(&(from, to)) {
+                    assert!(!can_transition(from, to), "{:?} → {:?} 应拒绝", from, to);
+                    assert_eq!(error_field(check_transition(&ncr(from), to)).as_deref(), Some("status"));
+                }
+            }
+        }
+        assert!(transitions(Closed).is_empty(), "关闭后不能再流转");
+    }
+
+    #[test]
+    fn backward_transitions_skip_step_checks() {
+        // 退回时不要求目标状态之后的步骤内容
+        let mut back_to_investigating = ncr(Action);
+        back_to_investigating.report.root_cause.clear();
+        assert!(check_transition(&back_to_investigating, Investigating).is_ok());
+
+        let mut back_to_action = ncr(Verified);
+        back_to_action.report.corrective_action.clear();
+        back_to_action.report.verification.clear();
+        assert!(check_transition(&back_to_action, Action).is_ok());
+        // 重新进入整改仍要求已有D4根本原因
+        back_to_action.report.root_cause.clear();
+        assert_eq!(error_field(check_transition(&back_to_action, Action)).as_deref(), Some("report.rootCause"));
+    }
+
+    #[test]
+    fn missing_steps_report_field() {
+        let cases: [StepCase; 6] = [
+            (Open, Investigating, |ncr| ncr.owner = None, "owner"),
+            (Open, Investigating, |ncr| ncr.owner = Some("  ".to_string()), "owner"),
+            (Investigating, Action, |ncr| ncr.report.root_cause = " ".to_string(), "report.rootCause"),
+            (Action, Verified, |ncr| ncr.report.corrective_action.clear(), "report.correctiveAction"),
+            (Verified, Closed, |ncr| ncr.report.verification.clear(), "report.verification"),
+            // 状态不允许时先报状态错误
+            (Open, Closed, |ncr| ncr.report.verification.clear(), "status"),
+        ];
+        for (from, to, clear, field) in cases {
+            let mut ncr = ncr(from);
+            clear(&mut ncr);
+            assert_eq!(error_field(check_transition(&ncr, to)).as_deref(), Some(field), "{:?} → {:?}", from, to);
+        }
+    }
+
+    #[test]
+    fn closed_reports_are_read_only() {
+        for status in NcrStatus::ALL {
+            assert_eq!(is_editable(status), status != Closed, "{:?}", status);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use mes_win_shared::report::ReportTable;
+use super::body_rows;
+
+/// 含逗号、引号或换行的字段加引号，内部引号加倍；
+/// 以 = + - @ 开头的非数字字段前加 ' ，避免Excel打开时作为公式执行
+fn escape(field: &str) -> String {
+    let field = if field.starts_with(['=', '+', '-', '@']) && field.parse::<f64>().is_err() {
+        format!("'{}", field)
+    } else {
+        field.to_string()
+    };
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+/// 导出CSV（UTF-8带BOM，Excel直接打开不乱码）
+pub fn write(table: &ReportTable) -> Vec<u8> {
+    let mut lines = Vec::with_capacity(table.rows.len() + 2);
+    lines.push(
+        table
+            .columns
+            .iter()
+            .map(|column| escape(&column.title))
+            .collect::<Vec<_>>()
+            .join(","),
+    );
+    for row in body_rows(table) {
+        lines.push(
+            row.iter()
+                .zip(&table.columns)
+                .map(|(cell, column)| escape(&cell.display(column.kind)))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+    }
+
+    let mut bytes = vec![0xEF, 0xBB, 0xBF];
+    bytes.extend(lines.join("\r\n").into_bytes());
+    bytes.extend(b"\r\n");
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::test_table;
+
+    #[test]
+    fn escape_quotes_separators() {
+        assert_eq!(escape("A001"), "A001");
+        assert_eq!(escape("a,b"), "\"a,b\"");
+        assert_eq!(escape("说明\"加引号\""), "\"说明\"\"加引号\"\"\"");
+        assert_eq!(escape("两行\n备注"), "\"两行\n备注\"");
+    }
+
+    #[test]
+    fn escape_neutralizes_formulas() {
+        let cases = [
+            ("=SUM(A1:A9)", "'=SUM(A1:A9)"),
+            ("+1+cmd", "'+1+cmd"),
+            ("-2+3", "'-2+3"),
+            ("@SUM(A1)", "'@SUM(A1)"),
+            ("=HYPERLINK(\"x\",\"y\")", "\"'=HYPERLINK(\"\"x\"\",\"\"y\"\")\""),
+            // 数字（如负数）保持原样
+            ("-12.5", "-12.5"),
+            ("1=1", "1=1"),
+        ];
+        for (field, expected) in cases {
+            assert_eq!(escape(field), expected, "字段 {}", field);
+        }
+    }
+
+    #[test]
+    fn write_adds_bom_and_crlf() {
+        let bytes = write(&test_table(1));
+        assert!(bytes.starts_with(&[0xEF, 0xBB, 0xBF]));
+        let text = String::from_utf8(bytes[3..].to_vec()).unwrap();
+        let lines: Vec<&str> = text.split("\r\n").collect();
+        assert_eq!(lines, ["工单号,报废数量", "WO-1,1", "合计,1", ""]);
+    }
+}
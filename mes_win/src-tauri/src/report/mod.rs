@@ -0,0 +1,228 @@
+// 报表子系统：报表定义、结果组装和各格式的导出
+pub mod csv;
+pub mod pdf;
+pub mod xlsx;
+
+use std::collections::BTreeMap;
+use serde_json::{Map, Value};
+use mes_win_shared::report::{
+    Cell, ColumnKind, ParamKind, ReportColumn, ReportDefinition, ReportKind, ReportParam, ReportTable,
+};
+
+fn param(key: &str, label: &str, kind: ParamKind, required: bool) -> ReportParam {
+    ReportParam {
+        key: key.to_string(),
+        label: label.to_string(),
+        kind,
+        required,
+    }
+}
+
+fn column(key: &str, title: &str, kind: ColumnKind, summable: bool) -> ReportColumn {
+    ReportColumn {
+        key: key.to_string(),
+        title: title.to_string(),
+        kind,
+        summable,
+    }
+}
+
+/// 报表定义
+pub fn definition(kind: ReportKind) -> ReportDefinition {
+    use ColumnKind::*;
+
+    let (title, description, params, columns) = match kind {
+        ReportKind::DailyProduction => (
+            "生产日报",
+            "按工单统计当日计划、完工和报废数量",
+            vec![
+                param("date", "日期", ParamKind::Date, true),
+                param("line", "产线", ParamKind::Text, false),
+            ],
+            vec![
+                column("orderNo", "工单号", Text, false),
+                column("productCode", "产品编码", Text, false),
+                column("productName", "产品名称", Text, false),
+                column("line", "产线", Text, false),
+                column("plannedQuantity", "计划数量", Number, true),
+                column("completedQuantity", "完工数量", Number, true),
+                column("scrapQuantity", "报废数量", Number, true),
+                column("attainment", "达成率", Percent, false),
+            ],
+        ),
+        ReportKind::Scrap => (
+            "报废统计",
+            "按日期、工单和报废原因统计报废数量与损失金额",
+            vec![
+                param("dateFrom", "开始日期", ParamKind::Date, true),
+                param("dateTo", "结束日期", ParamKind::Date, true),
+                param("line", "产线", ParamKind::Text, false),
+            ],
+            vec![
+                column("date", "日期", Text, false),
+                column("orderNo", "工单号", Text, false),
+                column("productCode", "产品编码", Text, false),
+                column("productName", "产品名称", Text, false),
+                column("operationSeq", "工序", Text, false),
+                column("reason", "报废原因", Text, false),
+                column("quantity", "报废数量", Number, true),
+                column("cost", "损失金额", Money, true),
+            ],
+        ),
+        ReportKind::InventoryValuation => (
+            "库存价值",
+            "按物料统计当前库存数量和金额",
+            vec![
+                param(
+                    "category",
+                    "类别",
+                    ParamKind::Select {
+                        options: vec!["原材料".to_string(), "成品".to_string()],
+                    },
+                    false,
+                ),
+                param("warehouse", "仓库", ParamKind::Text, false),
+            ],
+            vec![
+                column("itemCode", "物料编码", Text, false),
+                column("itemName", "物料名称", Text, false),
+                column("category", "类别", Text, false),
+                column("warehouse", "仓库", Text, false),
+                column("unit", "单位", Text, false),
+                column("quantity", "库存数量", Number, false),
+                column("unitCost", "单价", Money, false),
+                column("amount", "金额", Money, true),
+            ],
+        ),
+        ReportKind::InspectionSummary => (
+            "检验汇总",
+            "按物料和检验类型统计检验批数、合格率和不合格报告",
+            vec![
+                param("dateFrom", "开始日期", ParamKind::Date, true),
+                param("dateTo", "结束日期", ParamKind::Date, true),
+                param("itemCode", "物料编码", ParamKind::Text, false),
+            ],
+            vec![
+                column("itemCode", "物料编码", Text, false),
+                column("itemName", "物料名称", Text, false),
+                column("source", "检验类型", Text, false),
+                column("lots", "检验批数", Number, true),
+                column("accepted", "接收", Number, true),
+                column("rejected", "拒收", Number, true),
+                column("passRate", "批合格率", Percent, false),
+                column("ncrs", "不合格报告", Number, true),
+            ],
+        ),
+    };
+
+    ReportDefinition {
+        kind,
+        title: title.to_string(),
+        description: description.to_string(),
+        params,
+        columns,
+    }
+}
+
+/// 所有报表定义
+pub fn definitions() -> Vec<ReportDefinition> {
+    ReportKind::ALL.into_iter().map(definition).collect()
+}
+
+fn to_cell(value: Option<&Value>) -> Cell {
+    match value {
+        Some(Value::Number(number)) => number.as_f64().map(Cell::Number).unwrap_or(Cell::Empty),
+        Some(Value::String(text)) => Cell::Text(text.clone()),
+        Some(Value::Bool(flag)) => Cell::Text(if *flag { "是" } else { "否" }.to_string()),
+        _ => Cell::Empty,
+    }
+}
+
+/// 按报表定义的列把后端记录组装为表格，并计算合计行
+pub fn build_table(
+    definition: &ReportDefinition,
+    params: &BTreeMap<String, String>,
+    records: &[Map<String, Value>],
+    generated_at: String,
+) -> ReportTable {
+    let rows: Vec<Vec<Cell>> = records
+        .iter()
+        .map(|record| {
+            definition
+                .columns
+                .iter()
+                .map(|column| to_cell(record.get(&column.key)))
+                .collect()
+        })
+        .collect();
+
+    let totals = definition.columns.iter().any(|column| column.summable).then(|| {
+        definition
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| match (index, column.summable) {
+                (_, true) => Cell::Number(
+                    rows.iter()
+                        .filter_map(|row| match row[index] {
+                            Cell::Number(value) => Some(value),
+                            _ => None,
+                        })
+                        .sum(),
+                ),
+                (0, false) => Cell::Text("合计".to_string()),
+                _ => Cell::Empty,
+            })
+            .collect()
+    });
+
+    let subtitle = definition
+        .params
+        .iter()
+        .filter_map(|param| {
+            params
+                .get(&param.key)
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+                .map(|value| format!("{}：{}", param.label, value))
+        })
+        .collect::<Vec<_>>()
+        .join("；");
+
+    ReportTable {
+        kind: definition.kind,
+        title: definition.title.clone(),
+        subtitle,
+        columns: definition.columns.clone(),
+        rows,
+        totals,
+        generated_at,
+    }
+}
+
+/// 表格的全部数据行（含合计行）
+pub fn body_rows(table: &ReportTable) -> impl Iterator<Item = &Vec<Cell>> {
+    table.rows.iter().chain(table.totals.iter())
+}
+
+/// 测试用报表：`rows` 行数据和一行合计
+#[cfg(test)]
+pub(crate) fn test_table(rows: usize) -> ReportTable {
+    ReportTable {
+        kind: ReportKind::Scrap,
+        title: "报废统计".to_string(),
+        subtitle: "日期：2026-10-01".to_string(),
+        columns: vec![
+            column("orderNo", "工单号", ColumnKind::Text, false),
+            column("quantity", "报废数量", ColumnKind::Number, true),
+        ],
+        rows: (1..=rows)
+            .map(|index| vec![Cell::Text(format!("WO-{}", index)), Cell::Number(index as f64)])
+            .collect(),
+        totals: Some(vec![
+            Cell::Text("合计".to_string()),
+            Cell::Number((1..=rows).sum::<usize>() as f64),
+        ]),
+        generated_at: "2026-10-01 08:00".to_string(),
+    }
+}
@@ -0,0 +1,262 @@
+use mes_win_shared::report::{ColumnKind, ReportTable};
+use super::body_rows;
+
+// A4横向，单位为点（1/72英寸）
+const PAGE_WIDTH: f64 = 842.0;
+const PAGE_HEIGHT: f64 = 595.0;
+const MARGIN: f64 = 36.0;
+const FONT_SIZE: f64 = 9.0;
+const ROW_HEIGHT: f64 = 16.0;
+/// 标题区高度（仅首页）
+const TITLE_HEIGHT: f64 = 44.0;
+/// 单元格左右留白
+const CELL_PADDING: f64 = 4.0;
+
+/// 文本宽度（以字号为单位）：ASCII按半角，其余按全角
+fn text_width(text: &str) -> f64 {
+    text.chars().map(|c| if c.is_ascii() { 0.5 } else { 1.0 }).sum()
+}
+
+/// 按宽度截断文本，超出时以 "…" 结尾
+fn truncate(text: &str, max_width: f64) -> String {
+    if text_width(text) <= max_width {
+        return text.to_string();
+    }
+    let mut result = String::new();
+    let mut width = 0.0;
+    for c in text.chars() {
+        let w = if c.is_ascii() { 0.5 } else { 1.0 };
+        if width + w + 1.0 > max_width {
+            break;
+        }
+        width += w;
+        result.push(c);
+    }
+    result.push('…');
+    result
+}
+
+/// 文本编码为UCS-2大端的十六进制串（配合 UniGB-UCS2-H 编码），BMP以外的字符替换为 "?"
+fn hex_text(text: &str) -> String {
+    let mut hex = String::with_capacity(text.len() * 4 + 2);
+    hex.push('<');
+    for c in text.chars() {
+        let code = u16::try_from(c as u32).unwrap_or('?' as u16);
+        hex.push_str(&format!("{:04X}", code));
+    }
+    hex.push('>');
+    hex
+}
+
+/// 在 (x, y) 处输出文本，`right` 为真时右对齐到 x
+fn text_op(content: &mut String, x: f64, y: f64, size: f64, text: &str, right: bool) {
+    let x = if right { x - text_width(text) * size } else { x };
+    content.push_str(&format!(
+        "BT /F1 {} Tf {:.2} {:.2} Td {} Tj ET\n",
+        size,
+        x,
+        y,
+        hex_text(text)
+    ));
+}
+
+fn line_op(content: &mut String, x1: f64, y1: f64, x2: f64, y2: f64) {
+    content.push_str(&format!("{:.2} {:.2} m {:.2} {:.2} l S\n", x1, y1, x2, y2));
+}
+
+/// 各列宽度：按表头和内容的最大文本宽度分配，总宽度缩放到页面可用宽度
+fn column_widths(table: &ReportTable) -> Vec<f64> {
+    let available = PAGE_WIDTH - MARGIN * 2.0;
+    let natural: Vec<f64> = table
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            let content = body_rows(table)
+                .map(|row| text_width(&row[index].display(column.kind)))
+                .fold(text_width(&column.title), f64::max);
+            content.clamp(4.0, 24.0) * FONT_SIZE + CELL_PADDING * 2.0
+        })
+        .collect();
+    let total: f64 = natural.iter().sum();
+    natural.iter().map(|width| width * available / total).collect()
+}
+
+/// 生成各页的内容流，表头在每页重复
+fn page_contents(table: &ReportTable) -> Vec<String> {
+    let widths = column_widths(table);
+    let left = MARGIN;
+    let right = PAGE_WIDTH - MARGIN;
+    let rows: Vec<_> = body_rows(table).collect();
+    let total_index = table.totals.as_ref().map(|_| table.rows.len());
+
+    let mut pages = Vec::new();
+    let mut next = 0;
+    loop {
+        let mut content = String::from("0.5 w\n");
+        let mut y = PAGE_HEIGHT - MARGIN;
+        if pages.is_empty() {
+            text_op(&mut content, left, y - 14.0, 14.0, &table.title, false);
+            let subtitle = format!("{}  生成时间：{}", table.subtitle, table.generated_at);
+            text_op(&mut content, left, y - 32.0, FONT_SIZE, subtitle.trim(), false);
+            y -= TITLE_HEIGHT;
+        }
+
+        // 表头
+        line_op(&mut content, left, y, right, y);
+        let mut x = left;
+        for (column, width) in table.columns.iter().zip(&widths) {
+            let title = truncate(&column.title, (width - CELL_PADDING * 2.0) / FONT_SIZE);
+            text_op(&mut content, x + CELL_PADDING, y - ROW_HEIGHT + 5.0, FONT_SIZE, &title, false);
+            x += width;
+        }
+        y -= ROW_HEIGHT;
+        line_op(&mut content, left, y, right, y);
+
+        while next < rows.len() && y - ROW_HEIGHT >= MARGIN + ROW_HEIGHT {
+            if Some(next) == total_index {
+                line_op(&mut content, left, y, right, y);
+            }
+            let mut x = left;
+            for ((cell, column), width) in rows[next].iter().zip(&table.columns).zip(&widths) {
+                let text = truncate(&cell.display(column.kind), (width - CELL_PADDING * 2.0) / FONT_SIZE);
+                let numeric = column.kind != ColumnKind::Text;
+                let text_x = if numeric { x + width - CELL_PADDING } else { x + CELL_PADDING };
+                text_op(&mut content, text_x, y - ROW_HEIGHT + 5.0, FONT_SIZE, &text, numeric);
+                x += width;
+            }
+            y -= ROW_HEIGHT;
+            next += 1;
+        }
+        line_op(&mut content, left, y, right, y);
+
+        pages.push(content);
+        if next >= rows.len() {
+            break;
+        }
+    }
+
+    // 页码
+    let count = pages.len();
+    for (index, content) in pages.iter_mut().enumerate() {
+        let footer = format!("第 {} 页 / 共 {} 页", index + 1, count);
+        text_op(content, right, MARGIN - 14.0, 8.0, &footer, true);
+    }
+    pages
+}
+
+/// 导出PDF：A4横向，使用阅读器内置的宋体（STSong-Light），不嵌入字体
+pub fn write(table: &ReportTable) -> Vec<u8> {
+    let pages = page_contents(table);
+
+    // 对象编号：1 目录，2 页树，3~5 字体，之后每页占两个对象（页面、内容流）
+    let first_page = 6;
+    let page_refs: Vec<String> = (0..pages.len())
+        .map(|index| format!("{} 0 R", first_page + index * 2))
+        .collect();
+
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!("<< /Type /Pages /Kids [{}] /Count {} >>", page_refs.join(" "), pages.len()),
+        "<< /Type /Font /Subtype /Type0 /BaseFont /STSong-Light /Encoding /UniGB-UCS2-H /DescendantFonts [4 0 R] >>"
+            .to_string(),
+        "<< /Type /Font /Subtype /CIDFontType0 /BaseFont /STSong-Light \
+         /CIDSystemInfo << /Registry (Adobe) /Ordering (GB1) /Supplement 2 >> \
+         /FontDescriptor 5 0 R /DW 1000 /W [1 95 500] >>"
+            .to_string(),
+        "<< /Type /FontDescriptor /FontName /STSong-Light /Flags 6 /FontBBox [-25 -254 1000 880] \
+         /ItalicAngle 0 /Ascent 880 /Descent -120 /CapHeight 880 /StemV 93 >>"
+            .to_string(),
+    ];
+    for (index, content) in pages.iter().enumerate() {
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+            PAGE_WIDTH,
+            PAGE_HEIGHT,
+            first_page + index * 2 + 1
+        ));
+        objects.push(format!("<< /Length {} >>\nstream\n{}endstream", content.len(), content));
+    }
+
+    let mut output = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(output.len());
+        output.extend(format!("{} 0 obj\n{}\nendobj\n", index + 1, object).into_bytes());
+    }
+
+    let xref_offset = output.len();
+    let mut xref = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        xref.push_str(&format!("{:010} 00000 n \n", offset));
+    }
+    xref.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref_offset
+    ));
+    output.extend(xref.into_bytes());
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::test_table;
+
+    /// 解析交叉引用表，返回 startxref 偏移和各对象偏移
+    fn xref(data: &[u8]) -> (usize, Vec<usize>) {
+        let text = String::from_utf8_lossy(data);
+        let start: usize = text
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|tail| tail.lines().next())
+            .and_then(|line| line.parse().ok())
+            .unwrap();
+        let table = std::str::from_utf8(&data[start..]).unwrap();
+        let offsets = table
+            .lines()
+            .skip(3)
+            .take_while(|line| line.ends_with(" n "))
+            .map(|line| line[..10].parse().unwrap())
+            .collect();
+        (start, offsets)
+    }
+
+    #[test]
+    fn xref_offsets_point_at_objects() {
+        for rows in [0, 3, 120] {
+            let data = write(&test_table(rows));
+            let (start, offsets) = xref(&data);
+            assert!(data[start..].starts_with(b"xref\n"), "startxref 指向 xref");
+            assert!(offsets.len() >= 7, "至少一页");
+            for (index, offset) in offsets.into_iter().enumerate() {
+                let header = format!("{} 0 obj\n", index + 1);
+                assert!(
+                    data[offset..].starts_with(header.as_bytes()),
+                    "{} 行：对象 {} 的偏移 {} 不正确",
+                    rows,
+                    index + 1,
+                    offset
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn long_tables_repeat_pages() {
+        assert_eq!(page_contents(&test_table(3)).len(), 1);
+        let pages = page_contents(&test_table(120));
+        assert!(pages.len() > 1);
+        let footer = hex_text(&format!("第 {} 页 / 共 {} 页", pages.len(), pages.len()));
+        assert!(pages.last().unwrap().contains(&footer));
+    }
+
+    #[test]
+    fn truncate_and_hex_text() {
+        assert_eq!(truncate("ABC", 10.0), "ABC");
+        assert_eq!(truncate("报废统计报表", 4.0), "报废统…");
+        assert_eq!(hex_text("A中"), "<00414E2D>");
+        assert_eq!(hex_text("😀"), "<003F>");
+    }
+}
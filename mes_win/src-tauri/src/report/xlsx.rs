@@ -0,0 +1,295 @@
+use mes_win_shared::report::{Cell, ColumnKind, ReportTable};
+use super::body_rows;
+
+// 单元格样式序号，对应 styles.xml 中 cellXfs 的顺序
+const STYLE_DEFAULT: u32 = 0;
+const STYLE_BOLD: u32 = 1;
+const STYLE_PERCENT: u32 = 2;
+const STYLE_MONEY: u32 = 3;
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/><Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/></Types>"#;
+
+const ROOT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#;
+
+const WORKBOOK_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/></Relationships>"#;
+
+const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><fonts count="2"><font><sz val="11"/><name val="宋体"/></font><font><b/><sz val="11"/><name val="宋体"/></font></fonts><fills count="2"><fill><patternFill patternType="none"/></fill><fill><patternFill patternType="gray125"/></fill></fills><borders count="1"><border><left/><right/><top/><bottom/><diagonal/></border></borders><cellStyleXfs count="1"><xf numFmtId="0" fontId="0" fillId="0" borderId="0"/></cellStyleXfs><cellXfs count="4"><xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/><xf numFmtId="0" fontId="1" fillId="0" borderId="0" xfId="0" applyFont="1"/><xf numFmtId="10" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/><xf numFmtId="4" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/></cellXfs></styleSheet>"#;
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// 列序号转列名：0 → A，26 → AA
+fn column_name(index: usize) -> String {
+    let mut name = String::new();
+    let mut n = index + 1;
+    while n > 0 {
+        let rem = (n - 1) % 26;
+        name.insert(0, (b'A' + rem as u8) as char);
+        n = (n - 1) / 26;
+    }
+    name
+}
+
+fn text_cell(reference: &str, text: &str, style: u32) -> String {
+    format!(
+        r#"<c r="{}" t="inlineStr" s="{}"><is><t xml:space="preserve">{}</t></is></c>"#,
+        reference,
+        style,
+        escape_xml(text)
+    )
+}
+
+fn cell_xml(reference: &str, cell: &Cell, kind: ColumnKind, bold: bool) -> String {
+    match cell {
+        Cell::Number(value) if value.is_finite() => {
+            let style = match kind {
+                ColumnKind::Percent => STYLE_PERCENT,
+                ColumnKind::Money => STYLE_MONEY,
+                _ if bold => STYLE_BOLD,
+                _ => STYLE_DEFAULT,
+            };
+            format!(r#"<c r="{}" s="{}"><v>{}</v></c>"#, reference, style, value)
+        }
+        Cell::Text(text) => text_cell(reference, text, if bold { STYLE_BOLD } else { STYLE_DEFAULT }),
+        _ => String::new(),
+    }
+}
+
+fn sheet_xml(table: &ReportTable) -> String {
+    let mut rows = Vec::new();
+    rows.push(format!(r#"<row r="1">{}</row>"#, text_cell("A1", &table.title, STYLE_BOLD)));
+    rows.push(format!(
+        r#"<row r="2">{}</row>"#,
+        text_cell("A2", &format!("{}  生成时间：{}", table.subtitle, table.generated_at), STYLE_DEFAULT)
+    ));
+
+    let header: String = table
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| text_cell(&format!("{}4", column_name(index)), &column.title, STYLE_BOLD))
+        .collect();
+    rows.push(format!(r#"<row r="4">{}</row>"#, header));
+
+    let total_index = table.totals.as_ref().map(|_| table.rows.len());
+    for (offset, row) in body_rows(table).enumerate() {
+        let number = offset + 5;
+        let bold = Some(offset) == total_index;
+        let cells: String = row
+            .iter()
+            .zip(&table.columns)
+            .enumerate()
+            .map(|(index, (cell, column))| {
+                cell_xml(&format!("{}{}", column_name(index), number), cell, column.kind, bold)
+            })
+            .collect();
+        rows.push(format!(r#"<row r="{}">{}</row>"#, number, cells));
+    }
+
+    let widths: String = table
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            let width = if column.kind == ColumnKind::Text { 18 } else { 14 };
+            format!(r#"<col min="{0}" max="{0}" width="{1}" customWidth="1"/>"#, index + 1, width)
+        })
+        .collect();
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetViews><sheetView workbookViewId="0"><pane ySplit="4" topLeftCell="A5" activePane="bottomLeft" state="frozen"/></sheetView></sheetViews><cols>{}</cols><sheetData>{}</sheetData></worksheet>"#,
+        widths,
+        rows.join("")
+    )
+}
+
+fn workbook_xml(title: &str) -> String {
+    // 工作表名最长31个字符，且不能包含 []:*?/\
+    let name: String = title
+        .chars()
+        .filter(|c| !"[]:*?/\\".contains(*c))
+        .take(31)
+        .collect();
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="{}" sheetId="1" r:id="rId1"/></sheets></workbook>"#,
+        escape_xml(&name)
+    )
+}
+
+/// 导出XLSX：单工作表，标题、参数说明、表头和数据行（含合计行）
+pub fn write(table: &ReportTable) -> Vec<u8> {
+    let mut archive = ZipWriter::default();
+    archive.add("[Content_Types].xml", CONTENT_TYPES.as_bytes());
+    archive.add("_rels/.rels", ROOT_RELS.as_bytes());
+    archive.add("xl/workbook.xml", workbook_xml(&table.title).as_bytes());
+    archive.add("xl/_rels/workbook.xml.rels", WORKBOOK_RELS.as_bytes());
+    archive.add("xl/styles.xml", STYLES.as_bytes());
+    archive.add("xl/worksheets/sheet1.xml", sheet_xml(table).as_bytes());
+    archive.finish()
+}
+
+/// CRC-32（IEEE 802.3）
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+// 文件修改时间固定为 1980-01-01 00:00（DOS格式）
+const DOS_TIME: u16 = 0;
+const DOS_DATE: u16 = (1 << 5) | 1;
+
+/// 最简ZIP打包（仅存储、不压缩），XLSX对压缩方式没有要求
+#[derive(Default)]
+struct ZipWriter {
+    data: Vec<u8>,
+    directory: Vec<u8>,
+    entries: u16,
+}
+
+impl ZipWriter {
+    fn add(&mut self, name: &str, content: &[u8]) {
+        let offset = self.data.len() as u32;
+        let crc = crc32(content);
+        let size = content.len() as u32;
+        let name = name.as_bytes();
+
+        // 本地文件头
+        self.data.extend(0x0403_4b50u32.to_le_bytes());
+        self.data.extend(20u16.to_le_bytes()); // 解压所需版本
+        self.data.extend(0x0800u16.to_le_bytes()); // 文件名为UTF-8
+        self.data.extend(0u16.to_le_bytes()); // 存储
+        self.data.extend(DOS_TIME.to_le_bytes());
+        self.data.extend(DOS_DATE.to_le_bytes());
+        self.data.extend(crc.to_le_bytes());
+        self.data.extend(size.to_le_bytes());
+        self.data.extend(size.to_le_bytes());
+        self.data.extend((name.len() as u16).to_le_bytes());
+        self.data.extend(0u16.to_le_bytes());
+        self.data.extend(name);
+        self.data.extend(content);
+
+        // 中央目录项
+        self.directory.extend(0x0201_4b50u32.to_le_bytes());
+        self.directory.extend(20u16.to_le_bytes());
+        self.directory.extend(20u16.to_le_bytes());
+        self.directory.extend(0x0800u16.to_le_bytes());
+        self.directory.extend(0u16.to_le_bytes());
+        self.directory.extend(DOS_TIME.to_le_bytes());
+        self.directory.extend(DOS_DATE.to_le_bytes());
+        self.directory.extend(crc.to_le_bytes());
+        self.directory.extend(size.to_le_bytes());
+        self.directory.extend(size.to_le_bytes());
+        self.directory.extend((name.len() as u16).to_le_bytes());
+        self.directory.extend([0u8; 12]); // 扩展字段、注释长度、磁盘号、内部/外部属性
+        self.directory.extend(offset.to_le_bytes());
+        self.directory.extend(name);
+
+        self.entries += 1;
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        let directory_offset = self.data.len() as u32;
+        let directory_size = self.directory.len() as u32;
+        self.data.append(&mut self.directory);
+
+        // 中央目录结束记录
+        self.data.extend(0x0605_4b50u32.to_le_bytes());
+        self.data.extend([0u8; 4]);
+        self.data.extend(self.entries.to_le_bytes());
+        self.data.extend(self.entries.to_le_bytes());
+        self.data.extend(directory_size.to_le_bytes());
+        self.data.extend(directory_offset.to_le_bytes());
+        self.data.extend(0u16.to_le_bytes());
+        self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::test_table;
+
+    fn u16_at(data: &[u8], offset: usize) -> u16 {
+        u16::from_le_bytes([data[offset], data[offset + 1]])
+    }
+
+    fn u32_at(data: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn column_names() {
+        assert_eq!(column_name(0), "A");
+        assert_eq!(column_name(25), "Z");
+        assert_eq!(column_name(26), "AA");
+        assert_eq!(column_name(701), "ZZ");
+        assert_eq!(column_name(702), "AAA");
+    }
+
+    #[test]
+    fn end_of_central_directory_matches_entries() {
+        let mut archive = ZipWriter::default();
+        archive.add("a.txt", b"hello");
+        archive.add("目录/b.xml", b"<b/>");
+        let data = archive.finish();
+
+        // 中央目录结束记录固定22字节（无注释）
+        let eocd = data.len() - 22;
+        assert_eq!(u32_at(&data, eocd), 0x0605_4b50);
+        assert_eq!(u16_at(&data, eocd + 8), 2, "本磁盘条目数");
+        assert_eq!(u16_at(&data, eocd + 10), 2, "总条目数");
+        let directory_size = u32_at(&data, eocd + 12) as usize;
+        let directory_offset = u32_at(&data, eocd + 16) as usize;
+        assert_eq!(directory_offset + directory_size, eocd, "中央目录紧接在结束记录之前");
+
+        // 逐项核对中央目录中的本地文件头偏移和CRC
+        let mut cursor = directory_offset;
+        for (name, content) in [("a.txt", &b"hello"[..]), ("目录/b.xml", &b"<b/>"[..])] {
+            assert_eq!(u32_at(&data, cursor), 0x0201_4b50);
+            assert_eq!(u32_at(&data, cursor + 16), crc32(content));
+            let name_len = u16_at(&data, cursor + 28) as usize;
+            assert_eq!(&data[cursor + 46..cursor + 46 + name_len], name.as_bytes());
+
+            let local = u32_at(&data, cursor + 42) as usize;
+            assert_eq!(u32_at(&data, local), 0x0403_4b50, "{} 的本地文件头偏移", name);
+            assert_eq!(u32_at(&data, local + 14), crc32(content));
+            let start = local + 30 + name_len;
+            assert_eq!(&data[start..start + content.len()], content);
+
+            cursor += 46 + name_len;
+        }
+        assert_eq!(cursor, eocd);
+    }
+
+    #[test]
+    fn workbook_contains_all_parts() {
+        let data = write(&test_table(3));
+        let eocd = data.len() - 22;
+        assert_eq!(u16_at(&data, eocd + 10), 6);
+        let text = String::from_utf8_lossy(&data);
+        assert!(text.contains("xl/worksheets/sheet1.xml"));
+        assert!(text.contains(r#"<c r="A8" t="inlineStr" s="1"><is><t xml:space="preserve">合计</t></is></c>"#));
+    }
+}
@@ -1,11 +1,5 @@
 use tauri::{LogicalSize, Manager, Size, Window};
-use serde::{Deserialize, Serialize};
-
-#[derive(Serialize, Deserialize)]
-pub struct WindowSizeArgs {
-    pub width: f64,
-    pub height: f64,
-}
+use mes_win_shared::window::WindowSizeArgs;
 
 // 最小化窗口
 #[tauri::command]
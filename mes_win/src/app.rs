@@ -1,27 +1,10 @@
 use crate::ui::pages::login::Login;
 use crate::ui::pages::main_app::MainApp;
+use crate::ui::pages::settings::apply_appearance;
+use crate::services::tauri;
 use yew::prelude::*;
-use wasm_bindgen::prelude::*;
-use wasm_bindgen::closure::Closure;
 use wasm_bindgen_futures::spawn_local;
-use serde::{Deserialize, Serialize};
-use serde_json;
-
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
-    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
-    
-    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"])]
-    async fn listen(event: &str, handler: &js_sys::Function) -> JsValue;
-}
-
-// 窗口大小调整参数
-#[derive(Serialize, Deserialize)]
-struct ResizeArgs {
-    width: f64,
-    height: f64,
-}
+use mes_win_shared::events;
 
 // 应用状态
 #[derive(Clone, PartialEq)]
@@ -49,50 +32,47 @@ pub fn app() -> Html {
         use_effect_with((), move |_| {
             // 设置登出事件监听器
             let app_state_for_logout = app_state.clone();
-            spawn_local(async move {
-                let logout_handler = Closure::wrap(Box::new(move |_event: JsValue| {
-                    web_sys::console::log_1(&"收到登出事件，更新应用状态".into());
-                    app_state_for_logout.set(AppState::default());
-                    
-                    // 清除本地存储
-                    if let Ok(Some(storage)) = web_sys::window().unwrap().local_storage() {
-                        let _ = storage.remove_item("app_state");
-                    }
-                }) as Box<dyn Fn(JsValue)>);
-                
-                let _ = listen("logout", logout_handler.as_ref().unchecked_ref()).await;
-                logout_handler.forget(); // 防止过早释放
+            let unlisten_logout = tauri::listen_in_effect(events::LOGOUT, move |_event| {
+                web_sys::console::log_1(&"收到登出事件，更新应用状态".into());
+                app_state_for_logout.set(AppState::default());
+            });
+
+            // 设置会话事件监听器（token刷新失败时需要重新登录）
+            let app_state_for_expired = app_state.clone();
+            let unlisten_expired = tauri::listen_in_effect(events::SESSION_EXPIRED, move |_event| {
+                web_sys::console::log_1(&"登录已失效，请重新登录".into());
+                app_state_for_expired.set(AppState::default());
             });
             
-            // 恢复登录状态
-            if let Ok(Some(storage)) = web_sys::window().unwrap().local_storage() {
-                if let Ok(Some(stored_state)) = storage.get_item("app_state") {
-                    if let Ok(state_data) = serde_json::from_str::<serde_json::Value>(&stored_state) {
-                        if let (Some(is_logged_in), Some(username)) = 
-                            (state_data["is_logged_in"].as_bool(), state_data["username"].as_str()) {
-                            app_state.set(AppState {
-                                is_logged_in,
-                                username: username.to_string(),
-                            });
+            // 按本机设置应用界面语言和默认主题
+            if tauri::is_tauri_environment() {
+                spawn_local(async move {
+                    if let Ok(settings) = tauri::get_local_settings().await {
+                        apply_appearance(&settings);
+                    }
+                });
+            }
 
-                            // 如果恢复的状态是已登录，立即调整窗口大小
-                            if is_logged_in {
-                                spawn_local(async move {
-                                    let resize_args = serde_json::json!({
-                                        "args": {
-                                            "width": 1200,
-                                            "height": 800
-                                        }
-                                    });
-                                    let _result = invoke("resize_window", serde_wasm_bindgen::to_value(&resize_args).unwrap()).await;
-                                    web_sys::console::log_1(&"恢复状态时窗口调整为主界面大小".into());
-                                });
-                            }
-                        }
+            // 从原生层恢复登录状态（会话由原生层加密持久化）
+            if tauri::is_tauri_environment() {
+                spawn_local(async move {
+                    if !tauri::is_logged_in().await.unwrap_or(false) {
+                        return;
                     }
-                }
+
+                    if let Ok(Some((username, _user_id))) = tauri::get_current_user().await {
+                        web_sys::console::log_1(&format!("已恢复登录会话: {}", username).into());
+                        app_state.set(AppState {
+                            is_logged_in: true,
+                            username,
+                        });
+                    }
+                });
+            }
+            move || {
+                unlisten_logout();
+                unlisten_expired();
             }
-            || ()
         });
     }
 
@@ -104,30 +84,19 @@ pub fn app() -> Html {
                 
                 // 立即执行窗口调整，无需延迟
                 spawn_local(async move {
-                    let resize_args = serde_json::json!({
-                        "args": {
-                            "width": 1200.0,
-                            "height": 800.0
-                        }
-                    });
-                    
-                    let js_args = serde_wasm_bindgen::to_value(&resize_args).unwrap();
-                    let _result = invoke("resize_window", js_args).await;
-                    web_sys::console::log_1(&format!("Window resized successfully to {}x{}", 1200.0, 800.0).into());
+                    // 主窗口大小取本机设置，读取失败时使用默认值
+                    let settings = tauri::get_local_settings().await.unwrap_or_default();
+                    let (width, height) = (settings.window_width as f64, settings.window_height as f64);
+                    if tauri::resize_window(width, height).await.is_ok() {
+                        web_sys::console::log_1(&format!("Window resized successfully to {}x{}", width, height).into());
+                    }
                 });
             } else {
                 web_sys::console::log_1(&"User not logged in, resizing window to login size...".into());
                 spawn_local(async move {
-                    let resize_args = serde_json::json!({
-                        "args": {
-                            "width": 400.0,
-                            "height": 500.0
-                        }
-                    });
-                    
-                    let js_args = serde_wasm_bindgen::to_value(&resize_args).unwrap();
-                    let _result = invoke("resize_window", js_args).await;
-                    web_sys::console::log_1(&"Window resized successfully to login size".into());
+                    if tauri::resize_window(400.0, 500.0).await.is_ok() {
+                        web_sys::console::log_1(&"Window resized successfully to login size".into());
+                    }
                 });
             }
             || ()
@@ -142,16 +111,7 @@ pub fn app() -> Html {
                 is_logged_in: true,
                 username: username.clone(),
             };
-            app_state.set(new_state.clone());
-
-            // 保存状态到本地存储
-            if let Ok(Some(storage)) = web_sys::window().unwrap().local_storage() {
-                let state_json = serde_json::json!({
-                    "is_logged_in": new_state.is_logged_in,
-                    "username": new_state.username
-                });
-                let _ = storage.set_item("app_state", &state_json.to_string());
-            }
+            app_state.set(new_state);
         })
     };
 
@@ -160,11 +120,6 @@ pub fn app() -> Html {
         let app_state = app_state.clone();
         Callback::from(move |_| {
             app_state.set(AppState::default());
-            
-            // 清除本地存储
-            if let Ok(Some(storage)) = web_sys::window().unwrap().local_storage() {
-                let _ = storage.remove_item("app_state");
-            }
         })
     };
 
@@ -0,0 +1,17 @@
+// 错误类型定义在共享crate中，与原生层保持一致
+pub use mes_win_shared::error::ApiError;
+
+/// 将命令调用失败时的JS值转换为错误类型
+///
+/// 原生层返回的错误可以直接反序列化，其他异常（如命令不存在）按字符串处理
+pub fn api_error_from_js(value: wasm_bindgen::JsValue) -> ApiError {
+    if let Ok(error) = serde_wasm_bindgen::from_value::<ApiError>(value.clone()) {
+        return error;
+    }
+
+    ApiError::Decode {
+        message: value
+            .as_string()
+            .unwrap_or_else(|| format!("{:?}", value)),
+    }
+}
@@ -1 +1,2 @@
 // 核心功能模块
+pub mod error;
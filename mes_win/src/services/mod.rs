@@ -1,2 +1,2 @@
-// 服务模块，但目前services目录已存在，应该使用
-// 现有的 services 目录
+// 服务模块
+pub mod tauri; // Tauri命令调用层
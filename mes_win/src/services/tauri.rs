@@ -0,0 +1,694 @@
+// Tauri命令调用层
+//
+// 每个Tauri命令对应一个异步函数：负责序列化参数、捕获JS端的Promise拒绝，
+// 并把结果统一转换为 `Result<T, ApiError>`。页面组件不直接调用 invoke。
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::closure::Closure;
+use mes_win_shared::auth::LoginData;
+use mes_win_shared::commands::{
+    AssignUserRolesArgs, CreateNcrArgs, CreateNcrFromInspectionArgs, CreateUserArgs,
+    CreateWorkOrderArgs, CycleCountIdArgs, ExportReportArgs, FindSerialArgs, GetScheduleArgs,
+    GetSpcDatasetArgs, InspectionLotIdArgs, InspectionPlanIdArgs, IssueMaterialArgs,
+    ListBinStockArgs, ListCycleCountsArgs, ListInspectionLotsArgs, ListInspectionPlansArgs,
+    ListMaterialStockArgs, ListNcrsArgs, ListProductStockArgs, ListRoutingsArgs, ListUsersArgs,
+    ListWorkOrdersArgs, LocalSettingsArgs, LoginArgs, LookupMasterDataArgs, NcrIdArgs,
+    OfflineTransactionIdArgs, OpenInspectionLotArgs, PlantSettingsArgs, ReceiveMaterialArgs,
+    RegisterCompletionArgs, RescheduleArgs, ResetUserPasswordArgs, ResizeWindowArgs,
+    RevealExportArgs, ReviewCycleCountArgs, RoleArgs, RoleCodeArgs, RoutingIdArgs,
+    RunReportArgs, SaveDashboardLayoutArgs, SaveInspectionPlanArgs, SaveRoutingArgs, SearchArgs,
+    SetUserEnabledArgs, ShipProductsArgs, StartCycleCountArgs, SubmitCycleCountArgs,
+    SubmitInspectionArgs, SwitchProfileArgs, SyncMasterDataArgs, TraceLotArgs,
+    TransferStockArgs, TransitionNcrArgs, TransitionWorkOrderArgs, UpdateNcrArgs,
+    UpdateWorkOrderArgs, UploadNcrAttachmentArgs, UserIdArgs, WorkOrderIdArgs,
+};
+use mes_win_shared::config::BackendProfiles;
+use mes_win_shared::dashboard::{DashboardKpis, DashboardLayout};
+use mes_win_shared::inventory::finished_goods::{
+    CompletionRegistration, ProductStock, ProductStockQuery, SerialRecord, Shipment,
+};
+use mes_win_shared::inventory::material::{
+    GoodsReceipt, LotTrace, LotTransaction, MaterialIssue, MaterialLot, MaterialStock,
+    MaterialStockQuery,
+};
+use mes_win_shared::inventory::warehouse::{
+    BinStock, BinTransfer, CountReview, CycleCount, CycleCountDraft, CycleCountLine,
+    TransferRecord, Warehouse,
+};
+use mes_win_shared::master_data::{MasterDataItem, MasterDataKind, MasterDataStatus};
+use mes_win_shared::permission::SessionInfo;
+use mes_win_shared::production::routing::{Routing, RoutingSummary, WorkCenter};
+use mes_win_shared::production::schedule::{
+    RescheduleRequest, ScheduleBoard, ScheduleItem, ScheduleQuery,
+};
+use mes_win_shared::production::work_order::{
+    WorkOrder, WorkOrderDetail, WorkOrderDraft, WorkOrderQuery, WorkOrderStatus,
+};
+use mes_win_shared::quality::inspection::{
+    InspectionLot, InspectionLotDraft, InspectionLotQuery, InspectionRecord,
+};
+use mes_win_shared::quality::ncr::{
+    AttachmentUpload, Ncr, NcrDetail, NcrDraft, NcrQuery, NcrStatus, NcrUpdate,
+};
+use mes_win_shared::quality::plan::{InspectionPlan, InspectionPlanSummary};
+use mes_win_shared::quality::spc::{SpcDataset, SpcQuery};
+use mes_win_shared::report::{
+    ExportFormat, ExportResult, ReportDefinition, ReportRequest, ReportTable,
+};
+use mes_win_shared::offline::{OfflineStatus, QueuedTransaction};
+use mes_win_shared::response::{ApiResponse, PageData};
+use mes_win_shared::search::SearchResults;
+use mes_win_shared::settings::{LocalSettings, PlantSettings};
+use mes_win_shared::user::{Role, UserAccount, UserDraft, UserQuery};
+use mes_win_shared::window::WindowSizeArgs;
+use crate::core::error::{api_error_from_js, ApiError};
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = invoke, catch)]
+    async fn invoke_raw(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"], js_name = listen, catch)]
+    async fn listen_raw(event: &str, handler: &js_sys::Function) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"], js_name = open, catch)]
+    async fn open_dialog_raw(options: JsValue) -> Result<JsValue, JsValue>;
+}
+
+/// 系统文件对话框选项（tauri-plugin-dialog 的 `open`）
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenDialogOptions<'a> {
+    title: &'a str,
+    directory: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_path: Option<&'a str>,
+}
+
+/// 检查是否在Tauri环境中运行
+pub fn is_tauri_environment() -> bool {
+    web_sys::window()
+        .and_then(|w| w.get("__TAURI__"))
+        .and_then(|t| t.dyn_into::<web_sys::js_sys::Object>().ok())
+        .is_some()
+}
+
+/// 调用Tauri命令
+///
+/// `args` 序列化后作为命令参数（无参数时传 `&()`），返回值反序列化为 `T`
+pub async fn invoke<A, T>(cmd: &str, args: &A) -> Result<T, ApiError>
+where
+    A: Serialize + ?Sized,
+    T: DeserializeOwned,
+{
+    let js_args = serde_wasm_bindgen::to_value(args).map_err(|e| ApiError::Decode {
+        message: format!("命令参数序列化失败: {}", e),
+    })?;
+
+    let result = invoke_raw(cmd, js_args).await.map_err(|e| {
+        let error = api_error_from_js(e);
+        web_sys::console::log_1(&format!("命令 {} 调用失败: {}", cmd, error).into());
+        error
+    })?;
+
+    serde_wasm_bindgen::from_value::<T>(result).map_err(|e| ApiError::Decode {
+        message: format!("命令 {} 返回值解析失败: {}", cmd, e),
+    })
+}
+
+/// 事件监听句柄，释放时取消监听并释放回调
+pub struct EventListener {
+    unlisten: js_sys::Function,
+    _handler: Closure<dyn Fn(JsValue)>,
+}
+
+impl EventListener {
+    /// 取消监听
+    pub fn unlisten(self) {
+        drop(self);
+    }
+}
+
+impl Drop for EventListener {
+    fn drop(&mut self) {
+        let _ = self.unlisten.call0(&JsValue::NULL);
+    }
+}
+
+/// 监听原生层广播的事件
+///
+/// 回调在返回的句柄释放前有效
+pub async fn listen<F>(event: &str, handler: F) -> Result<EventListener, ApiError>
+where
+    F: Fn(JsValue) + 'static,
+{
+    let handler = Closure::wrap(Box::new(handler) as Box<dyn Fn(JsValue)>);
+    let unlisten = listen_raw(event, handler.as_ref().unchecked_ref())
+        .await
+        .map_err(api_error_from_js)?;
+    Ok(EventListener {
+        unlisten: unlisten.unchecked_into(),
+        _handler: handler,
+    })
+}
+
+/// 在 `use_effect` 中监听事件，返回的清理函数取消监听
+///
+/// 监听异步建立，组件在建立前卸载时，建立后立即取消
+pub fn listen_in_effect<F>(event: &'static str, handler: F) -> impl FnOnce()
+where
+    F: Fn(JsValue) + 'static,
+{
+    let listener: Rc<RefCell<Option<EventListener>>> = Rc::default();
+    let active = Rc::new(Cell::new(true));
+    {
+        let listener = listener.clone();
+        let active = active.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            match listen(event, handler).await {
+                Ok(handle) if active.get() => *listener.borrow_mut() = Some(handle),
+                Ok(handle) => handle.unlisten(),
+                Err(e) => web_sys::console::log_1(&format!("监听事件 {} 失败: {}", event, e).into()),
+            }
+        });
+    }
+    move || {
+        active.set(false);
+        if let Some(handle) = listener.borrow_mut().take() {
+            handle.unlisten();
+        }
+    }
+}
+
+// ---------- 认证 ----------
+
+/// 登录
+pub async fn login(username: String, password: String) -> Result<ApiResponse<LoginData>, ApiError> {
+    invoke("login", &LoginArgs { username, password }).await
+}
+
+/// 注销
+pub async fn logout() -> Result<String, ApiError> {
+    invoke("logout", &()).await
+}
+
+/// 检查用户是否已登录
+pub async fn is_logged_in() -> Result<bool, ApiError> {
+    invoke("is_logged_in", &()).await
+}
+
+/// 获取当前用户（用户名，用户ID）
+pub async fn get_current_user() -> Result<Option<(String, u32)>, ApiError> {
+    invoke("get_current_user", &()).await
+}
+
+/// 获取当前会话的角色和权限
+pub async fn get_session_info() -> Result<Option<SessionInfo>, ApiError> {
+    invoke("get_session_info", &()).await
+}
+
+// ---------- 后端服务器档案 ----------
+
+/// 获取后端服务器档案列表
+pub async fn list_backend_profiles() -> Result<BackendProfiles, ApiError> {
+    invoke("list_backend_profiles", &()).await
+}
+
+/// 切换后端服务器档案
+pub async fn switch_backend_profile(name: String) -> Result<BackendProfiles, ApiError> {
+    invoke("switch_backend_profile", &SwitchProfileArgs { name }).await
+}
+
+// ---------- 仪表板 ----------
+
+/// 获取仪表板指标
+pub async fn get_dashboard_kpis() -> Result<DashboardKpis, ApiError> {
+    invoke("get_dashboard_kpis", &()).await
+}
+
+/// 获取当前用户的仪表板布局
+pub async fn get_dashboard_layout() -> Result<DashboardLayout, ApiError> {
+    invoke("get_dashboard_layout", &()).await
+}
+
+/// 保存当前用户的仪表板布局
+pub async fn save_dashboard_layout(layout: DashboardLayout) -> Result<DashboardLayout, ApiError> {
+    invoke("save_dashboard_layout", &SaveDashboardLayoutArgs { layout }).await
+}
+
+// ---------- 生产工单 ----------
+
+/// 分页查询工单
+pub async fn list_work_orders(query: WorkOrderQuery) -> Result<PageData<WorkOrder>, ApiError> {
+    invoke("list_work_orders", &ListWorkOrdersArgs { query }).await
+}
+
+/// 获取工单详情
+pub async fn get_work_order(id: u64) -> Result<WorkOrderDetail, ApiError> {
+    invoke("get_work_order", &WorkOrderIdArgs { id }).await
+}
+
+/// 创建工单
+pub async fn create_work_order(draft: WorkOrderDraft) -> Result<WorkOrderDetail, ApiError> {
+    invoke("create_work_order", &CreateWorkOrderArgs { draft }).await
+}
+
+/// 编辑工单
+pub async fn update_work_order(id: u64, draft: WorkOrderDraft) -> Result<WorkOrderDetail, ApiError> {
+    invoke("update_work_order", &UpdateWorkOrderArgs { id, draft }).await
+}
+
+/// 变更工单状态
+pub async fn transition_work_order(id: u64, target: WorkOrderStatus) -> Result<WorkOrderDetail, ApiError> {
+    invoke("transition_work_order", &TransitionWorkOrderArgs { id, target }).await
+}
+
+// ---------- 生产排程 ----------
+
+/// 获取排程看板
+pub async fn get_schedule(query: ScheduleQuery) -> Result<ScheduleBoard, ApiError> {
+    invoke("get_schedule", &GetScheduleArgs { query }).await
+}
+
+/// 调整工单排程
+pub async fn reschedule(request: RescheduleRequest) -> Result<ScheduleItem, ApiError> {
+    invoke("reschedule", &RescheduleArgs { request }).await
+}
+
+// ---------- 工艺路线 ----------
+
+/// 获取工作中心列表
+pub async fn list_work_centers() -> Result<Vec<WorkCenter>, ApiError> {
+    invoke("list_work_centers", &()).await
+}
+
+/// 查询工艺路线
+pub async fn list_routings(keyword: Option<String>) -> Result<Vec<RoutingSummary>, ApiError> {
+    invoke("list_routings", &ListRoutingsArgs { keyword }).await
+}
+
+/// 获取工艺路线详情
+pub async fn get_routing(id: u64) -> Result<Routing, ApiError> {
+    invoke("get_routing", &RoutingIdArgs { id }).await
+}
+
+/// 保存工艺路线草稿
+pub async fn save_routing(routing: Routing) -> Result<Routing, ApiError> {
+    invoke("save_routing", &SaveRoutingArgs { routing }).await
+}
+
+/// 发布工艺路线
+pub async fn publish_routing(id: u64) -> Result<Routing, ApiError> {
+    invoke("publish_routing", &RoutingIdArgs { id }).await
+}
+
+/// 基于已有版本创建新版本
+pub async fn new_routing_version(id: u64) -> Result<Routing, ApiError> {
+    invoke("new_routing_version", &RoutingIdArgs { id }).await
+}
+
+// ---------- 原材料库存 ----------
+
+/// 分页查询原材料库存
+pub async fn list_material_stock(query: MaterialStockQuery) -> Result<PageData<MaterialStock>, ApiError> {
+    invoke("list_material_stock", &ListMaterialStockArgs { query }).await
+}
+
+/// 收货入库
+pub async fn receive_material(receipt: GoodsReceipt) -> Result<MaterialLot, ApiError> {
+    invoke("receive_material", &ReceiveMaterialArgs { receipt }).await
+}
+
+/// 按批次发料到工单
+pub async fn issue_material(issue: MaterialIssue) -> Result<LotTransaction, ApiError> {
+    invoke("issue_material", &IssueMaterialArgs { issue }).await
+}
+
+/// 批次追溯
+pub async fn trace_lot(lot: String) -> Result<LotTrace, ApiError> {
+    invoke("trace_lot", &TraceLotArgs { lot }).await
+}
+
+// ---------- 成品库存 ----------
+
+/// 分页查询成品库存
+pub async fn list_product_stock(query: ProductStockQuery) -> Result<PageData<ProductStock>, ApiError> {
+    invoke("list_product_stock", &ListProductStockArgs { query }).await
+}
+
+/// 完工入库登记
+pub async fn register_completion(registration: CompletionRegistration) -> Result<ProductStock, ApiError> {
+    invoke("register_completion", &RegisterCompletionArgs { registration }).await
+}
+
+/// 发货出库
+pub async fn ship_products(shipment: Shipment) -> Result<ProductStock, ApiError> {
+    invoke("ship_products", &ShipProductsArgs { shipment }).await
+}
+
+/// 按序列号查询生产档案
+pub async fn find_serial(serial: String) -> Result<SerialRecord, ApiError> {
+    invoke("find_serial", &FindSerialArgs { serial }).await
+}
+
+// ---------- 仓储管理 ----------
+
+/// 获取仓库结构（含库位占用）
+pub async fn list_warehouses() -> Result<Vec<Warehouse>, ApiError> {
+    invoke("list_warehouses", &()).await
+}
+
+/// 查询库位库存明细
+pub async fn list_bin_stock(bin: String) -> Result<Vec<BinStock>, ApiError> {
+    invoke("list_bin_stock", &ListBinStockArgs { bin }).await
+}
+
+/// 库位间移库
+pub async fn transfer_stock(transfer: BinTransfer) -> Result<TransferRecord, ApiError> {
+    invoke("transfer_stock", &TransferStockArgs { transfer }).await
+}
+
+/// 查询盘点单
+pub async fn list_cycle_counts(warehouse: Option<String>) -> Result<Vec<CycleCount>, ApiError> {
+    invoke("list_cycle_counts", &ListCycleCountsArgs { warehouse }).await
+}
+
+/// 新建盘点单
+pub async fn start_cycle_count(draft: CycleCountDraft) -> Result<CycleCount, ApiError> {
+    invoke("start_cycle_count", &StartCycleCountArgs { draft }).await
+}
+
+/// 获取盘点单详情
+pub async fn get_cycle_count(id: u64) -> Result<CycleCount, ApiError> {
+    invoke("get_cycle_count", &CycleCountIdArgs { id }).await
+}
+
+/// 提交实盘数量
+pub async fn submit_cycle_count(id: u64, lines: Vec<CycleCountLine>) -> Result<CycleCount, ApiError> {
+    invoke("submit_cycle_count", &SubmitCycleCountArgs { id, lines }).await
+}
+
+/// 审批盘点差异
+pub async fn review_cycle_count(id: u64, review: CountReview) -> Result<CycleCount, ApiError> {
+    invoke("review_cycle_count", &ReviewCycleCountArgs { id, review }).await
+}
+
+// ---------- 质量检验 ----------
+
+/// 分页查询检验批
+pub async fn list_inspection_lots(query: InspectionLotQuery) -> Result<PageData<InspectionLot>, ApiError> {
+    invoke("list_inspection_lots", &ListInspectionLotsArgs { query }).await
+}
+
+/// 新建检验批
+pub async fn open_inspection_lot(draft: InspectionLotDraft) -> Result<InspectionLot, ApiError> {
+    invoke("open_inspection_lot", &OpenInspectionLotArgs { draft }).await
+}
+
+/// 获取检验批详情
+pub async fn get_inspection_lot(id: u64) -> Result<InspectionLot, ApiError> {
+    invoke("get_inspection_lot", &InspectionLotIdArgs { id }).await
+}
+
+/// 提交检验结果
+pub async fn submit_inspection(id: u64, record: InspectionRecord) -> Result<InspectionLot, ApiError> {
+    invoke("submit_inspection", &SubmitInspectionArgs { id, record }).await
+}
+
+// ---------- 检验标准 ----------
+
+/// 查询检验标准
+pub async fn list_inspection_plans(keyword: Option<String>) -> Result<Vec<InspectionPlanSummary>, ApiError> {
+    invoke("list_inspection_plans", &ListInspectionPlansArgs { keyword }).await
+}
+
+/// 获取检验标准详情
+pub async fn get_inspection_plan(id: u64) -> Result<InspectionPlan, ApiError> {
+    invoke("get_inspection_plan", &InspectionPlanIdArgs { id }).await
+}
+
+/// 保存检验标准草稿
+pub async fn save_inspection_plan(plan: InspectionPlan) -> Result<InspectionPlan, ApiError> {
+    invoke("save_inspection_plan", &SaveInspectionPlanArgs { plan }).await
+}
+
+/// 发布检验标准
+pub async fn publish_inspection_plan(id: u64) -> Result<InspectionPlan, ApiError> {
+    invoke("publish_inspection_plan", &InspectionPlanIdArgs { id }).await
+}
+
+/// 基于已有版本创建新版本
+pub async fn new_inspection_plan_version(id: u64) -> Result<InspectionPlan, ApiError> {
+    invoke("new_inspection_plan_version", &InspectionPlanIdArgs { id }).await
+}
+
+// ---------- 质量问题 ----------
+
+/// 分页查询不合格报告
+pub async fn list_ncrs(query: NcrQuery) -> Result<PageData<Ncr>, ApiError> {
+    invoke("list_ncrs", &ListNcrsArgs { query }).await
+}
+
+/// 获取不合格报告详情
+pub async fn get_ncr(id: u64) -> Result<NcrDetail, ApiError> {
+    invoke("get_ncr", &NcrIdArgs { id }).await
+}
+
+/// 新建不合格报告
+pub async fn create_ncr(draft: NcrDraft) -> Result<NcrDetail, ApiError> {
+    invoke("create_ncr", &CreateNcrArgs { draft }).await
+}
+
+/// 由拒收的检验批创建不合格报告
+pub async fn create_ncr_from_inspection(inspection: u64) -> Result<NcrDetail, ApiError> {
+    invoke("create_ncr_from_inspection", &CreateNcrFromInspectionArgs { inspection }).await
+}
+
+/// 修改责任人、严重程度和8D报告
+pub async fn update_ncr(id: u64, update: NcrUpdate) -> Result<NcrDetail, ApiError> {
+    invoke("update_ncr", &UpdateNcrArgs { id, update }).await
+}
+
+/// 变更不合格报告状态
+pub async fn transition_ncr(id: u64, target: NcrStatus, comment: Option<String>) -> Result<NcrDetail, ApiError> {
+    invoke("transition_ncr", &TransitionNcrArgs { id, target, comment }).await
+}
+
+/// 上传附件
+pub async fn upload_ncr_attachment(id: u64, attachment: AttachmentUpload) -> Result<NcrDetail, ApiError> {
+    invoke("upload_ncr_attachment", &UploadNcrAttachmentArgs { id, attachment }).await
+}
+
+// ---------- 统计过程控制 ----------
+
+/// 获取物料某一检验特性的SPC数据
+pub async fn get_spc_dataset(query: SpcQuery) -> Result<SpcDataset, ApiError> {
+    invoke("get_spc_dataset", &GetSpcDatasetArgs { query }).await
+}
+
+// ---------- 报表 ----------
+
+/// 获取报表定义列表
+pub async fn list_report_definitions() -> Result<Vec<ReportDefinition>, ApiError> {
+    invoke("list_report_definitions", &()).await
+}
+
+/// 运行报表（预览）
+pub async fn run_report(request: ReportRequest) -> Result<ReportTable, ApiError> {
+    invoke("run_report", &RunReportArgs { request }).await
+}
+
+/// 导出报表到指定文件夹
+pub async fn export_report(
+    request: ReportRequest,
+    format: ExportFormat,
+    folder: String,
+) -> Result<ExportResult, ApiError> {
+    invoke("export_report", &ExportReportArgs { request, format, folder }).await
+}
+
+/// 获取默认导出文件夹
+pub async fn get_default_export_folder() -> Result<String, ApiError> {
+    invoke("get_default_export_folder", &()).await
+}
+
+/// 弹出系统对话框选择导出文件夹，取消时返回 `None`
+pub async fn pick_export_folder(current: &str) -> Result<Option<String>, ApiError> {
+    let options = OpenDialogOptions {
+        title: "选择导出文件夹",
+        directory: true,
+        default_path: Some(current).filter(|path| !path.is_empty()),
+    };
+    let js_options = serde_wasm_bindgen::to_value(&options).map_err(|e| ApiError::Decode {
+        message: format!("对话框参数序列化失败: {}", e),
+    })?;
+
+    let result = open_dialog_raw(js_options).await.map_err(api_error_from_js)?;
+    serde_wasm_bindgen::from_value::<Option<String>>(result).map_err(|e| ApiError::Decode {
+        message: format!("对话框返回值解析失败: {}", e),
+    })
+}
+
+/// 在文件管理器中显示导出文件
+pub async fn reveal_export(path: String) -> Result<(), ApiError> {
+    invoke("reveal_export", &RevealExportArgs { path }).await
+}
+
+// ---------- 用户管理 ----------
+
+/// 分页查询用户
+pub async fn list_users(query: UserQuery) -> Result<PageData<UserAccount>, ApiError> {
+    invoke("list_users", &ListUsersArgs { query }).await
+}
+
+/// 获取全部角色
+pub async fn list_roles() -> Result<Vec<Role>, ApiError> {
+    invoke("list_roles", &()).await
+}
+
+/// 新建用户
+pub async fn create_user(draft: UserDraft) -> Result<UserAccount, ApiError> {
+    invoke("create_user", &CreateUserArgs { draft }).await
+}
+
+/// 停用/启用用户
+pub async fn set_user_enabled(id: u64, enabled: bool) -> Result<UserAccount, ApiError> {
+    invoke("set_user_enabled", &SetUserEnabledArgs { id, enabled }).await
+}
+
+/// 解锁用户
+pub async fn unlock_user(id: u64) -> Result<UserAccount, ApiError> {
+    invoke("unlock_user", &UserIdArgs { id }).await
+}
+
+/// 重置用户密码
+pub async fn reset_user_password(id: u64, password: String) -> Result<(), ApiError> {
+    invoke("reset_user_password", &ResetUserPasswordArgs { id, password }).await
+}
+
+/// 分配用户角色
+pub async fn assign_user_roles(id: u64, roles: Vec<String>) -> Result<UserAccount, ApiError> {
+    invoke("assign_user_roles", &AssignUserRolesArgs { id, roles }).await
+}
+
+/// 新建角色
+pub async fn create_role(role: Role) -> Result<Role, ApiError> {
+    invoke("create_role", &RoleArgs { role }).await
+}
+
+/// 修改角色
+pub async fn update_role(role: Role) -> Result<Role, ApiError> {
+    invoke("update_role", &RoleArgs { role }).await
+}
+
+/// 删除角色
+pub async fn delete_role(code: String) -> Result<(), ApiError> {
+    invoke("delete_role", &RoleCodeArgs { code }).await
+}
+
+// ---------- 系统参数 ----------
+
+/// 获取本机客户端设置
+pub async fn get_local_settings() -> Result<LocalSettings, ApiError> {
+    invoke("get_local_settings", &()).await
+}
+
+/// 保存本机客户端设置（切换后端档案会退出登录）
+pub async fn save_local_settings(settings: LocalSettings) -> Result<LocalSettings, ApiError> {
+    invoke("save_local_settings", &LocalSettingsArgs { settings }).await
+}
+
+/// 获取工厂参数
+pub async fn get_plant_settings() -> Result<PlantSettings, ApiError> {
+    invoke("get_plant_settings", &()).await
+}
+
+/// 保存工厂参数
+pub async fn save_plant_settings(values: BTreeMap<String, String>) -> Result<PlantSettings, ApiError> {
+    invoke("save_plant_settings", &PlantSettingsArgs { values }).await
+}
+
+// ---------- 离线队列 ----------
+
+/// 获取离线状态
+pub async fn get_offline_status() -> Result<OfflineStatus, ApiError> {
+    invoke("get_offline_status", &()).await
+}
+
+/// 列出离线队列中的事务
+pub async fn list_offline_transactions() -> Result<Vec<QueuedTransaction>, ApiError> {
+    invoke("list_offline_transactions", &()).await
+}
+
+/// 立即重放离线队列
+pub async fn sync_offline_queue() -> Result<OfflineStatus, ApiError> {
+    invoke("sync_offline_queue", &()).await
+}
+
+/// 冲突事务重新提交
+pub async fn retry_offline_transaction(id: u64) -> Result<OfflineStatus, ApiError> {
+    invoke("retry_offline_transaction", &OfflineTransactionIdArgs { id }).await
+}
+
+/// 放弃离线事务
+pub async fn discard_offline_transaction(id: u64) -> Result<OfflineStatus, ApiError> {
+    invoke("discard_offline_transaction", &OfflineTransactionIdArgs { id }).await
+}
+
+// ---------- 主数据缓存 ----------
+
+/// 获取本地主数据缓存状态
+pub async fn get_master_data_status() -> Result<Vec<MasterDataStatus>, ApiError> {
+    invoke("get_master_data_status", &()).await
+}
+
+/// 立即同步主数据（`full` 为 true 时全量重建）
+pub async fn sync_master_data(full: bool) -> Result<Vec<MasterDataStatus>, ApiError> {
+    invoke("sync_master_data", &SyncMasterDataArgs { full }).await
+}
+
+/// 在本地缓存中联想查询主数据
+pub async fn lookup_master_data(kind: MasterDataKind, keyword: String, limit: Option<u32>) -> Result<Vec<MasterDataItem>, ApiError> {
+    invoke("lookup_master_data", &LookupMasterDataArgs { kind, keyword, limit }).await
+}
+
+// ---------- 全局搜索 ----------
+
+/// 全局搜索（本地缓存 + 后端）
+pub async fn search(keyword: String) -> Result<SearchResults, ApiError> {
+    invoke("search", &SearchArgs { keyword }).await
+}
+
+// ---------- 窗口 ----------
+
+/// 最小化窗口
+pub async fn minimize() -> Result<(), ApiError> {
+    invoke("minimize", &()).await
+}
+
+/// 关闭窗口
+pub async fn close() -> Result<(), ApiError> {
+    invoke("close", &()).await
+}
+
+/// 切换最大化/还原窗口
+pub async fn toggle_maximize() -> Result<(), ApiError> {
+    invoke("toggle_maximize", &()).await
+}
+
+/// 调整窗口大小并居中
+pub async fn resize_window(width: f64, height: f64) -> Result<(), ApiError> {
+    invoke("resize_window", &ResizeWindowArgs {
+        args: WindowSizeArgs { width, height },
+    })
+    .await
+}
+
+/// 打开个人中心窗口
+pub async fn open_profile_window() -> Result<(), ApiError> {
+    invoke("open_profile_window", &()).await
+}
@@ -0,0 +1,10 @@
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+
+/// 字段错误提示
+pub fn field_error(errors: &[FieldError], field: &str) -> Html {
+    match errors.iter().find(|error| error.field == field) {
+        Some(error) => html! { <div class="field-error">{&error.message}</div> },
+        None => html! {},
+    }
+}
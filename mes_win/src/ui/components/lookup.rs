@@ -0,0 +1,160 @@
+use yew::prelude::*;
+use mes_win_shared::master_data::{MasterDataItem, MasterDataKind};
+use crate::services::tauri;
+
+#[derive(Properties, PartialEq)]
+pub struct LookupInputProps {
+    pub kind: MasterDataKind,
+    pub value: String,
+    /// 输入内容变化（含手工输入的未匹配编码）
+    pub on_input: Callback<String>,
+    /// 从联想列表中选中条目
+    pub on_select: Callback<MasterDataItem>,
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+// 主数据联想输入框：查询本地缓存，服务器响应慢或离线时也能即时提示
+//
+// 上下键切换候选，回车选中，Esc 关闭列表
+#[function_component(LookupInput)]
+pub fn lookup_input(props: &LookupInputProps) -> Html {
+    let suggestions = use_state(Vec::<MasterDataItem>::new);
+    let active = use_state(|| 0usize);
+    let is_open = use_state(|| false);
+    // 只采用最后一次输入的查询结果
+    let sequence = use_mut_ref(|| 0u32);
+
+    let search = {
+        let suggestions = suggestions.clone();
+        let active = active.clone();
+        let is_open = is_open.clone();
+        let sequence = sequence.clone();
+        let kind = props.kind;
+        move |keyword: String| {
+            let suggestions = suggestions.clone();
+            let active = active.clone();
+            let is_open = is_open.clone();
+            let sequence = sequence.clone();
+            let current = *sequence.borrow() + 1;
+            *sequence.borrow_mut() = current;
+            wasm_bindgen_futures::spawn_local(async move {
+                let found = tauri::lookup_master_data(kind, keyword, None).await.unwrap_or_default();
+                if *sequence.borrow() != current {
+                    return;
+                }
+                is_open.set(!found.is_empty());
+                active.set(0);
+                suggestions.set(found);
+            });
+        }
+    };
+
+    let select = {
+        let is_open = is_open.clone();
+        let on_select = props.on_select.clone();
+        move |item: MasterDataItem| {
+            is_open.set(false);
+            on_select.emit(item);
+        }
+    };
+
+    let on_input = {
+        let search = search.clone();
+        let on_input = props.on_input.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let value = input.value();
+            on_input.emit(value.clone());
+            search(value);
+        })
+    };
+
+    let on_focus = {
+        let search = search.clone();
+        let value = props.value.clone();
+        Callback::from(move |_: FocusEvent| search(value.clone()))
+    };
+
+    let on_blur = {
+        let is_open = is_open.clone();
+        Callback::from(move |_: FocusEvent| is_open.set(false))
+    };
+
+    let on_keydown = {
+        let suggestions = suggestions.clone();
+        let active = active.clone();
+        let is_open = is_open.clone();
+        let select = select.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if !*is_open || suggestions.is_empty() {
+                return;
+            }
+            match e.key().as_str() {
+                "ArrowDown" => {
+                    e.prevent_default();
+                    active.set((*active + 1) % suggestions.len());
+                }
+                "ArrowUp" => {
+                    e.prevent_default();
+                    active.set((*active + suggestions.len() - 1) % suggestions.len());
+                }
+                "Enter" => {
+                    // 选中候选而不是提交表单
+                    e.prevent_default();
+                    if let Some(item) = suggestions.get(*active) {
+                        select(item.clone());
+                    }
+                }
+                "Escape" => {
+                    e.stop_propagation();
+                    is_open.set(false);
+                }
+                _ => {}
+            }
+        })
+    };
+
+    html! {
+        <div class="lookup">
+            <input class="form-input" type="text" autocomplete="off"
+                value={props.value.clone()}
+                placeholder={props.placeholder.clone()}
+                disabled={props.disabled}
+                oninput={on_input}
+                onfocus={on_focus}
+                onblur={on_blur}
+                onkeydown={on_keydown} />
+            if *is_open {
+                <ul class="lookup-menu">
+                    { for suggestions.iter().enumerate().map(|(index, item)| {
+                        let onmousedown = {
+                            let select = select.clone();
+                            let item = item.clone();
+                            // 用 mousedown 选中，避免输入框先失去焦点关闭列表
+                            Callback::from(move |e: MouseEvent| {
+                                e.prevent_default();
+                                select(item.clone());
+                            })
+                        };
+                        html! {
+                            <li class={classes!("lookup-item", (index == *active).then_some("active"))}
+                                onmousedown={onmousedown}>
+                                <span class="lookup-code">{&item.code}</span>
+                                <span>{&item.name}</span>
+                                if let Some(spec) = item.spec.clone().filter(|spec| !spec.is_empty()) {
+                                    <span class="lookup-detail">{spec}</span>
+                                }
+                                if let Some(unit) = item.unit.clone() {
+                                    <span class="lookup-detail">{unit}</span>
+                                }
+                            </li>
+                        }
+                    })}
+                </ul>
+            }
+        </div>
+    }
+}
@@ -1 +1,7 @@
-// UI组件模块 - 目前为空，后续可以添加通用组件
+// UI组件模块 - 各页面共用的通用组件
+pub mod form;
+pub mod lookup;
+pub mod modal;
+pub mod offline;
+pub mod pagination;
+pub mod search;
@@ -0,0 +1,36 @@
+use yew::prelude::*;
+
+// 模态对话框属性
+#[derive(Properties, PartialEq)]
+pub struct ModalProps {
+    pub title: AttrValue,
+    pub on_close: Callback<()>,
+    /// 宽对话框（用于表格内容）
+    #[prop_or_default]
+    pub wide: bool,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+// 模态对话框
+#[function_component(Modal)]
+pub fn modal(props: &ModalProps) -> Html {
+    let on_close = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| on_close.emit(()))
+    };
+
+    html! {
+        <div class="modal-overlay">
+            <div class={classes!("modal-dialog", props.wide.then_some("wide"))}>
+                <div class="modal-header">
+                    <span class="modal-title">{props.title.clone()}</span>
+                    <button class="message-close" onclick={on_close}>{"×"}</button>
+                </div>
+                <div class="modal-body">
+                    { props.children.clone() }
+                </div>
+            </div>
+        </div>
+    }
+}
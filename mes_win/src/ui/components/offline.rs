@@ -0,0 +1,241 @@
+use yew::prelude::*;
+use mes_win_shared::events;
+use mes_win_shared::offline::{OfflineStatus, QueuedStatus, QueuedTransaction};
+use crate::services::tauri;
+use crate::ui::components::modal::Modal;
+use crate::utils::time::format_unix_time;
+
+// 离线状态指示：显示连接状态和离线队列数量，点击查看队列明细
+#[function_component(OfflineIndicator)]
+pub fn offline_indicator() -> Html {
+    let status = use_state(|| OfflineStatus {
+        online: true,
+        ..OfflineStatus::default()
+    });
+    let is_open = use_state(|| false);
+
+    {
+        let status = status.clone();
+        use_effect_with((), move |_| {
+            let unlisten = tauri::is_tauri_environment().then(|| {
+                let initial = status.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    if let Ok(current) = tauri::get_offline_status().await {
+                        initial.set(current);
+                    }
+                });
+                // 每次登录都会重新挂载，卸载时取消监听
+                tauri::listen_in_effect(events::OFFLINE_STATUS, move |event| {
+                    let payload = js_sys::Reflect::get(&event, &"payload".into()).unwrap_or_default();
+                    if let Ok(current) = serde_wasm_bindgen::from_value::<OfflineStatus>(payload) {
+                        status.set(current);
+                    }
+                })
+            });
+            move || {
+                if let Some(unlisten) = unlisten {
+                    unlisten();
+                }
+            }
+        });
+    }
+
+    let on_open = {
+        let is_open = is_open.clone();
+        Callback::from(move |_: MouseEvent| is_open.set(true))
+    };
+    let on_close = {
+        let is_open = is_open.clone();
+        Callback::from(move |_: ()| is_open.set(false))
+    };
+    let on_status = {
+        let status = status.clone();
+        Callback::from(move |current: OfflineStatus| status.set(current))
+    };
+
+    let (state, text) = match (status.online, status.pending, status.conflicts) {
+        (true, 0, 0) => ("online", "在线".to_string()),
+        (false, 0, 0) => ("offline", "离线".to_string()),
+        (online, pending, conflicts) => {
+            let mut text = if online { String::new() } else { "离线 · ".to_string() };
+            text.push_str(&format!("待同步 {}", pending));
+            if conflicts > 0 {
+                text.push_str(&format!(" · 冲突 {}", conflicts));
+            }
+            (if conflicts > 0 { "conflict" } else { "queued" }, text)
+        }
+    };
+    let title = match status.last_sync_at {
+        Some(at) => format!("最近同步：{}", format_unix_time(at)),
+        None => "离线时的写操作保存在本机队列，恢复连接后按顺序自动提交".to_string(),
+    };
+
+    html! {
+        <>
+            <div class={classes!("offline-indicator", state)} title={title} onclick={on_open}>
+                <span class="offline-dot"></span>
+                <span>{text}</span>
+            </div>
+            if *is_open {
+                <QueueDialog status={(*status).clone()} on_status={on_status} on_close={on_close} />
+            }
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct QueueDialogProps {
+    status: OfflineStatus,
+    on_status: Callback<OfflineStatus>,
+    on_close: Callback<()>,
+}
+
+// 离线队列明细：立即同步、重试冲突事务或放弃
+#[function_component(QueueDialog)]
+fn queue_dialog(props: &QueueDialogProps) -> Html {
+    let transactions = use_state(Vec::<QueuedTransaction>::new);
+    let error_message = use_state(|| None::<String>);
+    let is_busy = use_state(|| false);
+
+    // 状态变化（入队、同步完成）后重新加载明细
+    {
+        let transactions = transactions.clone();
+        let error_message = error_message.clone();
+        use_effect_with(props.status.clone(), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_offline_transactions().await {
+                    Ok(list) => transactions.set(list),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    // 执行队列操作，完成后以返回的状态刷新
+    let run = {
+        let error_message = error_message.clone();
+        let is_busy = is_busy.clone();
+        let on_status = props.on_status.clone();
+        move |action: QueueAction| {
+            let error_message = error_message.clone();
+            let is_busy = is_busy.clone();
+            let on_status = on_status.clone();
+            is_busy.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = match action {
+                    QueueAction::Sync => tauri::sync_offline_queue().await,
+                    QueueAction::Retry(id) => tauri::retry_offline_transaction(id).await,
+                    QueueAction::Discard(id) => tauri::discard_offline_transaction(id).await,
+                };
+                is_busy.set(false);
+                match result {
+                    Ok(status) => {
+                        error_message.set(None);
+                        on_status.emit(status);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        }
+    };
+
+    let on_sync = {
+        let run = run.clone();
+        Callback::from(move |_: MouseEvent| run(QueueAction::Sync))
+    };
+
+    html! {
+        <Modal title="离线队列" wide={true} on_close={props.on_close.clone()}>
+            <div class="data-view">
+                <div class="toolbar">
+                    <span class="spc-meta">
+                        { if props.status.online { "后端连接正常" } else { "后端不可达，恢复连接后自动提交" } }
+                    </span>
+                    <div class="toolbar-actions">
+                        <button class="btn btn-primary" disabled={*is_busy || props.status.pending == 0}
+                            onclick={on_sync}>
+                            { if *is_busy { "同步中..." } else { "立即同步" } }
+                        </button>
+                    </div>
+                </div>
+
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+
+                if transactions.is_empty() {
+                    <div class="table-empty">{"离线队列为空"}</div>
+                } else {
+                    <table class="data-table">
+                        <thead>
+                            <tr>
+                                <th>{"序号"}</th>
+                                <th>{"类型"}</th>
+                                <th>{"内容"}</th>
+                                <th>{"状态"}</th>
+                                <th>{"入队时间"}</th>
+                                <th>{"说明"}</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for transactions.iter().map(|transaction| {
+                                let id = transaction.id;
+                                let summary = transaction.summary.clone();
+                                let on_retry = {
+                                    let run = run.clone();
+                                    Callback::from(move |_: MouseEvent| run(QueueAction::Retry(id)))
+                                };
+                                let on_discard = {
+                                    let run = run.clone();
+                                    Callback::from(move |_: MouseEvent| {
+                                        let confirmed = web_sys::window()
+                                            .and_then(|w| w.confirm_with_message(
+                                                &format!("放弃后「{}」将不会提交到服务器，确定放弃吗？", summary),
+                                            ).ok())
+                                            .unwrap_or(false);
+                                        if confirmed {
+                                            run(QueueAction::Discard(id));
+                                        }
+                                    })
+                                };
+                                html! {
+                                    <tr>
+                                        <td>{format!("#{}", id)}</td>
+                                        <td>{transaction.kind.label()}</td>
+                                        <td>{&transaction.summary}</td>
+                                        <td>
+                                            <span class={classes!("status-badge", format!("status-{}", transaction.status.css_class()))}>
+                                                {transaction.status.label()}
+                                            </span>
+                                        </td>
+                                        <td>{format_unix_time(transaction.created_at)}</td>
+                                        <td>
+                                            { transaction.last_error.clone().map(|error| {
+                                                format!("{}（已尝试 {} 次）", error, transaction.attempts)
+                                            }).unwrap_or_default() }
+                                        </td>
+                                        <td class="row-actions">
+                                            if transaction.status == QueuedStatus::Conflict {
+                                                <button class="btn" disabled={*is_busy} onclick={on_retry}>{"重试"}</button>
+                                            }
+                                            <button class="btn btn-danger" disabled={*is_busy} onclick={on_discard}>{"放弃"}</button>
+                                        </td>
+                                    </tr>
+                                }
+                            })}
+                        </tbody>
+                    </table>
+                }
+            </div>
+        </Modal>
+    }
+}
+
+#[derive(Clone, Copy)]
+enum QueueAction {
+    Sync,
+    Retry(u64),
+    Discard(u64),
+}
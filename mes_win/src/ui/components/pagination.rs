@@ -0,0 +1,34 @@
+use yew::prelude::*;
+
+// 分页组件属性
+#[derive(Properties, PartialEq)]
+pub struct PaginationProps {
+    /// 当前页（从1开始）
+    pub page: u32,
+    pub total_pages: u32,
+    pub total: u64,
+    pub on_change: Callback<u32>,
+}
+
+// 分页组件
+#[function_component(Pagination)]
+pub fn pagination(props: &PaginationProps) -> Html {
+    let page = props.page;
+    let total_pages = props.total_pages.max(1);
+
+    let go_to = |target: u32| {
+        let on_change = props.on_change.clone();
+        Callback::from(move |_: MouseEvent| on_change.emit(target))
+    };
+
+    html! {
+        <div class="pagination">
+            <span class="pagination-total">{format!("共 {} 条", props.total)}</span>
+            <button class="pagination-btn" disabled={page <= 1} onclick={go_to(1)}>{"首页"}</button>
+            <button class="pagination-btn" disabled={page <= 1} onclick={go_to(page.saturating_sub(1))}>{"上一页"}</button>
+            <span class="pagination-current">{format!("{} / {}", page, total_pages)}</span>
+            <button class="pagination-btn" disabled={page >= total_pages} onclick={go_to(page + 1)}>{"下一页"}</button>
+            <button class="pagination-btn" disabled={page >= total_pages} onclick={go_to(total_pages)}>{"末页"}</button>
+        </div>
+    }
+}
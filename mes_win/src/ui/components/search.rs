@@ -0,0 +1,220 @@
+use gloo_timers::future::TimeoutFuture;
+use yew::prelude::*;
+use mes_win_shared::search::{SearchHit, SearchTarget};
+use crate::services::tauri;
+
+/// 输入停顿多久后查询后端（毫秒）
+const SEARCH_DELAY_MS: u32 = 250;
+
+#[derive(Properties, PartialEq)]
+pub struct GlobalSearchProps {
+    /// 按关键字匹配功能菜单（菜单表和权限在主界面，匹配在前端完成）
+    pub menu_hits: Callback<String, Vec<SearchHit>>,
+    /// 选中结果后跳转
+    pub on_select: Callback<SearchTarget>,
+}
+
+// 全局搜索框：菜单即时匹配，工单、物料、批次、序列号、质量问题由 `search` 命令查询
+//
+// 上下键切换结果，回车跳转，Esc 清空并关闭
+#[function_component(GlobalSearch)]
+pub fn global_search(props: &GlobalSearchProps) -> Html {
+    let keyword = use_state(String::new);
+    let hits = use_state(Vec::<SearchHit>::new);
+    let active = use_state(|| 0usize);
+    let is_open = use_state(|| false);
+    let is_loading = use_state(|| false);
+    let partial = use_state(|| false);
+    // 只采用最后一次输入的查询结果
+    let sequence = use_mut_ref(|| 0u32);
+    let input_ref = use_node_ref();
+
+    let on_input = {
+        let keyword = keyword.clone();
+        let hits = hits.clone();
+        let active = active.clone();
+        let is_open = is_open.clone();
+        let is_loading = is_loading.clone();
+        let partial = partial.clone();
+        let sequence = sequence.clone();
+        let menu_hits = props.menu_hits.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let value = input.value();
+            let current = *sequence.borrow() + 1;
+            *sequence.borrow_mut() = current;
+
+            let trimmed = value.trim().to_string();
+            keyword.set(value);
+            active.set(0);
+            partial.set(false);
+            if trimmed.is_empty() {
+                hits.set(Vec::new());
+                is_open.set(false);
+                is_loading.set(false);
+                return;
+            }
+
+            let menus = menu_hits.emit(trimmed.clone());
+            hits.set(menus.clone());
+            is_open.set(true);
+            if !tauri::is_tauri_environment() {
+                return;
+            }
+
+            let hits = hits.clone();
+            let is_loading = is_loading.clone();
+            let partial = partial.clone();
+            let sequence = sequence.clone();
+            is_loading.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                TimeoutFuture::new(SEARCH_DELAY_MS).await;
+                if *sequence.borrow() != current {
+                    return;
+                }
+                let result = tauri::search(trimmed).await;
+                if *sequence.borrow() != current {
+                    return;
+                }
+                is_loading.set(false);
+                match result {
+                    Ok(results) => {
+                        let mut combined = menus;
+                        combined.extend(results.hits);
+                        hits.set(combined);
+                        partial.set(results.partial);
+                    }
+                    Err(_) => partial.set(true),
+                }
+            });
+        })
+    };
+
+    // 跳转后清空搜索框
+    let select = {
+        let keyword = keyword.clone();
+        let hits = hits.clone();
+        let is_open = is_open.clone();
+        let is_loading = is_loading.clone();
+        let sequence = sequence.clone();
+        let input_ref = input_ref.clone();
+        let on_select = props.on_select.clone();
+        move |hit: SearchHit| {
+            *sequence.borrow_mut() += 1;
+            keyword.set(String::new());
+            hits.set(Vec::new());
+            is_open.set(false);
+            is_loading.set(false);
+            if let Some(input) = input_ref.cast::<web_sys::HtmlInputElement>() {
+                let _ = input.blur();
+            }
+            on_select.emit(hit.target);
+        }
+    };
+
+    let on_keydown = {
+        let keyword = keyword.clone();
+        let hits = hits.clone();
+        let active = active.clone();
+        let is_open = is_open.clone();
+        let is_loading = is_loading.clone();
+        let sequence = sequence.clone();
+        let select = select.clone();
+        Callback::from(move |e: KeyboardEvent| match e.key().as_str() {
+            "ArrowDown" if !hits.is_empty() => {
+                e.prevent_default();
+                is_open.set(true);
+                active.set((*active + 1) % hits.len());
+            }
+            "ArrowUp" if !hits.is_empty() => {
+                e.prevent_default();
+                active.set((*active + hits.len() - 1) % hits.len());
+            }
+            "Enter" => {
+                e.prevent_default();
+                if let Some(hit) = hits.get(*active) {
+                    select(hit.clone());
+                }
+            }
+            "Escape" => {
+                *sequence.borrow_mut() += 1;
+                keyword.set(String::new());
+                hits.set(Vec::new());
+                is_open.set(false);
+                is_loading.set(false);
+            }
+            _ => {}
+        })
+    };
+
+    let on_focus = {
+        let keyword = keyword.clone();
+        let is_open = is_open.clone();
+        Callback::from(move |_: FocusEvent| is_open.set(!keyword.trim().is_empty()))
+    };
+
+    let on_blur = {
+        let is_open = is_open.clone();
+        Callback::from(move |_: FocusEvent| is_open.set(false))
+    };
+
+    let footer = if *is_loading {
+        Some("正在搜索...")
+    } else if *partial {
+        Some("服务器响应慢，部分结果未返回")
+    } else if hits.is_empty() {
+        Some("没有匹配的结果")
+    } else {
+        None
+    };
+
+    html! {
+        <div class="search-box">
+            <svg width="16" height="16" viewBox="0 0 24 24" fill="none" class="search-icon">
+                <circle cx="11" cy="11" r="8" stroke="currentColor" stroke-width="2"/>
+                <path d="M21 21l-4.35-4.35" stroke="currentColor" stroke-width="2"/>
+            </svg>
+            <input
+                ref={input_ref}
+                type="text"
+                placeholder="搜索功能、工单、物料、批次..."
+                autocomplete="off"
+                value={(*keyword).clone()}
+                oninput={on_input}
+                onkeydown={on_keydown}
+                onfocus={on_focus}
+                onblur={on_blur}
+            />
+            if *is_open {
+                <ul class="search-results">
+                    { for hits.iter().enumerate().map(|(index, hit)| {
+                        let onmousedown = {
+                            let select = select.clone();
+                            let hit = hit.clone();
+                            // 用 mousedown 选中，避免输入框先失去焦点关闭列表
+                            Callback::from(move |e: MouseEvent| {
+                                e.prevent_default();
+                                select(hit.clone());
+                            })
+                        };
+                        html! {
+                            <li class={classes!("search-result", (index == *active).then_some("active"))}
+                                onmousedown={onmousedown}>
+                                <span class="search-result-kind">{hit.target.label()}</span>
+                                <div class="search-result-text">
+                                    <div class="search-result-title">{&hit.title}</div>
+                                    if let Some(subtitle) = hit.subtitle.clone() {
+                                        <div class="search-result-subtitle">{subtitle}</div>
+                                    }
+                                </div>
+                            </li>
+                        }
+                    })}
+                    if let Some(message) = footer {
+                        <li class="search-results-footer">{message}</li>
+                    }
+                </ul>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,131 @@
+use yew::prelude::*;
+use mes_win_shared::quality::spc::ChartSeries;
+
+const WIDTH: f64 = 860.0;
+const HEIGHT: f64 = 240.0;
+const MARGIN_LEFT: f64 = 64.0;
+const MARGIN_RIGHT: f64 = 96.0;
+const MARGIN_TOP: f64 = 14.0;
+const MARGIN_BOTTOM: f64 = 30.0;
+/// 横轴最多显示的样本标签数
+const MAX_AXIS_LABELS: usize = 10;
+
+/// 控制图数值显示，最多保留4位小数
+pub fn format_value(value: f64) -> String {
+    let text = format!("{:.4}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+// 控制图属性
+#[derive(Properties, PartialEq)]
+pub struct ControlChartSvgProps {
+    pub series: ChartSeries,
+    pub labels: Vec<String>,
+    /// 判异点序号
+    pub flagged: Vec<usize>,
+    /// 绘制±1σ、±2σ分区线
+    #[prop_or_default]
+    pub zones: bool,
+}
+
+// 单条控制图（SVG），控制限按逐点绘制以支持p图的变动控制限
+#[function_component(ControlChartSvg)]
+pub fn control_chart_svg(props: &ControlChartSvgProps) -> Html {
+    let series = &props.series;
+    let count = series.points.len();
+    if count == 0 {
+        return html! {};
+    }
+
+    let (mut min, mut max) = series
+        .points
+        .iter()
+        .chain(series.limits.iter().flat_map(|l| [&l.upper, &l.lower]))
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| (min.min(*v), max.max(*v)));
+    if (max - min).abs() < f64::EPSILON {
+        min -= 1.0;
+        max += 1.0;
+    }
+    let padding = (max - min) * 0.08;
+    let (min, max) = (min - padding, max + padding);
+
+    let plot_width = WIDTH - MARGIN_LEFT - MARGIN_RIGHT;
+    let plot_height = HEIGHT - MARGIN_TOP - MARGIN_BOTTOM;
+    let x = |index: usize| MARGIN_LEFT + plot_width * index as f64 / (count.max(2) - 1) as f64;
+    let y = |value: f64| MARGIN_TOP + plot_height * (max - value) / (max - min);
+
+    let polyline = |value: &dyn Fn(usize) -> f64| -> String {
+        (0..count)
+            .map(|i| format!("{:.1},{:.1}", x(i), y(value(i))))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let limit_lines = [
+        ("spc-limit", polyline(&|i| series.limits[i].upper)),
+        ("spc-center", polyline(&|i| series.limits[i].center)),
+        ("spc-limit", polyline(&|i| series.limits[i].lower)),
+    ];
+    let zone_lines: Vec<String> = if props.zones {
+        [-2.0, -1.0, 1.0, 2.0]
+            .into_iter()
+            .map(|k| {
+                polyline(&|i| {
+                    let limits = &series.limits[i];
+                    limits.center + k * (limits.upper - limits.center) / 3.0
+                })
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let last = series.limits[count - 1];
+    let side_labels = [
+        (format!("UCL {}", format_value(last.upper)), last.upper),
+        (format!("CL {}", format_value(last.center)), last.center),
+        (format!("LCL {}", format_value(last.lower)), last.lower),
+    ];
+    let label_step = count.div_ceil(MAX_AXIS_LABELS).max(1);
+
+    html! {
+        <svg class="spc-chart" viewBox={format!("0 0 {} {}", WIDTH, HEIGHT)} preserveAspectRatio="xMidYMid meet">
+            <text class="spc-title" x="4" y={MARGIN_TOP.to_string()}>{&series.title}</text>
+            <line class="spc-axis" x1={MARGIN_LEFT.to_string()} y1={MARGIN_TOP.to_string()}
+                x2={MARGIN_LEFT.to_string()} y2={(HEIGHT - MARGIN_BOTTOM).to_string()} />
+            <line class="spc-axis" x1={MARGIN_LEFT.to_string()} y1={(HEIGHT - MARGIN_BOTTOM).to_string()}
+                x2={(WIDTH - MARGIN_RIGHT).to_string()} y2={(HEIGHT - MARGIN_BOTTOM).to_string()} />
+
+            { for zone_lines.into_iter().map(|points| html! { <polyline class="spc-zone" {points} /> }) }
+            { for limit_lines.into_iter().map(|(class, points)| html! { <polyline {class} {points} /> }) }
+            { for side_labels.into_iter().map(|(text, value)| html! {
+                <text class="spc-limit-label" x={(WIDTH - MARGIN_RIGHT + 6.0).to_string()} y={format!("{:.1}", y(value) + 4.0)}>
+                    {text}
+                </text>
+            })}
+
+            <polyline class="spc-line" points={polyline(&|i| series.points[i])} />
+            { for series.points.iter().enumerate().map(|(index, value)| {
+                let class = if props.flagged.contains(&index) { "spc-point violation" } else { "spc-point" };
+                let label = props.labels.get(index).cloned().unwrap_or_default();
+                html! {
+                    <circle {class} cx={format!("{:.1}", x(index))} cy={format!("{:.1}", y(*value))} r="3.5">
+                        <title>{format!("{}：{}", label, format_value(*value))}</title>
+                    </circle>
+                }
+            })}
+
+            { for (0..count).step_by(label_step).map(|index| html! {
+                <text class="spc-axis-label" x={format!("{:.1}", x(index))} y={(HEIGHT - 10.0).to_string()}>
+                    {props.labels.get(index).cloned().unwrap_or_default()}
+                </text>
+            })}
+            <text class="spc-axis-label" x={(MARGIN_LEFT - 6.0).to_string()} y={format!("{:.1}", y(max) + 10.0)} text-anchor="end">
+                {format_value(max)}
+            </text>
+            <text class="spc-axis-label" x={(MARGIN_LEFT - 6.0).to_string()} y={format!("{:.1}", y(min))} text-anchor="end">
+                {format_value(min)}
+            </text>
+        </svg>
+    }
+}
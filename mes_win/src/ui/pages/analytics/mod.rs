@@ -0,0 +1,309 @@
+// 数据分析页面
+//
+// 统计过程控制（SPC）：按物料和检验特性取最近的检验数据，
+// 在前端计算控制图、判异和过程能力，切换图形和参数无需重新查询
+mod chart;
+
+use yew::prelude::*;
+use mes_win_shared::quality::inspection::CharacteristicKind;
+use mes_win_shared::quality::spc::{self, ChartType, RuleSet, SpcDataset, SpcQuery};
+use crate::services::tauri;
+use crate::utils::format::format_datetime;
+use chart::{format_value, ControlChartSvg};
+
+/// 默认子组容量
+const DEFAULT_SUBGROUP_SIZE: usize = 5;
+
+#[function_component(AnalyticsPage)]
+pub fn analytics_page() -> Html {
+    let query = use_state(|| None::<SpcQuery>);
+    let item_code = use_state(String::new);
+    let dataset = use_state(|| None::<SpcDataset>);
+    let chart_type = use_state(|| ChartType::XbarR);
+    let subgroup_size = use_state(|| DEFAULT_SUBGROUP_SIZE);
+    let rule_set = use_state(|| RuleSet::WesternElectric);
+    let is_loading = use_state(|| false);
+    let error_message = use_state(|| None::<String>);
+
+    {
+        let dataset = dataset.clone();
+        let chart_type = chart_type.clone();
+        let is_loading = is_loading.clone();
+        let error_message = error_message.clone();
+        use_effect_with((*query).clone(), move |query| {
+            if let Some(query) = query.clone() {
+                is_loading.set(true);
+                wasm_bindgen_futures::spawn_local(async move {
+                    match tauri::get_spc_dataset(query).await {
+                        Ok(data) => {
+                            // 计数型特性只能使用p/np图
+                            let numeric = matches!(
+                                data.characteristic.as_ref().map(|c| &c.kind),
+                                Some(CharacteristicKind::Numeric { .. })
+                            );
+                            if !numeric && chart_type.is_variable() {
+                                chart_type.set(ChartType::P);
+                            }
+                            dataset.set(Some(data));
+                            error_message.set(None);
+                        }
+                        Err(error) => {
+                            dataset.set(None);
+                            error_message.set(Some(error.user_message()));
+                        }
+                    }
+                    is_loading.set(false);
+                });
+            }
+            || ()
+        });
+    }
+
+    let on_item_input = {
+        let item_code = item_code.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            item_code.set(input.value());
+        })
+    };
+
+    let on_search = {
+        let item_code = item_code.clone();
+        let query = query.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            query.set(Some(SpcQuery {
+                item_code: (*item_code).trim().to_string(),
+                ..SpcQuery::default()
+            }));
+        })
+    };
+
+    let on_characteristic_change = {
+        let query = query.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Some(current) = (*query).clone() {
+                query.set(Some(SpcQuery {
+                    characteristic: Some(select.value()),
+                    ..current
+                }));
+            }
+        })
+    };
+
+    let on_chart_change = {
+        let chart_type = chart_type.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Some(next) = ChartType::from_code(&select.value()) {
+                chart_type.set(next);
+            }
+        })
+    };
+
+    let on_subgroup_input = {
+        let subgroup_size = subgroup_size.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Ok(size) = input.value().parse::<usize>() {
+                subgroup_size.set(size);
+            }
+        })
+    };
+
+    let on_rules_change = {
+        let rule_set = rule_set.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if let Some(next) = RuleSet::from_code(&select.value()) {
+                rule_set.set(next);
+            }
+        })
+    };
+
+    let content = match (*dataset).clone() {
+        None => html! {
+            <div class="table-empty">
+                { if *is_loading { "加载中..." } else { "输入物料/产品编码查询检验数据" } }
+            </div>
+        },
+        Some(data) => render_analysis(&data, *chart_type, *subgroup_size, *rule_set, on_characteristic_change),
+    };
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"仪表板 - 分析"}</h2>
+                <p>{"统计过程控制：控制图、判异规则与过程能力分析"}</p>
+            </div>
+
+            <div class="data-view">
+                <div class="toolbar">
+                    <form class="toolbar-search" onsubmit={on_search}>
+                        <input class="form-input" type="text" placeholder="物料 / 产品编码"
+                            value={(*item_code).clone()} oninput={on_item_input} />
+                        <button class="btn" type="submit" disabled={*is_loading}>{"查询"}</button>
+                    </form>
+                    <div class="toolbar-actions">
+                        <select class="form-input" onchange={on_chart_change}>
+                            { for ChartType::ALL.iter().map(|chart| html! {
+                                <option value={chart.code()} selected={*chart_type == *chart}>{chart.label()}</option>
+                            })}
+                        </select>
+                        if chart_type.uses_subgroups() {
+                            <input class="form-input spc-subgroup" type="number" min="2"
+                                max={spc::MAX_SUBGROUP_SIZE.to_string()} step="1" title="子组容量"
+                                value={subgroup_size.to_string()} oninput={on_subgroup_input} />
+                        }
+                        <select class="form-input" onchange={on_rules_change}>
+                            { for RuleSet::ALL.iter().map(|rules| html! {
+                                <option value={rules.code()} selected={*rule_set == *rules}>{rules.label()}</option>
+                            })}
+                        </select>
+                    </div>
+                </div>
+
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+
+                { content }
+            </div>
+        </div>
+    }
+}
+
+/// 数据集的控制图、过程能力和判异明细
+fn render_analysis(
+    data: &SpcDataset,
+    chart_type: ChartType,
+    subgroup_size: usize,
+    rule_set: RuleSet,
+    on_characteristic_change: Callback<Event>,
+) -> Html {
+    let selected = data.characteristic.as_ref().map(|c| c.code.clone()).unwrap_or_default();
+    let header = html! {
+        <div class="detail-title">
+            <h3>{format!("{} {}", data.item_code, data.item_name)}</h3>
+            <select class="form-input" onchange={on_characteristic_change}>
+                { for data.characteristics.iter().map(|c| html! {
+                    <option value={c.code.clone()} selected={selected == c.code}>
+                        {format!("{} {}（{}）", c.code, c.name, c.specification())}
+                    </option>
+                })}
+            </select>
+            <span class="spc-meta">
+                {format!(
+                    "{} 批 · {} ~ {}",
+                    data.samples.len(),
+                    data.samples.first().map(|s| format_datetime(&s.inspected_at)).unwrap_or_default(),
+                    data.samples.last().map(|s| format_datetime(&s.inspected_at)).unwrap_or_default(),
+                )}
+            </span>
+        </div>
+    };
+
+    let is_numeric = matches!(
+        data.characteristic.as_ref().map(|c| &c.kind),
+        Some(CharacteristicKind::Numeric { .. })
+    );
+    if chart_type.is_variable() && !is_numeric {
+        return html! {
+            <div class="detail-card">
+                { header }
+                <div class="table-empty">{"该特性为计数型，请选择 p 图或 np 图"}</div>
+            </div>
+        };
+    }
+
+    let chart = match spc::build_chart(chart_type, &data.samples, subgroup_size, data.spec_limits(), rule_set) {
+        Ok(chart) => chart,
+        Err(message) => {
+            return html! {
+                <div class="detail-card">
+                    { header }
+                    <div class="table-empty">{message}</div>
+                </div>
+            };
+        }
+    };
+
+    let flagged = |secondary: bool| -> Vec<usize> {
+        chart
+            .violations
+            .iter()
+            .filter(|v| v.secondary == secondary)
+            .map(|v| v.index)
+            .collect()
+    };
+    let field = |label: &str, value: String| html! {
+        <div class="detail-field">
+            <div class="detail-label">{label.to_string()}</div>
+            <div class="detail-value">{value}</div>
+        </div>
+    };
+    let optional = |value: Option<f64>| value.map(format_value).unwrap_or_else(|| "-".to_string());
+    let (lower, upper) = data.spec_limits();
+
+    html! {
+        <>
+            <div class="detail-card">
+                { header }
+                <ControlChartSvg series={chart.primary.clone()} labels={chart.labels.clone()}
+                    flagged={flagged(false)} zones={true} />
+                if let Some(secondary) = chart.secondary.clone() {
+                    <ControlChartSvg series={secondary} labels={chart.labels.clone()} flagged={flagged(true)} />
+                }
+            </div>
+
+            if let Some(capability) = chart.capability {
+                <div class="detail-card">
+                    <div class="detail-title"><h3>{"过程能力"}</h3></div>
+                    <div class="detail-grid">
+                        { field("规格下限", optional(lower)) }
+                        { field("规格上限", optional(upper)) }
+                        { field("过程均值", format_value(capability.mean)) }
+                        { field("组内标准差 σ", format_value(capability.sigma)) }
+                        { field("Cp", optional(capability.cp)) }
+                        { field("Cpk", optional(capability.cpk)) }
+                        { field("能力评价", capability.rating().to_string()) }
+                    </div>
+                </div>
+            }
+
+            <div class="table-container">
+                <table class="data-table">
+                    <thead>
+                        <tr>
+                            <th>{"样本"}</th>
+                            <th>{"控制图"}</th>
+                            <th class="numeric">{"数值"}</th>
+                            <th>{"判异规则"}</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { for chart.violations.iter().map(|violation| {
+                            let series = if violation.secondary {
+                                chart.secondary.as_ref().unwrap_or(&chart.primary)
+                            } else {
+                                &chart.primary
+                            };
+                            html! {
+                                <tr>
+                                    <td>{chart.labels.get(violation.index).cloned().unwrap_or_default()}</td>
+                                    <td>{&series.title}</td>
+                                    <td class="numeric">{format_value(series.points[violation.index])}</td>
+                                    <td>{violation.rule.description()}</td>
+                                </tr>
+                            }
+                        })}
+                    </tbody>
+                </table>
+                if chart.violations.is_empty() {
+                    <div class="table-empty">{format!("按{}未发现异常，过程处于统计受控状态", rule_set.label())}</div>
+                }
+            </div>
+        </>
+    }
+}
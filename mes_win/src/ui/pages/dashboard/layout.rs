@@ -0,0 +1,170 @@
+use yew::prelude::*;
+use mes_win_shared::dashboard::{DashboardLayout, KpiCard, MAX_REFRESH_SECONDS, MIN_REFRESH_SECONDS};
+use mes_win_shared::error::FieldError;
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+use crate::ui::components::modal::Modal;
+
+// 自定义卡片对话框属性
+#[derive(Properties, PartialEq)]
+pub struct LayoutDialogProps {
+    pub layout: DashboardLayout,
+    pub on_close: Callback<()>,
+    pub on_saved: Callback<DashboardLayout>,
+}
+
+// 自定义卡片：选择显示的卡片、调整顺序和刷新间隔
+#[function_component(LayoutDialog)]
+pub fn layout_dialog(props: &LayoutDialogProps) -> Html {
+    // 已显示的卡片在前（保持顺序），未显示的排在后面
+    let items = use_state(|| {
+        let mut items: Vec<(KpiCard, bool)> = props.layout.cards.iter().map(|card| (*card, true)).collect();
+        items.extend(
+            KpiCard::ALL
+                .iter()
+                .filter(|card| !props.layout.cards.contains(card))
+                .map(|card| (*card, false)),
+        );
+        items
+    });
+    let refresh_seconds = use_state(|| props.layout.refresh_seconds);
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+
+    let on_toggle = {
+        let items = items.clone();
+        Callback::from(move |index: usize| {
+            let mut next = (*items).clone();
+            next[index].1 = !next[index].1;
+            items.set(next);
+        })
+    };
+
+    // 与相邻项交换位置，offset 为 -1 上移、1 下移
+    let on_move = {
+        let items = items.clone();
+        Callback::from(move |(index, offset): (usize, isize)| {
+            let target = index as isize + offset;
+            if target < 0 || target as usize >= items.len() {
+                return;
+            }
+            let mut next = (*items).clone();
+            next.swap(index, target as usize);
+            items.set(next);
+        })
+    };
+
+    let on_refresh_input = {
+        let refresh_seconds = refresh_seconds.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            refresh_seconds.set(input.value().parse().unwrap_or(0));
+        })
+    };
+
+    let on_reset = {
+        let items = items.clone();
+        let refresh_seconds = refresh_seconds.clone();
+        Callback::from(move |_: MouseEvent| {
+            let layout = DashboardLayout::default();
+            items.set(layout.cards.iter().map(|card| (*card, true)).collect());
+            refresh_seconds.set(layout.refresh_seconds);
+        })
+    };
+
+    let on_submit = {
+        let items = items.clone();
+        let refresh_seconds = refresh_seconds.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let on_saved = props.on_saved.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let layout = DashboardLayout {
+                cards: items.iter().filter(|(_, visible)| *visible).map(|(card, _)| *card).collect(),
+                refresh_seconds: *refresh_seconds,
+            };
+            let local_errors = layout.validate();
+            if !local_errors.is_empty() {
+                errors.set(local_errors);
+                return;
+            }
+
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            let on_saved = on_saved.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::save_dashboard_layout(layout).await;
+                is_submitting.set(false);
+                match result {
+                    Ok(saved) => on_saved.emit(saved),
+                    Err(ApiError::Validation { message, fields }) => {
+                        errors.set(fields);
+                        error_message.set(Some(message));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_cancel = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| on_close.emit(()))
+    };
+
+    let count = items.len();
+
+    html! {
+        <Modal title="自定义卡片" on_close={props.on_close.clone()}>
+            <form class="data-view" onsubmit={on_submit}>
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+                <ul class="layout-list">
+                    { for items.iter().enumerate().map(|(index, (card, visible))| {
+                        let on_toggle = on_toggle.clone();
+                        let on_up = on_move.reform(move |_: MouseEvent| (index, -1));
+                        let on_down = on_move.reform(move |_: MouseEvent| (index, 1));
+                        html! {
+                            <li class="layout-item">
+                                <label>
+                                    <input type="checkbox" checked={*visible}
+                                        onchange={Callback::from(move |_: Event| on_toggle.emit(index))} />
+                                    {card.label()}
+                                </label>
+                                <div class="row-actions">
+                                    <button class="btn" type="button" disabled={index == 0} onclick={on_up}>{"上移"}</button>
+                                    <button class="btn" type="button" disabled={index + 1 == count} onclick={on_down}>{"下移"}</button>
+                                </div>
+                            </li>
+                        }
+                    })}
+                </ul>
+                { field_error(&errors, "cards") }
+                <div class="form-grid">
+                    <div class="form-field">
+                        <label class="required">{"自动刷新间隔（秒）"}</label>
+                        <input class="form-input" type="number" step="1"
+                            min={MIN_REFRESH_SECONDS.to_string()} max={MAX_REFRESH_SECONDS.to_string()}
+                            value={refresh_seconds.to_string()} oninput={on_refresh_input} />
+                        { field_error(&errors, "refreshSeconds") }
+                    </div>
+                </div>
+                <div class="form-actions">
+                    <button class="btn" type="button" onclick={on_reset}>{"恢复默认"}</button>
+                    <button class="btn" type="button" onclick={on_cancel}>{"取消"}</button>
+                    <button class="btn btn-primary" type="submit" disabled={*is_submitting}>
+                        { if *is_submitting { "保存中..." } else { "保存" } }
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
@@ -0,0 +1,249 @@
+// 仪表板概览页面
+//
+// 按当前用户保存的布局显示指标卡片，并按设定的间隔自动刷新
+mod layout;
+
+use gloo_timers::callback::Interval;
+use yew::prelude::*;
+use mes_win_shared::dashboard::{DashboardKpis, DashboardLayout, KpiCard};
+use crate::services::tauri;
+use crate::utils::format::{format_datetime, format_quantity};
+use layout::LayoutDialog;
+
+/// 比率显示为百分比
+fn percent(value: f64) -> String {
+    format!("{:.1}%", value * 100.0)
+}
+
+/// 卡片图标
+fn card_icon(category: &str) -> Html {
+    match category {
+        "inventory" => html! {
+            <svg width="32" height="32" viewBox="0 0 24 24" fill="none">
+                <path d="M21 16V8a2 2 0 0 0-1-1.73L12 2L4 6.27A2 2 0 0 0 3 8v8a2 2 0 0 0 1 1.73L12 22l8-4.27A2 2 0 0 0 21 16z" stroke="currentColor" stroke-width="2"/>
+            </svg>
+        },
+        "quality" => html! {
+            <svg width="32" height="32" viewBox="0 0 24 24" fill="none">
+                <path d="M12 2L4 5v6c0 5 3.4 9.3 8 11c4.6-1.7 8-6 8-11V5l-8-3z" stroke="currentColor" stroke-width="2"/>
+                <path d="M9 12l2 2l4-4" stroke="currentColor" stroke-width="2"/>
+            </svg>
+        },
+        _ => html! {
+            <svg width="32" height="32" viewBox="0 0 24 24" fill="none">
+                <rect x="3" y="3" width="18" height="18" rx="2" stroke="currentColor" stroke-width="2"/>
+                <path d="M9 9H15V15H9V9Z" stroke="currentColor" stroke-width="2"/>
+            </svg>
+        },
+    }
+}
+
+/// 卡片的数值和说明，数值为空时显示 "-"
+fn card_content(card: KpiCard, kpis: &DashboardKpis) -> (String, String, bool) {
+    match card {
+        KpiCard::ActiveOrders => (
+            kpis.active_orders.to_string(),
+            format!("生产中工单，另有 {} 单待开工", kpis.released_orders),
+            false,
+        ),
+        KpiCard::OutputVsPlan => match kpis.output_attainment() {
+            Some(attainment) => (
+                percent(attainment),
+                format!("今日完工 {} / 计划 {}", format_quantity(kpis.actual_output), format_quantity(kpis.planned_output)),
+                attainment < 1.0,
+            ),
+            None => ("-".to_string(), "今日无计划产量".to_string(), false),
+        },
+        KpiCard::Oee => match kpis.oee {
+            Some(factors) => (
+                percent(factors.oee()),
+                format!(
+                    "开动率 {} · 性能 {} · 合格 {}",
+                    percent(factors.availability),
+                    percent(factors.performance),
+                    percent(factors.quality)
+                ),
+                false,
+            ),
+            None => ("-".to_string(), "暂无设备数据".to_string(), false),
+        },
+        KpiCard::FirstPassYield => match kpis.first_pass_yield {
+            Some(yield_rate) => (percent(yield_rate), "今日检验批一次检验合格比例".to_string(), false),
+            None => ("-".to_string(), "今日暂无检验".to_string(), false),
+        },
+        KpiCard::OpenNcrs => (
+            kpis.open_ncrs.to_string(),
+            if kpis.critical_ncrs > 0 {
+                format!("未关闭不合格报告，其中致命 {} 项", kpis.critical_ncrs)
+            } else {
+                "未关闭不合格报告".to_string()
+            },
+            kpis.critical_ncrs > 0,
+        ),
+        KpiCard::LowStock => match kpis.low_stock.len() {
+            0 => ("正常".to_string(), "所有物料充足".to_string(), false),
+            count => (
+                count.to_string(),
+                format!(
+                    "低于安全库存：{}",
+                    kpis.low_stock
+                        .iter()
+                        .take(3)
+                        .map(|item| format!("{} {}{}", item.material_name, format_quantity(item.on_hand), item.unit))
+                        .collect::<Vec<_>>()
+                        .join("、")
+                ),
+                true,
+            ),
+        },
+    }
+}
+
+#[function_component(OverviewPage)]
+pub fn overview_page() -> Html {
+    let layout = use_state(|| None::<DashboardLayout>);
+    let kpis = use_state(|| None::<DashboardKpis>);
+    let show_dialog = use_state(|| false);
+    let is_loading = use_state(|| false);
+    let error_message = use_state(|| None::<String>);
+
+    {
+        let layout = layout.clone();
+        let error_message = error_message.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::get_dashboard_layout().await {
+                    Ok(data) => layout.set(Some(data)),
+                    Err(error) => {
+                        layout.set(Some(DashboardLayout::default()));
+                        error_message.set(Some(error.user_message()));
+                    }
+                }
+            });
+            || ()
+        });
+    }
+
+    // 拉取指标；定时器只持有该回调，不依赖渲染时的状态值
+    let load = {
+        let kpis = kpis.clone();
+        let is_loading = is_loading.clone();
+        let error_message = error_message.clone();
+        Callback::from(move |_: ()| {
+            let kpis = kpis.clone();
+            let is_loading = is_loading.clone();
+            let error_message = error_message.clone();
+            is_loading.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::get_dashboard_kpis().await {
+                    Ok(data) => {
+                        kpis.set(Some(data));
+                        error_message.set(None);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+                is_loading.set(false);
+            });
+        })
+    };
+
+    {
+        let load = load.clone();
+        use_effect_with((), move |_| {
+            load.emit(());
+            || ()
+        });
+    }
+
+    // 自动刷新，间隔变化或离开页面时取消定时器
+    {
+        let load = load.clone();
+        let seconds = layout.as_ref().map(|layout| layout.refresh_seconds);
+        use_effect_with(seconds, move |seconds| {
+            let interval = seconds.map(|seconds| Interval::new(seconds * 1000, move || load.emit(())));
+            move || drop(interval)
+        });
+    }
+
+    let on_refresh = Callback::from(move |_: MouseEvent| load.emit(()));
+
+    let on_customize = {
+        let show_dialog = show_dialog.clone();
+        Callback::from(move |_: MouseEvent| show_dialog.set(true))
+    };
+
+    let on_close_dialog = {
+        let show_dialog = show_dialog.clone();
+        Callback::from(move |_: ()| show_dialog.set(false))
+    };
+
+    let on_saved = {
+        let layout = layout.clone();
+        let show_dialog = show_dialog.clone();
+        Callback::from(move |saved: DashboardLayout| {
+            layout.set(Some(saved));
+            show_dialog.set(false);
+        })
+    };
+
+    let cards = layout.as_ref().map(|layout| layout.cards.clone()).unwrap_or_default();
+    let refreshed_at = kpis
+        .as_ref()
+        .map(|kpis| format_datetime(&kpis.refreshed_at))
+        .filter(|text| !text.is_empty());
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"仪表板 - 概览"}</h2>
+                <p>{"系统概览和关键指标"}</p>
+            </div>
+
+            <div class="toolbar">
+                <span class="dashboard-meta">
+                    { match (&refreshed_at, layout.as_ref()) {
+                        (Some(time), Some(layout)) => format!("数据时间 {} · 每 {} 秒自动刷新", time, layout.refresh_seconds),
+                        _ => String::new(),
+                    }}
+                </span>
+                <div class="toolbar-actions">
+                    <button class="btn" onclick={on_refresh} disabled={*is_loading}>
+                        { if *is_loading { "刷新中..." } else { "刷新" } }
+                    </button>
+                    <button class="btn" onclick={on_customize} disabled={layout.is_none()}>{"自定义卡片"}</button>
+                </div>
+            </div>
+
+            if let Some(message) = (*error_message).clone() {
+                <div class="error-banner">{message}</div>
+            }
+
+            <div class="dashboard-grid">
+                { for cards.iter().map(|card| {
+                    let (value, description, warning) = kpis
+                        .as_ref()
+                        .map(|kpis| card_content(*card, kpis))
+                        .unwrap_or_else(|| ("-".to_string(), "加载中...".to_string(), false));
+                    html! {
+                        <div class="dashboard-card">
+                            <div class={classes!("card-icon", card.category())}>
+                                { card_icon(card.category()) }
+                            </div>
+                            <div class="card-content">
+                                <h3>{card.label()}</h3>
+                                <div class={classes!("card-value", warning.then_some("warning"))}>{value}</div>
+                                <div class="card-description">{description}</div>
+                            </div>
+                        </div>
+                    }
+                })}
+            </div>
+
+            if *show_dialog {
+                if let Some(current) = (*layout).clone() {
+                    <LayoutDialog layout={current} on_close={on_close_dialog} on_saved={on_saved} />
+                }
+            }
+        </div>
+    }
+}
@@ -0,0 +1,214 @@
+// 质量检验页面
+//
+// 检验批列表与检验记录录入两个视图，返回列表时保留查询条件
+mod open;
+mod record;
+
+use yew::prelude::*;
+use mes_win_shared::quality::inspection::{InspectionLot, InspectionLotQuery, InspectionStatus};
+use mes_win_shared::response::PageData;
+use crate::services::tauri;
+use crate::ui::components::pagination::Pagination;
+use crate::utils::format::{format_datetime, format_quantity};
+use open::OpenInspectionDialog;
+use record::InspectionRecordView;
+
+/// 检验结果标签
+pub fn inspection_status_badge(status: InspectionStatus) -> Html {
+    let class = format!("status-badge status-{}", status.css_class());
+    html! { <span class={class}>{status.label()}</span> }
+}
+
+#[function_component(InspectionPage)]
+pub fn inspection_page() -> Html {
+    let query = use_state(InspectionLotQuery::default);
+    let page_data = use_state(PageData::<InspectionLot>::default);
+    let keyword = use_state(String::new);
+    let opened = use_state(|| None::<u64>);
+    let show_dialog = use_state(|| false);
+    let reload = use_state(|| 0u32);
+    let is_loading = use_state(|| false);
+    let error_message = use_state(|| None::<String>);
+
+    {
+        let page_data = page_data.clone();
+        let is_loading = is_loading.clone();
+        let error_message = error_message.clone();
+        use_effect_with(((*query).clone(), *reload), move |(query, _)| {
+            let query = query.clone();
+            is_loading.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_inspection_lots(query).await {
+                    Ok(data) => {
+                        page_data.set(data);
+                        error_message.set(None);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+                is_loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    let on_keyword_input = {
+        let keyword = keyword.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            keyword.set(input.value());
+        })
+    };
+
+    let on_search = {
+        let keyword = keyword.clone();
+        let query = query.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let mut next = (*query).clone();
+            next.keyword = Some((*keyword).clone()).filter(|k| !k.trim().is_empty());
+            next.page = 1;
+            query.set(next);
+        })
+    };
+
+    let on_status_change = {
+        let query = query.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*query).clone();
+            next.status = InspectionStatus::from_code(&select.value());
+            next.page = 1;
+            query.set(next);
+        })
+    };
+
+    let on_page_change = {
+        let query = query.clone();
+        Callback::from(move |page: u32| {
+            let mut next = (*query).clone();
+            next.page = page;
+            query.set(next);
+        })
+    };
+
+    let on_back = {
+        let opened = opened.clone();
+        let reload = reload.clone();
+        Callback::from(move |_: ()| {
+            opened.set(None);
+            reload.set(*reload + 1);
+        })
+    };
+
+    // 新建检验批后直接进入录入
+    let on_created = {
+        let opened = opened.clone();
+        let show_dialog = show_dialog.clone();
+        Callback::from(move |id: u64| {
+            show_dialog.set(false);
+            opened.set(Some(id));
+        })
+    };
+
+    let on_close_dialog = {
+        let show_dialog = show_dialog.clone();
+        Callback::from(move |_: ()| show_dialog.set(false))
+    };
+
+    let on_create = {
+        let show_dialog = show_dialog.clone();
+        Callback::from(move |_: MouseEvent| show_dialog.set(true))
+    };
+
+    let selected_status = query.status.map(|status| status.code()).unwrap_or("");
+
+    let content = match *opened {
+        Some(id) => html! { <InspectionRecordView key={id} id={id} on_back={on_back} /> },
+        None => html! {
+            <div class="data-view">
+                <div class="toolbar">
+                    <form class="toolbar-search" onsubmit={on_search}>
+                        <input class="form-input" type="text" placeholder="检验单号 / 物料 / 来源"
+                            value={(*keyword).clone()} oninput={on_keyword_input} />
+                        <select class="form-input" onchange={on_status_change}>
+                            <option value="" selected={selected_status.is_empty()}>{"全部结果"}</option>
+                            { for InspectionStatus::ALL.iter().map(|status| html! {
+                                <option value={status.code()} selected={selected_status == status.code()}>
+                                    {status.label()}
+                                </option>
+                            })}
+                        </select>
+                        <button class="btn" type="submit">{"查询"}</button>
+                    </form>
+                    <button class="btn btn-primary" onclick={on_create}>{"新建检验批"}</button>
+                </div>
+
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+
+                <div class="table-container">
+                    <table class="data-table">
+                        <thead>
+                            <tr>
+                                <th>{"检验单号"}</th>
+                                <th>{"类型"}</th>
+                                <th>{"来源"}</th>
+                                <th>{"物料 / 产品"}</th>
+                                <th class="numeric">{"送检 / 样本"}</th>
+                                <th>{"检验员"}</th>
+                                <th>{"创建时间"}</th>
+                                <th>{"结果"}</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for page_data.items.iter().map(|lot| {
+                                let id = lot.id;
+                                let opened = opened.clone();
+                                html! {
+                                    <tr class="clickable" onclick={Callback::from(move |_: MouseEvent| opened.set(Some(id)))}>
+                                        <td>{&lot.inspection_no}</td>
+                                        <td>{lot.source.label()}</td>
+                                        <td>{&lot.source_ref}</td>
+                                        <td>{format!("{} {}", lot.item_code, lot.item_name)}</td>
+                                        <td class="numeric">{format!("{} / {}", format_quantity(lot.quantity), lot.sample_size)}</td>
+                                        <td>{lot.inspector.clone().unwrap_or_else(|| "-".to_string())}</td>
+                                        <td>{format_datetime(&lot.created_at)}</td>
+                                        <td>{inspection_status_badge(lot.status)}</td>
+                                    </tr>
+                                }
+                            })}
+                        </tbody>
+                    </table>
+                    if page_data.items.is_empty() {
+                        <div class="table-empty">
+                            { if *is_loading { "加载中..." } else { "暂无检验批" } }
+                        </div>
+                    }
+                </div>
+
+                <Pagination
+                    page={page_data.page}
+                    total_pages={page_data.total_pages()}
+                    total={page_data.total}
+                    on_change={on_page_change}
+                />
+            </div>
+        },
+    };
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"质量管理 - 检验"}</h2>
+                <p>{"来料与工序检验记录，按检验特性自动判定接收或拒收"}</p>
+            </div>
+
+            { content }
+
+            if *show_dialog {
+                <OpenInspectionDialog on_close={on_close_dialog} on_created={on_created} />
+            }
+        </div>
+    }
+}
@@ -0,0 +1,213 @@
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::production::work_order::{WorkOrder, WorkOrderQuery, WorkOrderStatus};
+use mes_win_shared::quality::inspection::{InspectionLotDraft, InspectionSource};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+use crate::ui::components::modal::Modal;
+
+// 新建检验批对话框属性
+#[derive(Properties, PartialEq)]
+pub struct OpenInspectionDialogProps {
+    pub on_close: Callback<()>,
+    /// 创建成功后返回检验批ID
+    pub on_created: Callback<u64>,
+}
+
+// 新建检验批：来料检验按原材料批次，工序检验按生产中工单的工序
+#[function_component(OpenInspectionDialog)]
+pub fn open_inspection_dialog(props: &OpenInspectionDialogProps) -> Html {
+    let draft = use_state(|| InspectionLotDraft {
+        source: InspectionSource::Receipt { lot_no: String::new() },
+        quantity: 0.0,
+    });
+    let orders = use_state(Vec::<WorkOrder>::new);
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+
+    {
+        let orders = orders.clone();
+        let error_message = error_message.clone();
+        use_effect_with((), move |_| {
+            let query = WorkOrderQuery {
+                page_size: 200,
+                status: Some(WorkOrderStatus::InProgress),
+                ..WorkOrderQuery::default()
+            };
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_work_orders(query).await {
+                    Ok(data) => orders.set(data.items),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_type_change = {
+        let draft = draft.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*draft).clone();
+            next.source = if select.value() == "operation" {
+                InspectionSource::Operation { work_order_id: 0, operation_seq: 0 }
+            } else {
+                InspectionSource::Receipt { lot_no: String::new() }
+            };
+            draft.set(next);
+        })
+    };
+
+    let on_lot_input = {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*draft).clone();
+            next.source = InspectionSource::Receipt { lot_no: input.value() };
+            draft.set(next);
+        })
+    };
+
+    let on_order_change = {
+        let draft = draft.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*draft).clone();
+            if let InspectionSource::Operation { work_order_id, .. } = &mut next.source {
+                *work_order_id = select.value().parse().unwrap_or(0);
+            }
+            draft.set(next);
+        })
+    };
+
+    let on_seq_input = {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*draft).clone();
+            if let InspectionSource::Operation { operation_seq, .. } = &mut next.source {
+                *operation_seq = input.value().parse().unwrap_or(0);
+            }
+            draft.set(next);
+        })
+    };
+
+    let on_quantity_input = {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*draft).clone();
+            next.quantity = input.value().parse().unwrap_or(0.0);
+            draft.set(next);
+        })
+    };
+
+    let on_submit = {
+        let draft = draft.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let on_created = props.on_created.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let local_errors = draft.validate();
+            if !local_errors.is_empty() {
+                errors.set(local_errors);
+                return;
+            }
+
+            let submitted = (*draft).clone();
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            let on_created = on_created.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::open_inspection_lot(submitted).await;
+                is_submitting.set(false);
+                match result {
+                    Ok(lot) => on_created.emit(lot.id),
+                    Err(ApiError::Validation { message, fields }) => {
+                        errors.set(fields);
+                        error_message.set(Some(message));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_cancel = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| on_close.emit(()))
+    };
+
+    let quantity = if draft.quantity > 0.0 { draft.quantity.to_string() } else { String::new() };
+    let is_operation = matches!(draft.source, InspectionSource::Operation { .. });
+
+    html! {
+        <Modal title="新建检验批" on_close={props.on_close.clone()}>
+            <form class="data-view" onsubmit={on_submit}>
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+                <div class="form-grid">
+                    <div class="form-field">
+                        <label class="required">{"检验类型"}</label>
+                        <select class="form-input" onchange={on_type_change}>
+                            <option value="receipt" selected={!is_operation}>{"来料检验"}</option>
+                            <option value="operation" selected={is_operation}>{"工序检验"}</option>
+                        </select>
+                    </div>
+                    <div class="form-field">
+                        <label class="required">{"送检数量"}</label>
+                        <input class="form-input" type="number" min="0" step="any" value={quantity}
+                            oninput={on_quantity_input} />
+                        { field_error(&errors, "quantity") }
+                    </div>
+                    { match &draft.source {
+                        InspectionSource::Receipt { lot_no } => html! {
+                            <div class="form-field form-field-wide">
+                                <label class="required">{"来料批次号"}</label>
+                                <input class="form-input" type="text" value={lot_no.clone()} oninput={on_lot_input} />
+                                { field_error(&errors, "lotNo") }
+                            </div>
+                        },
+                        InspectionSource::Operation { work_order_id, operation_seq } => html! {
+                            <>
+                                <div class="form-field">
+                                    <label class="required">{"工单"}</label>
+                                    <select class="form-input" onchange={on_order_change}>
+                                        <option value="0" selected={*work_order_id == 0}>{"请选择生产中的工单"}</option>
+                                        { for orders.iter().map(|order| html! {
+                                            <option value={order.id.to_string()} selected={*work_order_id == order.id}>
+                                                {format!("{} · {}", order.order_no, order.product_name)}
+                                            </option>
+                                        })}
+                                    </select>
+                                    { field_error(&errors, "workOrderId") }
+                                </div>
+                                <div class="form-field">
+                                    <label class="required">{"工序号"}</label>
+                                    <input class="form-input" type="number" min="1" step="1"
+                                        value={if *operation_seq > 0 { operation_seq.to_string() } else { String::new() }}
+                                        oninput={on_seq_input} />
+                                    { field_error(&errors, "operationSeq") }
+                                </div>
+                            </>
+                        },
+                    }}
+                </div>
+                <div class="form-actions">
+                    <button class="btn" type="button" onclick={on_cancel}>{"取消"}</button>
+                    <button class="btn btn-primary" type="submit" disabled={*is_submitting}>
+                        { if *is_submitting { "提交中..." } else { "开始检验" } }
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
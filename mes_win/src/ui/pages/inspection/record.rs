@@ -0,0 +1,413 @@
+use std::collections::HashMap;
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::quality::inspection::{
+    Characteristic, CharacteristicKind, InspectionLot, InspectionRecord, InspectionStatus,
+    MeasuredValue, Measurement,
+};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::utils::format::{format_datetime, format_quantity};
+use super::inspection_status_badge;
+
+// 检验记录属性
+#[derive(Properties, PartialEq)]
+pub struct InspectionRecordProps {
+    pub id: u64,
+    pub on_back: Callback<()>,
+}
+
+/// 单元格的字段名，与后端校验错误的字段名一致
+fn cell_key(code: &str, sample_no: u32) -> String {
+    format!("{}[{}]", code, sample_no)
+}
+
+/// 写入或清除某特性某样本的检验值
+fn set_value(lot: &mut InspectionLot, code: &str, sample_no: u32, value: Option<MeasuredValue>) {
+    lot.measurements
+        .retain(|m| !(m.characteristic_code == code && m.sample_no == sample_no));
+    if let Some(value) = value {
+        lot.measurements.push(Measurement {
+            characteristic_code: code.to_string(),
+            sample_no,
+            value,
+        });
+    }
+}
+
+/// 检验值的显示文字
+fn display_value(value: &MeasuredValue) -> String {
+    match value {
+        MeasuredValue::Numeric(v) => v.to_string(),
+        MeasuredValue::PassFail(true) => "合格".to_string(),
+        MeasuredValue::PassFail(false) => "不合格".to_string(),
+        MeasuredValue::Attribute(v) => v.clone(),
+    }
+}
+
+// 检验记录录入：行为检验特性，列为样本，超差值实时标红
+#[function_component(InspectionRecordView)]
+pub fn inspection_record_view(props: &InspectionRecordProps) -> Html {
+    let lot = use_state(|| None::<InspectionLot>);
+    // 计量值输入框的原始文本（允许输入过程中的中间状态，如 "1." 或 "-"）
+    let numeric_texts = use_state(HashMap::<String, String>::new);
+    let remark = use_state(String::new);
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+    let created_ncr = use_state(|| None::<String>);
+    // 离线提交的结果已保存到本机队列，同步前不再允许修改
+    let queued_notice = use_state(|| None::<String>);
+
+    {
+        let lot = lot.clone();
+        let numeric_texts = numeric_texts.clone();
+        let error_message = error_message.clone();
+        use_effect_with(props.id, move |id| {
+            let id = *id;
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::get_inspection_lot(id).await {
+                    Ok(data) => {
+                        let texts = data
+                            .measurements
+                            .iter()
+                            .filter_map(|m| match m.value {
+                                MeasuredValue::Numeric(v) => Some((cell_key(&m.characteristic_code, m.sample_no), v.to_string())),
+                                _ => None,
+                            })
+                            .collect();
+                        numeric_texts.set(texts);
+                        lot.set(Some(data));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_back = {
+        let on_back = props.on_back.clone();
+        Callback::from(move |_: MouseEvent| on_back.emit(()))
+    };
+
+    let Some(current) = (*lot).clone() else {
+        return html! {
+            <div class="data-view">
+                <div class="toolbar">
+                    <button class="btn" onclick={on_back}>{"返回列表"}</button>
+                </div>
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                } else {
+                    <div class="table-empty">{"加载中..."}</div>
+                }
+            </div>
+        };
+    };
+
+    let editable = current.status == InspectionStatus::Open && queued_notice.is_none();
+
+    // 拒收后登记不合格报告，进入质量问题跟踪
+    let on_create_ncr = {
+        let created_ncr = created_ncr.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let id = current.id;
+        Callback::from(move |_: MouseEvent| {
+            let created_ncr = created_ncr.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::create_ncr_from_inspection(id).await {
+                    Ok(detail) => {
+                        created_ncr.set(Some(detail.ncr.ncr_no));
+                        error_message.set(None);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+                is_submitting.set(false);
+            });
+        })
+    };
+
+    let on_remark_input = {
+        let remark = remark.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            remark.set(input.value());
+        })
+    };
+
+    let on_submit = {
+        let lot = lot.clone();
+        let remark = remark.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let queued_notice = queued_notice.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(current) = (*lot).clone() else {
+                return;
+            };
+            let local_errors = current.validate_measurements();
+            if !local_errors.is_empty() {
+                error_message.set(Some(local_errors[0].message.clone()));
+                errors.set(local_errors);
+                return;
+            }
+
+            let record = InspectionRecord {
+                measurements: current.measurements.clone(),
+                remark: Some(remark.trim().to_string()).filter(|r| !r.is_empty()),
+            };
+            let lot = lot.clone();
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            let queued_notice = queued_notice.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::submit_inspection(current.id, record).await;
+                is_submitting.set(false);
+                match result {
+                    Ok(data) => {
+                        lot.set(Some(data));
+                        errors.set(Vec::new());
+                        error_message.set(None);
+                    }
+                    Err(ApiError::Queued { message, .. }) => {
+                        errors.set(Vec::new());
+                        error_message.set(None);
+                        queued_notice.set(Some(message));
+                    }
+                    Err(ApiError::Validation { message, fields }) => {
+                        errors.set(fields);
+                        error_message.set(Some(message));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    // 单元格：可编辑时按特性类型显示输入控件
+    let render_cell = |characteristic: &Characteristic, sample_no: u32| -> Html {
+        let key = cell_key(&characteristic.code, sample_no);
+        let value = current.value_of(&characteristic.code, sample_no);
+        let text = numeric_texts.get(&key).cloned().unwrap_or_default();
+        // 计量值无法解析时同样视为超差
+        let out_of_tolerance = match value {
+            Some(value) => !characteristic.is_conforming(value),
+            None => !text.trim().is_empty(),
+        };
+        let has_error = errors.iter().any(|e| e.field == key);
+        let cell_class = classes!(out_of_tolerance.then_some("out-of-tolerance"));
+
+        if !editable {
+            return html! {
+                <td class={cell_class}>{value.map(display_value).unwrap_or_else(|| "-".to_string())}</td>
+            };
+        }
+
+        let input_class = classes!("form-input", has_error.then_some("input-error"));
+        let code = characteristic.code.clone();
+        match &characteristic.kind {
+            CharacteristicKind::Numeric { .. } => {
+                let lot = lot.clone();
+                let numeric_texts = numeric_texts.clone();
+                let oninput = Callback::from(move |e: InputEvent| {
+                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                    let raw = input.value();
+                    let parsed = raw.trim().parse::<f64>().ok().filter(|v| v.is_finite());
+                    let mut texts = (*numeric_texts).clone();
+                    texts.insert(cell_key(&code, sample_no), raw);
+                    numeric_texts.set(texts);
+                    if let Some(mut next) = (*lot).clone() {
+                        set_value(&mut next, &code, sample_no, parsed.map(MeasuredValue::Numeric));
+                        lot.set(Some(next));
+                    }
+                });
+                html! {
+                    <td class={cell_class}>
+                        <input class={input_class} type="text" inputmode="decimal" value={text} oninput={oninput} />
+                    </td>
+                }
+            }
+            CharacteristicKind::PassFail => {
+                let lot = lot.clone();
+                let onchange = Callback::from(move |e: Event| {
+                    let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                    let value = match select.value().as_str() {
+                        "pass" => Some(MeasuredValue::PassFail(true)),
+                        "fail" => Some(MeasuredValue::PassFail(false)),
+                        _ => None,
+                    };
+                    if let Some(mut next) = (*lot).clone() {
+                        set_value(&mut next, &code, sample_no, value);
+                        lot.set(Some(next));
+                    }
+                });
+                html! {
+                    <td class={cell_class}>
+                        <select class={input_class} onchange={onchange}>
+                            <option value="" selected={value.is_none()}>{"-"}</option>
+                            <option value="pass" selected={value == Some(&MeasuredValue::PassFail(true))}>{"合格"}</option>
+                            <option value="fail" selected={value == Some(&MeasuredValue::PassFail(false))}>{"不合格"}</option>
+                        </select>
+                    </td>
+                }
+            }
+            CharacteristicKind::Attribute { options, .. } => {
+                let lot = lot.clone();
+                let onchange = Callback::from(move |e: Event| {
+                    let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                    let value = Some(select.value()).filter(|v| !v.is_empty()).map(MeasuredValue::Attribute);
+                    if let Some(mut next) = (*lot).clone() {
+                        set_value(&mut next, &code, sample_no, value);
+                        lot.set(Some(next));
+                    }
+                });
+                let selected = match value {
+                    Some(MeasuredValue::Attribute(v)) => v.clone(),
+                    _ => String::new(),
+                };
+                html! {
+                    <td class={cell_class}>
+                        <select class={input_class} onchange={onchange}>
+                            <option value="" selected={selected.is_empty()}>{"-"}</option>
+                            { for options.iter().map(|option| html! {
+                                <option value={option.clone()} selected={selected == *option}>{option}</option>
+                            })}
+                        </select>
+                    </td>
+                }
+            }
+        }
+    };
+
+    let total_cells = current.characteristics.len() * current.sample_size as usize;
+    let verdict = match (editable, current.decide()) {
+        (false, _) => inspection_status_badge(current.status),
+        (true, Some(decision)) => html! {
+            <span class={format!("status-badge status-{}", decision.css_class())}>
+                {format!("预判：{}", decision.label())}
+            </span>
+        },
+        (true, None) => html! {
+            <span class="status-badge">{format!("已录入 {} / {}", current.measurements.len(), total_cells)}</span>
+        },
+    };
+
+    html! {
+        <div class="data-view">
+            <div class="toolbar">
+                <button class="btn" onclick={on_back}>{"返回列表"}</button>
+                if editable {
+                    <div class="toolbar-actions">
+                        <input class="form-input" type="text" placeholder="备注"
+                            value={(*remark).clone()} oninput={on_remark_input} />
+                        <button class="btn btn-primary" disabled={*is_submitting} onclick={on_submit}>
+                            { if *is_submitting { "提交中..." } else { "提交检验结果" } }
+                        </button>
+                    </div>
+                } else if current.status == InspectionStatus::Rejected {
+                    <div class="toolbar-actions">
+                        if let Some(ncr_no) = (*created_ncr).clone() {
+                            <span class="status-badge">{format!("已创建不合格报告 {}，可在“质量管理 - 问题”中跟踪", ncr_no)}</span>
+                        } else {
+                            <button class="btn btn-danger" disabled={*is_submitting} onclick={on_create_ncr}>
+                                {"创建质量问题"}
+                            </button>
+                        }
+                    </div>
+                }
+            </div>
+
+            if let Some(message) = (*error_message).clone() {
+                <div class="error-banner">{message}</div>
+            }
+            if let Some(message) = (*queued_notice).clone() {
+                <div class="notice-banner">{message}</div>
+            }
+
+            <div class="detail-card">
+                <div class="detail-title">
+                    <h3>{format!("检验批 {}", current.inspection_no)}</h3>
+                    { verdict }
+                </div>
+                <div class="detail-grid">
+                    <div class="detail-field">
+                        <div class="detail-label">{current.source.label()}</div>
+                        <div class="detail-value">{&current.source_ref}</div>
+                    </div>
+                    <div class="detail-field">
+                        <div class="detail-label">{"物料 / 产品"}</div>
+                        <div class="detail-value">{format!("{} {}", current.item_code, current.item_name)}</div>
+                    </div>
+                    <div class="detail-field">
+                        <div class="detail-label">{"送检数量 / 样本数"}</div>
+                        <div class="detail-value">{format!("{} / {}", format_quantity(current.quantity), current.sample_size)}</div>
+                    </div>
+                    <div class="detail-field">
+                        <div class="detail-label">{"检验标准版本"}</div>
+                        <div class="detail-value">
+                            {current.plan_version.map(|version| format!("第{}版", version)).unwrap_or_else(|| "-".to_string())}
+                        </div>
+                    </div>
+                    <div class="detail-field">
+                        <div class="detail-label">{"不合格品数 / Ac / Re"}</div>
+                        <div class="detail-value">
+                            {format!("{} / {} / {}", current.defective_count(), current.accept, current.reject)}
+                        </div>
+                    </div>
+                    <div class="detail-field">
+                        <div class="detail-label">{"检验员"}</div>
+                        <div class="detail-value">{current.inspector.clone().unwrap_or_else(|| "-".to_string())}</div>
+                    </div>
+                    <div class="detail-field">
+                        <div class="detail-label">{"判定时间"}</div>
+                        <div class="detail-value">
+                            {current.decided_at.as_deref().map(format_datetime).unwrap_or_else(|| "-".to_string())}
+                        </div>
+                    </div>
+                </div>
+                if let Some(remark) = &current.remark {
+                    <div class="detail-remark">{remark}</div>
+                }
+            </div>
+
+            <div class="table-container">
+                <table class="data-table inspection-table">
+                    <thead>
+                        <tr>
+                            <th>{"检验特性"}</th>
+                            <th>{"规格"}</th>
+                            { for (1..=current.sample_size).map(|sample_no| html! {
+                                <th>{format!("样本{}", sample_no)}</th>
+                            })}
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { for current.characteristics.iter().map(|characteristic| html! {
+                            <tr>
+                                <td>
+                                    {&characteristic.name}
+                                    if characteristic.critical {
+                                        <span class="status-badge status-rejected">{"关键"}</span>
+                                    }
+                                </td>
+                                <td>{characteristic.specification()}</td>
+                                { for (1..=current.sample_size).map(|sample_no| render_cell(characteristic, sample_no)) }
+                            </tr>
+                        })}
+                    </tbody>
+                </table>
+                if current.characteristics.is_empty() {
+                    <div class="table-empty">{"未配置检验特性"}</div>
+                }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,383 @@
+use yew::prelude::*;
+use mes_win_shared::quality::ncr::{
+    AttachmentUpload, AuditEntry, EightD, NcrDetail, NcrStatus, NcrUpdate, Severity, MAX_ATTACHMENT_BYTES,
+};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::utils::format::{format_datetime, format_quantity};
+use super::ncr_status_badge;
+
+// 不合格报告详情属性
+#[derive(Properties, PartialEq)]
+pub struct NcrDetailProps {
+    pub id: u64,
+    pub on_back: Callback<()>,
+}
+
+/// 附件大小显示
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1} MB", bytes as f64 / 1024.0 / 1024.0)
+    } else {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    }
+}
+
+/// 审计记录的说明文字
+fn describe_entry(entry: &AuditEntry) -> String {
+    match (entry.from_status, entry.to_status) {
+        (Some(from), Some(to)) => format!("{}：{} → {}", entry.action, from.label(), to.label()),
+        (None, Some(to)) => format!("{}：{}", entry.action, to.label()),
+        _ => entry.action.clone(),
+    }
+}
+
+/// 读取文件选择框中的第一个文件
+async fn read_file(input: web_sys::HtmlInputElement) -> Result<Option<AttachmentUpload>, String> {
+    let Some(file) = input.files().and_then(|files| files.get(0)) else {
+        return Ok(None);
+    };
+    if file.size() as usize > MAX_ATTACHMENT_BYTES {
+        return Err(format!("附件不能超过 {} MB", MAX_ATTACHMENT_BYTES / 1024 / 1024));
+    }
+
+    let buffer = wasm_bindgen_futures::JsFuture::from(file.array_buffer())
+        .await
+        .map_err(|_| format!("读取文件 {} 失败", file.name()))?;
+    Ok(Some(AttachmentUpload {
+        file_name: file.name(),
+        content_type: file.type_(),
+        content: js_sys::Uint8Array::new(&buffer).to_vec(),
+    }))
+}
+
+// 不合格报告详情：状态流转、8D报告编辑、附件和审计记录
+#[function_component(NcrDetailView)]
+pub fn ncr_detail_view(props: &NcrDetailProps) -> Html {
+    let detail = use_state(|| None::<NcrDetail>);
+    let update = use_state(NcrUpdate::default);
+    let error_message = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+
+    // 详情更新后同步表单内容
+    let apply_detail = {
+        let detail = detail.clone();
+        let update = update.clone();
+        let error_message = error_message.clone();
+        Callback::from(move |data: NcrDetail| {
+            update.set(NcrUpdate::from(&data.ncr));
+            detail.set(Some(data));
+            error_message.set(None);
+        })
+    };
+
+    {
+        let apply_detail = apply_detail.clone();
+        let error_message = error_message.clone();
+        use_effect_with(props.id, move |id| {
+            let id = *id;
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::get_ncr(id).await {
+                    Ok(data) => apply_detail.emit(data),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    let dirty = detail
+        .as_ref()
+        .is_some_and(|data| NcrUpdate::from(&data.ncr) != *update);
+
+    // 执行状态流转，可填写说明写入审计记录
+    let on_transition = {
+        let apply_detail = apply_detail.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let id = props.id;
+        Callback::from(move |target: NcrStatus| {
+            if dirty {
+                error_message.set(Some("8D报告有未保存的修改，请先保存".to_string()));
+                return;
+            }
+            let message = if target == NcrStatus::Closed {
+                "关闭后报告不可再修改。请输入关闭说明（可留空）："
+            } else {
+                "请输入处理说明（可留空）："
+            };
+            let Some(comment) = web_sys::window()
+                .and_then(|w| w.prompt_with_message_and_default(message, "").ok())
+                .flatten()
+            else {
+                return;
+            };
+
+            let apply_detail = apply_detail.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let comment = Some(comment).filter(|c| !c.trim().is_empty());
+                match tauri::transition_ncr(id, target, comment).await {
+                    Ok(data) => apply_detail.emit(data),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+                is_submitting.set(false);
+            });
+        })
+    };
+
+    let on_save = {
+        let update = update.clone();
+        let apply_detail = apply_detail.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let id = props.id;
+        Callback::from(move |_: MouseEvent| {
+            let submitted = (*update).clone();
+            let apply_detail = apply_detail.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::update_ncr(id, submitted).await {
+                    Ok(data) => apply_detail.emit(data),
+                    Err(ApiError::Validation { message, .. }) => error_message.set(Some(message)),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+                is_submitting.set(false);
+            });
+        })
+    };
+
+    let on_file_change = {
+        let apply_detail = apply_detail.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let id = props.id;
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let apply_detail = apply_detail.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match read_file(input.clone()).await {
+                    Ok(Some(attachment)) => match tauri::upload_ncr_attachment(id, attachment).await {
+                        Ok(data) => apply_detail.emit(data),
+                        Err(error) => error_message.set(Some(error.user_message())),
+                    },
+                    Ok(None) => {}
+                    Err(message) => error_message.set(Some(message)),
+                }
+                // 清空选择，允许重复上传同名文件
+                input.set_value("");
+                is_submitting.set(false);
+            });
+        })
+    };
+
+    let on_owner_input = {
+        let update = update.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*update).clone();
+            next.owner = Some(input.value()).filter(|v| !v.trim().is_empty());
+            update.set(next);
+        })
+    };
+
+    let on_severity_change = {
+        let update = update.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*update).clone();
+            next.severity = Severity::from_code(&select.value()).unwrap_or_default();
+            update.set(next);
+        })
+    };
+
+    let on_step_input = {
+        let update = update.clone();
+        Callback::from(move |(index, value): (usize, String)| {
+            let mut next = (*update).clone();
+            next.report.set_step(index, value);
+            update.set(next);
+        })
+    };
+
+    let on_back = {
+        let on_back = props.on_back.clone();
+        Callback::from(move |_: MouseEvent| on_back.emit(()))
+    };
+
+    let Some(data) = (*detail).clone() else {
+        return html! {
+            <div class="data-view">
+                <div class="toolbar">
+                    <button class="btn" onclick={on_back}>{"返回列表"}</button>
+                </div>
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                } else {
+                    <div class="table-empty">{"加载中..."}</div>
+                }
+            </div>
+        };
+    };
+
+    let ncr = &data.ncr;
+    let editable = data.editable && !*is_submitting;
+
+    let field = |label: &str, value: String| html! {
+        <div class="detail-field">
+            <div class="detail-label">{label.to_string()}</div>
+            <div class="detail-value">{value}</div>
+        </div>
+    };
+
+    html! {
+        <div class="data-view">
+            <div class="toolbar">
+                <button class="btn" onclick={on_back}>{"返回列表"}</button>
+                <div class="toolbar-actions">
+                    if data.editable {
+                        <button class="btn" disabled={!editable || !dirty} onclick={on_save}>
+                            { if *is_submitting { "保存中..." } else { "保存" } }
+                        </button>
+                    }
+                    { for data.transitions.iter().map(|target| {
+                        let target = *target;
+                        let on_transition = on_transition.clone();
+                        let class = match target {
+                            NcrStatus::Closed => "btn btn-danger",
+                            // 退回上一步
+                            _ if target < ncr.status => "btn",
+                            _ => "btn btn-primary",
+                        };
+                        html! {
+                            <button class={class} disabled={*is_submitting}
+                                onclick={Callback::from(move |_: MouseEvent| on_transition.emit(target))}>
+                                {target.action_label()}
+                            </button>
+                        }
+                    })}
+                </div>
+            </div>
+
+            if let Some(message) = (*error_message).clone() {
+                <div class="error-banner">{message}</div>
+            }
+
+            <div class="detail-card">
+                <div class="detail-title">
+                    <h3>{format!("{} {}", ncr.ncr_no, ncr.title)}</h3>
+                    {ncr_status_badge(ncr.status)}
+                </div>
+                <div class="detail-grid">
+                    { field("物料 / 产品", format!("{} {}", ncr.item_code, ncr.item_name)) }
+                    { field("不合格数量", format_quantity(ncr.quantity)) }
+                    { field("来源检验批", ncr.inspection_no.clone().unwrap_or_else(|| "-".to_string())) }
+                    { field("创建人", ncr.created_by.clone().unwrap_or_else(|| "-".to_string())) }
+                    { field("创建时间", format_datetime(&ncr.created_at)) }
+                    { field("更新时间", ncr.updated_at.as_deref().map(format_datetime).unwrap_or_default()) }
+                    <div class="detail-field">
+                        <div class="detail-label">{"责任人"}</div>
+                        <input class="form-input" type="text" disabled={!editable}
+                            value={update.owner.clone().unwrap_or_default()} oninput={on_owner_input} />
+                    </div>
+                    <div class="detail-field">
+                        <div class="detail-label">{"严重程度"}</div>
+                        <select class="form-input" disabled={!editable} onchange={on_severity_change}>
+                            { for Severity::ALL.iter().map(|severity| html! {
+                                <option value={severity.code()} selected={update.severity == *severity}>
+                                    {severity.label()}
+                                </option>
+                            })}
+                        </select>
+                    </div>
+                </div>
+            </div>
+
+            <div class="detail-card">
+                <div class="detail-title"><h3>{"8D报告"}</h3></div>
+                <div class="form-grid">
+                    { for update.report.steps().iter().enumerate().map(|(index, value)| {
+                        let on_step_input = on_step_input.clone();
+                        let oninput = Callback::from(move |e: InputEvent| {
+                            let input: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+                            on_step_input.emit((index, input.value()));
+                        });
+                        html! {
+                            <div class="form-field">
+                                <label>{EightD::TITLES[index]}</label>
+                                <textarea class="form-input" rows="3" disabled={!editable}
+                                    value={value.to_string()} {oninput} />
+                            </div>
+                        }
+                    })}
+                </div>
+            </div>
+
+            <div class="detail-card">
+                <div class="detail-title">
+                    <h3>{"附件"}</h3>
+                    if data.editable {
+                        <label class={if editable { "btn" } else { "btn disabled" }}>
+                            {"上传附件"}
+                            <input type="file" class="file-input-hidden" disabled={!editable} onchange={on_file_change} />
+                        </label>
+                    }
+                </div>
+                if ncr.attachments.is_empty() {
+                    <div class="table-empty">{"暂无附件"}</div>
+                } else {
+                    <table class="data-table">
+                        <thead>
+                            <tr>
+                                <th>{"文件名"}</th>
+                                <th class="numeric">{"大小"}</th>
+                                <th>{"上传人"}</th>
+                                <th>{"上传时间"}</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for ncr.attachments.iter().map(|attachment| html! {
+                                <tr>
+                                    <td>{&attachment.file_name}</td>
+                                    <td class="numeric">{format_size(attachment.size)}</td>
+                                    <td>{attachment.uploaded_by.clone().unwrap_or_else(|| "-".to_string())}</td>
+                                    <td>{format_datetime(&attachment.uploaded_at)}</td>
+                                </tr>
+                            })}
+                        </tbody>
+                    </table>
+                }
+            </div>
+
+            <div class="detail-card">
+                <div class="detail-title"><h3>{"处理记录"}</h3></div>
+                if data.history.is_empty() {
+                    <div class="table-empty">{"暂无记录"}</div>
+                } else {
+                    <ul class="timeline">
+                        { for data.history.iter().map(|entry| html! {
+                            <li class="timeline-item">
+                                <div class="timeline-head">
+                                    <span>{describe_entry(entry)}</span>
+                                    <span class="timeline-meta">
+                                        {format!("{} · {}", entry.operator, format_datetime(&entry.occurred_at))}
+                                    </span>
+                                </div>
+                                if let Some(comment) = entry.comment.clone() {
+                                    <div class="timeline-comment">{comment}</div>
+                                }
+                            </li>
+                        })}
+                    </ul>
+                }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,153 @@
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::quality::ncr::{NcrDraft, Severity};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+use crate::ui::components::modal::Modal;
+
+// 新建不合格报告对话框属性
+#[derive(Properties, PartialEq)]
+pub struct CreateNcrDialogProps {
+    pub on_close: Callback<()>,
+    /// 创建成功后返回报告ID
+    pub on_created: Callback<u64>,
+}
+
+// 手工登记不合格报告（客户投诉、现场发现等非检验来源）
+#[function_component(CreateNcrDialog)]
+pub fn create_ncr_dialog(props: &CreateNcrDialogProps) -> Html {
+    let draft = use_state(NcrDraft::default);
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+
+    let text_input = |apply: fn(&mut NcrDraft, String)| {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*draft).clone();
+            apply(&mut next, input.value());
+            draft.set(next);
+        })
+    };
+
+    let on_severity_change = {
+        let draft = draft.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*draft).clone();
+            next.severity = Severity::from_code(&select.value()).unwrap_or_default();
+            draft.set(next);
+        })
+    };
+
+    let on_description_input = {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            let mut next = (*draft).clone();
+            next.description = input.value();
+            draft.set(next);
+        })
+    };
+
+    let on_submit = {
+        let draft = draft.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let on_created = props.on_created.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let local_errors = draft.validate();
+            if !local_errors.is_empty() {
+                errors.set(local_errors);
+                return;
+            }
+
+            let submitted = (*draft).clone();
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            let on_created = on_created.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::create_ncr(submitted).await;
+                is_submitting.set(false);
+                match result {
+                    Ok(detail) => on_created.emit(detail.ncr.id),
+                    Err(ApiError::Validation { message, fields }) => {
+                        errors.set(fields);
+                        error_message.set(Some(message));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_cancel = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| on_close.emit(()))
+    };
+
+    let quantity = if draft.quantity > 0.0 { draft.quantity.to_string() } else { String::new() };
+
+    html! {
+        <Modal title="新建质量问题" on_close={props.on_close.clone()}>
+            <form class="data-view" onsubmit={on_submit}>
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+                <div class="form-grid">
+                    <div class="form-field form-field-wide">
+                        <label class="required">{"问题标题"}</label>
+                        <input class="form-input" type="text" value={draft.title.clone()}
+                            oninput={text_input(|d, v| d.title = v)} />
+                        { field_error(&errors, "title") }
+                    </div>
+                    <div class="form-field">
+                        <label class="required">{"物料 / 产品编码"}</label>
+                        <input class="form-input" type="text" value={draft.item_code.clone()}
+                            oninput={text_input(|d, v| d.item_code = v)} />
+                        { field_error(&errors, "itemCode") }
+                    </div>
+                    <div class="form-field">
+                        <label class="required">{"不合格数量"}</label>
+                        <input class="form-input" type="number" min="0" step="any" value={quantity}
+                            oninput={text_input(|d, v| d.quantity = v.parse().unwrap_or(0.0))} />
+                        { field_error(&errors, "quantity") }
+                    </div>
+                    <div class="form-field">
+                        <label class="required">{"严重程度"}</label>
+                        <select class="form-input" onchange={on_severity_change}>
+                            { for Severity::ALL.iter().map(|severity| html! {
+                                <option value={severity.code()} selected={draft.severity == *severity}>
+                                    {severity.label()}
+                                </option>
+                            })}
+                        </select>
+                    </div>
+                    <div class="form-field">
+                        <label>{"责任人"}</label>
+                        <input class="form-input" type="text" value={draft.owner.clone().unwrap_or_default()}
+                            oninput={text_input(|d, v| d.owner = Some(v).filter(|v| !v.trim().is_empty()))} />
+                    </div>
+                    <div class="form-field form-field-wide">
+                        <label>{"问题描述"}</label>
+                        <textarea class="form-input" rows="4" value={draft.description.clone()}
+                            oninput={on_description_input} />
+                    </div>
+                </div>
+                <div class="form-actions">
+                    <button class="btn" type="button" onclick={on_cancel}>{"取消"}</button>
+                    <button class="btn btn-primary" type="submit" disabled={*is_submitting}>
+                        { if *is_submitting { "提交中..." } else { "创建" } }
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
@@ -0,0 +1,227 @@
+// 质量问题页面
+//
+// 不合格报告列表与详情（8D报告、附件、审计记录）两个视图，返回列表时保留查询条件
+mod detail;
+mod form;
+
+use yew::prelude::*;
+use mes_win_shared::quality::ncr::{Ncr, NcrQuery, NcrStatus};
+use mes_win_shared::response::PageData;
+use mes_win_shared::search::SearchTarget;
+use crate::services::tauri;
+use crate::ui::components::pagination::Pagination;
+use crate::utils::format::{format_datetime, format_quantity};
+use detail::NcrDetailView;
+use form::CreateNcrDialog;
+
+/// 不合格报告状态标签
+pub fn ncr_status_badge(status: NcrStatus) -> Html {
+    let class = format!("status-badge status-{}", status.css_class());
+    html! { <span class={class}>{status.label()}</span> }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct IssuesPageProps {
+    /// 全局搜索跳转的目标，为不合格报告时直接打开详情
+    #[prop_or_default]
+    pub focus: Option<SearchTarget>,
+}
+
+#[function_component(IssuesPage)]
+pub fn issues_page(props: &IssuesPageProps) -> Html {
+    let query = use_state(NcrQuery::default);
+    let page_data = use_state(PageData::<Ncr>::default);
+    let keyword = use_state(String::new);
+    let opened = use_state(|| match props.focus {
+        Some(SearchTarget::Ncr { id }) => Some(id),
+        _ => None,
+    });
+    let show_dialog = use_state(|| false);
+    let reload = use_state(|| 0u32);
+    let is_loading = use_state(|| false);
+    let error_message = use_state(|| None::<String>);
+
+    {
+        let page_data = page_data.clone();
+        let is_loading = is_loading.clone();
+        let error_message = error_message.clone();
+        use_effect_with(((*query).clone(), *reload), move |(query, _)| {
+            let query = query.clone();
+            is_loading.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_ncrs(query).await {
+                    Ok(data) => {
+                        page_data.set(data);
+                        error_message.set(None);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+                is_loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    let on_keyword_input = {
+        let keyword = keyword.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            keyword.set(input.value());
+        })
+    };
+
+    let on_search = {
+        let keyword = keyword.clone();
+        let query = query.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let mut next = (*query).clone();
+            next.keyword = Some((*keyword).clone()).filter(|k| !k.trim().is_empty());
+            next.page = 1;
+            query.set(next);
+        })
+    };
+
+    let on_status_change = {
+        let query = query.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*query).clone();
+            next.status = NcrStatus::from_code(&select.value());
+            next.page = 1;
+            query.set(next);
+        })
+    };
+
+    let on_page_change = {
+        let query = query.clone();
+        Callback::from(move |page: u32| {
+            let mut next = (*query).clone();
+            next.page = page;
+            query.set(next);
+        })
+    };
+
+    let on_back = {
+        let opened = opened.clone();
+        let reload = reload.clone();
+        Callback::from(move |_: ()| {
+            opened.set(None);
+            reload.set(*reload + 1);
+        })
+    };
+
+    // 新建后直接进入详情填写8D报告
+    let on_created = {
+        let opened = opened.clone();
+        let show_dialog = show_dialog.clone();
+        Callback::from(move |id: u64| {
+            show_dialog.set(false);
+            opened.set(Some(id));
+        })
+    };
+
+    let on_close_dialog = {
+        let show_dialog = show_dialog.clone();
+        Callback::from(move |_: ()| show_dialog.set(false))
+    };
+
+    let on_create = {
+        let show_dialog = show_dialog.clone();
+        Callback::from(move |_: MouseEvent| show_dialog.set(true))
+    };
+
+    let selected_status = query.status.map(|status| status.code()).unwrap_or("");
+
+    let content = match *opened {
+        Some(id) => html! { <NcrDetailView key={id} id={id} on_back={on_back} /> },
+        None => html! {
+            <div class="data-view">
+                <div class="toolbar">
+                    <form class="toolbar-search" onsubmit={on_search}>
+                        <input class="form-input" type="text" placeholder="编号 / 标题 / 物料"
+                            value={(*keyword).clone()} oninput={on_keyword_input} />
+                        <select class="form-input" onchange={on_status_change}>
+                            <option value="" selected={selected_status.is_empty()}>{"全部状态"}</option>
+                            { for NcrStatus::ALL.iter().map(|status| html! {
+                                <option value={status.code()} selected={selected_status == status.code()}>
+                                    {status.label()}
+                                </option>
+                            })}
+                        </select>
+                        <button class="btn" type="submit">{"查询"}</button>
+                    </form>
+                    <button class="btn btn-primary" onclick={on_create}>{"新建问题"}</button>
+                </div>
+
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+
+                <div class="table-container">
+                    <table class="data-table">
+                        <thead>
+                            <tr>
+                                <th>{"编号"}</th>
+                                <th>{"标题"}</th>
+                                <th>{"物料 / 产品"}</th>
+                                <th class="numeric">{"数量"}</th>
+                                <th>{"严重程度"}</th>
+                                <th>{"来源检验"}</th>
+                                <th>{"责任人"}</th>
+                                <th>{"创建时间"}</th>
+                                <th>{"状态"}</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for page_data.items.iter().map(|ncr| {
+                                let id = ncr.id;
+                                let opened = opened.clone();
+                                html! {
+                                    <tr class="clickable" onclick={Callback::from(move |_: MouseEvent| opened.set(Some(id)))}>
+                                        <td>{&ncr.ncr_no}</td>
+                                        <td>{&ncr.title}</td>
+                                        <td>{format!("{} {}", ncr.item_code, ncr.item_name)}</td>
+                                        <td class="numeric">{format_quantity(ncr.quantity)}</td>
+                                        <td>{ncr.severity.label()}</td>
+                                        <td>{ncr.inspection_no.clone().unwrap_or_else(|| "-".to_string())}</td>
+                                        <td>{ncr.owner.clone().unwrap_or_else(|| "-".to_string())}</td>
+                                        <td>{format_datetime(&ncr.created_at)}</td>
+                                        <td>{ncr_status_badge(ncr.status)}</td>
+                                    </tr>
+                                }
+                            })}
+                        </tbody>
+                    </table>
+                    if page_data.items.is_empty() {
+                        <div class="table-empty">
+                            { if *is_loading { "加载中..." } else { "暂无质量问题" } }
+                        </div>
+                    }
+                </div>
+
+                <Pagination
+                    page={page_data.page}
+                    total_pages={page_data.total_pages()}
+                    total={page_data.total}
+                    on_change={on_page_change}
+                />
+            </div>
+        },
+    };
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"质量管理 - 问题"}</h2>
+                <p>{"不合格报告（NCR）跟踪，按8D方法完成调查、整改、验证和关闭"}</p>
+            </div>
+
+            { content }
+
+            if *show_dialog {
+                <CreateNcrDialog on_close={on_close_dialog} on_created={on_created} />
+            }
+        </div>
+    }
+}
@@ -1,22 +1,11 @@
 // 一次性导入 Yew 框架中所有最核心和常用的项
 use yew::prelude::*;
-use wasm_bindgen::prelude::*;
-use serde::{Deserialize, Serialize};
 use gloo_timers::future::TimeoutFuture;
-
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
-    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
-}
-
-// 检查是否在Tauri环境中运行
-fn is_tauri_environment() -> bool {
-    web_sys::window()
-        .and_then(|w| w.get("__TAURI__"))
-        .and_then(|t| t.dyn_into::<web_sys::js_sys::Object>().ok())
-        .is_some()
-}
+use crate::services::tauri::{self, is_tauri_environment};
+use mes_win_shared::auth::LoginData;
+use mes_win_shared::config::BackendProfiles;
+use mes_win_shared::permission::ALL_PERMISSIONS;
+use mes_win_shared::response::ApiResponse;
 
 // 模拟登录API调用（用于网页版）
 async fn mock_login_api(username: &str, password: &str) -> Result<LoginData, String> {
@@ -31,6 +20,10 @@ async fn mock_login_api(username: &str, password: &str) -> Result<LoginData, Str
             expires_in: 3600,
             username: username.to_string(),
             user_id: 1,
+            refresh_token: None,
+            refresh_expires_in: None,
+            roles: vec!["系统管理员".to_string()],
+            permissions: vec![ALL_PERMISSIONS.to_string()],
         })
     } else {
         Err("用户名或密码错误".to_string())
@@ -43,38 +36,6 @@ pub struct LoginProps {
     pub on_login_success: Callback<String>, // 登录成功回调，传递用户名
 }
 
-// 登录请求参数
-#[derive(Serialize)]
-struct LoginArgs {
-    username: String,
-    password: String,
-}
-
-// API通用响应结构
-#[derive(Debug, Deserialize)]
-#[allow(dead_code)] // 允许未使用的字段，这些字段来自API响应
-struct ApiResponse<T> {
-    success: bool,
-    code: u32,
-    message: String,
-    data: Option<T>,
-}
-
-// 登录响应数据
-#[derive(Debug, Deserialize)]
-#[allow(dead_code)] // 允许未使用的字段，这些字段来自API响应
-struct LoginData {
-    #[serde(rename = "accessToken")]
-    access_token: String,
-    #[serde(rename = "tokenType")]
-    token_type: String,
-    #[serde(rename = "expiresIn")]
-    expires_in: u32,
-    username: String,
-    #[serde(rename = "userId")]
-    user_id: u32,
-}
-
 //定义登录表单的状态结构
 // 宏定义允许结构体被克隆和比较
 #[derive(Clone, PartialEq)]
@@ -104,6 +65,51 @@ impl Default for LoginState{
 pub fn login(props: &LoginProps) -> Html { 
     // 定义不可变状态变量 使用 use_state 钩子来管理登录状态
     let login_state = use_state(LoginState::default);
+    let backend_profiles = use_state(BackendProfiles::default);
+
+    // 加载后端服务器档案列表
+    {
+        let backend_profiles = backend_profiles.clone();
+        use_effect_with((), move |_| {
+            if is_tauri_environment() {
+                wasm_bindgen_futures::spawn_local(async move {
+                    match tauri::list_backend_profiles().await {
+                        Ok(profiles) => backend_profiles.set(profiles),
+                        Err(error) => {
+                            web_sys::console::log_1(&format!("加载服务器列表失败: {}", error.message()).into());
+                        }
+                    }
+                });
+            }
+            || ()
+        });
+    }
+
+    // 处理服务器切换
+    let on_profile_change = {
+        let backend_profiles = backend_profiles.clone();
+        let login_state = login_state.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let name = select.value();
+            let backend_profiles = backend_profiles.clone();
+            let login_state = login_state.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::switch_backend_profile(name).await {
+                    Ok(profiles) => {
+                        web_sys::console::log_1(&format!("已切换服务器: {}", profiles.active).into());
+                        backend_profiles.set(profiles);
+                    }
+                    Err(error) => {
+                        let mut new_state = (*login_state).clone();
+                        new_state.error_message = Some(error.user_message());
+                        new_state.show_message = true;
+                        login_state.set(new_state);
+                    }
+                }
+            });
+        })
+    };
 
     // 处理用户名输入变化
     let on_username_change = {
@@ -146,32 +152,17 @@ pub fn login(props: &LoginProps) -> Html {
             let password = new_state.password.clone();
             
             wasm_bindgen_futures::spawn_local(async move {
-                let args = LoginArgs { username: username.clone(), password: password.clone() };
-                
                 let mut final_state = (*login_state_inner).clone();
                 final_state.is_loading = false;
                 
                 // 检查是否在Tauri环境中
                 if is_tauri_environment() {
                     // Tauri环境：调用原生API
-                    let args_value = serde_wasm_bindgen::to_value(&args).unwrap();
-                    let result = invoke("login", args_value).await;
-                    
-                    // 尝试解析API响应
-                    if let Ok(api_response) = serde_wasm_bindgen::from_value::<ApiResponse<LoginData>>(result.clone()) {
-                        if api_response.success && api_response.data.is_some() {
+                    match tauri::login(username, password).await {
+                        Ok(ApiResponse { data: Some(login_data), .. }) => {
                             // 登录成功
-                            let login_data = api_response.data.unwrap();
                             let username = login_data.username.clone();
                             
-                            // 保存 token
-                            if let Some(window) = web_sys::window() {
-                                if let Ok(Some(storage)) = window.local_storage() {
-                                    let _ = storage.set_item("access_token", &login_data.access_token);
-                                    let _ = storage.set_item("username", &username);
-                                }
-                            }
-                            
                             web_sys::console::log_1(&format!("登录成功！用户: {}", username).into());
                             
                             // 触发登录成功回调，切换到主界面
@@ -182,21 +173,17 @@ pub fn login(props: &LoginProps) -> Html {
                             final_state.password.clear();
                             final_state.error_message = None;
                             final_state.show_message = false;
-                        } else {
-                            // 登录失败，使用API返回的错误消息
+                        }
+                        Ok(api_response) => {
                             final_state.error_message = Some(api_response.message);
                             final_state.show_message = true;
                         }
-                    } else {
-                        // 解析失败，使用默认错误消息
-                        let error_msg = if let Some(error_str) = result.as_string() {
-                            error_str
-                        } else {
-                            "登录失败，请检查网络连接".to_string()
-                        };
-                        
-                        final_state.error_message = Some(error_msg);
-                        final_state.show_message = true;
+                        Err(error) => {
+                            // 按错误类型给出提示
+                            web_sys::console::log_1(&format!("登录失败: {:?}", error).into());
+                            final_state.error_message = Some(error.user_message());
+                            final_state.show_message = true;
+                        }
                     }
                 } else {
                     // 网页环境：使用模拟API
@@ -206,14 +193,6 @@ pub fn login(props: &LoginProps) -> Html {
                         Ok(login_data) => {
                             let username = login_data.username.clone();
                             
-                            // 保存 token
-                            if let Some(window) = web_sys::window() {
-                                if let Ok(Some(storage)) = window.local_storage() {
-                                    let _ = storage.set_item("access_token", &login_data.access_token);
-                                    let _ = storage.set_item("username", &username);
-                                }
-                            }
-                            
                             web_sys::console::log_1(&format!("模拟登录成功！用户: {}", username).into());
                             
                             // 触发登录成功回调，切换到主界面
@@ -240,7 +219,7 @@ pub fn login(props: &LoginProps) -> Html {
     let on_minimize = {
         Callback::from(move |_e: MouseEvent| {
             wasm_bindgen_futures::spawn_local(async move {
-                let _ = invoke("minimize", serde_wasm_bindgen::to_value(&()).unwrap()).await;
+                let _ = tauri::minimize().await;
             });
         })
     };
@@ -249,7 +228,7 @@ pub fn login(props: &LoginProps) -> Html {
     let on_close = {
         Callback::from(move |_e: MouseEvent| {
             wasm_bindgen_futures::spawn_local(async move {
-                let _ = invoke("close", serde_wasm_bindgen::to_value(&()).unwrap()).await;
+                let _ = tauri::close().await;
             });
         })
     };
@@ -348,6 +327,26 @@ pub fn login(props: &LoginProps) -> Html {
                         />
                     </div>
                     
+                    if !backend_profiles.profiles.is_empty() {
+                        <div class="form-group">
+                            <select
+                                class="login-input server-select"
+                                onchange={on_profile_change}
+                                disabled={login_state.is_loading}
+                            >
+                                { for backend_profiles.profiles.iter().map(|profile| html! {
+                                    <option
+                                        value={profile.name.clone()}
+                                        selected={profile.name == backend_profiles.active}
+                                        title={profile.base_url.clone()}
+                                    >
+                                        { &profile.label }
+                                    </option>
+                                }) }
+                            </select>
+                        </div>
+                    }
+
                     <div class="login-actions">
                         <button 
                             type="button" 
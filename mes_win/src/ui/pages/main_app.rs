@@ -1,11 +1,26 @@
 use yew::prelude::*;
-use wasm_bindgen::prelude::*;
-
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
-    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
-}
+use mes_win_shared::events;
+use mes_win_shared::permission::{has_permission, Permission, SessionInfo, ALL_PERMISSIONS};
+use mes_win_shared::search::{SearchHit, SearchTarget};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::offline::OfflineIndicator;
+use crate::ui::components::search::GlobalSearch;
+use crate::ui::pages::analytics::AnalyticsPage;
+use crate::ui::pages::dashboard::OverviewPage;
+use crate::ui::pages::inspection::InspectionPage;
+use crate::ui::pages::issues::IssuesPage;
+use crate::ui::pages::materials::MaterialsPage;
+use crate::ui::pages::permissions::PermissionsPage;
+use crate::ui::pages::products::ProductsPage;
+use crate::ui::pages::reports::ReportsPage;
+use crate::ui::pages::routing::RoutingPage;
+use crate::ui::pages::schedule::SchedulePage;
+use crate::ui::pages::settings::SettingsPage;
+use crate::ui::pages::standards::StandardsPage;
+use crate::ui::pages::users::UsersPage;
+use crate::ui::pages::warehouse::WarehousePage;
+use crate::ui::pages::work_orders::WorkOrdersPage;
 
 // 主界面属性
 #[derive(Properties, PartialEq)]
@@ -14,6 +29,14 @@ pub struct MainAppProps {
     pub on_logout: Callback<()>,
 }
 
+// 当前会话权限的加载状态
+#[derive(Clone, PartialEq)]
+enum SessionState {
+    Loading,
+    Loaded(SessionInfo),
+    Failed(String),
+}
+
 // 一级菜单项枚举
 #[derive(Clone, PartialEq, Copy)]
 pub enum PrimaryMenuItem {
@@ -24,6 +47,18 @@ pub enum PrimaryMenuItem {
     Settings,
 }
 
+impl PrimaryMenuItem {
+    fn label(&self) -> &'static str {
+        match self {
+            PrimaryMenuItem::Dashboard => "仪表板",
+            PrimaryMenuItem::Production => "生产管理",
+            PrimaryMenuItem::Inventory => "库存管理",
+            PrimaryMenuItem::Quality => "质量管理",
+            PrimaryMenuItem::Settings => "系统管理",
+        }
+    }
+}
+
 // 二级菜单项枚举
 #[derive(Clone, PartialEq, Copy)]
 pub enum SecondaryMenuItem {
@@ -49,27 +84,242 @@ pub enum SecondaryMenuItem {
     System,
 }
 
+impl SecondaryMenuItem {
+    /// 菜单编码（全局搜索的菜单结果使用）
+    fn code(&self) -> &'static str {
+        match self {
+            SecondaryMenuItem::Overview => "overview",
+            SecondaryMenuItem::Analytics => "analytics",
+            SecondaryMenuItem::Reports => "reports",
+            SecondaryMenuItem::Orders => "orders",
+            SecondaryMenuItem::Schedule => "schedule",
+            SecondaryMenuItem::Workflow => "workflow",
+            SecondaryMenuItem::Materials => "materials",
+            SecondaryMenuItem::Products => "products",
+            SecondaryMenuItem::Warehouse => "warehouse",
+            SecondaryMenuItem::Inspection => "inspection",
+            SecondaryMenuItem::Standards => "standards",
+            SecondaryMenuItem::Issues => "issues",
+            SecondaryMenuItem::Users => "users",
+            SecondaryMenuItem::Permissions => "permissions",
+            SecondaryMenuItem::System => "system",
+        }
+    }
+
+    /// 访问该页面所需的权限（系统参数页面中的本机设置对所有用户开放）
+    fn required_permission(&self) -> Option<Permission> {
+        match self {
+            SecondaryMenuItem::Overview | SecondaryMenuItem::Reports => Some(Permission::DashboardView),
+            SecondaryMenuItem::Orders | SecondaryMenuItem::Schedule | SecondaryMenuItem::Workflow => {
+                Some(Permission::ProductionView)
+            }
+            SecondaryMenuItem::Materials | SecondaryMenuItem::Products | SecondaryMenuItem::Warehouse => {
+                Some(Permission::InventoryView)
+            }
+            SecondaryMenuItem::Analytics
+            | SecondaryMenuItem::Inspection
+            | SecondaryMenuItem::Standards
+            | SecondaryMenuItem::Issues => Some(Permission::QualityView),
+            SecondaryMenuItem::Users => Some(Permission::UserManage),
+            SecondaryMenuItem::Permissions => Some(Permission::RoleManage),
+            SecondaryMenuItem::System => None,
+        }
+    }
+
+    /// 当前权限下是否可以访问
+    fn is_allowed(&self, granted: &[String]) -> bool {
+        self.required_permission()
+            .is_none_or(|permission| has_permission(granted, permission))
+    }
+}
+
+/// 一级菜单（按显示顺序）
+const PRIMARY_MENUS: [PrimaryMenuItem; 5] = [
+    PrimaryMenuItem::Dashboard,
+    PrimaryMenuItem::Production,
+    PrimaryMenuItem::Inventory,
+    PrimaryMenuItem::Quality,
+    PrimaryMenuItem::Settings,
+];
+
+/// 一级菜单下的二级菜单项（菜单项、标题、说明）
+fn secondary_items(primary_menu: PrimaryMenuItem) -> Vec<(SecondaryMenuItem, &'static str, &'static str)> {
+    match primary_menu {
+        PrimaryMenuItem::Dashboard => vec![
+            (SecondaryMenuItem::Overview, "概览", "总体数据统计"),
+            (SecondaryMenuItem::Analytics, "分析", "数据分析报告"),
+            (SecondaryMenuItem::Reports, "报表", "生成各类报表"),
+        ],
+        PrimaryMenuItem::Production => vec![
+            (SecondaryMenuItem::Orders, "订单", "生产订单管理"),
+            (SecondaryMenuItem::Schedule, "排程", "生产计划排程"),
+            (SecondaryMenuItem::Workflow, "工艺", "生产工艺流程"),
+        ],
+        PrimaryMenuItem::Inventory => vec![
+            (SecondaryMenuItem::Materials, "物料", "原材料管理"),
+            (SecondaryMenuItem::Products, "产品", "成品库存管理"),
+            (SecondaryMenuItem::Warehouse, "仓库", "仓储管理"),
+        ],
+        PrimaryMenuItem::Quality => vec![
+            (SecondaryMenuItem::Inspection, "检验", "质量检验记录"),
+            (SecondaryMenuItem::Standards, "标准", "质量标准管理"),
+            (SecondaryMenuItem::Issues, "问题", "质量问题跟踪"),
+        ],
+        PrimaryMenuItem::Settings => vec![
+            (SecondaryMenuItem::Users, "用户", "用户账户管理"),
+            (SecondaryMenuItem::Permissions, "权限", "权限角色管理"),
+            (SecondaryMenuItem::System, "系统", "系统参数设置"),
+        ],
+    }
+}
+
+/// 一级菜单下第一个有权限访问的二级菜单项，全部无权限时一级菜单隐藏
+fn first_allowed(primary_menu: PrimaryMenuItem, granted: &[String]) -> Option<SecondaryMenuItem> {
+    secondary_items(primary_menu)
+        .into_iter()
+        .map(|(item, _, _)| item)
+        .find(|item| item.is_allowed(granted))
+}
+
+/// 全部菜单项（一级菜单、二级菜单、标题、说明）
+fn all_menu_entries() -> impl Iterator<Item = (PrimaryMenuItem, SecondaryMenuItem, &'static str, &'static str)> {
+    PRIMARY_MENUS.into_iter().flat_map(|primary| {
+        secondary_items(primary)
+            .into_iter()
+            .map(move |(item, title, description)| (primary, item, title, description))
+    })
+}
+
+/// 按关键字匹配有权限的菜单（一级菜单名、标题或说明）
+fn menu_hits(keyword: &str, granted: &[String]) -> Vec<SearchHit> {
+    let keyword = keyword.to_lowercase();
+    all_menu_entries()
+        .filter(|(_, item, _, _)| item.is_allowed(granted))
+        .filter(|(primary, _, title, description)| {
+            [primary.label(), *title, *description]
+                .iter()
+                .any(|text| text.to_lowercase().contains(&keyword))
+        })
+        .map(|(primary, item, title, description)| SearchHit {
+            target: SearchTarget::Menu { code: item.code().to_string() },
+            title: format!("{} - {}", primary.label(), title),
+            subtitle: Some(description.to_string()),
+        })
+        .collect()
+}
+
+/// 搜索结果对应的页面
+fn search_destination(target: &SearchTarget) -> Option<(PrimaryMenuItem, SecondaryMenuItem)> {
+    match target {
+        SearchTarget::Menu { code } => all_menu_entries()
+            .find(|(_, item, _, _)| item.code() == code)
+            .map(|(primary, item, _, _)| (primary, item)),
+        SearchTarget::WorkOrder { .. } => Some((PrimaryMenuItem::Production, SecondaryMenuItem::Orders)),
+        SearchTarget::Material { .. } | SearchTarget::Lot { .. } => {
+            Some((PrimaryMenuItem::Inventory, SecondaryMenuItem::Materials))
+        }
+        SearchTarget::Serial { .. } => Some((PrimaryMenuItem::Inventory, SecondaryMenuItem::Products)),
+        SearchTarget::Ncr { .. } => Some((PrimaryMenuItem::Quality, SecondaryMenuItem::Issues)),
+    }
+}
+
 // 主界面组件
 #[function_component(MainApp)]
 pub fn main_app(props: &MainAppProps) -> Html {
     let selected_primary_menu = use_state(|| PrimaryMenuItem::Dashboard);
     let selected_secondary_menu = use_state(|| SecondaryMenuItem::Overview);
-    let search_term = use_state(|| String::new());
+    // 搜索跳转的目标，序号变化时重新创建目标页面以打开对应详情
+    let focus = use_state(|| (0u32, None::<SearchTarget>));
+    let session = use_state(|| SessionState::Loading);
+
+    // 加载当前会话的权限，并定位到第一个有权限的页面；会话已失效时回到登录页
+    let load_session = {
+        let session = session.clone();
+        let selected_primary_menu = selected_primary_menu.clone();
+        let selected_secondary_menu = selected_secondary_menu.clone();
+        let username = props.username.clone();
+        let on_logout = props.on_logout.clone();
+        Callback::from(move |_: ()| {
+            let session = session.clone();
+            let selected_primary_menu = selected_primary_menu.clone();
+            let selected_secondary_menu = selected_secondary_menu.clone();
+            let username = username.clone();
+            let on_logout = on_logout.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = if tauri::is_tauri_environment() {
+                    tauri::get_session_info().await
+                } else {
+                    // 网页版（模拟登录）不做权限限制
+                    Ok(Some(SessionInfo {
+                        username,
+                        permissions: vec![ALL_PERMISSIONS.to_string()],
+                        ..SessionInfo::default()
+                    }))
+                };
+                let info = match result {
+                    Ok(Some(info)) => info,
+                    Ok(None) | Err(ApiError::Unauthorized { .. }) => {
+                        web_sys::console::log_1(&"会话已失效，返回登录页".into());
+                        on_logout.emit(());
+                        return;
+                    }
+                    Err(e) => {
+                        session.set(SessionState::Failed(format!("加载用户权限失败: {}", e.message())));
+                        return;
+                    }
+                };
+                if !selected_secondary_menu.is_allowed(&info.permissions) {
+                    let first = PRIMARY_MENUS
+                        .into_iter()
+                        .find_map(|primary| first_allowed(primary, &info.permissions).map(|item| (primary, item)));
+                    if let Some((primary, item)) = first {
+                        selected_primary_menu.set(primary);
+                        selected_secondary_menu.set(item);
+                    }
+                }
+                session.set(SessionState::Loaded(info));
+            });
+        })
+    };
+    {
+        let load_session = load_session.clone();
+        use_effect_with((), move |_| {
+            load_session.emit(());
+            // 后台续期后角色权限可能已变化，重新加载
+            let unlisten = tauri::is_tauri_environment().then(|| {
+                tauri::listen_in_effect(events::SESSION_REFRESHED, move |_event| load_session.emit(()))
+            });
+            move || {
+                if let Some(unlisten) = unlisten {
+                    unlisten();
+                }
+            }
+        });
+    }
+    let granted = match &*session {
+        SessionState::Loaded(info) => info.permissions.clone(),
+        _ => Vec::new(),
+    };
+    let on_reload_session = {
+        let session = session.clone();
+        Callback::from(move |_: MouseEvent| {
+            session.set(SessionState::Loading);
+            load_session.emit(());
+        })
+    };
 
     // 处理一级菜单选择
     let on_primary_menu_select = {
         let selected_primary_menu = selected_primary_menu.clone();
         let selected_secondary_menu = selected_secondary_menu.clone();
+        let focus = focus.clone();
+        let granted = granted.clone();
         Callback::from(move |item: PrimaryMenuItem| {
-            selected_primary_menu.set(item.clone());
-            // 根据一级菜单设置默认的二级菜单
-            let default_secondary = match item {
-                PrimaryMenuItem::Dashboard => SecondaryMenuItem::Overview,
-                PrimaryMenuItem::Production => SecondaryMenuItem::Orders,
-                PrimaryMenuItem::Inventory => SecondaryMenuItem::Materials,
-                PrimaryMenuItem::Quality => SecondaryMenuItem::Inspection,
-                PrimaryMenuItem::Settings => SecondaryMenuItem::Users,
-            };
+            focus.set((focus.0, None));
+            selected_primary_menu.set(item);
+            // 根据一级菜单设置默认的二级菜单（第一个有权限的菜单项）
+            let default_secondary = first_allowed(item, &granted)
+                .unwrap_or_else(|| secondary_items(item)[0].0);
             selected_secondary_menu.set(default_secondary);
         })
     };
@@ -77,50 +327,82 @@ pub fn main_app(props: &MainAppProps) -> Html {
     // 处理二级菜单选择
     let on_secondary_menu_select = {
         let selected_secondary_menu = selected_secondary_menu.clone();
+        let focus = focus.clone();
         Callback::from(move |item: SecondaryMenuItem| {
+            focus.set((focus.0, None));
             selected_secondary_menu.set(item);
         })
     };
 
-    // 处理搜索
-    let on_search_change = {
-        let search_term = search_term.clone();
-        Callback::from(move |e: Event| {
-            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-            search_term.set(input.value());
+    // 全局搜索：菜单在前端匹配
+    let on_menu_hits = {
+        let granted = granted.clone();
+        Callback::from(move |keyword: String| menu_hits(&keyword, &granted))
+    };
+
+    // 全局搜索：跳转到结果所在页面，数据结果同时打开详情
+    let on_search_select = {
+        let selected_primary_menu = selected_primary_menu.clone();
+        let selected_secondary_menu = selected_secondary_menu.clone();
+        let focus = focus.clone();
+        Callback::from(move |target: SearchTarget| {
+            let Some((primary, item)) = search_destination(&target) else {
+                return;
+            };
+            let detail = (!matches!(target, SearchTarget::Menu { .. })).then_some(target);
+            focus.set((focus.0 + 1, detail));
+            selected_primary_menu.set(primary);
+            selected_secondary_menu.set(item);
         })
     };
 
-    // 处理退出登录
-    let _on_logout = {
+    // 处理退出登录：先通知后端注销并清除原生层会话，失败时仍退出到登录页
+    let on_logout = {
         let callback = props.on_logout.clone();
         Callback::from(move |_e: MouseEvent| {
-            // 清除本地存储的token
-            if let Some(window) = web_sys::window() {
-                if let Ok(Some(storage)) = window.local_storage() {
-                    let _ = storage.remove_item("access_token");
-                    let _ = storage.remove_item("username");
+            let callback = callback.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                if tauri::is_tauri_environment() {
+                    if let Err(e) = tauri::logout().await {
+                        web_sys::console::log_1(&format!("退出登录失败，继续清理本地状态: {}", e.message()).into());
+                    }
                 }
-            }
-            web_sys::console::log_1(&"用户退出登录".into());
-            callback.emit(());
+                // 清除本地存储的token
+                if let Some(window) = web_sys::window() {
+                    if let Ok(Some(storage)) = window.local_storage() {
+                        let _ = storage.remove_item("access_token");
+                        let _ = storage.remove_item("username");
+                    }
+                }
+                web_sys::console::log_1(&"用户退出登录".into());
+                callback.emit(());
+            });
         })
     };
 
     // 处理窗口最小化
-    let _on_minimize = {
+    let on_minimize = {
         Callback::from(move |_e: MouseEvent| {
             wasm_bindgen_futures::spawn_local(async move {
-                let _ = invoke("minimize", serde_wasm_bindgen::to_value(&()).unwrap()).await;
+                let _ = tauri::minimize().await;
+            });
+        })
+    };
+
+    // 处理窗口最大化/还原
+    let on_toggle_maximize = {
+        Callback::from(move |_e: MouseEvent| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let _ = tauri::toggle_maximize().await;
             });
         })
     };
 
     // 处理窗口关闭
-    let _on_close = {
+    let on_close = {
         Callback::from(move |_e: MouseEvent| {
             wasm_bindgen_futures::spawn_local(async move {
-                let _ = invoke("close", serde_wasm_bindgen::to_value(&()).unwrap()).await;
+                let _ = tauri::close().await;
             });
         })
     };
@@ -142,18 +424,12 @@ pub fn main_app(props: &MainAppProps) -> Html {
             
             // 调用Tauri命令打开个人中心窗口
             wasm_bindgen_futures::spawn_local(async move {
-                let result = invoke("open_profile_window", serde_wasm_bindgen::to_value(&()).unwrap()).await;
-                
-                // 尝试解析结果
-                match serde_wasm_bindgen::from_value::<Result<(), String>>(result) {
-                    Ok(Ok(_)) => {
+                match tauri::open_profile_window().await {
+                    Ok(_) => {
                         web_sys::console::log_1(&"个人中心窗口打开成功".into());
                     },
-                    Ok(Err(e)) => {
-                        web_sys::console::log_1(&format!("打开个人中心窗口失败: {}", e).into());
-                    },
                     Err(e) => {
-                        web_sys::console::log_1(&format!("解析结果失败: {:?}", e).into());
+                        web_sys::console::log_1(&format!("打开个人中心窗口失败: {}", e.message()).into());
                     }
                 }
             });
@@ -166,35 +442,17 @@ pub fn main_app(props: &MainAppProps) -> Html {
             // Windows风格的窗口控制按钮
             <div class="window-titlebar" data-tauri-drag-region="true">
                 <div class="window-controls">
-                    <button class="window-control minimize" onclick={
-                        Callback::from(move |_e: MouseEvent| {
-                            wasm_bindgen_futures::spawn_local(async move {
-                                let _ = invoke("minimize", serde_wasm_bindgen::to_value(&()).unwrap()).await;
-                            });
-                        })
-                    }>
+                    <button class="window-control minimize" onclick={on_minimize}>
                         <svg width="10" height="10" viewBox="0 0 10 10">
                             <path d="M0,5 L10,5" stroke="currentColor" stroke-width="1"/>
                         </svg>
                     </button>
-                    <button class="window-control maximize" onclick={
-                        Callback::from(move |_e: MouseEvent| {
-                            wasm_bindgen_futures::spawn_local(async move {
-                                let _ = invoke("toggle_maximize", serde_wasm_bindgen::to_value(&()).unwrap()).await;
-                            });
-                        })
-                    }>
+                    <button class="window-control maximize" onclick={on_toggle_maximize}>
                         <svg width="10" height="10" viewBox="0 0 10 10">
                             <path d="M0,0 L10,0 L10,10 L0,10 Z" stroke="currentColor" stroke-width="1" fill="none"/>
                         </svg>
                     </button>
-                    <button class="window-control close" onclick={
-                        Callback::from(move |_e: MouseEvent| {
-                            wasm_bindgen_futures::spawn_local(async move {
-                                let _ = invoke("close", serde_wasm_bindgen::to_value(&()).unwrap()).await;
-                            });
-                        })
-                    }>
+                    <button class="window-control close" onclick={on_close}>
                         <svg width="10" height="10" viewBox="0 0 10 10">
                             <path d="M0,0 L10,10 M0,10 L10,0" stroke="currentColor" stroke-width="1"/>
                         </svg>
@@ -216,75 +474,85 @@ pub fn main_app(props: &MainAppProps) -> Html {
                     
                     // 一级菜单
                     <nav class="primary-menu">
-                        <div class={if *selected_primary_menu == PrimaryMenuItem::Dashboard { "menu-item active" } else { "menu-item" }}
-                             onclick={
-                                 let on_primary_menu_select = on_primary_menu_select.clone();
-                                 Callback::from(move |_| on_primary_menu_select.emit(PrimaryMenuItem::Dashboard))
-                             }>
-                            <svg width="20" height="20" viewBox="0 0 24 24" fill="none">
-                                <rect x="3" y="3" width="7" height="7" rx="1" stroke="currentColor" stroke-width="2"/>
-                                <rect x="14" y="3" width="7" height="7" rx="1" stroke="currentColor" stroke-width="2"/>
-                                <rect x="14" y="14" width="7" height="7" rx="1" stroke="currentColor" stroke-width="2"/>
-                                <rect x="3" y="14" width="7" height="7" rx="1" stroke="currentColor" stroke-width="2"/>
-                            </svg>
-                            <span class="menu-tooltip">{"仪表板"}</span>
-                        </div>
+                        if first_allowed(PrimaryMenuItem::Dashboard, &granted).is_some() {
+                            <div class={if *selected_primary_menu == PrimaryMenuItem::Dashboard { "menu-item active" } else { "menu-item" }}
+                                 onclick={
+                                     let on_primary_menu_select = on_primary_menu_select.clone();
+                                     Callback::from(move |_| on_primary_menu_select.emit(PrimaryMenuItem::Dashboard))
+                                 }>
+                                <svg width="20" height="20" viewBox="0 0 24 24" fill="none">
+                                    <rect x="3" y="3" width="7" height="7" rx="1" stroke="currentColor" stroke-width="2"/>
+                                    <rect x="14" y="3" width="7" height="7" rx="1" stroke="currentColor" stroke-width="2"/>
+                                    <rect x="14" y="14" width="7" height="7" rx="1" stroke="currentColor" stroke-width="2"/>
+                                    <rect x="3" y="14" width="7" height="7" rx="1" stroke="currentColor" stroke-width="2"/>
+                                </svg>
+                                <span class="menu-tooltip">{"仪表板"}</span>
+                            </div>
+                        }
                         
-                        <div class={if *selected_primary_menu == PrimaryMenuItem::Production { "menu-item active" } else { "menu-item" }}
-                             onclick={
-                                 let on_primary_menu_select = on_primary_menu_select.clone();
-                                 Callback::from(move |_| on_primary_menu_select.emit(PrimaryMenuItem::Production))
-                             }>
-                            <svg width="20" height="20" viewBox="0 0 24 24" fill="none">
-                                <path d="M9 7H6a2 2 0 0 0-2 2v9a2 2 0 0 0 2 2h8a2 2 0 0 0 2-2V9a2 2 0 0 0-2-2h-3" stroke="currentColor" stroke-width="2"/>
-                                <rect x="9" y="1" width="6" height="6" rx="2" stroke="currentColor" stroke-width="2"/>
-                            </svg>
-                            <span class="menu-tooltip">{"生产管理"}</span>
-                        </div>
+                        if first_allowed(PrimaryMenuItem::Production, &granted).is_some() {
+                            <div class={if *selected_primary_menu == PrimaryMenuItem::Production { "menu-item active" } else { "menu-item" }}
+                                 onclick={
+                                     let on_primary_menu_select = on_primary_menu_select.clone();
+                                     Callback::from(move |_| on_primary_menu_select.emit(PrimaryMenuItem::Production))
+                                 }>
+                                <svg width="20" height="20" viewBox="0 0 24 24" fill="none">
+                                    <path d="M9 7H6a2 2 0 0 0-2 2v9a2 2 0 0 0 2 2h8a2 2 0 0 0 2-2V9a2 2 0 0 0-2-2h-3" stroke="currentColor" stroke-width="2"/>
+                                    <rect x="9" y="1" width="6" height="6" rx="2" stroke="currentColor" stroke-width="2"/>
+                                </svg>
+                                <span class="menu-tooltip">{"生产管理"}</span>
+                            </div>
+                        }
                         
-                        <div class={if *selected_primary_menu == PrimaryMenuItem::Inventory { "menu-item active" } else { "menu-item" }}
-                             onclick={
-                                 let on_primary_menu_select = on_primary_menu_select.clone();
-                                 Callback::from(move |_| on_primary_menu_select.emit(PrimaryMenuItem::Inventory))
-                             }>
-                            <svg width="20" height="20" viewBox="0 0 24 24" fill="none">
-                                <path d="M21 16V8a2 2 0 0 0-1-1.73L12 2L4 6.27A2 2 0 0 0 3 8v8a2 2 0 0 0 1 1.73L12 22l8-4.27A2 2 0 0 0 21 16z" stroke="currentColor" stroke-width="2"/>
-                            </svg>
-                            <span class="menu-tooltip">{"库存管理"}</span>
-                        </div>
+                        if first_allowed(PrimaryMenuItem::Inventory, &granted).is_some() {
+                            <div class={if *selected_primary_menu == PrimaryMenuItem::Inventory { "menu-item active" } else { "menu-item" }}
+                                 onclick={
+                                     let on_primary_menu_select = on_primary_menu_select.clone();
+                                     Callback::from(move |_| on_primary_menu_select.emit(PrimaryMenuItem::Inventory))
+                                 }>
+                                <svg width="20" height="20" viewBox="0 0 24 24" fill="none">
+                                    <path d="M21 16V8a2 2 0 0 0-1-1.73L12 2L4 6.27A2 2 0 0 0 3 8v8a2 2 0 0 0 1 1.73L12 22l8-4.27A2 2 0 0 0 21 16z" stroke="currentColor" stroke-width="2"/>
+                                </svg>
+                                <span class="menu-tooltip">{"库存管理"}</span>
+                            </div>
+                        }
                         
-                        <div class={if *selected_primary_menu == PrimaryMenuItem::Quality { "menu-item active" } else { "menu-item" }}
-                             onclick={
-                                 let on_primary_menu_select = on_primary_menu_select.clone();
-                                 Callback::from(move |_| on_primary_menu_select.emit(PrimaryMenuItem::Quality))
-                             }>
-                            <svg width="20" height="20" viewBox="0 0 24 24" fill="none">
-                                <path d="M9 12l2 2 4-4" stroke="currentColor" stroke-width="2"/>
-                                <path d="M21 12c-1 0-3-1-3-3s2-3 3-3 3 1 3 3-2 3-3 3" stroke="currentColor" stroke-width="2"/>
-                                <path d="M3 12c1 0 3-1 3-3s-2-3-3-3-3 1-3 3 2 3 3 3" stroke="currentColor" stroke-width="2"/>
-                                <path d="M12 21c0-1-1-3-3-3s-3 2-3 3 1 3 3 3 3-2 3-3" stroke="currentColor" stroke-width="2"/>
-                                <path d="M12 3c0 1-1 3-3 3s-3-2-3-3 1-3 3-3 3 2 3 3" stroke="currentColor" stroke-width="2"/>
-                            </svg>
-                            <span class="menu-tooltip">{"质量管理"}</span>
-                        </div>
+                        if first_allowed(PrimaryMenuItem::Quality, &granted).is_some() {
+                            <div class={if *selected_primary_menu == PrimaryMenuItem::Quality { "menu-item active" } else { "menu-item" }}
+                                 onclick={
+                                     let on_primary_menu_select = on_primary_menu_select.clone();
+                                     Callback::from(move |_| on_primary_menu_select.emit(PrimaryMenuItem::Quality))
+                                 }>
+                                <svg width="20" height="20" viewBox="0 0 24 24" fill="none">
+                                    <path d="M9 12l2 2 4-4" stroke="currentColor" stroke-width="2"/>
+                                    <path d="M21 12c-1 0-3-1-3-3s2-3 3-3 3 1 3 3-2 3-3 3" stroke="currentColor" stroke-width="2"/>
+                                    <path d="M3 12c1 0 3-1 3-3s-2-3-3-3-3 1-3 3 2 3 3 3" stroke="currentColor" stroke-width="2"/>
+                                    <path d="M12 21c0-1-1-3-3-3s-3 2-3 3 1 3 3 3 3-2 3-3" stroke="currentColor" stroke-width="2"/>
+                                    <path d="M12 3c0 1-1 3-3 3s-3-2-3-3 1-3 3-3 3 2 3 3" stroke="currentColor" stroke-width="2"/>
+                                </svg>
+                                <span class="menu-tooltip">{"质量管理"}</span>
+                            </div>
+                        }
                         
-                        <div class={if *selected_primary_menu == PrimaryMenuItem::Settings { "menu-item active" } else { "menu-item" }}
-                             onclick={
-                                 let on_primary_menu_select = on_primary_menu_select.clone();
-                                 Callback::from(move |_| on_primary_menu_select.emit(PrimaryMenuItem::Settings))
-                             }>
-                            <svg width="20" height="20" viewBox="0 0 24 24" fill="none">
-                                <rect x="3" y="3" width="18" height="18" rx="2" ry="2" stroke="currentColor" stroke-width="2"/>
-                                <path d="M9 9h6v6H9z" stroke="currentColor" stroke-width="2"/>
-                                <path d="M9 3v6" stroke="currentColor" stroke-width="2"/>
-                                <path d="M15 3v6" stroke="currentColor" stroke-width="2"/>
-                                <path d="M9 15v6" stroke="currentColor" stroke-width="2"/>
-                                <path d="M15 15v6" stroke="currentColor" stroke-width="2"/>
-                                <path d="M3 9h6" stroke="currentColor" stroke-width="2"/>
-                                <path d="M15 9h6" stroke="currentColor" stroke-width="2"/>
-                            </svg>
-                            <span class="menu-tooltip">{"系统管理"}</span>
-                        </div>
+                        if first_allowed(PrimaryMenuItem::Settings, &granted).is_some() {
+                            <div class={if *selected_primary_menu == PrimaryMenuItem::Settings { "menu-item active" } else { "menu-item" }}
+                                 onclick={
+                                     let on_primary_menu_select = on_primary_menu_select.clone();
+                                     Callback::from(move |_| on_primary_menu_select.emit(PrimaryMenuItem::Settings))
+                                 }>
+                                <svg width="20" height="20" viewBox="0 0 24 24" fill="none">
+                                    <rect x="3" y="3" width="18" height="18" rx="2" ry="2" stroke="currentColor" stroke-width="2"/>
+                                    <path d="M9 9h6v6H9z" stroke="currentColor" stroke-width="2"/>
+                                    <path d="M9 3v6" stroke="currentColor" stroke-width="2"/>
+                                    <path d="M15 3v6" stroke="currentColor" stroke-width="2"/>
+                                    <path d="M9 15v6" stroke="currentColor" stroke-width="2"/>
+                                    <path d="M15 15v6" stroke="currentColor" stroke-width="2"/>
+                                    <path d="M3 9h6" stroke="currentColor" stroke-width="2"/>
+                                    <path d="M15 9h6" stroke="currentColor" stroke-width="2"/>
+                                </svg>
+                                <span class="menu-tooltip">{"系统管理"}</span>
+                            </div>
+                        }
                     </nav>
                     
                     // 主题切换按钮
@@ -304,35 +572,40 @@ pub fn main_app(props: &MainAppProps) -> Html {
                             <path d="M21 12.79A9 9 0 1 1 11.21 3 7 7 0 0 0 21 12.79z"></path>
                         </svg>
                     </button>
+
+                    // 退出登录按钮
+                    <button class="logout-toggle" onclick={on_logout} title="退出登录">
+                        <svg width="18" height="18" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round">
+                            <path d="M9 21H5a2 2 0 0 1-2-2V5a2 2 0 0 1 2-2h4"></path>
+                            <polyline points="16,17 21,12 16,7"></polyline>
+                            <line x1="21" y1="12" x2="9" y2="12"></line>
+                        </svg>
+                    </button>
                 </aside>
 
                 // 中间二级菜单栏
                 <aside class="sidebar-secondary">
                     // 搜索框
                     <div class="search-container">
-                        <div class="search-box">
-                            <svg width="16" height="16" viewBox="0 0 24 24" fill="none" class="search-icon">
-                                <circle cx="11" cy="11" r="8" stroke="currentColor" stroke-width="2"/>
-                                <path d="M21 21l-4.35-4.35" stroke="currentColor" stroke-width="2"/>
-                            </svg>
-                            <input 
-                                type="text" 
-                                placeholder="搜索功能..." 
-                                value={(*search_term).clone()}
-                                onchange={on_search_change}
-                            />
-                        </div>
+                        <GlobalSearch menu_hits={on_menu_hits} on_select={on_search_select} />
                     </div>
                     
                     // 二级菜单
                     <nav class="secondary-menu">
-                        { render_secondary_menu(&selected_primary_menu, &selected_secondary_menu, &on_secondary_menu_select) }
+                        { render_secondary_menu(&selected_primary_menu, &selected_secondary_menu, &on_secondary_menu_select, &granted) }
                     </nav>
+
+                    // 连接状态和离线队列
+                    <OfflineIndicator />
                 </aside>
 
                 // 右侧内容区域
                 <section class="content-area">
-                    { render_content(&selected_primary_menu, &selected_secondary_menu) }
+                    if selected_secondary_menu.is_allowed(&granted) {
+                        { render_content(&selected_primary_menu, &selected_secondary_menu, &focus) }
+                    } else {
+                        { render_forbidden(&session, &on_reload_session) }
+                    }
                 </section>
             </main>
         </div>
@@ -340,49 +613,30 @@ pub fn main_app(props: &MainAppProps) -> Html {
     }
 }
 
-// 渲染二级菜单
+// 渲染二级菜单（无权限的菜单项置灰且不可点击）
 fn render_secondary_menu(
     primary_menu: &UseStateHandle<PrimaryMenuItem>,
     selected_secondary_menu: &UseStateHandle<SecondaryMenuItem>,
     on_secondary_menu_select: &Callback<SecondaryMenuItem>,
+    granted: &[String],
 ) -> Html {
-    let secondary_items = match **primary_menu {
-        PrimaryMenuItem::Dashboard => vec![
-            (SecondaryMenuItem::Overview, "概览", "总体数据统计"),
-            (SecondaryMenuItem::Analytics, "分析", "数据分析报告"),
-            (SecondaryMenuItem::Reports, "报表", "生成各类报表"),
-        ],
-        PrimaryMenuItem::Production => vec![
-            (SecondaryMenuItem::Orders, "订单", "生产订单管理"),
-            (SecondaryMenuItem::Schedule, "排程", "生产计划排程"),
-            (SecondaryMenuItem::Workflow, "工艺", "生产工艺流程"),
-        ],
-        PrimaryMenuItem::Inventory => vec![
-            (SecondaryMenuItem::Materials, "物料", "原材料管理"),
-            (SecondaryMenuItem::Products, "产品", "成品库存管理"),
-            (SecondaryMenuItem::Warehouse, "仓库", "仓储管理"),
-        ],
-        PrimaryMenuItem::Quality => vec![
-            (SecondaryMenuItem::Inspection, "检验", "质量检验记录"),
-            (SecondaryMenuItem::Standards, "标准", "质量标准管理"),
-            (SecondaryMenuItem::Issues, "问题", "质量问题跟踪"),
-        ],
-        PrimaryMenuItem::Settings => vec![
-            (SecondaryMenuItem::Users, "用户", "用户账户管理"),
-            (SecondaryMenuItem::Permissions, "权限", "权限角色管理"),
-            (SecondaryMenuItem::System, "系统", "系统参数设置"),
-        ],
-    };
+    let secondary_items = secondary_items(**primary_menu);
 
     html! {
         <div class="secondary-menu-list">
             { for secondary_items.iter().map(|(item, title, description)| {
-                let item_clone = item.clone();
+                let item_clone = *item;
                 let on_secondary_menu_select = on_secondary_menu_select.clone();
+                let allowed = item.is_allowed(granted);
                 html! {
-                    <div class={if **selected_secondary_menu == *item { "secondary-menu-item active" } else { "secondary-menu-item" }}
+                    <div class={classes!(
+                            "secondary-menu-item",
+                            (**selected_secondary_menu == *item).then_some("active"),
+                            (!allowed).then_some("disabled"),
+                         )}
+                         title={if allowed { "" } else { "没有访问权限" }}
                          onclick={
-                             Callback::from(move |_| on_secondary_menu_select.emit(item_clone.clone()))
+                             Callback::from(move |_| if allowed { on_secondary_menu_select.emit(item_clone) })
                          }>
                         <div class="secondary-menu-title">{title}</div>
                         <div class="secondary-menu-description">{description}</div>
@@ -393,46 +647,89 @@ fn render_secondary_menu(
     }
 }
 
+// 无权限访问时的内容区域（权限加载中或加载失败时显示对应提示）
+fn render_forbidden(session: &SessionState, on_reload: &Callback<MouseEvent>) -> Html {
+    match session {
+        SessionState::Loading => {
+            return html! {
+                <div class="content-panel">
+                    <div class="table-empty">{"加载中..."}</div>
+                </div>
+            };
+        }
+        SessionState::Failed(message) => {
+            return html! {
+                <div class="content-panel">
+                    <div class="error-banner">{message}</div>
+                    <button class="btn" onclick={on_reload.clone()}>{"重试"}</button>
+                </div>
+            };
+        }
+        SessionState::Loaded(_) => {}
+    }
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"没有访问权限"}</h2>
+                <p>{"当前账户的角色不包含该功能的权限，如需使用请联系系统管理员"}</p>
+            </div>
+        </div>
+    }
+}
+
 // 渲染不同内容区域
 fn render_content(
     selected_primary_menu: &UseStateHandle<PrimaryMenuItem>,
     selected_secondary_menu: &UseStateHandle<SecondaryMenuItem>,
+    focus: &UseStateHandle<(u32, Option<SearchTarget>)>,
 ) -> Html {
+    let (sequence, target) = (**focus).clone();
     match (**selected_primary_menu, **selected_secondary_menu) {
         (PrimaryMenuItem::Dashboard, SecondaryMenuItem::Overview) => html! {
-            <div class="content-panel">
-                <div class="panel-header">
-                    <h2>{"仪表板 - 概览"}</h2>
-                    <p>{"系统概览和关键指标"}</p>
-                </div>
-                <div class="dashboard-grid">
-                    <div class="dashboard-card">
-                        <div class="card-icon production">
-                            <svg width="32" height="32" viewBox="0 0 24 24" fill="none">
-                                <rect x="3" y="3" width="18" height="18" rx="2" stroke="currentColor" stroke-width="2"/>
-                                <path d="M9 9H15V15H9V9Z" stroke="currentColor" stroke-width="2"/>
-                            </svg>
-                        </div>
-                        <div class="card-content">
-                            <h3>{"生产订单"}</h3>
-                            <div class="card-value">{"24"}</div>
-                            <div class="card-description">{"今日活跃订单"}</div>
-                        </div>
-                    </div>
-                    <div class="dashboard-card">
-                        <div class="card-icon inventory">
-                            <svg width="32" height="32" viewBox="0 0 24 24" fill="none">
-                                <path d="M21 16V8a2 2 0 0 0-1-1.73L12 2L4 6.27A2 2 0 0 0 3 8v8a2 2 0 0 0 1 1.73L12 22l8-4.27A2 2 0 0 0 21 16z" stroke="currentColor" stroke-width="2"/>
-                            </svg>
-                        </div>
-                        <div class="card-content">
-                            <h3>{"库存状态"}</h3>
-                            <div class="card-value">{"正常"}</div>
-                            <div class="card-description">{"所有物料充足"}</div>
-                        </div>
-                    </div>
-                </div>
-            </div>
+            <OverviewPage />
+        },
+        (PrimaryMenuItem::Dashboard, SecondaryMenuItem::Analytics) => html! {
+            <AnalyticsPage />
+        },
+        (PrimaryMenuItem::Dashboard, SecondaryMenuItem::Reports) => html! {
+            <ReportsPage />
+        },
+        (PrimaryMenuItem::Production, SecondaryMenuItem::Orders) => html! {
+            <WorkOrdersPage key={sequence} focus={target.clone()} />
+        },
+        (PrimaryMenuItem::Production, SecondaryMenuItem::Schedule) => html! {
+            <SchedulePage />
+        },
+        (PrimaryMenuItem::Production, SecondaryMenuItem::Workflow) => html! {
+            <RoutingPage />
+        },
+        (PrimaryMenuItem::Inventory, SecondaryMenuItem::Materials) => html! {
+            <MaterialsPage key={sequence} focus={target.clone()} />
+        },
+        (PrimaryMenuItem::Inventory, SecondaryMenuItem::Products) => html! {
+            <ProductsPage key={sequence} focus={target.clone()} />
+        },
+        (PrimaryMenuItem::Inventory, SecondaryMenuItem::Warehouse) => html! {
+            <WarehousePage />
+        },
+        (PrimaryMenuItem::Quality, SecondaryMenuItem::Inspection) => html! {
+            <InspectionPage />
+        },
+        (PrimaryMenuItem::Quality, SecondaryMenuItem::Standards) => html! {
+            <StandardsPage />
+        },
+        (PrimaryMenuItem::Quality, SecondaryMenuItem::Issues) => html! {
+            <IssuesPage key={sequence} focus={target.clone()} />
+        },
+        (PrimaryMenuItem::Settings, SecondaryMenuItem::Users) => html! {
+            <UsersPage />
+        },
+        (PrimaryMenuItem::Settings, SecondaryMenuItem::Permissions) => html! {
+            <PermissionsPage />
+        },
+        (PrimaryMenuItem::Settings, SecondaryMenuItem::System) => html! {
+            <SettingsPage />
         },
         _ => html! {
             <div class="content-panel">
@@ -0,0 +1,199 @@
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::inventory::material::{MaterialIssue, MaterialLot};
+use mes_win_shared::production::work_order::{WorkOrder, WorkOrderQuery, WorkOrderStatus};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+use crate::ui::components::modal::Modal;
+use crate::utils::format::format_quantity;
+
+// 发料对话框属性
+#[derive(Properties, PartialEq)]
+pub struct IssueDialogProps {
+    pub lot: MaterialLot,
+    pub on_close: Callback<()>,
+    pub on_done: Callback<()>,
+}
+
+/// 加载可领料的工单（已下达和生产中）
+async fn load_open_orders() -> Result<Vec<WorkOrder>, ApiError> {
+    let mut orders = Vec::new();
+    for status in [WorkOrderStatus::InProgress, WorkOrderStatus::Released] {
+        let query = WorkOrderQuery {
+            page_size: 200,
+            status: Some(status),
+            ..WorkOrderQuery::default()
+        };
+        orders.extend(tauri::list_work_orders(query).await?.items);
+    }
+    Ok(orders)
+}
+
+// 按批次发料到工单
+#[function_component(IssueDialog)]
+pub fn issue_dialog(props: &IssueDialogProps) -> Html {
+    let issue = use_state(|| MaterialIssue {
+        work_order_id: 0,
+        material_code: props.lot.material_code.clone(),
+        lot_no: props.lot.lot_no.clone(),
+        quantity: 0.0,
+        remark: None,
+    });
+    let orders = use_state(Vec::<WorkOrder>::new);
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+
+    {
+        let orders = orders.clone();
+        let error_message = error_message.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match load_open_orders().await {
+                    Ok(data) => orders.set(data),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_order_change = {
+        let issue = issue.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*issue).clone();
+            next.work_order_id = select.value().parse().unwrap_or(0);
+            issue.set(next);
+        })
+    };
+
+    let on_quantity_input = {
+        let issue = issue.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*issue).clone();
+            next.quantity = input.value().parse().unwrap_or(0.0);
+            issue.set(next);
+        })
+    };
+
+    let on_remark_input = {
+        let issue = issue.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*issue).clone();
+            next.remark = Some(input.value()).filter(|v| !v.trim().is_empty());
+            issue.set(next);
+        })
+    };
+
+    let on_submit = {
+        let issue = issue.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let on_done = props.on_done.clone();
+        let available = props.lot.quantity;
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let mut local_errors = issue.validate();
+            if issue.quantity > available {
+                local_errors.push(FieldError {
+                    field: "quantity".to_string(),
+                    message: format!("超出批次库存（剩余 {}）", format_quantity(available)),
+                });
+            }
+            if !local_errors.is_empty() {
+                errors.set(local_errors);
+                return;
+            }
+
+            let submitted = (*issue).clone();
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            let on_done = on_done.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::issue_material(submitted).await;
+                is_submitting.set(false);
+                match result {
+                    // 离线时已保存到本机队列，按已受理处理
+                    Ok(_) | Err(ApiError::Queued { .. }) => on_done.emit(()),
+                    Err(ApiError::Validation { message, fields }) => {
+                        errors.set(fields);
+                        error_message.set(Some(message));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_cancel = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| on_close.emit(()))
+    };
+
+    let lot = &props.lot;
+    let quantity = if issue.quantity > 0.0 { issue.quantity.to_string() } else { String::new() };
+
+    html! {
+        <Modal title="发料到工单" on_close={props.on_close.clone()}>
+            <form class="data-view" onsubmit={on_submit}>
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+                <div class="detail-grid">
+                    <div class="detail-field">
+                        <div class="detail-label">{"物料"}</div>
+                        <div class="detail-value">{format!("{} {}", lot.material_code, lot.material_name)}</div>
+                    </div>
+                    <div class="detail-field">
+                        <div class="detail-label">{"批次 / 库位"}</div>
+                        <div class="detail-value">{format!("{} / {}", lot.lot_no, lot.location)}</div>
+                    </div>
+                    <div class="detail-field">
+                        <div class="detail-label">{"可用数量"}</div>
+                        <div class="detail-value">{format!("{} {}", format_quantity(lot.quantity), lot.unit)}</div>
+                    </div>
+                </div>
+                <div class="form-grid">
+                    <div class="form-field form-field-wide">
+                        <label class="required">{"工单"}</label>
+                        <select class="form-input" onchange={on_order_change}>
+                            <option value="0" selected={issue.work_order_id == 0}>{"请选择工单"}</option>
+                            { for orders.iter().map(|order| html! {
+                                <option value={order.id.to_string()} selected={issue.work_order_id == order.id}>
+                                    {format!("{} · {} · {}", order.order_no, order.product_name, order.status.label())}
+                                </option>
+                            })}
+                        </select>
+                        { field_error(&errors, "workOrderId") }
+                    </div>
+                    <div class="form-field">
+                        <label class="required">{format!("发料数量（{}）", lot.unit)}</label>
+                        <input class="form-input" type="number" min="0" step="any" value={quantity}
+                            oninput={on_quantity_input} />
+                        { field_error(&errors, "quantity") }
+                        { field_error(&errors, "lotNo") }
+                    </div>
+                    <div class="form-field">
+                        <label>{"备注"}</label>
+                        <input class="form-input" type="text" value={issue.remark.clone().unwrap_or_default()}
+                            oninput={on_remark_input} />
+                    </div>
+                </div>
+                <div class="form-actions">
+                    <button class="btn" type="button" onclick={on_cancel}>{"取消"}</button>
+                    <button class="btn btn-primary" type="submit" disabled={*is_submitting}>
+                        { if *is_submitting { "提交中..." } else { "确认发料" } }
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
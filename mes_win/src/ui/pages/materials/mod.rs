@@ -0,0 +1,273 @@
+// 原材料库存页面
+//
+// 按物料汇总显示各批次库存，支持收货入库、按批次发料到工单和批次追溯
+mod issue;
+mod receipt;
+mod trace;
+
+use yew::prelude::*;
+use mes_win_shared::inventory::material::{ExpiryState, MaterialLot, MaterialStock, MaterialStockQuery};
+use mes_win_shared::response::PageData;
+use mes_win_shared::search::SearchTarget;
+use crate::services::tauri;
+use crate::ui::components::pagination::Pagination;
+use crate::utils::format::{format_datetime, format_quantity};
+use crate::utils::time::today_days;
+use issue::IssueDialog;
+use receipt::ReceiptDialog;
+use trace::LotTraceDialog;
+
+// 当前打开的对话框
+#[derive(Clone, PartialEq)]
+enum Dialog {
+    None,
+    Receipt,
+    Issue(Box<MaterialLot>),
+    Trace(String),
+}
+
+/// 有效期标签
+fn expiry_badge(lot: &MaterialLot, today: i64) -> Html {
+    let expiry = lot.expiry_date.clone().unwrap_or_else(|| "-".to_string());
+    match lot.expiry_state(today) {
+        ExpiryState::Expired => html! { <span class="expiry expired" title="已过期">{expiry}</span> },
+        ExpiryState::Expiring => html! { <span class="expiry expiring" title="即将过期">{expiry}</span> },
+        ExpiryState::Valid => html! { <span class="expiry">{expiry}</span> },
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct MaterialsPageProps {
+    /// 全局搜索跳转的目标：物料按编码筛选库存，批次直接打开追溯
+    #[prop_or_default]
+    pub focus: Option<SearchTarget>,
+}
+
+#[function_component(MaterialsPage)]
+pub fn materials_page(props: &MaterialsPageProps) -> Html {
+    let focus_material = match &props.focus {
+        Some(SearchTarget::Material { code }) => Some(code.clone()),
+        _ => None,
+    };
+    let focus_lot = match &props.focus {
+        Some(SearchTarget::Lot { lot_no }) => Some(lot_no.clone()),
+        _ => None,
+    };
+
+    let query = use_state(|| MaterialStockQuery {
+        keyword: focus_material.clone(),
+        ..MaterialStockQuery::default()
+    });
+    let page_data = use_state(PageData::<MaterialStock>::default);
+    let keyword = use_state(|| focus_material.unwrap_or_default());
+    let trace_lot_no = use_state(|| focus_lot.clone().unwrap_or_default());
+    let dialog = use_state(|| focus_lot.map(Dialog::Trace).unwrap_or(Dialog::None));
+    let reload = use_state(|| 0u32);
+    let is_loading = use_state(|| false);
+    let error_message = use_state(|| None::<String>);
+
+    {
+        let page_data = page_data.clone();
+        let is_loading = is_loading.clone();
+        let error_message = error_message.clone();
+        use_effect_with(((*query).clone(), *reload), move |(query, _)| {
+            let query = query.clone();
+            is_loading.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_material_stock(query).await {
+                    Ok(data) => {
+                        page_data.set(data);
+                        error_message.set(None);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+                is_loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    let open_dialog = |target: Dialog| {
+        let dialog = dialog.clone();
+        Callback::from(move |_: MouseEvent| dialog.set(target.clone()))
+    };
+
+    let on_close_dialog = {
+        let dialog = dialog.clone();
+        Callback::from(move |_: ()| dialog.set(Dialog::None))
+    };
+
+    // 收货或发料完成后关闭对话框并刷新库存
+    let on_done = {
+        let dialog = dialog.clone();
+        let reload = reload.clone();
+        Callback::from(move |_: ()| {
+            dialog.set(Dialog::None);
+            reload.set(*reload + 1);
+        })
+    };
+
+    let on_keyword_input = {
+        let keyword = keyword.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            keyword.set(input.value());
+        })
+    };
+
+    let on_search = {
+        let keyword = keyword.clone();
+        let query = query.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let mut next = (*query).clone();
+            next.keyword = Some((*keyword).clone()).filter(|k| !k.trim().is_empty());
+            next.page = 1;
+            query.set(next);
+        })
+    };
+
+    let on_include_empty_change = {
+        let query = query.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*query).clone();
+            next.include_empty = input.checked();
+            next.page = 1;
+            query.set(next);
+        })
+    };
+
+    let on_trace_input = {
+        let trace_lot_no = trace_lot_no.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            trace_lot_no.set(input.value());
+        })
+    };
+
+    let on_trace_submit = {
+        let trace_lot_no = trace_lot_no.clone();
+        let dialog = dialog.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let lot_no = trace_lot_no.trim().to_string();
+            if !lot_no.is_empty() {
+                dialog.set(Dialog::Trace(lot_no));
+            }
+        })
+    };
+
+    let on_page_change = {
+        let query = query.clone();
+        Callback::from(move |page: u32| {
+            let mut next = (*query).clone();
+            next.page = page;
+            query.set(next);
+        })
+    };
+
+    let today = today_days();
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"库存管理 - 物料"}</h2>
+                <p>{"原材料批次库存、收货入库、工单发料与批次追溯"}</p>
+            </div>
+
+            <div class="data-view">
+                <div class="toolbar">
+                    <form class="toolbar-search" onsubmit={on_search}>
+                        <input class="form-input" type="text" placeholder="物料编码 / 名称 / 批次号"
+                            value={(*keyword).clone()} oninput={on_keyword_input} />
+                        <button class="btn" type="submit">{"查询"}</button>
+                        <label class="toolbar-text checkbox-label">
+                            <input type="checkbox" checked={query.include_empty} onchange={on_include_empty_change} />
+                            {"显示零库存批次"}
+                        </label>
+                    </form>
+                    <div class="toolbar-actions">
+                        <form class="toolbar-search" onsubmit={on_trace_submit}>
+                            <input class="form-input" type="text" placeholder="批次号追溯"
+                                value={(*trace_lot_no).clone()} oninput={on_trace_input} />
+                            <button class="btn" type="submit">{"追溯"}</button>
+                        </form>
+                        <button class="btn btn-primary" onclick={open_dialog(Dialog::Receipt)}>{"收货入库"}</button>
+                    </div>
+                </div>
+
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+
+                <div class="table-container">
+                    <table class="data-table">
+                        <thead>
+                            <tr>
+                                <th>{"批次号"}</th>
+                                <th>{"库位"}</th>
+                                <th class="numeric">{"数量"}</th>
+                                <th>{"有效期至"}</th>
+                                <th>{"供应商"}</th>
+                                <th>{"收货时间"}</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for page_data.items.iter().map(|stock| html! {
+                                <>
+                                    <tr class="group-row">
+                                        <td colspan="2">{format!("{} {}", stock.material_code, stock.material_name)}</td>
+                                        <td class="numeric">{format!("{} {}", format_quantity(stock.total_quantity), stock.unit)}</td>
+                                        <td colspan="4">{format!("{} 个批次", stock.lots.len())}</td>
+                                    </tr>
+                                    { for stock.lots.iter().map(|lot| html! {
+                                        <tr>
+                                            <td>{&lot.lot_no}</td>
+                                            <td>{&lot.location}</td>
+                                            <td class="numeric">{format!("{} {}", format_quantity(lot.quantity), lot.unit)}</td>
+                                            <td>{expiry_badge(lot, today)}</td>
+                                            <td>{lot.supplier.clone().unwrap_or_else(|| "-".to_string())}</td>
+                                            <td>{lot.received_at.as_deref().map(format_datetime).unwrap_or_default()}</td>
+                                            <td class="row-actions">
+                                                <button class="btn" onclick={open_dialog(Dialog::Trace(lot.lot_no.clone()))}>{"追溯"}</button>
+                                                <button class="btn" disabled={lot.quantity <= 0.0 || lot.expiry_state(today) == ExpiryState::Expired}
+                                                    onclick={open_dialog(Dialog::Issue(Box::new(lot.clone())))}>{"发料"}</button>
+                                            </td>
+                                        </tr>
+                                    })}
+                                </>
+                            })}
+                        </tbody>
+                    </table>
+                    if page_data.items.is_empty() {
+                        <div class="table-empty">
+                            { if *is_loading { "加载中..." } else { "暂无库存" } }
+                        </div>
+                    }
+                </div>
+
+                <Pagination
+                    page={page_data.page}
+                    total_pages={page_data.total_pages()}
+                    total={page_data.total}
+                    on_change={on_page_change}
+                />
+            </div>
+
+            { match (*dialog).clone() {
+                Dialog::None => html! {},
+                Dialog::Receipt => html! {
+                    <ReceiptDialog on_close={on_close_dialog} on_done={on_done} />
+                },
+                Dialog::Issue(lot) => html! {
+                    <IssueDialog lot={*lot} on_close={on_close_dialog} on_done={on_done} />
+                },
+                Dialog::Trace(lot_no) => html! {
+                    <LotTraceDialog lot_no={lot_no} on_close={on_close_dialog} />
+                },
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,174 @@
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::inventory::material::GoodsReceipt;
+use mes_win_shared::master_data::{MasterDataItem, MasterDataKind};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+use crate::ui::components::lookup::LookupInput;
+use crate::ui::components::modal::Modal;
+
+// 收货对话框属性
+#[derive(Properties, PartialEq)]
+pub struct ReceiptDialogProps {
+    pub on_close: Callback<()>,
+    pub on_done: Callback<()>,
+}
+
+/// 可选文本：空白视为未填写
+fn optional(value: String) -> Option<String> {
+    Some(value.trim().to_string()).filter(|v| !v.is_empty())
+}
+
+// 收货入库对话框
+#[function_component(ReceiptDialog)]
+pub fn receipt_dialog(props: &ReceiptDialogProps) -> Html {
+    let receipt = use_state(GoodsReceipt::default);
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+
+    let bind_input = |apply: fn(&mut GoodsReceipt, String)| {
+        let receipt = receipt.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*receipt).clone();
+            apply(&mut next, input.value());
+            receipt.set(next);
+        })
+    };
+
+    let on_material_input = {
+        let receipt = receipt.clone();
+        Callback::from(move |value: String| {
+            let mut next = (*receipt).clone();
+            next.material_code = value.trim().to_string();
+            receipt.set(next);
+        })
+    };
+
+    // 从联想列表选中物料时带出库存单位
+    let on_material_select = {
+        let receipt = receipt.clone();
+        Callback::from(move |item: MasterDataItem| {
+            let mut next = (*receipt).clone();
+            next.material_code = item.code;
+            if let Some(unit) = item.unit {
+                next.unit = unit;
+            }
+            receipt.set(next);
+        })
+    };
+
+    let on_submit = {
+        let receipt = receipt.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let on_done = props.on_done.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let local_errors = receipt.validate();
+            if !local_errors.is_empty() {
+                errors.set(local_errors);
+                return;
+            }
+
+            let submitted = (*receipt).clone();
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            let on_done = on_done.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::receive_material(submitted).await;
+                is_submitting.set(false);
+                match result {
+                    // 离线时已保存到本机队列，按已受理处理
+                    Ok(_) | Err(ApiError::Queued { .. }) => on_done.emit(()),
+                    Err(ApiError::Validation { message, fields }) => {
+                        errors.set(fields);
+                        error_message.set(Some(message));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_cancel = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| on_close.emit(()))
+    };
+
+    let quantity = if receipt.quantity > 0.0 { receipt.quantity.to_string() } else { String::new() };
+
+    html! {
+        <Modal title="收货入库" on_close={props.on_close.clone()}>
+            <form class="data-view" onsubmit={on_submit}>
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+                <div class="form-grid">
+                    <div class="form-field">
+                        <label class="required">{"物料编码"}</label>
+                        <LookupInput kind={MasterDataKind::Material} value={receipt.material_code.clone()}
+                            placeholder="输入编码或名称查找"
+                            on_input={on_material_input} on_select={on_material_select} />
+                        { field_error(&errors, "materialCode") }
+                    </div>
+                    <div class="form-field">
+                        <label>{"批次号"}</label>
+                        <input class="form-input" type="text" placeholder="留空自动生成"
+                            value={receipt.lot_no.clone().unwrap_or_default()}
+                            oninput={bind_input(|r, v| r.lot_no = optional(v))} />
+                    </div>
+                    <div class="form-field">
+                        <label class="required">{"数量"}</label>
+                        <input class="form-input" type="number" min="0" step="any" value={quantity}
+                            oninput={bind_input(|r, v| r.quantity = v.parse().unwrap_or(0.0))} />
+                        { field_error(&errors, "quantity") }
+                    </div>
+                    <div class="form-field">
+                        <label class="required">{"单位"}</label>
+                        <input class="form-input" type="text" value={receipt.unit.clone()}
+                            oninput={bind_input(|r, v| r.unit = v)} />
+                        { field_error(&errors, "unit") }
+                    </div>
+                    <div class="form-field">
+                        <label class="required">{"库位"}</label>
+                        <input class="form-input" type="text" value={receipt.location.clone()}
+                            oninput={bind_input(|r, v| r.location = v.trim().to_string())} />
+                        { field_error(&errors, "location") }
+                    </div>
+                    <div class="form-field">
+                        <label>{"有效期至"}</label>
+                        <input class="form-input" type="date"
+                            value={receipt.expiry_date.clone().unwrap_or_default()}
+                            oninput={bind_input(|r, v| r.expiry_date = optional(v))} />
+                        { field_error(&errors, "expiryDate") }
+                    </div>
+                    <div class="form-field form-field-wide">
+                        <label>{"供应商"}</label>
+                        <input class="form-input" type="text"
+                            value={receipt.supplier.clone().unwrap_or_default()}
+                            oninput={bind_input(|r, v| r.supplier = optional(v))} />
+                    </div>
+                    <div class="form-field form-field-wide">
+                        <label>{"备注"}</label>
+                        <input class="form-input" type="text"
+                            value={receipt.remark.clone().unwrap_or_default()}
+                            oninput={bind_input(|r, v| r.remark = optional(v))} />
+                    </div>
+                </div>
+                <div class="form-actions">
+                    <button class="btn" type="button" onclick={on_cancel}>{"取消"}</button>
+                    <button class="btn btn-primary" type="submit" disabled={*is_submitting}>
+                        { if *is_submitting { "提交中..." } else { "确认收货" } }
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
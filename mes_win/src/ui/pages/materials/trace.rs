@@ -0,0 +1,106 @@
+use yew::prelude::*;
+use mes_win_shared::inventory::material::LotTrace;
+use crate::services::tauri;
+use crate::ui::components::modal::Modal;
+use crate::utils::format::{format_datetime, format_quantity};
+
+// 批次追溯对话框属性
+#[derive(Properties, PartialEq)]
+pub struct LotTraceDialogProps {
+    pub lot_no: String,
+    pub on_close: Callback<()>,
+}
+
+// 批次追溯：从收货到工单消耗的全部事务
+#[function_component(LotTraceDialog)]
+pub fn lot_trace_dialog(props: &LotTraceDialogProps) -> Html {
+    let trace = use_state(|| None::<LotTrace>);
+    let error_message = use_state(|| None::<String>);
+
+    {
+        let trace = trace.clone();
+        let error_message = error_message.clone();
+        use_effect_with(props.lot_no.clone(), move |lot_no| {
+            let lot_no = lot_no.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::trace_lot(lot_no).await {
+                    Ok(data) => trace.set(Some(data)),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    let content = match ((*trace).clone(), (*error_message).clone()) {
+        (_, Some(message)) => html! { <div class="error-banner">{message}</div> },
+        (None, None) => html! { <div class="table-empty">{"加载中..."}</div> },
+        (Some(trace), None) => {
+            let lot = &trace.lot;
+            // 已消耗到的工单
+            let mut orders: Vec<String> = trace
+                .transactions
+                .iter()
+                .filter_map(|tx| tx.work_order_no.clone())
+                .collect();
+            orders.dedup();
+
+            html! {
+                <>
+                    <div class="detail-grid">
+                        <div class="detail-field">
+                            <div class="detail-label">{"物料"}</div>
+                            <div class="detail-value">{format!("{} {}", lot.material_code, lot.material_name)}</div>
+                        </div>
+                        <div class="detail-field">
+                            <div class="detail-label">{"当前库存"}</div>
+                            <div class="detail-value">{format!("{} {} @ {}", format_quantity(lot.quantity), lot.unit, lot.location)}</div>
+                        </div>
+                        <div class="detail-field">
+                            <div class="detail-label">{"供应商"}</div>
+                            <div class="detail-value">{lot.supplier.clone().unwrap_or_else(|| "-".to_string())}</div>
+                        </div>
+                        <div class="detail-field">
+                            <div class="detail-label">{"消耗工单"}</div>
+                            <div class="detail-value">
+                                { if orders.is_empty() { "-".to_string() } else { orders.join("、") } }
+                            </div>
+                        </div>
+                    </div>
+                    <div class="table-container">
+                        <table class="data-table">
+                            <thead>
+                                <tr>
+                                    <th>{"时间"}</th>
+                                    <th>{"类型"}</th>
+                                    <th class="numeric">{"数量"}</th>
+                                    <th>{"工单"}</th>
+                                    <th>{"操作人"}</th>
+                                    <th>{"备注"}</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                { for trace.transactions.iter().map(|tx| html! {
+                                    <tr>
+                                        <td>{format_datetime(&tx.occurred_at)}</td>
+                                        <td>{tx.kind.label()}</td>
+                                        <td class="numeric">{format!("{} {}", format_quantity(tx.quantity), tx.unit)}</td>
+                                        <td>{tx.work_order_no.clone().unwrap_or_else(|| "-".to_string())}</td>
+                                        <td>{tx.operator.clone().unwrap_or_else(|| "-".to_string())}</td>
+                                        <td>{tx.remark.clone().unwrap_or_default()}</td>
+                                    </tr>
+                                })}
+                            </tbody>
+                        </table>
+                    </div>
+                </>
+            }
+        }
+    };
+
+    html! {
+        <Modal title={format!("批次追溯 - {}", props.lot_no)} wide=true on_close={props.on_close.clone()}>
+            <div class="data-view">{content}</div>
+        </Modal>
+    }
+}
@@ -1,3 +1,18 @@
+pub mod analytics;
+pub mod dashboard;
+pub mod inspection;
+pub mod issues;
 pub mod login;
 pub mod main_app;
+pub mod materials;
+pub mod permissions;
+pub mod products;
 pub mod profile;
+pub mod reports;
+pub mod routing;
+pub mod schedule;
+pub mod settings;
+pub mod standards;
+pub mod users;
+pub mod warehouse;
+pub mod work_orders;
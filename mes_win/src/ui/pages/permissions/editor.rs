@@ -0,0 +1,223 @@
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::permission::{has_permission, Permission, ALL_PERMISSIONS};
+use mes_win_shared::user::Role;
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+
+// 角色编辑器属性
+#[derive(Properties, PartialEq)]
+pub struct RoleEditorProps {
+    pub role: Role,
+    pub is_new: bool,
+    /// 保存成功后返回角色编码
+    pub on_saved: Callback<String>,
+    pub on_deleted: Callback<()>,
+}
+
+/// 权限模块分组（保持 Permission::ALL 中的顺序）
+fn permission_groups() -> Vec<(&'static str, Vec<Permission>)> {
+    let mut groups: Vec<(&'static str, Vec<Permission>)> = Vec::new();
+    for permission in Permission::ALL {
+        match groups.iter_mut().find(|(group, _)| *group == permission.group()) {
+            Some((_, items)) => items.push(permission),
+            None => groups.push((permission.group(), vec![permission])),
+        }
+    }
+    groups
+}
+
+// 角色编辑器
+#[function_component(RoleEditor)]
+pub fn role_editor(props: &RoleEditorProps) -> Html {
+    let role = use_state(|| props.role.clone());
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+
+    let text_input = |apply: fn(&mut Role, String)| {
+        let role = role.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*role).clone();
+            apply(&mut next, input.value());
+            role.set(next);
+        })
+    };
+
+    // 勾选/取消某项权限；取消时一并去掉覆盖它的模块通配权限
+    let toggle = |permission: Permission| {
+        let role = role.clone();
+        Callback::from(move |_: Event| {
+            let mut next = (*role).clone();
+            if has_permission(&next.permissions, permission) {
+                let module = permission.code().split('.').next().unwrap_or_default();
+                next.permissions
+                    .retain(|code| code != permission.code() && *code != format!("{}.*", module));
+                // 去掉通配权限后保留同模块的其他权限
+                for other in Permission::ALL {
+                    let covered = other.code().starts_with(&format!("{}.", module));
+                    if covered && other != permission && !next.permissions.iter().any(|c| c == other.code()) {
+                        next.permissions.push(other.code().to_string());
+                    }
+                }
+            } else {
+                next.permissions.push(permission.code().to_string());
+            }
+            role.set(next);
+        })
+    };
+
+    let on_all_change = {
+        let role = role.clone();
+        Callback::from(move |_: Event| {
+            let mut next = (*role).clone();
+            if next.permissions.iter().any(|code| code == ALL_PERMISSIONS) {
+                next.permissions.clear();
+            } else {
+                next.permissions = vec![ALL_PERMISSIONS.to_string()];
+            }
+            role.set(next);
+        })
+    };
+
+    let on_submit = {
+        let role = role.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let is_new = props.is_new;
+        let on_saved = props.on_saved.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let local_errors = role.validate();
+            if !local_errors.is_empty() {
+                errors.set(local_errors);
+                return;
+            }
+
+            let submitted = (*role).clone();
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            let on_saved = on_saved.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = if is_new {
+                    tauri::create_role(submitted).await
+                } else {
+                    tauri::update_role(submitted).await
+                };
+                is_submitting.set(false);
+                match result {
+                    Ok(saved) => on_saved.emit(saved.code),
+                    Err(ApiError::Validation { message, fields }) => {
+                        errors.set(fields);
+                        error_message.set(Some(message));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_delete = {
+        let code = props.role.code.clone();
+        let name = props.role.name.clone();
+        let error_message = error_message.clone();
+        let on_deleted = props.on_deleted.clone();
+        Callback::from(move |_: MouseEvent| {
+            let confirmed = web_sys::window()
+                .and_then(|w| w.confirm_with_message(&format!("确定删除角色「{}」吗？", name)).ok())
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+
+            let code = code.clone();
+            let error_message = error_message.clone();
+            let on_deleted = on_deleted.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::delete_role(code).await {
+                    Ok(()) => on_deleted.emit(()),
+                    Err(ApiError::Validation { message, .. }) => error_message.set(Some(message)),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let all_granted = role.permissions.iter().any(|code| code == ALL_PERMISSIONS);
+    let title = if props.is_new { "新建角色".to_string() } else { props.role.name.clone() };
+
+    html! {
+        <form class="data-view" onsubmit={on_submit}>
+            <div class="detail-title">
+                <h3>{title}</h3>
+                if props.role.builtin {
+                    <span class="status-badge status-closed">{"内置角色"}</span>
+                }
+            </div>
+
+            if let Some(message) = (*error_message).clone() {
+                <div class="error-banner">{message}</div>
+            }
+
+            <div class="form-grid">
+                <div class="form-field">
+                    <label class="required">{"角色编码"}</label>
+                    <input class="form-input" type="text" value={role.code.clone()} disabled={!props.is_new}
+                        oninput={text_input(|r, v| r.code = v)} />
+                    { field_error(&errors, "code") }
+                </div>
+                <div class="form-field">
+                    <label class="required">{"角色名称"}</label>
+                    <input class="form-input" type="text" value={role.name.clone()}
+                        oninput={text_input(|r, v| r.name = v)} />
+                    { field_error(&errors, "name") }
+                </div>
+                <div class="form-field form-field-wide">
+                    <label>{"说明"}</label>
+                    <input class="form-input" type="text" value={role.description.clone().unwrap_or_default()}
+                        oninput={text_input(|r, v| r.description = Some(v).filter(|v| !v.trim().is_empty()))} />
+                </div>
+            </div>
+
+            <div class="permission-groups">
+                <label class="checkbox-label">
+                    <input type="checkbox" checked={all_granted} onchange={on_all_change} />
+                    {"全部权限（系统管理员）"}
+                </label>
+                { for permission_groups().into_iter().map(|(group, permissions)| html! {
+                    <div class="permission-group">
+                        <div class="permission-group-title">{group}</div>
+                        <div class="role-options">
+                            { for permissions.into_iter().map(|permission| html! {
+                                <label class="checkbox-label" title={permission.code()}>
+                                    <input type="checkbox" disabled={all_granted}
+                                        checked={has_permission(&role.permissions, permission)}
+                                        onchange={toggle(permission)} />
+                                    {permission.label()}
+                                </label>
+                            })}
+                        </div>
+                    </div>
+                })}
+                { field_error(&errors, "permissions") }
+            </div>
+
+            <div class="form-actions">
+                if !props.is_new {
+                    <button class="btn btn-danger" type="button" disabled={props.role.builtin} onclick={on_delete}>
+                        {"删除角色"}
+                    </button>
+                }
+                <button class="btn btn-primary" type="submit" disabled={*is_submitting}>
+                    { if *is_submitting { "保存中..." } else { "保存" } }
+                </button>
+            </div>
+        </form>
+    }
+}
@@ -0,0 +1,140 @@
+// 权限角色管理页面
+//
+// 左侧角色列表，右侧编辑角色名称和按模块分组的功能权限；
+// 权限修改后对重新登录（或token刷新）的用户生效
+mod editor;
+
+use yew::prelude::*;
+use mes_win_shared::user::Role;
+use crate::services::tauri;
+use editor::RoleEditor;
+
+// 右侧编辑区内容
+#[derive(Clone, PartialEq)]
+enum Selection {
+    None,
+    Existing(String),
+    New,
+}
+
+#[function_component(PermissionsPage)]
+pub fn permissions_page() -> Html {
+    let roles = use_state(Vec::<Role>::new);
+    let selection = use_state(|| Selection::None);
+    let editor_key = use_state(|| 0u32);
+    let reload = use_state(|| 0u32);
+    let error_message = use_state(|| None::<String>);
+
+    {
+        let roles = roles.clone();
+        let error_message = error_message.clone();
+        use_effect_with(*reload, move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_roles().await {
+                    Ok(list) => {
+                        roles.set(list);
+                        error_message.set(None);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    // 切换角色时重建编辑器，丢弃未保存的修改
+    let select = {
+        let selection = selection.clone();
+        let editor_key = editor_key.clone();
+        Callback::from(move |next: Selection| {
+            selection.set(next);
+            editor_key.set(*editor_key + 1);
+        })
+    };
+
+    let on_create = {
+        let select = select.clone();
+        Callback::from(move |_: MouseEvent| select.emit(Selection::New))
+    };
+
+    let on_saved = {
+        let select = select.clone();
+        let reload = reload.clone();
+        Callback::from(move |code: String| {
+            select.emit(Selection::Existing(code));
+            reload.set(*reload + 1);
+        })
+    };
+
+    let on_deleted = {
+        let select = select.clone();
+        let reload = reload.clone();
+        Callback::from(move |_: ()| {
+            select.emit(Selection::None);
+            reload.set(*reload + 1);
+        })
+    };
+
+    let editing = match &*selection {
+        Selection::None => None,
+        Selection::New => Some((Role::default(), true)),
+        Selection::Existing(code) => roles.iter().find(|role| role.code == *code).map(|role| (role.clone(), false)),
+    };
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"系统设置 - 权限"}</h2>
+                <p>{"维护角色及其功能权限，用户的菜单和操作按所属角色的权限开放"}</p>
+            </div>
+
+            if let Some(message) = (*error_message).clone() {
+                <div class="error-banner">{message}</div>
+            }
+
+            <div class="split-view">
+                <div class="split-list">
+                    <button class="btn btn-primary" onclick={on_create}>{"新建角色"}</button>
+                    <div class="list-items">
+                        { for roles.iter().map(|role| {
+                            let code = role.code.clone();
+                            let select = select.clone();
+                            let active = *selection == Selection::Existing(role.code.clone());
+                            html! {
+                                <div class={classes!("list-item", active.then_some("active"))}
+                                    onclick={Callback::from(move |_: MouseEvent| select.emit(Selection::Existing(code.clone())))}>
+                                    <div class="list-item-title">
+                                        {&role.name}
+                                        if role.builtin {
+                                            <span class="status-badge status-closed">{"内置"}</span>
+                                        }
+                                    </div>
+                                    <div class="list-item-description">
+                                        {format!("{} · {} 项权限", role.code, role.permissions.len())}
+                                    </div>
+                                </div>
+                            }
+                        })}
+                        if roles.is_empty() {
+                            <div class="table-empty">{"暂无角色"}</div>
+                        }
+                    </div>
+                </div>
+
+                <div class="split-detail">
+                    if let Some((role, is_new)) = editing {
+                        <RoleEditor
+                            key={*editor_key}
+                            role={role}
+                            is_new={is_new}
+                            on_saved={on_saved}
+                            on_deleted={on_deleted}
+                        />
+                    } else {
+                        <div class="table-empty">{"选择左侧的角色，或新建角色"}</div>
+                    }
+                </div>
+            </div>
+        </div>
+    }
+}
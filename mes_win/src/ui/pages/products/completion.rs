@@ -0,0 +1,218 @@
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::inventory::finished_goods::CompletionRegistration;
+use mes_win_shared::production::work_order::{WorkOrder, WorkOrderQuery, WorkOrderStatus};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+use crate::ui::components::modal::Modal;
+use crate::utils::format::format_quantity;
+use super::parse_serials;
+
+// 完工入库对话框属性
+#[derive(Properties, PartialEq)]
+pub struct CompletionDialogProps {
+    pub on_close: Callback<()>,
+    pub on_done: Callback<()>,
+}
+
+// 完工入库登记：选择生产中的工单，录入完工数量、批次和序列号
+#[function_component(CompletionDialog)]
+pub fn completion_dialog(props: &CompletionDialogProps) -> Html {
+    let registration = use_state(CompletionRegistration::default);
+    let serials_text = use_state(String::new);
+    let orders = use_state(Vec::<WorkOrder>::new);
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+
+    // 只有生产中的工单可以报工入库
+    {
+        let orders = orders.clone();
+        let error_message = error_message.clone();
+        use_effect_with((), move |_| {
+            let query = WorkOrderQuery {
+                page_size: 200,
+                status: Some(WorkOrderStatus::InProgress),
+                ..WorkOrderQuery::default()
+            };
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_work_orders(query).await {
+                    Ok(data) => orders.set(data.items),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    let selected = orders.iter().find(|o| o.id == registration.work_order_id).cloned();
+
+    let on_order_change = {
+        let registration = registration.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*registration).clone();
+            next.work_order_id = select.value().parse().unwrap_or(0);
+            registration.set(next);
+        })
+    };
+
+    let on_quantity_input = {
+        let registration = registration.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*registration).clone();
+            next.quantity = input.value().parse().unwrap_or(0.0);
+            registration.set(next);
+        })
+    };
+
+    let on_lot_input = {
+        let registration = registration.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*registration).clone();
+            next.lot_no = Some(input.value()).filter(|v| !v.trim().is_empty());
+            registration.set(next);
+        })
+    };
+
+    let on_location_input = {
+        let registration = registration.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*registration).clone();
+            next.location = Some(input.value()).filter(|v| !v.trim().is_empty());
+            registration.set(next);
+        })
+    };
+
+    let on_serials_input = {
+        let serials_text = serials_text.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            serials_text.set(input.value());
+        })
+    };
+
+    let on_submit = {
+        let registration = registration.clone();
+        let serials_text = serials_text.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let on_done = props.on_done.clone();
+        let remaining = selected.as_ref().map(|o| o.quantity - o.completed_quantity);
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let submitted = CompletionRegistration {
+                serials: parse_serials(&serials_text),
+                ..(*registration).clone()
+            };
+            let mut local_errors = submitted.validate();
+            if let Some(remaining) = remaining {
+                if submitted.quantity > remaining {
+                    local_errors.push(FieldError {
+                        field: "quantity".to_string(),
+                        message: format!("超出工单剩余数量（{}）", format_quantity(remaining)),
+                    });
+                }
+            }
+            if !local_errors.is_empty() {
+                errors.set(local_errors);
+                return;
+            }
+
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            let on_done = on_done.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::register_completion(submitted).await;
+                is_submitting.set(false);
+                match result {
+                    // 离线时已保存到本机队列，按已受理处理
+                    Ok(_) | Err(ApiError::Queued { .. }) => on_done.emit(()),
+                    Err(ApiError::Validation { message, fields }) => {
+                        errors.set(fields);
+                        error_message.set(Some(message));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_cancel = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| on_close.emit(()))
+    };
+
+    let quantity = if registration.quantity > 0.0 { registration.quantity.to_string() } else { String::new() };
+    let serial_count = parse_serials(&serials_text).len();
+
+    html! {
+        <Modal title="完工入库" on_close={props.on_close.clone()}>
+            <form class="data-view" onsubmit={on_submit}>
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+                <div class="form-grid">
+                    <div class="form-field form-field-wide">
+                        <label class="required">{"工单"}</label>
+                        <select class="form-input" onchange={on_order_change}>
+                            <option value="0" selected={registration.work_order_id == 0}>{"请选择生产中的工单"}</option>
+                            { for orders.iter().map(|order| html! {
+                                <option value={order.id.to_string()} selected={registration.work_order_id == order.id}>
+                                    {format!(
+                                        "{} · {} · 已完工 {}/{} {}",
+                                        order.order_no,
+                                        order.product_name,
+                                        format_quantity(order.completed_quantity),
+                                        format_quantity(order.quantity),
+                                        order.unit
+                                    )}
+                                </option>
+                            })}
+                        </select>
+                        { field_error(&errors, "workOrderId") }
+                    </div>
+                    <div class="form-field">
+                        <label class="required">
+                            { format!("完工数量（{}）", selected.as_ref().map(|o| o.unit.as_str()).unwrap_or("件")) }
+                        </label>
+                        <input class="form-input" type="number" min="0" step="any" value={quantity}
+                            oninput={on_quantity_input} />
+                        { field_error(&errors, "quantity") }
+                    </div>
+                    <div class="form-field">
+                        <label>{"批次号"}</label>
+                        <input class="form-input" type="text" placeholder="留空自动生成"
+                            value={registration.lot_no.clone().unwrap_or_default()} oninput={on_lot_input} />
+                        { field_error(&errors, "lotNo") }
+                    </div>
+                    <div class="form-field">
+                        <label>{"入库库位"}</label>
+                        <input class="form-input" type="text"
+                            value={registration.location.clone().unwrap_or_default()} oninput={on_location_input} />
+                    </div>
+                    <div class="form-field form-field-wide">
+                        <label>{format!("序列号（每行一个，已录入 {} 个）", serial_count)}</label>
+                        <textarea class="form-input" rows="5" placeholder="按序列号管理的产品需逐件录入"
+                            value={(*serials_text).clone()} oninput={on_serials_input} />
+                        { field_error(&errors, "serials") }
+                    </div>
+                </div>
+                <div class="form-actions">
+                    <button class="btn" type="button" onclick={on_cancel}>{"取消"}</button>
+                    <button class="btn btn-primary" type="submit" disabled={*is_submitting}>
+                        { if *is_submitting { "提交中..." } else { "确认入库" } }
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
@@ -0,0 +1,296 @@
+// 成品库存页面
+//
+// 按SKU显示成品库存，支持完工入库登记、发货出库和序列号追溯
+mod completion;
+mod shipment;
+
+use yew::prelude::*;
+use mes_win_shared::inventory::finished_goods::{ProductStock, ProductStockQuery, SerialRecord};
+use mes_win_shared::response::PageData;
+use mes_win_shared::search::SearchTarget;
+use crate::services::tauri;
+use crate::ui::components::pagination::Pagination;
+use crate::utils::format::{format_datetime, format_quantity};
+use completion::CompletionDialog;
+use shipment::ShipmentDialog;
+
+/// 解析多行输入的序列号（按换行、逗号或空格分隔）
+fn parse_serials(text: &str) -> Vec<String> {
+    text.split(|c: char| c == '\n' || c == ',' || c == '，' || c.is_whitespace())
+        .map(|serial| serial.trim().to_string())
+        .filter(|serial| !serial.is_empty())
+        .collect()
+}
+
+// 当前打开的对话框
+#[derive(Clone, PartialEq)]
+enum Dialog {
+    None,
+    Completion,
+    Shipment(Option<Box<ProductStock>>),
+}
+
+/// 序列号档案卡片
+fn serial_card(record: &SerialRecord) -> Html {
+    let field = |label: &str, value: String| html! {
+        <div class="detail-field">
+            <div class="detail-label">{label.to_string()}</div>
+            <div class="detail-value">{value}</div>
+        </div>
+    };
+    let or_dash = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+
+    html! {
+        <div class="detail-card">
+            <div class="detail-title">
+                <h3>{format!("序列号 {}", record.serial_no)}</h3>
+                <span class="status-badge">{record.status.label()}</span>
+            </div>
+            <div class="detail-grid">
+                { field("产品", format!("{} {}", record.sku, record.product_name)) }
+                { field("生产工单", record.work_order_no.clone()) }
+                { field("产线", or_dash(&record.line)) }
+                { field("操作员", or_dash(&record.operator)) }
+                { field("生产时间", format_datetime(&record.produced_at)) }
+                { field("批次", or_dash(&record.lot_no)) }
+                { field("客户", or_dash(&record.customer)) }
+                { field("发货时间", record.shipped_at.as_deref().map(format_datetime).unwrap_or_else(|| "-".to_string())) }
+            </div>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ProductsPageProps {
+    /// 全局搜索跳转的目标，为序列号时直接追溯
+    #[prop_or_default]
+    pub focus: Option<SearchTarget>,
+}
+
+#[function_component(ProductsPage)]
+pub fn products_page(props: &ProductsPageProps) -> Html {
+    let focus_serial = match &props.focus {
+        Some(SearchTarget::Serial { serial_no }) => Some(serial_no.clone()),
+        _ => None,
+    };
+
+    let query = use_state(ProductStockQuery::default);
+    let page_data = use_state(PageData::<ProductStock>::default);
+    let keyword = use_state(String::new);
+    let serial_input = use_state(|| focus_serial.clone().unwrap_or_default());
+    let serial_record = use_state(|| None::<SerialRecord>);
+    let dialog = use_state(|| Dialog::None);
+    let reload = use_state(|| 0u32);
+    let is_loading = use_state(|| false);
+    let error_message = use_state(|| None::<String>);
+
+    {
+        let page_data = page_data.clone();
+        let is_loading = is_loading.clone();
+        let error_message = error_message.clone();
+        use_effect_with(((*query).clone(), *reload), move |(query, _)| {
+            let query = query.clone();
+            is_loading.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_product_stock(query).await {
+                    Ok(data) => {
+                        page_data.set(data);
+                        error_message.set(None);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+                is_loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    let open_dialog = |target: Dialog| {
+        let dialog = dialog.clone();
+        Callback::from(move |_: MouseEvent| dialog.set(target.clone()))
+    };
+
+    let on_close_dialog = {
+        let dialog = dialog.clone();
+        Callback::from(move |_: ()| dialog.set(Dialog::None))
+    };
+
+    let on_done = {
+        let dialog = dialog.clone();
+        let reload = reload.clone();
+        Callback::from(move |_: ()| {
+            dialog.set(Dialog::None);
+            reload.set(*reload + 1);
+        })
+    };
+
+    let on_keyword_input = {
+        let keyword = keyword.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            keyword.set(input.value());
+        })
+    };
+
+    let on_search = {
+        let keyword = keyword.clone();
+        let query = query.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let mut next = (*query).clone();
+            next.keyword = Some((*keyword).clone()).filter(|k| !k.trim().is_empty());
+            next.page = 1;
+            query.set(next);
+        })
+    };
+
+    let on_serial_input = {
+        let serial_input = serial_input.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            serial_input.set(input.value());
+        })
+    };
+
+    // 序列号追溯
+    let find_serial = {
+        let serial_record = serial_record.clone();
+        let error_message = error_message.clone();
+        move |serial: String| {
+            if serial.is_empty() {
+                serial_record.set(None);
+                return;
+            }
+            let serial_record = serial_record.clone();
+            let error_message = error_message.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::find_serial(serial).await {
+                    Ok(record) => {
+                        serial_record.set(Some(record));
+                        error_message.set(None);
+                    }
+                    Err(error) => {
+                        serial_record.set(None);
+                        error_message.set(Some(error.user_message()));
+                    }
+                }
+            });
+        }
+    };
+
+    // 从全局搜索跳转过来时直接追溯
+    {
+        let find_serial = find_serial.clone();
+        use_effect_with((), move |_| {
+            if let Some(serial) = focus_serial {
+                find_serial(serial);
+            }
+            || ()
+        });
+    }
+
+    let on_serial_search = {
+        let serial_input = serial_input.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            find_serial(serial_input.trim().to_string());
+        })
+    };
+
+    let on_page_change = {
+        let query = query.clone();
+        Callback::from(move |page: u32| {
+            let mut next = (*query).clone();
+            next.page = page;
+            query.set(next);
+        })
+    };
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"库存管理 - 产品"}</h2>
+                <p>{"成品库存、完工入库、发货出库与序列号追溯"}</p>
+            </div>
+
+            <div class="data-view">
+                <div class="toolbar">
+                    <form class="toolbar-search" onsubmit={on_search}>
+                        <input class="form-input" type="text" placeholder="SKU / 产品名称"
+                            value={(*keyword).clone()} oninput={on_keyword_input} />
+                        <button class="btn" type="submit">{"查询"}</button>
+                    </form>
+                    <div class="toolbar-actions">
+                        <form class="toolbar-search" onsubmit={on_serial_search}>
+                            <input class="form-input" type="text" placeholder="序列号"
+                                value={(*serial_input).clone()} oninput={on_serial_input} />
+                            <button class="btn" type="submit">{"追溯"}</button>
+                        </form>
+                        <button class="btn" onclick={open_dialog(Dialog::Shipment(None))}>{"发货出库"}</button>
+                        <button class="btn btn-primary" onclick={open_dialog(Dialog::Completion)}>{"完工入库"}</button>
+                    </div>
+                </div>
+
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+
+                if let Some(record) = (*serial_record).clone() {
+                    { serial_card(&record) }
+                }
+
+                <div class="table-container">
+                    <table class="data-table">
+                        <thead>
+                            <tr>
+                                <th>{"SKU"}</th>
+                                <th>{"产品名称"}</th>
+                                <th class="numeric">{"在库数量"}</th>
+                                <th>{"管理方式"}</th>
+                                <th>{"更新时间"}</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for page_data.items.iter().map(|stock| html! {
+                                <tr>
+                                    <td>{&stock.sku}</td>
+                                    <td>{&stock.product_name}</td>
+                                    <td class="numeric">{format!("{} {}", format_quantity(stock.on_hand), stock.unit)}</td>
+                                    <td>{ if stock.serial_tracked { "序列号" } else { "批次" } }</td>
+                                    <td>{stock.updated_at.as_deref().map(format_datetime).unwrap_or_default()}</td>
+                                    <td class="row-actions">
+                                        <button class="btn" disabled={stock.on_hand <= 0.0}
+                                            onclick={open_dialog(Dialog::Shipment(Some(Box::new(stock.clone()))))}>{"发货"}</button>
+                                    </td>
+                                </tr>
+                            })}
+                        </tbody>
+                    </table>
+                    if page_data.items.is_empty() {
+                        <div class="table-empty">
+                            { if *is_loading { "加载中..." } else { "暂无成品库存" } }
+                        </div>
+                    }
+                </div>
+
+                <Pagination
+                    page={page_data.page}
+                    total_pages={page_data.total_pages()}
+                    total={page_data.total}
+                    on_change={on_page_change}
+                />
+            </div>
+
+            { match (*dialog).clone() {
+                Dialog::None => html! {},
+                Dialog::Completion => html! {
+                    <CompletionDialog on_close={on_close_dialog} on_done={on_done} />
+                },
+                Dialog::Shipment(stock) => html! {
+                    <ShipmentDialog stock={stock.map(|s| *s)} on_close={on_close_dialog} on_done={on_done} />
+                },
+            }}
+        </div>
+    }
+}
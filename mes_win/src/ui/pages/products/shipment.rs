@@ -0,0 +1,180 @@
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::inventory::finished_goods::{ProductStock, Shipment};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+use crate::ui::components::modal::Modal;
+use crate::utils::format::format_quantity;
+use super::parse_serials;
+
+// 发货对话框属性
+#[derive(Properties, PartialEq)]
+pub struct ShipmentDialogProps {
+    /// 从库存行发起时预填产品
+    #[prop_or_default]
+    pub stock: Option<ProductStock>,
+    pub on_close: Callback<()>,
+    pub on_done: Callback<()>,
+}
+
+// 发货出库：扣减成品库存，按序列号管理的产品需指定发出的序列号
+#[function_component(ShipmentDialog)]
+pub fn shipment_dialog(props: &ShipmentDialogProps) -> Html {
+    let shipment = use_state(|| Shipment {
+        sku: props.stock.as_ref().map(|s| s.sku.clone()).unwrap_or_default(),
+        ..Shipment::default()
+    });
+    let serials_text = use_state(String::new);
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+
+    let text_input = |apply: fn(&mut Shipment, String)| {
+        let shipment = shipment.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*shipment).clone();
+            apply(&mut next, input.value());
+            shipment.set(next);
+        })
+    };
+
+    let on_serials_input = {
+        let serials_text = serials_text.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            serials_text.set(input.value());
+        })
+    };
+
+    let on_submit = {
+        let shipment = shipment.clone();
+        let serials_text = serials_text.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let on_done = props.on_done.clone();
+        let stock = props.stock.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let submitted = Shipment {
+                serials: parse_serials(&serials_text),
+                ..(*shipment).clone()
+            };
+            let mut local_errors = submitted.validate();
+            if let Some(stock) = &stock {
+                if submitted.quantity > stock.on_hand {
+                    local_errors.push(FieldError {
+                        field: "quantity".to_string(),
+                        message: format!("超出在库数量（{}）", format_quantity(stock.on_hand)),
+                    });
+                }
+                if stock.serial_tracked && submitted.serials.is_empty() {
+                    local_errors.push(FieldError {
+                        field: "serials".to_string(),
+                        message: "该产品按序列号管理，请录入发货序列号".to_string(),
+                    });
+                }
+            }
+            if !local_errors.is_empty() {
+                errors.set(local_errors);
+                return;
+            }
+
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            let on_done = on_done.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::ship_products(submitted).await;
+                is_submitting.set(false);
+                match result {
+                    // 离线时已保存到本机队列，按已受理处理
+                    Ok(_) | Err(ApiError::Queued { .. }) => on_done.emit(()),
+                    Err(ApiError::Validation { message, fields }) => {
+                        errors.set(fields);
+                        error_message.set(Some(message));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_cancel = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| on_close.emit(()))
+    };
+
+    let quantity = if shipment.quantity > 0.0 { shipment.quantity.to_string() } else { String::new() };
+    let unit = props.stock.as_ref().map(|s| s.unit.as_str()).unwrap_or("件");
+
+    html! {
+        <Modal title="发货出库" on_close={props.on_close.clone()}>
+            <form class="data-view" onsubmit={on_submit}>
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+                if let Some(stock) = &props.stock {
+                    <div class="detail-grid">
+                        <div class="detail-field">
+                            <div class="detail-label">{"产品"}</div>
+                            <div class="detail-value">{format!("{} {}", stock.sku, stock.product_name)}</div>
+                        </div>
+                        <div class="detail-field">
+                            <div class="detail-label">{"在库数量"}</div>
+                            <div class="detail-value">{format!("{} {}", format_quantity(stock.on_hand), stock.unit)}</div>
+                        </div>
+                    </div>
+                }
+                <div class="form-grid">
+                    if props.stock.is_none() {
+                        <div class="form-field">
+                            <label class="required">{"SKU"}</label>
+                            <input class="form-input" type="text" value={shipment.sku.clone()}
+                                oninput={text_input(|s, v| s.sku = v)} />
+                            { field_error(&errors, "sku") }
+                        </div>
+                    }
+                    <div class="form-field">
+                        <label class="required">{format!("发货数量（{}）", unit)}</label>
+                        <input class="form-input" type="number" min="0" step="any" value={quantity}
+                            oninput={text_input(|s, v| s.quantity = v.parse().unwrap_or(0.0))} />
+                        { field_error(&errors, "quantity") }
+                    </div>
+                    <div class="form-field">
+                        <label class="required">{"客户"}</label>
+                        <input class="form-input" type="text" value={shipment.customer.clone()}
+                            oninput={text_input(|s, v| s.customer = v)} />
+                        { field_error(&errors, "customer") }
+                    </div>
+                    <div class="form-field">
+                        <label>{"发货单号"}</label>
+                        <input class="form-input" type="text" value={shipment.reference_no.clone().unwrap_or_default()}
+                            oninput={text_input(|s, v| s.reference_no = Some(v).filter(|v| !v.trim().is_empty()))} />
+                    </div>
+                    <div class="form-field form-field-wide">
+                        <label>{"序列号（每行一个）"}</label>
+                        <textarea class="form-input" rows="4" value={(*serials_text).clone()}
+                            oninput={on_serials_input} />
+                        { field_error(&errors, "serials") }
+                    </div>
+                    <div class="form-field form-field-wide">
+                        <label>{"备注"}</label>
+                        <input class="form-input" type="text" value={shipment.remark.clone().unwrap_or_default()}
+                            oninput={text_input(|s, v| s.remark = Some(v).filter(|v| !v.trim().is_empty()))} />
+                    </div>
+                </div>
+                <div class="form-actions">
+                    <button class="btn" type="button" onclick={on_cancel}>{"取消"}</button>
+                    <button class="btn btn-primary" type="submit" disabled={*is_submitting}>
+                        { if *is_submitting { "提交中..." } else { "确认发货" } }
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
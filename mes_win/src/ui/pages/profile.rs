@@ -1,26 +1,14 @@
 use yew::prelude::*;
-use wasm_bindgen::prelude::*;
 use gloo_timers::future::TimeoutFuture;
-
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
-    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
-}
-
-// 检查是否在Tauri环境中运行
-#[allow(dead_code)]
-fn is_tauri_environment() -> bool {
-    web_sys::window()
-        .and_then(|w| w.get("__TAURI__"))
-        .and_then(|t| t.dyn_into::<web_sys::js_sys::Object>().ok())
-        .is_some()
-}
+use crate::services::tauri::{self, is_tauri_environment};
 
 // 个人中心组件属性
 #[derive(Properties, PartialEq)]
 pub struct ProfileProps {
     pub username: String,
+    /// 角色名称
+    #[prop_or_default]
+    pub roles: Vec<String>,
     pub is_visible: bool,
     pub on_close: Callback<()>,
     pub on_logout: Callback<()>,
@@ -44,13 +32,11 @@ pub fn profile_panel(props: &ProfileProps) -> Html {
             wasm_bindgen_futures::spawn_local(async move {
                 if is_tauri_environment() {
                     // Tauri环境：调用后端API
-                    let result = invoke("logout", serde_wasm_bindgen::to_value(&()).unwrap()).await;
-                    
-                    match serde_wasm_bindgen::from_value::<Result<String, String>>(result) {
-                        Ok(Ok(_)) => {
+                    match tauri::logout().await {
+                        Ok(_) => {
                             web_sys::console::log_1(&"用户退出登录成功".into());
                         }
-                        _ => {
+                        Err(_) => {
                             web_sys::console::log_1(&"退出登录失败，但继续执行本地清理".into());
                         }
                     }
@@ -102,6 +88,9 @@ pub fn profile_panel(props: &ProfileProps) -> Html {
 
     web_sys::console::log_1(&"ProfilePanel: visible, rendering panel".into());
 
+    let roles = if props.roles.is_empty() { "未分配角色".to_string() } else { props.roles.join("、") };
+    let primary_role = props.roles.first().cloned().unwrap_or_else(|| "未分配角色".to_string());
+
     html! {
         <div class="profile-overlay" onclick={on_panel_close} style="background-color: rgba(255, 0, 0, 0.8); z-index: 99999;">
             <div class="profile-panel" onclick={on_panel_click} style="background: white; border: 3px solid red;">
@@ -111,7 +100,7 @@ pub fn profile_panel(props: &ProfileProps) -> Html {
                     </div>
                     <div class="profile-info">
                         <h3 class="profile-username">{ &props.username }</h3>
-                        <p class="profile-role">{ "管理员" }</p>
+                        <p class="profile-role">{ primary_role }</p>
                     </div>
                     <button class="profile-close-btn" onclick={on_close_btn_click}>
                         <svg width="20" height="20" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
@@ -130,7 +119,7 @@ pub fn profile_panel(props: &ProfileProps) -> Html {
                         </div>
                         <div class="profile-item">
                             <span class="profile-label">{ "角色:" }</span>
-                            <span class="profile-value">{ "系统管理员" }</span>
+                            <span class="profile-value">{ roles }</span>
                         </div>
                         <div class="profile-item">
                             <span class="profile-label">{ "登录时间:" }</span>
@@ -0,0 +1,358 @@
+// 报表页面
+//
+// 参数表单由报表定义生成，先预览再导出；导出文件写入用户在系统对话框中选择的文件夹
+use std::collections::BTreeMap;
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::report::{
+    ColumnKind, ExportFormat, ExportResult, ParamKind, ReportDefinition, ReportParam, ReportRequest,
+    ReportTable,
+};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+
+#[function_component(ReportsPage)]
+pub fn reports_page() -> Html {
+    let definitions = use_state(Vec::<ReportDefinition>::new);
+    let selected = use_state(|| 0usize);
+    let params = use_state(BTreeMap::<String, String>::new);
+    let folder = use_state(String::new);
+    let table = use_state(|| None::<ReportTable>);
+    let exported = use_state(|| None::<ExportResult>);
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let is_running = use_state(|| false);
+
+    {
+        let definitions = definitions.clone();
+        let folder = folder.clone();
+        let error_message = error_message.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_report_definitions().await {
+                    Ok(list) => definitions.set(list),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+                // 默认导出到文档目录，取不到时留空由用户选择
+                if let Ok(path) = tauri::get_default_export_folder().await {
+                    folder.set(path);
+                }
+            });
+            || ()
+        });
+    }
+
+    let definition = definitions.get(*selected).cloned();
+
+    let on_report_change = {
+        let selected = selected.clone();
+        let params = params.clone();
+        let table = table.clone();
+        let exported = exported.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            selected.set(select.value().parse().unwrap_or(0));
+            params.set(BTreeMap::new());
+            table.set(None);
+            exported.set(None);
+            errors.set(Vec::new());
+            error_message.set(None);
+        })
+    };
+
+    let on_param_change = {
+        let params = params.clone();
+        Callback::from(move |(key, value): (String, String)| {
+            let mut next = (*params).clone();
+            next.insert(key, value);
+            params.set(next);
+        })
+    };
+
+    let on_pick_folder = {
+        let folder = folder.clone();
+        let error_message = error_message.clone();
+        Callback::from(move |_: MouseEvent| {
+            let folder = folder.clone();
+            let error_message = error_message.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::pick_export_folder(&folder).await {
+                    Ok(Some(path)) => folder.set(path),
+                    Ok(None) => {}
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    // 本地校验，通过时返回报表请求
+    let prepare = {
+        let definition = definition.clone();
+        let params = params.clone();
+        let errors = errors.clone();
+        move || -> Option<ReportRequest> {
+            let definition = definition.clone()?;
+            let local_errors = definition.validate(&params);
+            errors.set(local_errors.clone());
+            local_errors.is_empty().then(|| ReportRequest {
+                kind: definition.kind,
+                params: (*params).clone(),
+            })
+        }
+    };
+
+    let on_preview = {
+        let prepare = prepare.clone();
+        let table = table.clone();
+        let exported = exported.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let is_running = is_running.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let Some(request) = prepare() else { return };
+
+            let table = table.clone();
+            let exported = exported.clone();
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let is_running = is_running.clone();
+            is_running.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::run_report(request).await;
+                is_running.set(false);
+                exported.set(None);
+                match result {
+                    Ok(data) => {
+                        table.set(Some(data));
+                        error_message.set(None);
+                    }
+                    Err(ApiError::Validation { message, fields }) => {
+                        errors.set(fields);
+                        error_message.set(Some(message));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_export = {
+        let prepare = prepare.clone();
+        let folder = folder.clone();
+        let exported = exported.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let is_running = is_running.clone();
+        move |format: ExportFormat| {
+            let prepare = prepare.clone();
+            let folder = folder.clone();
+            let exported = exported.clone();
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let is_running = is_running.clone();
+            Callback::from(move |_: MouseEvent| {
+                let Some(request) = prepare() else { return };
+
+                let target = (*folder).clone();
+                let exported = exported.clone();
+                let errors = errors.clone();
+                let error_message = error_message.clone();
+                let is_running = is_running.clone();
+                is_running.set(true);
+                wasm_bindgen_futures::spawn_local(async move {
+                    let result = tauri::export_report(request, format, target).await;
+                    is_running.set(false);
+                    match result {
+                        Ok(result) => {
+                            exported.set(Some(result));
+                            error_message.set(None);
+                        }
+                        Err(ApiError::Validation { message, fields }) => {
+                            errors.set(fields);
+                            error_message.set(Some(message));
+                        }
+                        Err(error) => error_message.set(Some(error.user_message())),
+                    }
+                });
+            })
+        }
+    };
+
+    let on_reveal = {
+        let exported = exported.clone();
+        let error_message = error_message.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(result) = (*exported).clone() {
+                let error_message = error_message.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    if let Err(error) = tauri::reveal_export(result.path).await {
+                        error_message.set(Some(error.user_message()));
+                    }
+                });
+            }
+        })
+    };
+
+    let form = match definition.as_ref() {
+        None => html! {},
+        Some(definition) => html! {
+            <form class="data-view" onsubmit={on_preview}>
+                <p class="report-description">{&definition.description}</p>
+                <div class="form-grid">
+                    { for definition.params.iter().map(|param| {
+                        let value = params.get(&param.key).cloned().unwrap_or_default();
+                        html! {
+                            <div class="form-field">
+                                <label class={classes!(param.required.then_some("required"))}>{&param.label}</label>
+                                { param_input(param, value, on_param_change.clone()) }
+                                { field_error(&errors, &param.key) }
+                            </div>
+                        }
+                    })}
+                    <div class="form-field form-field-wide">
+                        <label>{"导出文件夹"}</label>
+                        <div class="folder-picker">
+                            <input class="form-input" type="text" readonly=true placeholder="请选择导出文件夹"
+                                value={(*folder).clone()} />
+                            <button class="btn" type="button" onclick={on_pick_folder}>{"选择..."}</button>
+                        </div>
+                        { field_error(&errors, "folder") }
+                    </div>
+                </div>
+                <div class="form-actions">
+                    <button class="btn btn-primary" type="submit" disabled={*is_running}>
+                        { if *is_running { "运行中..." } else { "预览" } }
+                    </button>
+                    { for ExportFormat::ALL.iter().map(|format| html! {
+                        <button class="btn" type="button" disabled={*is_running}
+                            onclick={on_export(*format)}>
+                            {format!("导出{}", format.label())}
+                        </button>
+                    })}
+                </div>
+            </form>
+        },
+    };
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"仪表板 - 报表"}</h2>
+                <p>{"生产日报、报废、库存价值和检验汇总报表，支持导出CSV、Excel和PDF"}</p>
+            </div>
+
+            <div class="data-view">
+                <div class="toolbar">
+                    <div class="toolbar-actions">
+                        <select class="form-input" onchange={on_report_change}>
+                            { for definitions.iter().enumerate().map(|(index, definition)| html! {
+                                <option value={index.to_string()} selected={*selected == index}>
+                                    {&definition.title}
+                                </option>
+                            })}
+                        </select>
+                    </div>
+                </div>
+
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+                if let Some(result) = (*exported).clone() {
+                    <div class="report-exported">
+                        <span>{format!("已导出 {} 行：{}", result.rows, result.path)}</span>
+                        <button class="btn" type="button" onclick={on_reveal}>{"打开所在文件夹"}</button>
+                    </div>
+                }
+
+                { form }
+
+                { match (*table).as_ref() {
+                    Some(table) => render_table(table),
+                    None => html! { <div class="table-empty">{"设置参数后点击预览"}</div> },
+                }}
+            </div>
+        </div>
+    }
+}
+
+/// 按参数类型生成输入控件
+fn param_input(param: &ReportParam, value: String, on_change: Callback<(String, String)>) -> Html {
+    let key = param.key.clone();
+    match &param.kind {
+        ParamKind::Date => html! {
+            <input class="form-input" type="date" value={value}
+                onchange={Callback::from(move |e: Event| {
+                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                    on_change.emit((key.clone(), input.value()));
+                })} />
+        },
+        ParamKind::Text => html! {
+            <input class="form-input" type="text" value={value}
+                oninput={Callback::from(move |e: InputEvent| {
+                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                    on_change.emit((key.clone(), input.value()));
+                })} />
+        },
+        ParamKind::Select { options } => html! {
+            <select class="form-input"
+                onchange={Callback::from(move |e: Event| {
+                    let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                    on_change.emit((key.clone(), select.value()));
+                })}>
+                <option value="" selected={value.is_empty()}>{"全部"}</option>
+                { for options.iter().map(|option| html! {
+                    <option value={option.clone()} selected={value == *option}>{option}</option>
+                })}
+            </select>
+        },
+    }
+}
+
+/// 预览表格（含合计行）
+fn render_table(table: &ReportTable) -> Html {
+    let numeric = |kind: ColumnKind| (kind != ColumnKind::Text).then_some("numeric");
+
+    html! {
+        <div class="report-preview">
+            <div class="detail-title">
+                <h3>{&table.title}</h3>
+                <span class="spc-meta">{format!("{} · 生成于 {}", table.subtitle, table.generated_at)}</span>
+            </div>
+            if table.rows.is_empty() {
+                <div class="table-empty">{"没有符合条件的数据"}</div>
+            } else {
+                <table class="data-table">
+                    <thead>
+                        <tr>
+                            { for table.columns.iter().map(|column| html! {
+                                <th class={classes!(numeric(column.kind))}>{&column.title}</th>
+                            })}
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { for table.rows.iter().map(|row| html! {
+                            <tr>
+                                { for table.columns.iter().zip(row).map(|(column, cell)| html! {
+                                    <td class={classes!(numeric(column.kind))}>{cell.display(column.kind)}</td>
+                                })}
+                            </tr>
+                        })}
+                    </tbody>
+                    if let Some(totals) = table.totals.as_ref() {
+                        <tfoot>
+                            <tr class="report-totals">
+                                { for table.columns.iter().zip(totals).map(|(column, cell)| html! {
+                                    <td class={classes!(numeric(column.kind))}>{cell.display(column.kind)}</td>
+                                })}
+                            </tr>
+                        </tfoot>
+                    }
+                </table>
+            }
+        </div>
+    }
+}
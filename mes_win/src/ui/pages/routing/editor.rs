@@ -0,0 +1,436 @@
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::production::routing::{
+    Operation, OperationMaterial, Routing, RoutingStatus, WorkCenter,
+};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use super::routing_status_badge;
+
+// 工艺路线编辑器属性
+#[derive(Properties, PartialEq)]
+pub struct RoutingEditorProps {
+    pub routing: Routing,
+    pub work_centers: Vec<WorkCenter>,
+    /// 保存、发布或创建新版本后回调
+    pub on_changed: Callback<Routing>,
+}
+
+/// 解析前序工序号（逗号或空格分隔）
+fn parse_predecessors(value: &str) -> Vec<u32> {
+    value
+        .split([',', '，', ' '])
+        .filter_map(|part| part.trim().parse().ok())
+        .collect()
+}
+
+/// 命令结果的统一处理
+fn handle_result(
+    result: Result<Routing, ApiError>,
+    errors: &UseStateHandle<Vec<FieldError>>,
+    on_changed: &Callback<Routing>,
+) {
+    match result {
+        Ok(routing) => on_changed.emit(routing),
+        Err(ApiError::Validation { fields, message }) if fields.is_empty() => {
+            errors.set(vec![FieldError { field: String::new(), message }]);
+        }
+        Err(ApiError::Validation { fields, .. }) => errors.set(fields),
+        Err(error) => errors.set(vec![FieldError {
+            field: String::new(),
+            message: error.user_message(),
+        }]),
+    }
+}
+
+// 工艺路线编辑器
+#[function_component(RoutingEditor)]
+pub fn routing_editor(props: &RoutingEditorProps) -> Html {
+    let routing = use_state(|| props.routing.clone());
+    let errors = use_state(Vec::<FieldError>::new);
+    let selected_op = use_state(|| None::<usize>);
+    let is_submitting = use_state(|| false);
+
+    let read_only = routing.status != RoutingStatus::Draft || *is_submitting;
+    let has_error = |field: String| errors.iter().any(|error| error.field == field);
+
+    // 修改工序字段（输入框失焦时提交）
+    let bind_op = |index: usize, apply: fn(&mut Operation, &web_sys::HtmlInputElement)| {
+        let routing = routing.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*routing).clone();
+            if let Some(operation) = next.operations.get_mut(index) {
+                apply(operation, &input);
+            }
+            routing.set(next);
+        })
+    };
+
+    // 修改物料字段
+    let bind_material = |op_index: usize, index: usize, apply: fn(&mut OperationMaterial, String)| {
+        let routing = routing.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*routing).clone();
+            if let Some(material) = next
+                .operations
+                .get_mut(op_index)
+                .and_then(|operation| operation.materials.get_mut(index))
+            {
+                apply(material, input.value());
+            }
+            routing.set(next);
+        })
+    };
+
+    let on_header_change = |apply: fn(&mut Routing, String)| {
+        let routing = routing.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*routing).clone();
+            apply(&mut next, input.value());
+            routing.set(next);
+        })
+    };
+
+    let on_add_operation = {
+        let routing = routing.clone();
+        let selected_op = selected_op.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut next = (*routing).clone();
+            let last = next.operations.iter().map(|op| op.seq).max();
+            let seq = last.map(|seq| seq + 10).unwrap_or(10);
+            next.operations.push(Operation::new(seq, last));
+            selected_op.set(Some(next.operations.len() - 1));
+            routing.set(next);
+        })
+    };
+
+    let on_remove_operation = {
+        let routing = routing.clone();
+        let selected_op = selected_op.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(index) = *selected_op else {
+                return;
+            };
+            let mut next = (*routing).clone();
+            if index < next.operations.len() {
+                let removed = next.operations.remove(index);
+                // 后序工序改为接在被删除工序的前序之后
+                for operation in next.operations.iter_mut() {
+                    if operation.predecessors.contains(&removed.seq) {
+                        operation.predecessors.retain(|seq| *seq != removed.seq);
+                        for predecessor in &removed.predecessors {
+                            if !operation.predecessors.contains(predecessor) {
+                                operation.predecessors.push(*predecessor);
+                            }
+                        }
+                    }
+                }
+            }
+            selected_op.set(None);
+            routing.set(next);
+        })
+    };
+
+    let on_add_material = {
+        let routing = routing.clone();
+        let selected_op = selected_op.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(index) = *selected_op else {
+                return;
+            };
+            let mut next = (*routing).clone();
+            if let Some(operation) = next.operations.get_mut(index) {
+                operation.materials.push(OperationMaterial::default());
+            }
+            routing.set(next);
+        })
+    };
+
+    // 保存草稿：先本地校验（与命令端规则相同）
+    let on_save = {
+        let routing = routing.clone();
+        let errors = errors.clone();
+        let is_submitting = is_submitting.clone();
+        let work_centers = props.work_centers.clone();
+        let on_changed = props.on_changed.clone();
+        Callback::from(move |_: MouseEvent| {
+            let local_errors = routing.validate(&work_centers);
+            if !local_errors.is_empty() {
+                errors.set(local_errors);
+                return;
+            }
+
+            let submitted = (*routing).clone();
+            let errors = errors.clone();
+            let is_submitting = is_submitting.clone();
+            let on_changed = on_changed.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::save_routing(submitted).await;
+                is_submitting.set(false);
+                handle_result(result, &errors, &on_changed);
+            });
+        })
+    };
+
+    // 发布与新建版本都针对已保存的版本
+    let run_action = |publish: bool| {
+        let errors = errors.clone();
+        let is_submitting = is_submitting.clone();
+        let on_changed = props.on_changed.clone();
+        let id = routing.id;
+        let is_dirty = *routing != props.routing;
+        Callback::from(move |_: MouseEvent| {
+            let Some(id) = id else {
+                return;
+            };
+            if publish && is_dirty {
+                errors.set(vec![FieldError {
+                    field: String::new(),
+                    message: "存在未保存的修改，请先保存再发布".to_string(),
+                }]);
+                return;
+            }
+            if publish {
+                let confirmed = web_sys::window()
+                    .and_then(|w| w.confirm_with_message("发布后该版本将用于生产且不可再修改，确定发布吗？").ok())
+                    .unwrap_or(false);
+                if !confirmed {
+                    return;
+                }
+            }
+
+            let errors = errors.clone();
+            let is_submitting = is_submitting.clone();
+            let on_changed = on_changed.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = if publish {
+                    tauri::publish_routing(id).await
+                } else {
+                    tauri::new_routing_version(id).await
+                };
+                is_submitting.set(false);
+                handle_result(result, &errors, &on_changed);
+            });
+        })
+    };
+
+    let selected_operation = (*selected_op).and_then(|index| routing.operations.get(index).map(|op| (index, op.clone())));
+
+    html! {
+        <div class="data-view">
+            <div class="toolbar">
+                <div class="detail-title">
+                    <h3>
+                        { if routing.id.is_some() {
+                            format!("{} · 第{}版", routing.product_code, routing.version)
+                        } else {
+                            "新工艺路线".to_string()
+                        }}
+                    </h3>
+                    {routing_status_badge(routing.status)}
+                </div>
+                <div class="toolbar-actions">
+                    if routing.status == RoutingStatus::Draft {
+                        <button class="btn" onclick={on_save} disabled={*is_submitting}>{"保存"}</button>
+                        if routing.id.is_some() {
+                            <button class="btn btn-primary" onclick={run_action(true)} disabled={*is_submitting}>{"发布"}</button>
+                        }
+                    } else {
+                        <button class="btn btn-primary" onclick={run_action(false)} disabled={*is_submitting}>{"创建新版本"}</button>
+                    }
+                </div>
+            </div>
+
+            if !errors.is_empty() {
+                <div class="error-banner">
+                    <ul class="error-list">
+                        { for errors.iter().map(|error| html! { <li>{&error.message}</li> }) }
+                    </ul>
+                </div>
+            }
+
+            <div class="form-grid">
+                <div class="form-field">
+                    <label class="required">{"产品编码"}</label>
+                    <input class={classes!("form-input", has_error("productCode".to_string()).then_some("input-error"))}
+                        type="text" value={routing.product_code.clone()}
+                        disabled={read_only || routing.id.is_some()}
+                        onchange={on_header_change(|r, v| r.product_code = v.trim().to_string())} />
+                </div>
+                <div class="form-field">
+                    <label>{"产品名称"}</label>
+                    <input class="form-input" type="text" value={routing.product_name.clone()}
+                        disabled={read_only}
+                        onchange={on_header_change(|r, v| r.product_name = v)} />
+                </div>
+            </div>
+
+            <div class="toolbar">
+                <h3 class="form-title">{"工序"}</h3>
+                if !read_only {
+                    <div class="toolbar-actions">
+                        <button class="btn" onclick={on_add_operation}>{"添加工序"}</button>
+                        <button class="btn btn-danger" onclick={on_remove_operation} disabled={selected_op.is_none()}>{"删除工序"}</button>
+                    </div>
+                }
+            </div>
+
+            <div class="table-container">
+                <table class="data-table editable-table">
+                    <thead>
+                        <tr>
+                            <th>{"工序号"}</th>
+                            <th>{"工序名称"}</th>
+                            <th>{"工作中心"}</th>
+                            <th class="numeric">{"标准工时(分)"}</th>
+                            <th class="numeric">{"准备时间(分)"}</th>
+                            <th>{"前序工序"}</th>
+                            <th>{"检验点"}</th>
+                            <th class="numeric">{"物料"}</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { for routing.operations.iter().enumerate().map(|(index, operation)| {
+                            let field = |name: &str| format!("operations[{}].{}", index, name);
+                            let cell_class = |name: &str| classes!("form-input", has_error(field(name)).then_some("input-error"));
+                            let on_row_click = {
+                                let selected_op = selected_op.clone();
+                                Callback::from(move |_: MouseEvent| selected_op.set(Some(index)))
+                            };
+                            let on_work_center_change = {
+                                let routing = routing.clone();
+                                Callback::from(move |e: Event| {
+                                    let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                                    let mut next = (*routing).clone();
+                                    if let Some(operation) = next.operations.get_mut(index) {
+                                        operation.work_center = select.value();
+                                    }
+                                    routing.set(next);
+                                })
+                            };
+                            let predecessors: Vec<String> = operation.predecessors.iter().map(|seq| seq.to_string()).collect();
+                            html! {
+                                <tr class={classes!("clickable", (*selected_op == Some(index)).then_some("selected"))} onclick={on_row_click}>
+                                    <td>
+                                        <input class={cell_class("seq")} type="number" min="1" step="1"
+                                            value={operation.seq.to_string()} disabled={read_only}
+                                            onchange={bind_op(index, |op, input| op.seq = input.value().parse().unwrap_or(0))} />
+                                    </td>
+                                    <td>
+                                        <input class={cell_class("name")} type="text"
+                                            value={operation.name.clone()} disabled={read_only}
+                                            onchange={bind_op(index, |op, input| op.name = input.value())} />
+                                    </td>
+                                    <td>
+                                        <select class={cell_class("workCenter")} disabled={read_only} onchange={on_work_center_change}>
+                                            <option value="" selected={operation.work_center.is_empty()}>{"请选择"}</option>
+                                            { for props.work_centers.iter().map(|center| html! {
+                                                <option value={center.code.clone()} selected={center.code == operation.work_center}>
+                                                    {format!("{} {}", center.code, center.name)}
+                                                </option>
+                                            })}
+                                            // 工作中心已被删除时仍显示原编码，便于发现问题
+                                            if !operation.work_center.is_empty() && !props.work_centers.iter().any(|c| c.code == operation.work_center) {
+                                                <option value={operation.work_center.clone()} selected=true>
+                                                    {format!("{}（不存在）", operation.work_center)}
+                                                </option>
+                                            }
+                                        </select>
+                                    </td>
+                                    <td class="numeric">
+                                        <input class={cell_class("standardMinutes")} type="number" min="0" step="any"
+                                            value={operation.standard_minutes.to_string()} disabled={read_only}
+                                            onchange={bind_op(index, |op, input| op.standard_minutes = input.value().parse().unwrap_or(0.0))} />
+                                    </td>
+                                    <td class="numeric">
+                                        <input class={cell_class("setupMinutes")} type="number" min="0" step="any"
+                                            value={operation.setup_minutes.to_string()} disabled={read_only}
+                                            onchange={bind_op(index, |op, input| op.setup_minutes = input.value().parse().unwrap_or(0.0))} />
+                                    </td>
+                                    <td>
+                                        <input class={cell_class("predecessors")} type="text" placeholder="如 10,20"
+                                            value={predecessors.join(",")} disabled={read_only}
+                                            onchange={bind_op(index, |op, input| op.predecessors = parse_predecessors(&input.value()))} />
+                                    </td>
+                                    <td>
+                                        <input type="checkbox" checked={operation.inspection_point} disabled={read_only}
+                                            onchange={bind_op(index, |op, input| op.inspection_point = input.checked())} />
+                                    </td>
+                                    <td class={classes!("numeric", has_error(field("materials")).then_some("cell-error"))}>
+                                        {operation.materials.len()}
+                                    </td>
+                                </tr>
+                            }
+                        })}
+                    </tbody>
+                </table>
+            </div>
+
+            if let Some((op_index, operation)) = selected_operation {
+                <div class="detail-card">
+                    <div class="toolbar">
+                        <h3 class="form-title">{format!("工序 {} {} · 所需物料", operation.seq, operation.name)}</h3>
+                        if !read_only {
+                            <button class="btn" onclick={on_add_material}>{"添加物料"}</button>
+                        }
+                    </div>
+                    <table class="data-table editable-table">
+                        <thead>
+                            <tr>
+                                <th>{"物料编码"}</th>
+                                <th class="numeric">{"单件用量"}</th>
+                                <th>{"单位"}</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for operation.materials.iter().enumerate().map(|(index, material)| {
+                                let on_remove = {
+                                    let routing = routing.clone();
+                                    Callback::from(move |_: MouseEvent| {
+                                        let mut next = (*routing).clone();
+                                        if let Some(operation) = next.operations.get_mut(op_index) {
+                                            if index < operation.materials.len() {
+                                                operation.materials.remove(index);
+                                            }
+                                        }
+                                        routing.set(next);
+                                    })
+                                };
+                                html! {
+                                    <tr>
+                                        <td>
+                                            <input class="form-input" type="text" value={material.material_code.clone()} disabled={read_only}
+                                                onchange={bind_material(op_index, index, |m, v| m.material_code = v.trim().to_string())} />
+                                        </td>
+                                        <td class="numeric">
+                                            <input class="form-input" type="number" min="0" step="any" value={material.quantity.to_string()} disabled={read_only}
+                                                onchange={bind_material(op_index, index, |m, v| m.quantity = v.parse().unwrap_or(0.0))} />
+                                        </td>
+                                        <td>
+                                            <input class="form-input" type="text" value={material.unit.clone()} disabled={read_only}
+                                                onchange={bind_material(op_index, index, |m, v| m.unit = v)} />
+                                        </td>
+                                        <td>
+                                            if !read_only {
+                                                <button class="btn" onclick={on_remove}>{"移除"}</button>
+                                            }
+                                        </td>
+                                    </tr>
+                                }
+                            })}
+                        </tbody>
+                    </table>
+                    if operation.materials.is_empty() {
+                        <div class="table-empty">{"该工序无需物料"}</div>
+                    }
+                </div>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,176 @@
+// 工艺路线页面
+//
+// 左侧为工艺路线版本列表，右侧为工序编辑器
+mod editor;
+
+use yew::prelude::*;
+use mes_win_shared::production::routing::{Routing, RoutingStatus, RoutingSummary, WorkCenter};
+use crate::services::tauri;
+use editor::RoutingEditor;
+
+/// 工艺路线状态标签
+pub fn routing_status_badge(status: RoutingStatus) -> Html {
+    let class = format!("status-badge status-{}", status.css_class());
+    html! { <span class={class}>{status.label()}</span> }
+}
+
+#[function_component(RoutingPage)]
+pub fn routing_page() -> Html {
+    let routings = use_state(Vec::<RoutingSummary>::new);
+    let work_centers = use_state(Vec::<WorkCenter>::new);
+    let keyword = use_state(String::new);
+    let selected = use_state(|| None::<Routing>);
+    // 每次切换编辑对象时递增，使编辑器重新初始化
+    let editor_key = use_state(|| 0u32);
+    let reload = use_state(|| 0u32);
+    let error_message = use_state(|| None::<String>);
+
+    // 加载工作中心
+    {
+        let work_centers = work_centers.clone();
+        let error_message = error_message.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_work_centers().await {
+                    Ok(data) => work_centers.set(data),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    // 加载工艺路线列表
+    {
+        let routings = routings.clone();
+        let error_message = error_message.clone();
+        let keyword = (*keyword).clone();
+        use_effect_with(*reload, move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_routings(Some(keyword)).await {
+                    Ok(data) => routings.set(data),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    let open_routing = {
+        let selected = selected.clone();
+        let editor_key = editor_key.clone();
+        Callback::from(move |routing: Routing| {
+            selected.set(Some(routing));
+            editor_key.set(*editor_key + 1);
+        })
+    };
+
+    let on_select = {
+        let open_routing = open_routing.clone();
+        let error_message = error_message.clone();
+        Callback::from(move |id: u64| {
+            let open_routing = open_routing.clone();
+            let error_message = error_message.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::get_routing(id).await {
+                    Ok(routing) => {
+                        error_message.set(None);
+                        open_routing.emit(routing);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_create = {
+        let open_routing = open_routing.clone();
+        Callback::from(move |_: MouseEvent| open_routing.emit(Routing::default()))
+    };
+
+    // 保存/发布/新建版本后刷新列表并切换到返回的版本
+    let on_changed = {
+        let open_routing = open_routing.clone();
+        let reload = reload.clone();
+        Callback::from(move |routing: Routing| {
+            open_routing.emit(routing);
+            reload.set(*reload + 1);
+        })
+    };
+
+    let on_keyword_input = {
+        let keyword = keyword.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            keyword.set(input.value());
+        })
+    };
+
+    let on_search = {
+        let reload = reload.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            reload.set(*reload + 1);
+        })
+    };
+
+    let selected_id = selected.as_ref().and_then(|routing| routing.id);
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"生产管理 - 工艺"}</h2>
+                <p>{"维护产品工艺路线：工序、工作中心、标准工时、物料和检验点"}</p>
+            </div>
+
+            if let Some(message) = (*error_message).clone() {
+                <div class="error-banner">{message}</div>
+            }
+
+            <div class="split-view">
+                <div class="split-list">
+                    <form class="toolbar-search" onsubmit={on_search}>
+                        <input class="form-input" type="text" placeholder="产品编码 / 名称"
+                            value={(*keyword).clone()} oninput={on_keyword_input} />
+                        <button class="btn" type="submit">{"查询"}</button>
+                    </form>
+                    <button class="btn btn-primary" onclick={on_create}>{"新建工艺路线"}</button>
+                    <div class="list-items">
+                        { for routings.iter().map(|summary| {
+                            let id = summary.id;
+                            let on_select = on_select.clone();
+                            let class = classes!("list-item", (selected_id == Some(id)).then_some("active"));
+                            html! {
+                                <div class={class} onclick={Callback::from(move |_: MouseEvent| on_select.emit(id))}>
+                                    <div class="list-item-title">
+                                        {format!("{} · 第{}版", summary.product_code, summary.version)}
+                                        {routing_status_badge(summary.status)}
+                                    </div>
+                                    <div class="list-item-description">
+                                        {format!("{} · {} 道工序", summary.product_name, summary.operation_count)}
+                                    </div>
+                                </div>
+                            }
+                        })}
+                        if routings.is_empty() {
+                            <div class="table-empty">{"暂无工艺路线"}</div>
+                        }
+                    </div>
+                </div>
+
+                <div class="split-detail">
+                    if let Some(routing) = (*selected).clone() {
+                        <RoutingEditor
+                            key={*editor_key}
+                            routing={routing}
+                            work_centers={(*work_centers).clone()}
+                            on_changed={on_changed}
+                        />
+                    } else {
+                        <div class="table-empty">{"选择左侧的工艺路线，或新建工艺路线"}</div>
+                    }
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,301 @@
+// 生产排程看板
+//
+// 按产线/机台分行显示工单的计划时段，拖拽工单条可调整开始时间或换线，
+// 提交前先在本地检测时段重叠和产能冲突
+use yew::prelude::*;
+use wasm_bindgen::JsCast;
+use mes_win_shared::production::schedule::{
+    RescheduleRequest, ScheduleBoard, ScheduleItem, ScheduleQuery,
+};
+use mes_win_shared::production::work_order::WorkOrderStatus;
+use mes_win_shared::time::{format_minutes, parse_minutes};
+use crate::services::tauri;
+use crate::ui::pages::work_orders::status_badge;
+use crate::utils::format::format_datetime;
+use crate::utils::time::today_start_minutes;
+
+/// 拖拽落点对齐的粒度（分钟）
+const SNAP_MINUTES: i64 = 30;
+/// 可选的显示天数
+const DAY_OPTIONS: [i64; 3] = [3, 7, 14];
+
+// 正在拖拽的工单
+#[derive(Clone, Copy)]
+struct DragState {
+    work_order_id: u64,
+    /// 鼠标按下位置距工单条左边缘的像素
+    grab_offset_px: f64,
+}
+
+#[function_component(SchedulePage)]
+pub fn schedule_page() -> Html {
+    let range_start = use_state(today_start_minutes);
+    let days = use_state(|| 7i64);
+    let board = use_state(ScheduleBoard::default);
+    let is_loading = use_state(|| false);
+    let error_message = use_state(|| None::<String>);
+    let drag_state = use_mut_ref(|| None::<DragState>);
+
+    let range_minutes = *days * 1440;
+
+    // 时间范围变化时重新加载
+    {
+        let board = board.clone();
+        let is_loading = is_loading.clone();
+        let error_message = error_message.clone();
+        use_effect_with((*range_start, *days), move |(start, days)| {
+            let query = ScheduleQuery {
+                start: format_minutes(*start),
+                end: format_minutes(*start + *days * 1440),
+            };
+            is_loading.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::get_schedule(query).await {
+                    Ok(data) => {
+                        board.set(data);
+                        error_message.set(None);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+                is_loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    let shift_range = |delta_days: i64| {
+        let range_start = range_start.clone();
+        let days = days.clone();
+        Callback::from(move |_: MouseEvent| {
+            range_start.set(if delta_days == 0 {
+                today_start_minutes()
+            } else {
+                *range_start + delta_days * *days * 1440
+            });
+        })
+    };
+
+    let on_days_change = {
+        let days = days.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            days.set(select.value().parse().unwrap_or(7));
+        })
+    };
+
+    // 拖拽放下：计算新时段，本地无冲突时提交
+    let on_drop = {
+        let board = board.clone();
+        let error_message = error_message.clone();
+        let drag_state = drag_state.clone();
+        let range_start = *range_start;
+        Callback::from(move |(e, resource): (DragEvent, String)| {
+            e.prevent_default();
+            let Some(drag) = drag_state.borrow_mut().take() else {
+                return;
+            };
+            let Some(track) = e.current_target().and_then(|t| t.dyn_into::<web_sys::Element>().ok()) else {
+                return;
+            };
+            let Some(item) = board.items.iter().find(|i| i.work_order_id == drag.work_order_id).cloned() else {
+                return;
+            };
+            let (Some(old_start), Some(old_end)) = (parse_minutes(&item.start), parse_minutes(&item.end)) else {
+                return;
+            };
+
+            let rect = track.get_bounding_client_rect();
+            if rect.width() <= 0.0 {
+                return;
+            }
+            let offset_px = e.client_x() as f64 - rect.left() - drag.grab_offset_px;
+            let offset_minutes = (offset_px / rect.width() * range_minutes as f64) as i64;
+            let new_start = range_start + (offset_minutes as f64 / SNAP_MINUTES as f64).round() as i64 * SNAP_MINUTES;
+            if new_start == old_start && resource == item.resource {
+                return;
+            }
+
+            let candidate = ScheduleItem {
+                resource,
+                start: format_minutes(new_start),
+                end: format_minutes(new_start + (old_end - old_start)),
+                ..item
+            };
+
+            let conflicts = board.conflicts_for(&candidate);
+            if !conflicts.is_empty() {
+                let details: Vec<String> = conflicts.iter().map(|c| c.describe()).collect();
+                error_message.set(Some(format!(
+                    "工单 {} 无法调整：{}",
+                    candidate.order_no,
+                    details.join("；")
+                )));
+                return;
+            }
+
+            let request = RescheduleRequest {
+                work_order_id: candidate.work_order_id,
+                resource: candidate.resource.clone(),
+                start: candidate.start.clone(),
+                end: candidate.end.clone(),
+            };
+            let board = board.clone();
+            let error_message = error_message.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::reschedule(request).await {
+                    Ok(updated) => {
+                        let mut next = (*board).clone();
+                        if let Some(slot) = next.items.iter_mut().find(|i| i.work_order_id == updated.work_order_id) {
+                            *slot = updated;
+                        }
+                        board.set(next);
+                        error_message.set(None);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let conflicting = board.conflicting_orders();
+
+    // 工单条
+    let render_bar = |item: &ScheduleItem| -> Html {
+        let (Some(start), Some(end)) = (parse_minutes(&item.start), parse_minutes(&item.end)) else {
+            return html! {};
+        };
+        let visible_start = start.max(*range_start);
+        let visible_end = end.min(*range_start + range_minutes);
+        if visible_end <= visible_start {
+            return html! {};
+        }
+
+        let left = (visible_start - *range_start) as f64 / range_minutes as f64 * 100.0;
+        let width = (visible_end - visible_start) as f64 / range_minutes as f64 * 100.0;
+        let class = classes!(
+            "gantt-bar",
+            format!("status-{}", item.status.code().to_lowercase().replace('_', "-")),
+            item.movable.then_some("movable"),
+            conflicting.contains(&item.work_order_id).then_some("conflict"),
+        );
+        let title = format!(
+            "{} {}\n{} ~ {}\n{}",
+            item.order_no,
+            item.product_name,
+            format_datetime(&item.start),
+            format_datetime(&item.end),
+            item.status.label()
+        );
+
+        let ondragstart = {
+            let drag_state = drag_state.clone();
+            let work_order_id = item.work_order_id;
+            Callback::from(move |e: DragEvent| {
+                if let Some(data_transfer) = e.data_transfer() {
+                    let _ = data_transfer.set_data("text/plain", &work_order_id.to_string());
+                }
+                *drag_state.borrow_mut() = Some(DragState {
+                    work_order_id,
+                    grab_offset_px: e.offset_x() as f64,
+                });
+            })
+        };
+
+        html! {
+            <div class={class}
+                style={format!("left: {:.3}%; width: {:.3}%;", left, width)}
+                title={title}
+                draggable={if item.movable { "true" } else { "false" }}
+                ondragstart={ondragstart}>
+                <span class="gantt-bar-label">{format!("{} {}", item.order_no, item.product_name)}</span>
+            </div>
+        }
+    };
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"生产管理 - 排程"}</h2>
+                <p>{"拖拽工单条调整计划时间或切换产线，冲突时段以红框标出"}</p>
+            </div>
+
+            <div class="data-view">
+                <div class="toolbar">
+                    <div class="toolbar-actions">
+                        <button class="btn" onclick={shift_range(-1)}>{"上一段"}</button>
+                        <button class="btn" onclick={shift_range(0)}>{"今天"}</button>
+                        <button class="btn" onclick={shift_range(1)}>{"下一段"}</button>
+                        <span class="toolbar-text">
+                            {format!(
+                                "{} ~ {}",
+                                &format_minutes(*range_start)[..10],
+                                &format_minutes(*range_start + range_minutes - 1)[..10]
+                            )}
+                        </span>
+                    </div>
+                    <div class="toolbar-actions">
+                        if *is_loading {
+                            <span class="toolbar-text">{"加载中..."}</span>
+                        }
+                        <select class="form-input" onchange={on_days_change}>
+                            { for DAY_OPTIONS.iter().map(|option| html! {
+                                <option value={option.to_string()} selected={*days == *option}>
+                                    {format!("{} 天", option)}
+                                </option>
+                            })}
+                        </select>
+                    </div>
+                </div>
+
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+
+                <div class="gantt">
+                    <div class="gantt-row gantt-header">
+                        <div class="gantt-resource">{"产线 / 机台"}</div>
+                        <div class="gantt-track">
+                            { for (0..*days).map(|day| {
+                                let label = format_minutes(*range_start + day * 1440);
+                                html! {
+                                    <div class="gantt-day"
+                                        style={format!("left: {:.3}%; width: {:.3}%;", day as f64 / *days as f64 * 100.0, 100.0 / *days as f64)}>
+                                        {&label[5..10]}
+                                    </div>
+                                }
+                            })}
+                        </div>
+                    </div>
+
+                    { for board.resources.iter().map(|resource| {
+                        let code = resource.code.clone();
+                        let on_drop = on_drop.clone();
+                        html! {
+                            <div class="gantt-row">
+                                <div class="gantt-resource" title={format!("产能：{}", resource.capacity)}>
+                                    {&resource.name}
+                                </div>
+                                <div class="gantt-track"
+                                    ondragover={Callback::from(|e: DragEvent| e.prevent_default())}
+                                    ondrop={Callback::from(move |e: DragEvent| on_drop.emit((e, code.clone())))}>
+                                    { for (1..*days).map(|day| html! {
+                                        <div class="gantt-gridline" style={format!("left: {:.3}%;", day as f64 / *days as f64 * 100.0)} />
+                                    })}
+                                    { for board.items.iter().filter(|item| item.resource == resource.code).map(render_bar) }
+                                </div>
+                            </div>
+                        }
+                    })}
+
+                    if board.resources.is_empty() && !*is_loading {
+                        <div class="table-empty">{"暂无产线数据"}</div>
+                    }
+                </div>
+
+                <div class="gantt-legend">
+                    { for WorkOrderStatus::ALL.iter().map(|status| status_badge(*status)) }
+                </div>
+            </div>
+        </div>
+    }
+}
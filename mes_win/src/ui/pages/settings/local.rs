@@ -0,0 +1,203 @@
+use yew::prelude::*;
+use mes_win_shared::config::ServerProfile;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::settings::{Language, LocalSettings, LogLevel, Theme, MAX_WINDOW_SIZE, MIN_WINDOW_SIZE};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+use super::apply_appearance;
+
+// 本机客户端设置表单
+#[function_component(LocalSettingsForm)]
+pub fn local_settings_form() -> Html {
+    let settings = use_state(LocalSettings::default);
+    // 已保存的后端档案，用于判断本次保存是否会切换档案
+    let saved_profile = use_state(String::new);
+    let profiles = use_state(Vec::<ServerProfile>::new);
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let notice = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+
+    {
+        let settings = settings.clone();
+        let saved_profile = saved_profile.clone();
+        let profiles = profiles.clone();
+        let error_message = error_message.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::get_local_settings().await {
+                    Ok(loaded) => {
+                        saved_profile.set(loaded.backend_profile.clone());
+                        settings.set(loaded);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+                if let Ok(list) = tauri::list_backend_profiles().await {
+                    profiles.set(list.profiles);
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_select = |apply: fn(&mut LocalSettings, &str)| {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*settings).clone();
+            apply(&mut next, &select.value());
+            settings.set(next);
+        })
+    };
+
+    let on_size = |apply: fn(&mut LocalSettings, u32)| {
+        let settings = settings.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*settings).clone();
+            apply(&mut next, input.value().trim().parse().unwrap_or(0));
+            settings.set(next);
+        })
+    };
+
+    let on_submit = {
+        let settings = settings.clone();
+        let saved_profile = saved_profile.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let notice = notice.clone();
+        let is_submitting = is_submitting.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let local_errors = settings.validate();
+            errors.set(local_errors.clone());
+            if !local_errors.is_empty() {
+                return;
+            }
+
+            // 切换后端档案会清理当前会话
+            if settings.backend_profile != *saved_profile {
+                let confirmed = web_sys::window()
+                    .and_then(|w| w.confirm_with_message("切换后端档案将退出当前登录，确定继续吗？").ok())
+                    .unwrap_or(false);
+                if !confirmed {
+                    return;
+                }
+            }
+
+            let submitted = (*settings).clone();
+            let settings = settings.clone();
+            let saved_profile = saved_profile.clone();
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let notice = notice.clone();
+            let is_submitting = is_submitting.clone();
+            is_submitting.set(true);
+            notice.set(None);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::save_local_settings(submitted).await;
+                is_submitting.set(false);
+                match result {
+                    Ok(saved) => {
+                        apply_appearance(&saved);
+                        saved_profile.set(saved.backend_profile.clone());
+                        settings.set(saved);
+                        error_message.set(None);
+                        notice.set(Some("本机设置已保存，窗口大小在下次登录时生效".to_string()));
+                    }
+                    Err(ApiError::Validation { message, fields }) => {
+                        errors.set(fields);
+                        error_message.set(Some(message));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let (min_width, min_height) = MIN_WINDOW_SIZE;
+    let (max_width, max_height) = MAX_WINDOW_SIZE;
+
+    html! {
+        <form class="data-view settings-section" onsubmit={on_submit}>
+            <div class="detail-title">
+                <h3>{"本机设置"}</h3>
+            </div>
+
+            if let Some(message) = (*error_message).clone() {
+                <div class="error-banner">{message}</div>
+            }
+            if let Some(message) = (*notice).clone() {
+                <div class="notice-banner">{message}</div>
+            }
+
+            <div class="form-grid">
+                <div class="form-field">
+                    <label>{"界面语言"}</label>
+                    <select class="form-input"
+                        onchange={on_select(|s, v| s.language = Language::from_code(v).unwrap_or(s.language))}>
+                        { for Language::ALL.iter().map(|language| html! {
+                            <option value={language.code()} selected={settings.language == *language}>
+                                {language.label()}
+                            </option>
+                        })}
+                    </select>
+                </div>
+                <div class="form-field">
+                    <label>{"默认主题"}</label>
+                    <select class="form-input"
+                        onchange={on_select(|s, v| s.theme = Theme::from_code(v).unwrap_or(s.theme))}>
+                        { for Theme::ALL.iter().map(|theme| html! {
+                            <option value={theme.code()} selected={settings.theme == *theme}>{theme.label()}</option>
+                        })}
+                    </select>
+                    <div class="field-hint">{"启动时使用，左下角按钮可临时切换"}</div>
+                </div>
+                <div class="form-field">
+                    <label class="required">{"主窗口宽度"}</label>
+                    <input class="form-input" type="number" step="1"
+                        min={min_width.to_string()} max={max_width.to_string()}
+                        value={settings.window_width.to_string()}
+                        oninput={on_size(|s, v| s.window_width = v)} />
+                    { field_error(&errors, "windowWidth") }
+                </div>
+                <div class="form-field">
+                    <label class="required">{"主窗口高度"}</label>
+                    <input class="form-input" type="number" step="1"
+                        min={min_height.to_string()} max={max_height.to_string()}
+                        value={settings.window_height.to_string()}
+                        oninput={on_size(|s, v| s.window_height = v)} />
+                    { field_error(&errors, "windowHeight") }
+                </div>
+                <div class="form-field">
+                    <label class="required">{"后端档案"}</label>
+                    <select class="form-input" onchange={on_select(|s, v| s.backend_profile = v.to_string())}>
+                        { for profiles.iter().map(|profile| html! {
+                            <option value={profile.name.clone()} selected={settings.backend_profile == profile.name}>
+                                {format!("{}（{}）", profile.label, profile.base_url)}
+                            </option>
+                        })}
+                    </select>
+                    { field_error(&errors, "backendProfile") }
+                </div>
+                <div class="form-field">
+                    <label>{"日志级别"}</label>
+                    <select class="form-input"
+                        onchange={on_select(|s, v| s.log_level = LogLevel::from_code(v).unwrap_or(s.log_level))}>
+                        { for LogLevel::ALL.iter().map(|level| html! {
+                            <option value={level.code()} selected={settings.log_level == *level}>{level.label()}</option>
+                        })}
+                    </select>
+                </div>
+            </div>
+
+            <div class="form-actions">
+                <button class="btn btn-primary" type="submit" disabled={*is_submitting}>
+                    { if *is_submitting { "保存中..." } else { "保存本机设置" } }
+                </button>
+            </div>
+        </form>
+    }
+}
@@ -0,0 +1,113 @@
+use yew::prelude::*;
+use mes_win_shared::master_data::MasterDataStatus;
+use crate::services::tauri;
+use crate::utils::time::format_unix_time;
+
+// 主数据缓存：各类主数据的缓存条数和同步时间，可手动增量同步或全量重建
+#[function_component(MasterDataCacheSection)]
+pub fn master_data_cache_section() -> Html {
+    let statuses = use_state(Vec::<MasterDataStatus>::new);
+    let error_message = use_state(|| None::<String>);
+    let notice = use_state(|| None::<String>);
+    let is_syncing = use_state(|| false);
+
+    {
+        let statuses = statuses.clone();
+        let error_message = error_message.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::get_master_data_status().await {
+                    Ok(list) => statuses.set(list),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    let sync = {
+        let statuses = statuses.clone();
+        let error_message = error_message.clone();
+        let notice = notice.clone();
+        let is_syncing = is_syncing.clone();
+        move |full: bool| {
+            let statuses = statuses.clone();
+            let error_message = error_message.clone();
+            let notice = notice.clone();
+            let is_syncing = is_syncing.clone();
+            is_syncing.set(true);
+            notice.set(None);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::sync_master_data(full).await;
+                is_syncing.set(false);
+                match result {
+                    Ok(list) => {
+                        error_message.set(None);
+                        notice.set(Some(if full { "主数据缓存已重建" } else { "主数据已同步" }.to_string()));
+                        statuses.set(list);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        }
+    };
+
+    let on_sync = {
+        let sync = sync.clone();
+        Callback::from(move |_: MouseEvent| sync(false))
+    };
+    let on_rebuild = Callback::from(move |_: MouseEvent| {
+        let confirmed = web_sys::window()
+            .and_then(|w| w.confirm_with_message("将重新下载全部主数据并替换本机缓存，确定重建吗？").ok())
+            .unwrap_or(false);
+        if confirmed {
+            sync(true);
+        }
+    });
+
+    html! {
+        <div class="data-view settings-section">
+            <div class="detail-title">
+                <h3>{"主数据缓存"}</h3>
+            </div>
+            <div class="spc-meta">
+                {"物料、产品、工作中心和用户保存在本机，用于输入时的即时联想；登录后及每10分钟自动增量同步"}
+            </div>
+
+            if let Some(message) = (*error_message).clone() {
+                <div class="error-banner">{message}</div>
+            }
+            if let Some(message) = (*notice).clone() {
+                <div class="notice-banner">{message}</div>
+            }
+
+            <table class="data-table">
+                <thead>
+                    <tr>
+                        <th>{"类型"}</th>
+                        <th>{"缓存条数"}</th>
+                        <th>{"同步水位"}</th>
+                        <th>{"最近同步"}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    { for statuses.iter().map(|status| html! {
+                        <tr>
+                            <td>{status.kind.label()}</td>
+                            <td>{status.count}</td>
+                            <td>{status.watermark.clone().unwrap_or_else(|| "-".to_string())}</td>
+                            <td>{status.synced_at.map(format_unix_time).unwrap_or_else(|| "未同步".to_string())}</td>
+                        </tr>
+                    })}
+                </tbody>
+            </table>
+
+            <div class="form-actions">
+                <button class="btn" type="button" disabled={*is_syncing} onclick={on_rebuild}>{"全量重建"}</button>
+                <button class="btn btn-primary" type="button" disabled={*is_syncing} onclick={on_sync}>
+                    { if *is_syncing { "同步中..." } else { "立即同步" } }
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,71 @@
+// 系统参数设置页面
+//
+// 上半部分为本机客户端设置（保存在应用配置目录），所有用户可修改；
+// 其次是本机主数据缓存的同步状态；
+// 最后为服务端工厂参数，表单由后端下发的参数定义生成，需要系统参数权限
+mod local;
+mod master_data;
+mod plant;
+
+use yew::prelude::*;
+use mes_win_shared::permission::Permission;
+use mes_win_shared::settings::LocalSettings;
+use crate::services::tauri;
+use local::LocalSettingsForm;
+use master_data::MasterDataCacheSection;
+use plant::PlantSettingsForm;
+
+/// 应用界面语言和主题（启动时和保存设置后调用）
+pub fn apply_appearance(settings: &LocalSettings) {
+    let Some(html) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element())
+    else {
+        return;
+    };
+
+    let _ = html.set_attribute("lang", settings.language.code());
+    let _ = match settings.theme.code() {
+        "light" => html.set_attribute("data-theme", "light"),
+        _ => html.remove_attribute("data-theme"),
+    };
+    // 与主题切换按钮共用 localStorage 中的记录，并刷新按钮图标
+    let _ = js_sys::eval(&format!(
+        "localStorage.setItem('theme', '{}'); updateThemeToggleButtons();",
+        settings.theme.code()
+    ));
+}
+
+#[function_component(SettingsPage)]
+pub fn settings_page() -> Html {
+    let can_edit_plant = use_state(|| false);
+
+    {
+        let can_edit_plant = can_edit_plant.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Ok(Some(session)) = tauri::get_session_info().await {
+                    can_edit_plant.set(session.can(Permission::SystemSettings));
+                }
+            });
+            || ()
+        });
+    }
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"系统设置 - 系统参数"}</h2>
+                <p>{"本机的界面、窗口和日志设置，以及班次日历、计量单位、编号规则等工厂参数"}</p>
+            </div>
+
+            <LocalSettingsForm />
+
+            <MasterDataCacheSection />
+
+            if *can_edit_plant {
+                <PlantSettingsForm />
+            }
+        </div>
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::BTreeMap;
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::settings::{validate_plant_values, PlantSettings, SettingField, SettingKind};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+
+// 工厂参数表单（按后端下发的参数定义生成）
+#[function_component(PlantSettingsForm)]
+pub fn plant_settings_form() -> Html {
+    let plant = use_state(|| None::<PlantSettings>);
+    let values = use_state(BTreeMap::<String, String>::new);
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let notice = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+
+    {
+        let plant = plant.clone();
+        let values = values.clone();
+        let error_message = error_message.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::get_plant_settings().await {
+                    Ok(loaded) => {
+                        values.set(loaded.values.clone());
+                        plant.set(Some(loaded));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_value_change = {
+        let values = values.clone();
+        Callback::from(move |(key, value): (String, String)| {
+            let mut next = (*values).clone();
+            next.insert(key, value);
+            values.set(next);
+        })
+    };
+
+    let on_reset = {
+        let plant = plant.clone();
+        let values = values.clone();
+        let errors = errors.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(plant) = (*plant).as_ref() {
+                values.set(plant.values.clone());
+                errors.set(Vec::new());
+            }
+        })
+    };
+
+    let on_submit = {
+        let plant = plant.clone();
+        let values = values.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let notice = notice.clone();
+        let is_submitting = is_submitting.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let Some(schema) = (*plant).as_ref().map(|plant| plant.schema.clone()) else { return };
+
+            let local_errors = validate_plant_values(&schema, &values);
+            errors.set(local_errors.clone());
+            if !local_errors.is_empty() {
+                return;
+            }
+
+            let submitted = (*values).clone();
+            let plant = plant.clone();
+            let values = values.clone();
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let notice = notice.clone();
+            let is_submitting = is_submitting.clone();
+            is_submitting.set(true);
+            notice.set(None);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::save_plant_settings(submitted).await;
+                is_submitting.set(false);
+                match result {
+                    Ok(saved) => {
+                        values.set(saved.values.clone());
+                        plant.set(Some(saved));
+                        error_message.set(None);
+                        notice.set(Some("工厂参数已保存".to_string()));
+                    }
+                    Err(ApiError::Validation { message, fields }) => {
+                        errors.set(fields);
+                        error_message.set(Some(message));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let updated = (*plant).as_ref().and_then(|plant| match (&plant.updated_by, &plant.updated_at) {
+        (Some(by), Some(at)) => Some(format!("最后由 {} 修改于 {}", by, at)),
+        (None, Some(at)) => Some(format!("最后修改于 {}", at)),
+        _ => None,
+    });
+
+    html! {
+        <form class="data-view settings-section" onsubmit={on_submit}>
+            <div class="detail-title">
+                <h3>{"工厂参数"}</h3>
+                if let Some(updated) = updated {
+                    <span class="spc-meta">{updated}</span>
+                }
+            </div>
+
+            if let Some(message) = (*error_message).clone() {
+                <div class="error-banner">{message}</div>
+            }
+            if let Some(message) = (*notice).clone() {
+                <div class="notice-banner">{message}</div>
+            }
+
+            if let Some(plant) = (*plant).as_ref() {
+                { for plant.groups().into_iter().map(|(group, fields)| html! {
+                    <div class="settings-group">
+                        <div class="permission-group-title">{group}</div>
+                        <div class="form-grid">
+                            { for fields.into_iter().map(|field| {
+                                let value = values.get(&field.key).cloned().unwrap_or_default();
+                                let label = match &field.unit {
+                                    Some(unit) => format!("{}（{}）", field.label, unit),
+                                    None => field.label.clone(),
+                                };
+                                html! {
+                                    <div class="form-field">
+                                        <label class={classes!(field.required.then_some("required"))}>{label}</label>
+                                        { setting_input(field, value, on_value_change.clone()) }
+                                        if let Some(description) = &field.description {
+                                            <div class="field-hint">{description}</div>
+                                        }
+                                        { field_error(&errors, &field.key) }
+                                    </div>
+                                }
+                            })}
+                        </div>
+                    </div>
+                })}
+
+                <div class="form-actions">
+                    <button class="btn" type="button" disabled={*is_submitting} onclick={on_reset}>{"还原"}</button>
+                    <button class="btn btn-primary" type="submit" disabled={*is_submitting}>
+                        { if *is_submitting { "保存中..." } else { "保存工厂参数" } }
+                    </button>
+                </div>
+            } else if error_message.is_none() {
+                <div class="table-empty">{"加载中..."}</div>
+            }
+        </form>
+    }
+}
+
+/// 按参数类型生成输入控件
+fn setting_input(field: &SettingField, value: String, on_change: Callback<(String, String)>) -> Html {
+    let key = field.key.clone();
+    let on_input = {
+        let key = key.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            on_change.emit((key.clone(), input.value()));
+        })
+    };
+
+    match &field.kind {
+        SettingKind::Integer { min, max } => html! {
+            <input class="form-input" type="number" step="1" value={value} oninput={on_input}
+                min={min.map(|min| min.to_string())} max={max.map(|max| max.to_string())} />
+        },
+        SettingKind::Decimal { min, max } => html! {
+            <input class="form-input" type="number" step="any" value={value} oninput={on_input}
+                min={min.map(|min| min.to_string())} max={max.map(|max| max.to_string())} />
+        },
+        SettingKind::Text { max_length } => html! {
+            <input class="form-input" type="text" value={value} oninput={on_input}
+                maxlength={max_length.map(|max| max.to_string())} />
+        },
+        SettingKind::Time => html! {
+            <input class="form-input" type="time" value={value} oninput={on_input} />
+        },
+        SettingKind::Boolean => html! {
+            <label class="checkbox-label">
+                <input type="checkbox" checked={value == "true"}
+                    onchange={Callback::from(move |e: Event| {
+                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                        on_change.emit((key.clone(), input.checked().to_string()));
+                    })} />
+                {"启用"}
+            </label>
+        },
+        SettingKind::Select { options } => html! {
+            <select class="form-input"
+                onchange={Callback::from(move |e: Event| {
+                    let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                    on_change.emit((key.clone(), select.value()));
+                })}>
+                if !field.required {
+                    <option value="" selected={value.is_empty()}>{"（未设置）"}</option>
+                }
+                { for options.iter().map(|option| html! {
+                    <option value={option.clone()} selected={value == *option}>{option}</option>
+                })}
+            </select>
+        },
+    }
+}
@@ -0,0 +1,549 @@
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::quality::inspection::{Characteristic, CharacteristicKind};
+use mes_win_shared::quality::plan::{InspectionLevel, InspectionPlan, PlanStatus, SamplingRule};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use super::plan_status_badge;
+
+// 检验标准编辑器属性
+#[derive(Properties, PartialEq)]
+pub struct PlanEditorProps {
+    pub plan: InspectionPlan,
+    /// 保存、发布或创建新版本后回调
+    pub on_changed: Callback<InspectionPlan>,
+}
+
+/// 解析选项列表（逗号、顿号分隔）
+fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split([',', '，', '、'])
+        .map(|part| part.trim().to_string())
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+/// 解析可为空的数值
+fn parse_limit(value: &str) -> Option<f64> {
+    value.trim().parse().ok()
+}
+
+/// 特性类型编码
+fn kind_code(kind: &CharacteristicKind) -> &'static str {
+    match kind {
+        CharacteristicKind::Numeric { .. } => "numeric",
+        CharacteristicKind::PassFail => "passFail",
+        CharacteristicKind::Attribute { .. } => "attribute",
+    }
+}
+
+/// 按类型编码生成空的特性类型
+fn kind_from_code(code: &str) -> CharacteristicKind {
+    match code {
+        "passFail" => CharacteristicKind::PassFail,
+        "attribute" => CharacteristicKind::Attribute {
+            options: vec!["合格".to_string(), "不合格".to_string()],
+            accepted: vec!["合格".to_string()],
+        },
+        _ => CharacteristicKind::Numeric {
+            nominal: 0.0,
+            lower_limit: None,
+            upper_limit: None,
+            unit: String::new(),
+        },
+    }
+}
+
+/// 命令结果的统一处理
+fn handle_result(
+    result: Result<InspectionPlan, ApiError>,
+    errors: &UseStateHandle<Vec<FieldError>>,
+    on_changed: &Callback<InspectionPlan>,
+) {
+    match result {
+        Ok(plan) => on_changed.emit(plan),
+        Err(ApiError::Validation { fields, message }) if fields.is_empty() => {
+            errors.set(vec![FieldError { field: String::new(), message }]);
+        }
+        Err(ApiError::Validation { fields, .. }) => errors.set(fields),
+        Err(error) => errors.set(vec![FieldError {
+            field: String::new(),
+            message: error.user_message(),
+        }]),
+    }
+}
+
+// 检验标准编辑器
+#[function_component(PlanEditor)]
+pub fn plan_editor(props: &PlanEditorProps) -> Html {
+    let plan = use_state(|| props.plan.clone());
+    let errors = use_state(Vec::<FieldError>::new);
+    let preview_quantity = use_state(|| 500.0);
+    let is_submitting = use_state(|| false);
+
+    let read_only = plan.status != PlanStatus::Draft || *is_submitting;
+    let has_error = |field: String| errors.iter().any(|error| error.field == field);
+
+    let on_header_change = |apply: fn(&mut InspectionPlan, String)| {
+        let plan = plan.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*plan).clone();
+            apply(&mut next, input.value());
+            plan.set(next);
+        })
+    };
+
+    // 修改特性字段（输入框失焦时提交）
+    let bind_char = |index: usize, apply: fn(&mut Characteristic, String)| {
+        let plan = plan.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*plan).clone();
+            if let Some(characteristic) = next.characteristics.get_mut(index) {
+                apply(characteristic, input.value());
+            }
+            plan.set(next);
+        })
+    };
+
+    let on_sampling_type_change = {
+        let plan = plan.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*plan).clone();
+            next.sampling = match select.value().as_str() {
+                "fixed" => SamplingRule::Fixed { sample_size: 5 },
+                "percentage" => SamplingRule::Percentage { percent: 10.0, minimum: 1 },
+                _ => SamplingRule::default(),
+            };
+            plan.set(next);
+        })
+    };
+
+    let on_sampling_change = |apply: fn(&mut SamplingRule, String)| {
+        let plan = plan.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*plan).clone();
+            apply(&mut next.sampling, input.value());
+            plan.set(next);
+        })
+    };
+
+    let on_level_change = {
+        let plan = plan.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*plan).clone();
+            if let SamplingRule::Aql { level, .. } = &mut next.sampling {
+                *level = InspectionLevel::ALL
+                    .into_iter()
+                    .find(|l| l.label() == select.value())
+                    .unwrap_or(InspectionLevel::II);
+            }
+            plan.set(next);
+        })
+    };
+
+    let on_preview_input = {
+        let preview_quantity = preview_quantity.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            preview_quantity.set(input.value().parse().unwrap_or(0.0));
+        })
+    };
+
+    let on_add_characteristic = {
+        let plan = plan.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut next = (*plan).clone();
+            let code = format!("C{:02}", next.characteristics.len() + 1);
+            next.characteristics.push(Characteristic {
+                code,
+                name: String::new(),
+                kind: kind_from_code("numeric"),
+                critical: false,
+            });
+            plan.set(next);
+        })
+    };
+
+    // 保存草稿：先本地校验（与命令端规则相同）
+    let on_save = {
+        let plan = plan.clone();
+        let errors = errors.clone();
+        let is_submitting = is_submitting.clone();
+        let on_changed = props.on_changed.clone();
+        Callback::from(move |_: MouseEvent| {
+            let local_errors = plan.validate();
+            if !local_errors.is_empty() {
+                errors.set(local_errors);
+                return;
+            }
+
+            let submitted = (*plan).clone();
+            let errors = errors.clone();
+            let is_submitting = is_submitting.clone();
+            let on_changed = on_changed.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::save_inspection_plan(submitted).await;
+                is_submitting.set(false);
+                handle_result(result, &errors, &on_changed);
+            });
+        })
+    };
+
+    // 发布与新建版本都针对已保存的版本
+    let run_action = |publish: bool| {
+        let errors = errors.clone();
+        let is_submitting = is_submitting.clone();
+        let on_changed = props.on_changed.clone();
+        let id = plan.id;
+        let is_dirty = *plan != props.plan;
+        Callback::from(move |_: MouseEvent| {
+            let Some(id) = id else {
+                return;
+            };
+            if publish && is_dirty {
+                errors.set(vec![FieldError {
+                    field: String::new(),
+                    message: "存在未保存的修改，请先保存再发布".to_string(),
+                }]);
+                return;
+            }
+            if publish {
+                let confirmed = web_sys::window()
+                    .and_then(|w| w.confirm_with_message("发布后新建的检验批将使用该版本且不可再修改，确定发布吗？").ok())
+                    .unwrap_or(false);
+                if !confirmed {
+                    return;
+                }
+            }
+
+            let errors = errors.clone();
+            let is_submitting = is_submitting.clone();
+            let on_changed = on_changed.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = if publish {
+                    tauri::publish_inspection_plan(id).await
+                } else {
+                    tauri::new_inspection_plan_version(id).await
+                };
+                is_submitting.set(false);
+                handle_result(result, &errors, &on_changed);
+            });
+        })
+    };
+
+    let sampling_type = match plan.sampling {
+        SamplingRule::Fixed { .. } => "fixed",
+        SamplingRule::Percentage { .. } => "percentage",
+        SamplingRule::Aql { .. } => "aql",
+    };
+    let sampling_class = classes!("form-input", has_error("sampling".to_string()).then_some("input-error"));
+    let preview = {
+        let size = plan.sampling.sample_size(*preview_quantity);
+        match plan.sampling.acceptance(*preview_quantity) {
+            Some(acceptance) => format!(
+                "样本量字码 {}，抽检 {} 件，Ac {} / Re {}",
+                acceptance.code_letter, size, acceptance.accept, acceptance.reject
+            ),
+            None => format!("抽检 {} 件", size),
+        }
+    };
+
+    html! {
+        <div class="data-view">
+            <div class="toolbar">
+                <div class="detail-title">
+                    <h3>
+                        { if plan.id.is_some() {
+                            format!("{} · 第{}版", plan.item_code, plan.version)
+                        } else {
+                            "新检验标准".to_string()
+                        }}
+                    </h3>
+                    {plan_status_badge(plan.status)}
+                </div>
+                <div class="toolbar-actions">
+                    if plan.status == PlanStatus::Draft {
+                        <button class="btn" onclick={on_save} disabled={*is_submitting}>{"保存"}</button>
+                        if plan.id.is_some() {
+                            <button class="btn btn-primary" onclick={run_action(true)} disabled={*is_submitting}>{"发布"}</button>
+                        }
+                    } else {
+                        <button class="btn btn-primary" onclick={run_action(false)} disabled={*is_submitting}>{"创建新版本"}</button>
+                    }
+                </div>
+            </div>
+
+            if !errors.is_empty() {
+                <div class="error-banner">
+                    <ul class="error-list">
+                        { for errors.iter().map(|error| html! { <li>{&error.message}</li> }) }
+                    </ul>
+                </div>
+            }
+
+            <div class="form-grid">
+                <div class="form-field">
+                    <label class="required">{"物料 / 产品编码"}</label>
+                    <input class={classes!("form-input", has_error("itemCode".to_string()).then_some("input-error"))}
+                        type="text" value={plan.item_code.clone()}
+                        disabled={read_only || plan.id.is_some()}
+                        onchange={on_header_change(|p, v| p.item_code = v.trim().to_string())} />
+                </div>
+                <div class="form-field">
+                    <label>{"名称"}</label>
+                    <input class="form-input" type="text" value={plan.item_name.clone()}
+                        disabled={read_only}
+                        onchange={on_header_change(|p, v| p.item_name = v)} />
+                </div>
+                <div class="form-field">
+                    <label>{"工艺路线工序号"}</label>
+                    <input class={classes!("form-input", has_error("operationSeq".to_string()).then_some("input-error"))}
+                        type="number" min="1" step="1" placeholder="留空为来料检验"
+                        value={plan.operation_seq.map(|seq| seq.to_string()).unwrap_or_default()}
+                        disabled={read_only || plan.id.is_some()}
+                        onchange={on_header_change(|p, v| p.operation_seq = v.trim().parse().ok())} />
+                </div>
+                <div class="form-field">
+                    <label>{"备注"}</label>
+                    <input class="form-input" type="text" value={plan.remark.clone().unwrap_or_default()}
+                        disabled={read_only}
+                        onchange={on_header_change(|p, v| p.remark = Some(v).filter(|v| !v.trim().is_empty()))} />
+                </div>
+            </div>
+
+            <div class="detail-card">
+                <h3 class="form-title">{"抽样规则"}</h3>
+                <div class="form-grid">
+                    <div class="form-field">
+                        <label>{"抽样方式"}</label>
+                        <select class="form-input" disabled={read_only} onchange={on_sampling_type_change}>
+                            <option value="aql" selected={sampling_type == "aql"}>{"按AQL"}</option>
+                            <option value="fixed" selected={sampling_type == "fixed"}>{"固定样本量"}</option>
+                            <option value="percentage" selected={sampling_type == "percentage"}>{"按比例"}</option>
+                        </select>
+                    </div>
+                    { match &plan.sampling {
+                        SamplingRule::Aql { level, aql } => html! {
+                            <>
+                                <div class="form-field">
+                                    <label>{"检验水平"}</label>
+                                    <select class="form-input" disabled={read_only} onchange={on_level_change}>
+                                        { for InspectionLevel::ALL.iter().map(|l| html! {
+                                            <option value={l.label()} selected={l == level}>{format!("一般检验水平 {}", l.label())}</option>
+                                        })}
+                                    </select>
+                                </div>
+                                <div class="form-field">
+                                    <label>{"AQL"}</label>
+                                    <input class={sampling_class.clone()} type="number" min="0" step="any"
+                                        value={aql.to_string()} disabled={read_only}
+                                        onchange={on_sampling_change(|s, v| if let SamplingRule::Aql { aql, .. } = s {
+                                            *aql = v.parse().unwrap_or(0.0);
+                                        })} />
+                                </div>
+                            </>
+                        },
+                        SamplingRule::Fixed { sample_size } => html! {
+                            <div class="form-field">
+                                <label>{"样本量"}</label>
+                                <input class={sampling_class.clone()} type="number" min="1" step="1"
+                                    value={sample_size.to_string()} disabled={read_only}
+                                    onchange={on_sampling_change(|s, v| if let SamplingRule::Fixed { sample_size } = s {
+                                        *sample_size = v.parse().unwrap_or(0);
+                                    })} />
+                            </div>
+                        },
+                        SamplingRule::Percentage { percent, minimum } => html! {
+                            <>
+                                <div class="form-field">
+                                    <label>{"抽样比例(%)"}</label>
+                                    <input class={sampling_class.clone()} type="number" min="0" max="100" step="any"
+                                        value={percent.to_string()} disabled={read_only}
+                                        onchange={on_sampling_change(|s, v| if let SamplingRule::Percentage { percent, .. } = s {
+                                            *percent = v.parse().unwrap_or(0.0);
+                                        })} />
+                                </div>
+                                <div class="form-field">
+                                    <label>{"最小样本量"}</label>
+                                    <input class="form-input" type="number" min="0" step="1"
+                                        value={minimum.to_string()} disabled={read_only}
+                                        onchange={on_sampling_change(|s, v| if let SamplingRule::Percentage { minimum, .. } = s {
+                                            *minimum = v.parse().unwrap_or(0);
+                                        })} />
+                                </div>
+                            </>
+                        },
+                    }}
+                    <div class="form-field">
+                        <label>{"样本量试算：批量"}</label>
+                        <input class="form-input" type="number" min="1" step="1"
+                            value={preview_quantity.to_string()} oninput={on_preview_input} />
+                        <span class="toolbar-text">{preview}</span>
+                    </div>
+                </div>
+            </div>
+
+            <div class="toolbar">
+                <h3 class="form-title">{"检验特性"}</h3>
+                if !read_only {
+                    <button class="btn" onclick={on_add_characteristic}>{"添加特性"}</button>
+                }
+            </div>
+
+            <div class="table-container">
+                <table class="data-table editable-table">
+                    <thead>
+                        <tr>
+                            <th>{"编码"}</th>
+                            <th>{"名称"}</th>
+                            <th>{"类型"}</th>
+                            <th class="numeric">{"标准值"}</th>
+                            <th class="numeric">{"下限"}</th>
+                            <th class="numeric">{"上限"}</th>
+                            <th>{"单位"}</th>
+                            <th>{"可选项"}</th>
+                            <th>{"可接收项"}</th>
+                            <th>{"关键"}</th>
+                            <th></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { for plan.characteristics.iter().enumerate().map(|(index, characteristic)| {
+                            let field = |name: &str| format!("characteristics[{}].{}", index, name);
+                            let cell_class = |name: &str| classes!("form-input", has_error(field(name)).then_some("input-error"));
+                            let on_kind_change = {
+                                let plan = plan.clone();
+                                Callback::from(move |e: Event| {
+                                    let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                                    let mut next = (*plan).clone();
+                                    if let Some(characteristic) = next.characteristics.get_mut(index) {
+                                        characteristic.kind = kind_from_code(&select.value());
+                                    }
+                                    plan.set(next);
+                                })
+                            };
+                            let on_critical_change = {
+                                let plan = plan.clone();
+                                Callback::from(move |e: Event| {
+                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                    let mut next = (*plan).clone();
+                                    if let Some(characteristic) = next.characteristics.get_mut(index) {
+                                        characteristic.critical = input.checked();
+                                    }
+                                    plan.set(next);
+                                })
+                            };
+                            let on_remove = {
+                                let plan = plan.clone();
+                                Callback::from(move |_: MouseEvent| {
+                                    let mut next = (*plan).clone();
+                                    if index < next.characteristics.len() {
+                                        next.characteristics.remove(index);
+                                    }
+                                    plan.set(next);
+                                })
+                            };
+                            let kind = kind_code(&characteristic.kind);
+                            html! {
+                                <tr>
+                                    <td>
+                                        <input class={cell_class("code")} type="text" value={characteristic.code.clone()} disabled={read_only}
+                                            onchange={bind_char(index, |c, v| c.code = v.trim().to_string())} />
+                                    </td>
+                                    <td>
+                                        <input class={cell_class("name")} type="text" value={characteristic.name.clone()} disabled={read_only}
+                                            onchange={bind_char(index, |c, v| c.name = v)} />
+                                    </td>
+                                    <td>
+                                        <select class="form-input" disabled={read_only} onchange={on_kind_change}>
+                                            <option value="numeric" selected={kind == "numeric"}>{"计量"}</option>
+                                            <option value="passFail" selected={kind == "passFail"}>{"合格/不合格"}</option>
+                                            <option value="attribute" selected={kind == "attribute"}>{"属性"}</option>
+                                        </select>
+                                    </td>
+                                    { match &characteristic.kind {
+                                        CharacteristicKind::Numeric { nominal, lower_limit, upper_limit, unit } => html! {
+                                            <>
+                                                <td class="numeric">
+                                                    <input class="form-input" type="number" step="any" value={nominal.to_string()} disabled={read_only}
+                                                        onchange={bind_char(index, |c, v| if let CharacteristicKind::Numeric { nominal, .. } = &mut c.kind {
+                                                            *nominal = v.parse().unwrap_or(0.0);
+                                                        })} />
+                                                </td>
+                                                <td class="numeric">
+                                                    <input class={cell_class("lowerLimit")} type="number" step="any"
+                                                        value={lower_limit.map(|v| v.to_string()).unwrap_or_default()} disabled={read_only}
+                                                        onchange={bind_char(index, |c, v| if let CharacteristicKind::Numeric { lower_limit, .. } = &mut c.kind {
+                                                            *lower_limit = parse_limit(&v);
+                                                        })} />
+                                                </td>
+                                                <td class="numeric">
+                                                    <input class={cell_class("upperLimit")} type="number" step="any"
+                                                        value={upper_limit.map(|v| v.to_string()).unwrap_or_default()} disabled={read_only}
+                                                        onchange={bind_char(index, |c, v| if let CharacteristicKind::Numeric { upper_limit, .. } = &mut c.kind {
+                                                            *upper_limit = parse_limit(&v);
+                                                        })} />
+                                                </td>
+                                                <td>
+                                                    <input class="form-input" type="text" value={unit.clone()} disabled={read_only}
+                                                        onchange={bind_char(index, |c, v| if let CharacteristicKind::Numeric { unit, .. } = &mut c.kind {
+                                                            *unit = v.trim().to_string();
+                                                        })} />
+                                                </td>
+                                                <td></td>
+                                                <td></td>
+                                            </>
+                                        },
+                                        CharacteristicKind::PassFail => html! {
+                                            <>
+                                                <td></td><td></td><td></td><td></td><td></td><td></td>
+                                            </>
+                                        },
+                                        CharacteristicKind::Attribute { options, accepted } => html! {
+                                            <>
+                                                <td></td><td></td><td></td><td></td>
+                                                <td>
+                                                    <input class={cell_class("options")} type="text" placeholder="逗号分隔"
+                                                        value={options.join(",")} disabled={read_only}
+                                                        onchange={bind_char(index, |c, v| if let CharacteristicKind::Attribute { options, .. } = &mut c.kind {
+                                                            *options = parse_list(&v);
+                                                        })} />
+                                                </td>
+                                                <td>
+                                                    <input class={cell_class("accepted")} type="text" placeholder="逗号分隔"
+                                                        value={accepted.join(",")} disabled={read_only}
+                                                        onchange={bind_char(index, |c, v| if let CharacteristicKind::Attribute { accepted, .. } = &mut c.kind {
+                                                            *accepted = parse_list(&v);
+                                                        })} />
+                                                </td>
+                                            </>
+                                        },
+                                    }}
+                                    <td>
+                                        <input type="checkbox" checked={characteristic.critical} disabled={read_only}
+                                            onchange={on_critical_change} />
+                                    </td>
+                                    <td>
+                                        if !read_only {
+                                            <button class="btn" onclick={on_remove}>{"移除"}</button>
+                                        }
+                                    </td>
+                                </tr>
+                            }
+                        })}
+                    </tbody>
+                </table>
+                if plan.characteristics.is_empty() {
+                    <div class="table-empty">{"尚未添加检验特性"}</div>
+                }
+            </div>
+        </div>
+    }
+}
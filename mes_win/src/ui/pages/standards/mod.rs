@@ -0,0 +1,158 @@
+// 检验标准页面
+//
+// 左侧为检验标准版本列表，右侧为检验特性与抽样规则编辑器
+mod editor;
+
+use yew::prelude::*;
+use mes_win_shared::quality::plan::{InspectionPlan, InspectionPlanSummary, PlanStatus};
+use crate::services::tauri;
+use editor::PlanEditor;
+
+/// 检验标准状态标签
+pub fn plan_status_badge(status: PlanStatus) -> Html {
+    let class = format!("status-badge status-{}", status.css_class());
+    html! { <span class={class}>{status.label()}</span> }
+}
+
+#[function_component(StandardsPage)]
+pub fn standards_page() -> Html {
+    let plans = use_state(Vec::<InspectionPlanSummary>::new);
+    let keyword = use_state(String::new);
+    let selected = use_state(|| None::<InspectionPlan>);
+    // 每次切换编辑对象时递增，使编辑器重新初始化
+    let editor_key = use_state(|| 0u32);
+    let reload = use_state(|| 0u32);
+    let error_message = use_state(|| None::<String>);
+
+    {
+        let plans = plans.clone();
+        let error_message = error_message.clone();
+        let keyword = (*keyword).clone();
+        use_effect_with(*reload, move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_inspection_plans(Some(keyword)).await {
+                    Ok(data) => plans.set(data),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    let open_plan = {
+        let selected = selected.clone();
+        let editor_key = editor_key.clone();
+        Callback::from(move |plan: InspectionPlan| {
+            selected.set(Some(plan));
+            editor_key.set(*editor_key + 1);
+        })
+    };
+
+    let on_select = {
+        let open_plan = open_plan.clone();
+        let error_message = error_message.clone();
+        Callback::from(move |id: u64| {
+            let open_plan = open_plan.clone();
+            let error_message = error_message.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::get_inspection_plan(id).await {
+                    Ok(plan) => {
+                        error_message.set(None);
+                        open_plan.emit(plan);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_create = {
+        let open_plan = open_plan.clone();
+        Callback::from(move |_: MouseEvent| open_plan.emit(InspectionPlan::default()))
+    };
+
+    // 保存/发布/新建版本后刷新列表并切换到返回的版本
+    let on_changed = {
+        let open_plan = open_plan.clone();
+        let reload = reload.clone();
+        Callback::from(move |plan: InspectionPlan| {
+            open_plan.emit(plan);
+            reload.set(*reload + 1);
+        })
+    };
+
+    let on_keyword_input = {
+        let keyword = keyword.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            keyword.set(input.value());
+        })
+    };
+
+    let on_search = {
+        let reload = reload.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            reload.set(*reload + 1);
+        })
+    };
+
+    let selected_id = selected.as_ref().and_then(|plan| plan.id);
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"质量管理 - 标准"}</h2>
+                <p>{"维护检验标准：检验特性、公差、抽样规则及适用的物料和工序"}</p>
+            </div>
+
+            if let Some(message) = (*error_message).clone() {
+                <div class="error-banner">{message}</div>
+            }
+
+            <div class="split-view">
+                <div class="split-list">
+                    <form class="toolbar-search" onsubmit={on_search}>
+                        <input class="form-input" type="text" placeholder="物料 / 产品编码或名称"
+                            value={(*keyword).clone()} oninput={on_keyword_input} />
+                        <button class="btn" type="submit">{"查询"}</button>
+                    </form>
+                    <button class="btn btn-primary" onclick={on_create}>{"新建检验标准"}</button>
+                    <div class="list-items">
+                        { for plans.iter().map(|summary| {
+                            let id = summary.id;
+                            let on_select = on_select.clone();
+                            let class = classes!("list-item", (selected_id == Some(id)).then_some("active"));
+                            let scope = match summary.operation_seq {
+                                Some(seq) => format!("工序 {}", seq),
+                                None => "来料".to_string(),
+                            };
+                            html! {
+                                <div class={class} onclick={Callback::from(move |_: MouseEvent| on_select.emit(id))}>
+                                    <div class="list-item-title">
+                                        {format!("{} · 第{}版", summary.item_code, summary.version)}
+                                        {plan_status_badge(summary.status)}
+                                    </div>
+                                    <div class="list-item-description">
+                                        {format!("{} · {} · {} 项特性", summary.item_name, scope, summary.characteristic_count)}
+                                    </div>
+                                </div>
+                            }
+                        })}
+                        if plans.is_empty() {
+                            <div class="table-empty">{"暂无检验标准"}</div>
+                        }
+                    </div>
+                </div>
+
+                <div class="split-detail">
+                    if let Some(plan) = (*selected).clone() {
+                        <PlanEditor key={*editor_key} plan={plan} on_changed={on_changed} />
+                    } else {
+                        <div class="table-empty">{"选择左侧的检验标准，或新建检验标准"}</div>
+                    }
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,196 @@
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::user::{password_problem, Role, UserAccount};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+use crate::ui::components::modal::Modal;
+use super::form::role_checkboxes;
+
+/// 单字段错误
+fn single_error(field: &str, message: &str) -> Vec<FieldError> {
+    vec![FieldError {
+        field: field.to_string(),
+        message: message.to_string(),
+    }]
+}
+
+// 分配角色对话框属性
+#[derive(Properties, PartialEq)]
+pub struct RolesDialogProps {
+    pub user: UserAccount,
+    pub roles: Vec<Role>,
+    pub on_close: Callback<()>,
+    /// 保存成功后返回提示信息
+    pub on_saved: Callback<String>,
+}
+
+// 分配角色
+#[function_component(RolesDialog)]
+pub fn roles_dialog(props: &RolesDialogProps) -> Html {
+    let selected = use_state(|| props.user.roles.clone());
+    let errors = use_state(Vec::<FieldError>::new);
+    let is_submitting = use_state(|| false);
+
+    let on_change = {
+        let selected = selected.clone();
+        Callback::from(move |roles: Vec<String>| selected.set(roles))
+    };
+
+    let on_submit = {
+        let id = props.user.id;
+        let selected = selected.clone();
+        let errors = errors.clone();
+        let is_submitting = is_submitting.clone();
+        let on_saved = props.on_saved.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if selected.is_empty() {
+                errors.set(single_error("roles", "请至少分配一个角色"));
+                return;
+            }
+
+            let roles = (*selected).clone();
+            let errors = errors.clone();
+            let is_submitting = is_submitting.clone();
+            let on_saved = on_saved.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::assign_user_roles(id, roles).await;
+                is_submitting.set(false);
+                match result {
+                    Ok(user) => on_saved.emit(format!("已更新「{}」的角色", user.username)),
+                    Err(ApiError::Validation { message, fields }) if fields.is_empty() => {
+                        errors.set(single_error("roles", &message));
+                    }
+                    Err(ApiError::Validation { fields, .. }) => errors.set(fields),
+                    Err(error) => errors.set(single_error("roles", &error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_cancel = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| on_close.emit(()))
+    };
+
+    html! {
+        <Modal title={format!("分配角色 - {}", props.user.display_name)} on_close={props.on_close.clone()}>
+            <form class="data-view" onsubmit={on_submit}>
+                { role_checkboxes(&props.roles, &selected, on_change) }
+                { field_error(&errors, "roles") }
+                <div class="form-actions">
+                    <button class="btn" type="button" onclick={on_cancel}>{"取消"}</button>
+                    <button class="btn btn-primary" type="submit" disabled={*is_submitting}>
+                        { if *is_submitting { "保存中..." } else { "保存" } }
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
+
+// 重置密码对话框属性
+#[derive(Properties, PartialEq)]
+pub struct PasswordDialogProps {
+    pub user: UserAccount,
+    pub on_close: Callback<()>,
+    /// 重置成功后返回提示信息
+    pub on_saved: Callback<String>,
+}
+
+// 重置密码（用户下次登录时必须修改）
+#[function_component(PasswordDialog)]
+pub fn password_dialog(props: &PasswordDialogProps) -> Html {
+    let password = use_state(String::new);
+    let confirm = use_state(String::new);
+    let errors = use_state(Vec::<FieldError>::new);
+    let is_submitting = use_state(|| false);
+
+    let on_password_input = {
+        let password = password.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            password.set(input.value());
+        })
+    };
+
+    let on_confirm_input = {
+        let confirm = confirm.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            confirm.set(input.value());
+        })
+    };
+
+    let on_submit = {
+        let id = props.user.id;
+        let password = password.clone();
+        let confirm = confirm.clone();
+        let errors = errors.clone();
+        let is_submitting = is_submitting.clone();
+        let on_saved = props.on_saved.clone();
+        let username = props.user.username.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if let Some(message) = password_problem(&password) {
+                errors.set(single_error("password", message));
+                return;
+            }
+            if *password != *confirm {
+                errors.set(single_error("confirm", "两次输入的密码不一致"));
+                return;
+            }
+
+            let submitted = (*password).clone();
+            let username = username.clone();
+            let errors = errors.clone();
+            let is_submitting = is_submitting.clone();
+            let on_saved = on_saved.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::reset_user_password(id, submitted).await;
+                is_submitting.set(false);
+                match result {
+                    Ok(()) => on_saved.emit(format!("已重置「{}」的密码，下次登录需修改", username)),
+                    Err(ApiError::Validation { message, fields }) if fields.is_empty() => {
+                        errors.set(single_error("password", &message));
+                    }
+                    Err(ApiError::Validation { fields, .. }) => errors.set(fields),
+                    Err(error) => errors.set(single_error("password", &error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_cancel = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| on_close.emit(()))
+    };
+
+    html! {
+        <Modal title={format!("重置密码 - {}", props.user.display_name)} on_close={props.on_close.clone()}>
+            <form class="data-view" onsubmit={on_submit}>
+                <div class="form-field">
+                    <label class="required">{"新密码"}</label>
+                    <input class="form-input" type="password" autocomplete="new-password"
+                        value={(*password).clone()} oninput={on_password_input} />
+                    { field_error(&errors, "password") }
+                </div>
+                <div class="form-field">
+                    <label class="required">{"确认密码"}</label>
+                    <input class="form-input" type="password" autocomplete="new-password"
+                        value={(*confirm).clone()} oninput={on_confirm_input} />
+                    { field_error(&errors, "confirm") }
+                </div>
+                <div class="form-actions">
+                    <button class="btn" type="button" onclick={on_cancel}>{"取消"}</button>
+                    <button class="btn btn-primary" type="submit" disabled={*is_submitting}>
+                        { if *is_submitting { "提交中..." } else { "重置" } }
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
@@ -0,0 +1,164 @@
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::user::{Role, UserDraft};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+use crate::ui::components::modal::Modal;
+
+/// 角色复选框组，勾选变化时返回新的角色编码列表
+pub fn role_checkboxes(roles: &[Role], selected: &[String], on_change: Callback<Vec<String>>) -> Html {
+    html! {
+        <div class="role-options">
+            { for roles.iter().map(|role| {
+                let code = role.code.clone();
+                let checked = selected.contains(&code);
+                let selected = selected.to_vec();
+                let on_change = on_change.clone();
+                let onchange = Callback::from(move |_: Event| {
+                    let mut next: Vec<String> = selected.iter().filter(|c| **c != code).cloned().collect();
+                    if !checked {
+                        next.push(code.clone());
+                    }
+                    on_change.emit(next);
+                });
+                html! {
+                    <label class="checkbox-label" title={role.description.clone().unwrap_or_default()}>
+                        <input type="checkbox" checked={checked} onchange={onchange} />
+                        {&role.name}
+                    </label>
+                }
+            })}
+        </div>
+    }
+}
+
+// 新建用户对话框属性
+#[derive(Properties, PartialEq)]
+pub struct CreateUserDialogProps {
+    pub roles: Vec<Role>,
+    pub on_close: Callback<()>,
+    /// 创建成功后返回提示信息
+    pub on_created: Callback<String>,
+}
+
+// 新建用户
+#[function_component(CreateUserDialog)]
+pub fn create_user_dialog(props: &CreateUserDialogProps) -> Html {
+    let draft = use_state(UserDraft::default);
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+
+    let text_input = |apply: fn(&mut UserDraft, String)| {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*draft).clone();
+            apply(&mut next, input.value());
+            draft.set(next);
+        })
+    };
+
+    let on_roles_change = {
+        let draft = draft.clone();
+        Callback::from(move |roles: Vec<String>| {
+            let mut next = (*draft).clone();
+            next.roles = roles;
+            draft.set(next);
+        })
+    };
+
+    let on_submit = {
+        let draft = draft.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let on_created = props.on_created.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let local_errors = draft.validate();
+            if !local_errors.is_empty() {
+                errors.set(local_errors);
+                return;
+            }
+
+            let submitted = (*draft).clone();
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            let on_created = on_created.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::create_user(submitted).await;
+                is_submitting.set(false);
+                match result {
+                    Ok(user) => on_created.emit(format!("已创建用户「{}」，首次登录需修改密码", user.username)),
+                    Err(ApiError::Validation { message, fields }) => {
+                        errors.set(fields);
+                        error_message.set(Some(message));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_cancel = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| on_close.emit(()))
+    };
+
+    html! {
+        <Modal title="新建用户" on_close={props.on_close.clone()}>
+            <form class="data-view" onsubmit={on_submit}>
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+                <div class="form-grid">
+                    <div class="form-field">
+                        <label class="required">{"用户名"}</label>
+                        <input class="form-input" type="text" value={draft.username.clone()}
+                            oninput={text_input(|d, v| d.username = v)} />
+                        { field_error(&errors, "username") }
+                    </div>
+                    <div class="form-field">
+                        <label class="required">{"姓名"}</label>
+                        <input class="form-input" type="text" value={draft.display_name.clone()}
+                            oninput={text_input(|d, v| d.display_name = v)} />
+                        { field_error(&errors, "displayName") }
+                    </div>
+                    <div class="form-field">
+                        <label>{"邮箱"}</label>
+                        <input class="form-input" type="email" value={draft.email.clone().unwrap_or_default()}
+                            oninput={text_input(|d, v| d.email = Some(v).filter(|v| !v.trim().is_empty()))} />
+                        { field_error(&errors, "email") }
+                    </div>
+                    <div class="form-field">
+                        <label>{"电话"}</label>
+                        <input class="form-input" type="text" value={draft.phone.clone().unwrap_or_default()}
+                            oninput={text_input(|d, v| d.phone = Some(v).filter(|v| !v.trim().is_empty()))} />
+                    </div>
+                    <div class="form-field form-field-wide">
+                        <label class="required">{"初始密码"}</label>
+                        <input class="form-input" type="password" autocomplete="new-password"
+                            value={draft.password.clone()} oninput={text_input(|d, v| d.password = v)} />
+                        { field_error(&errors, "password") }
+                    </div>
+                    <div class="form-field form-field-wide">
+                        <label class="required">{"角色"}</label>
+                        { role_checkboxes(&props.roles, &draft.roles, on_roles_change) }
+                        { field_error(&errors, "roles") }
+                    </div>
+                </div>
+                <div class="form-actions">
+                    <button class="btn" type="button" onclick={on_cancel}>{"取消"}</button>
+                    <button class="btn btn-primary" type="submit" disabled={*is_submitting}>
+                        { if *is_submitting { "提交中..." } else { "创建" } }
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
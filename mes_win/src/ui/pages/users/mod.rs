@@ -0,0 +1,327 @@
+// 用户账户管理页面
+//
+// 用户列表支持按关键字和状态查询，行内完成停用/启用、解锁、重置密码和分配角色
+mod dialogs;
+mod form;
+
+use yew::prelude::*;
+use mes_win_shared::response::PageData;
+use mes_win_shared::user::{Role, UserAccount, UserQuery, UserStatus};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::pagination::Pagination;
+use crate::utils::format::format_datetime;
+use dialogs::{PasswordDialog, RolesDialog};
+use form::CreateUserDialog;
+
+/// 用户状态标签
+fn user_status_badge(status: UserStatus) -> Html {
+    let class = format!("status-badge status-{}", status.css_class());
+    html! { <span class={class}>{status.label()}</span> }
+}
+
+/// 角色编码显示为角色名称，未知编码原样显示
+fn role_names(roles: &[Role], codes: &[String]) -> String {
+    codes
+        .iter()
+        .map(|code| {
+            roles
+                .iter()
+                .find(|role| role.code == *code)
+                .map(|role| role.name.clone())
+                .unwrap_or_else(|| code.clone())
+        })
+        .collect::<Vec<_>>()
+        .join("、")
+}
+
+// 当前打开的对话框
+#[derive(Clone, PartialEq)]
+enum UserDialog {
+    Create,
+    Roles(Box<UserAccount>),
+    Password(Box<UserAccount>),
+}
+
+#[function_component(UsersPage)]
+pub fn users_page() -> Html {
+    let query = use_state(UserQuery::default);
+    let page_data = use_state(PageData::<UserAccount>::default);
+    let roles = use_state(Vec::<Role>::new);
+    let keyword = use_state(String::new);
+    let dialog = use_state(|| None::<UserDialog>);
+    let reload = use_state(|| 0u32);
+    let is_loading = use_state(|| false);
+    let error_message = use_state(|| None::<String>);
+    let notice = use_state(|| None::<String>);
+
+    {
+        let roles = roles.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Ok(list) = tauri::list_roles().await {
+                    roles.set(list);
+                }
+            });
+            || ()
+        });
+    }
+
+    {
+        let page_data = page_data.clone();
+        let is_loading = is_loading.clone();
+        let error_message = error_message.clone();
+        use_effect_with(((*query).clone(), *reload), move |(query, _)| {
+            let query = query.clone();
+            is_loading.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_users(query).await {
+                    Ok(data) => {
+                        page_data.set(data);
+                        error_message.set(None);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+                is_loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    let on_keyword_input = {
+        let keyword = keyword.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            keyword.set(input.value());
+        })
+    };
+
+    let on_search = {
+        let keyword = keyword.clone();
+        let query = query.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let mut next = (*query).clone();
+            next.keyword = Some((*keyword).clone()).filter(|k| !k.trim().is_empty());
+            next.page = 1;
+            query.set(next);
+        })
+    };
+
+    let on_status_change = {
+        let query = query.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*query).clone();
+            next.status = UserStatus::from_code(&select.value());
+            next.page = 1;
+            query.set(next);
+        })
+    };
+
+    let on_page_change = {
+        let query = query.clone();
+        Callback::from(move |page: u32| {
+            let mut next = (*query).clone();
+            next.page = page;
+            query.set(next);
+        })
+    };
+
+    // 对话框完成后关闭并刷新列表
+    let on_done = {
+        let dialog = dialog.clone();
+        let reload = reload.clone();
+        let notice = notice.clone();
+        Callback::from(move |message: String| {
+            dialog.set(None);
+            notice.set(Some(message));
+            reload.set(*reload + 1);
+        })
+    };
+
+    let on_close_dialog = {
+        let dialog = dialog.clone();
+        Callback::from(move |_: ()| dialog.set(None))
+    };
+
+    let on_create = {
+        let dialog = dialog.clone();
+        Callback::from(move |_: MouseEvent| dialog.set(Some(UserDialog::Create)))
+    };
+
+    // 停用/启用、解锁这类无需输入的操作
+    let run_action = {
+        let reload = reload.clone();
+        let error_message = error_message.clone();
+        let notice = notice.clone();
+        move |user: &UserAccount, action: UserAction| {
+            let id = user.id;
+            let username = user.username.clone();
+            let reload = reload.clone();
+            let error_message = error_message.clone();
+            let notice = notice.clone();
+            Callback::from(move |e: MouseEvent| {
+                e.stop_propagation();
+                if action == UserAction::Disable {
+                    let confirmed = web_sys::window()
+                        .and_then(|w| w.confirm_with_message(&format!("停用后「{}」将无法登录，确定停用吗？", username)).ok())
+                        .unwrap_or(false);
+                    if !confirmed {
+                        return;
+                    }
+                }
+
+                let reload = reload.clone();
+                let error_message = error_message.clone();
+                let notice = notice.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let result = match action {
+                        UserAction::Enable => tauri::set_user_enabled(id, true).await,
+                        UserAction::Disable => tauri::set_user_enabled(id, false).await,
+                        UserAction::Unlock => tauri::unlock_user(id).await,
+                    };
+                    match result {
+                        Ok(user) => {
+                            notice.set(Some(format!("{}「{}」", action.done_label(), user.username)));
+                            error_message.set(None);
+                            reload.set(*reload + 1);
+                        }
+                        Err(ApiError::Validation { message, .. }) => error_message.set(Some(message)),
+                        Err(error) => error_message.set(Some(error.user_message())),
+                    }
+                });
+            })
+        }
+    };
+
+    let open_dialog = |next: UserDialog| {
+        let dialog = dialog.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            dialog.set(Some(next.clone()));
+        })
+    };
+
+    let selected_status = query.status.map(|status| status.code()).unwrap_or("");
+
+    let dialog_view = match (*dialog).clone() {
+        None => html! {},
+        Some(UserDialog::Create) => html! {
+            <CreateUserDialog roles={(*roles).clone()} on_close={on_close_dialog} on_created={on_done} />
+        },
+        Some(UserDialog::Roles(user)) => html! {
+            <RolesDialog user={*user} roles={(*roles).clone()} on_close={on_close_dialog} on_saved={on_done} />
+        },
+        Some(UserDialog::Password(user)) => html! {
+            <PasswordDialog user={*user} on_close={on_close_dialog} on_saved={on_done} />
+        },
+    };
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"系统设置 - 用户"}</h2>
+                <p>{"用户账户的创建、停用、解锁、密码重置和角色分配"}</p>
+            </div>
+
+            <div class="data-view">
+                <div class="toolbar">
+                    <form class="toolbar-search" onsubmit={on_search}>
+                        <input class="form-input" type="text" placeholder="用户名 / 姓名"
+                            value={(*keyword).clone()} oninput={on_keyword_input} />
+                        <select class="form-input" onchange={on_status_change}>
+                            <option value="" selected={selected_status.is_empty()}>{"全部状态"}</option>
+                            { for UserStatus::ALL.iter().map(|status| html! {
+                                <option value={status.code()} selected={selected_status == status.code()}>
+                                    {status.label()}
+                                </option>
+                            })}
+                        </select>
+                        <button class="btn" type="submit">{"查询"}</button>
+                    </form>
+                    <button class="btn btn-primary" onclick={on_create}>{"新建用户"}</button>
+                </div>
+
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+                if let Some(message) = (*notice).clone() {
+                    <div class="notice-banner">{message}</div>
+                }
+
+                <div class="table-container">
+                    <table class="data-table">
+                        <thead>
+                            <tr>
+                                <th>{"用户名"}</th>
+                                <th>{"姓名"}</th>
+                                <th>{"角色"}</th>
+                                <th>{"邮箱"}</th>
+                                <th>{"最近登录"}</th>
+                                <th>{"状态"}</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for page_data.items.iter().map(|user| html! {
+                                <tr>
+                                    <td>{&user.username}</td>
+                                    <td>{&user.display_name}</td>
+                                    <td>{role_names(&roles, &user.roles)}</td>
+                                    <td>{user.email.clone().unwrap_or_else(|| "-".to_string())}</td>
+                                    <td>{user.last_login_at.as_deref().map(format_datetime).unwrap_or_else(|| "-".to_string())}</td>
+                                    <td>{user_status_badge(user.status)}</td>
+                                    <td class="row-actions">
+                                        <button class="btn" onclick={open_dialog(UserDialog::Roles(Box::new(user.clone())))}>{"角色"}</button>
+                                        <button class="btn" onclick={open_dialog(UserDialog::Password(Box::new(user.clone())))}>{"重置密码"}</button>
+                                        if user.status == UserStatus::Locked {
+                                            <button class="btn" onclick={run_action(user, UserAction::Unlock)}>{"解锁"}</button>
+                                        }
+                                        if user.status == UserStatus::Disabled {
+                                            <button class="btn" onclick={run_action(user, UserAction::Enable)}>{"启用"}</button>
+                                        } else {
+                                            <button class="btn btn-danger" onclick={run_action(user, UserAction::Disable)}>{"停用"}</button>
+                                        }
+                                    </td>
+                                </tr>
+                            })}
+                        </tbody>
+                    </table>
+                    if page_data.items.is_empty() {
+                        <div class="table-empty">
+                            { if *is_loading { "加载中..." } else { "暂无用户" } }
+                        </div>
+                    }
+                </div>
+
+                <Pagination
+                    page={page_data.page}
+                    total_pages={page_data.total_pages()}
+                    total={page_data.total}
+                    on_change={on_page_change}
+                />
+            </div>
+
+            { dialog_view }
+        </div>
+    }
+}
+
+// 无需输入的行内操作
+#[derive(Clone, Copy, PartialEq)]
+enum UserAction {
+    Enable,
+    Disable,
+    Unlock,
+}
+
+impl UserAction {
+    fn done_label(&self) -> &'static str {
+        match self {
+            UserAction::Enable => "已启用",
+            UserAction::Disable => "已停用",
+            UserAction::Unlock => "已解锁",
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use yew::prelude::*;
+use mes_win_shared::inventory::warehouse::{Bin, BinStock, Warehouse};
+use crate::services::tauri;
+use crate::utils::format::format_quantity;
+use super::transfer::TransferDialog;
+
+// 库位看板属性
+#[derive(Properties, PartialEq)]
+pub struct BinBoardProps {
+    pub warehouse: Warehouse,
+    pub on_changed: Callback<()>,
+}
+
+/// 库位方块的提示文字
+fn bin_title(bin: &Bin) -> String {
+    let capacity = match bin.capacity {
+        Some(capacity) => format!("{} / {}", format_quantity(bin.occupied), format_quantity(capacity)),
+        None => format!("{}（不限容量）", format_quantity(bin.occupied)),
+    };
+    let blocked = if bin.blocked { "\n已冻结" } else { "" };
+    format!("{} {}\n{}{}", bin.code, bin.name, capacity, blocked)
+}
+
+// 库位占用看板：按库区显示库位方块，点击查看库位库存并移库
+#[function_component(BinBoard)]
+pub fn bin_board(props: &BinBoardProps) -> Html {
+    let selected_bin = use_state(|| None::<String>);
+    let stock = use_state(Vec::<BinStock>::new);
+    let transferring = use_state(|| None::<BinStock>);
+    let reload = use_state(|| 0u32);
+    let error_message = use_state(|| None::<String>);
+
+    {
+        let stock = stock.clone();
+        let error_message = error_message.clone();
+        use_effect_with(((*selected_bin).clone(), *reload), move |(bin, _)| {
+            match bin.clone() {
+                Some(bin) => wasm_bindgen_futures::spawn_local(async move {
+                    match tauri::list_bin_stock(bin).await {
+                        Ok(data) => {
+                            stock.set(data);
+                            error_message.set(None);
+                        }
+                        Err(error) => error_message.set(Some(error.user_message())),
+                    }
+                }),
+                None => stock.set(Vec::new()),
+            }
+            || ()
+        });
+    }
+
+    let on_close_transfer = {
+        let transferring = transferring.clone();
+        Callback::from(move |_: ()| transferring.set(None))
+    };
+
+    let on_transferred = {
+        let transferring = transferring.clone();
+        let reload = reload.clone();
+        let on_changed = props.on_changed.clone();
+        Callback::from(move |_: ()| {
+            transferring.set(None);
+            reload.set(*reload + 1);
+            on_changed.emit(());
+        })
+    };
+
+    let render_bin = |bin: &Bin| {
+        let state = bin.occupancy_state();
+        let class = classes!(
+            "bin-tile",
+            state.css_class(),
+            bin.blocked.then_some("blocked"),
+            (selected_bin.as_ref() == Some(&bin.code)).then_some("active"),
+        );
+        let onclick = {
+            let selected_bin = selected_bin.clone();
+            let code = bin.code.clone();
+            Callback::from(move |_: MouseEvent| selected_bin.set(Some(code.clone())))
+        };
+        let ratio = bin.occupancy().map(|ratio| format!("{:.0}%", ratio * 100.0));
+        html! {
+            <div class={class} title={bin_title(bin)} onclick={onclick}>
+                <div class="bin-code">{&bin.code}</div>
+                <div class="bin-usage">{ratio.unwrap_or_else(|| state.label().to_string())}</div>
+            </div>
+        }
+    };
+
+    html! {
+        <>
+            { for props.warehouse.zones.iter().map(|zone| html! {
+                <div class="bin-zone">
+                    <div class="bin-zone-title">
+                        {format!("{} {}", zone.code, zone.name)}
+                        <span class="toolbar-text">{format!("{} 个库位", zone.bins.len())}</span>
+                    </div>
+                    <div class="bin-grid">
+                        { for zone.bins.iter().map(render_bin) }
+                    </div>
+                </div>
+            })}
+
+            if props.warehouse.zones.is_empty() {
+                <div class="table-empty">{"该仓库尚未配置库区"}</div>
+            }
+
+            if let Some(message) = (*error_message).clone() {
+                <div class="error-banner">{message}</div>
+            }
+
+            if let Some(bin) = selected_bin.as_ref().and_then(|code| props.warehouse.find_bin(code)) {
+                <div class="table-container">
+                    <table class="data-table">
+                        <thead>
+                            <tr>
+                                <th>{"物料 / 产品"}</th>
+                                <th>{"批次"}</th>
+                                <th class="numeric">{"数量"}</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for stock.iter().map(|item| {
+                                let onclick = {
+                                    let transferring = transferring.clone();
+                                    let item = item.clone();
+                                    Callback::from(move |_: MouseEvent| transferring.set(Some(item.clone())))
+                                };
+                                html! {
+                                    <tr>
+                                        <td>{format!("{} {}", item.item_code, item.item_name)}</td>
+                                        <td>{item.lot_no.clone().unwrap_or_else(|| "-".to_string())}</td>
+                                        <td class="numeric">{format!("{} {}", format_quantity(item.quantity), item.unit)}</td>
+                                        <td class="row-actions">
+                                            <button class="btn" disabled={bin.blocked} onclick={onclick}>{"移库"}</button>
+                                        </td>
+                                    </tr>
+                                }
+                            })}
+                        </tbody>
+                    </table>
+                    if stock.is_empty() {
+                        <div class="table-empty">{format!("库位 {} 暂无库存", bin.code)}</div>
+                    }
+                </div>
+            }
+
+            if let Some(item) = (*transferring).clone() {
+                <TransferDialog
+                    stock={item}
+                    warehouse={props.warehouse.clone()}
+                    on_close={on_close_transfer}
+                    on_done={on_transferred}
+                />
+            }
+        </>
+    }
+}
@@ -0,0 +1,362 @@
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::inventory::warehouse::{
+    CountReview, CycleCount, CycleCountDraft, CycleCountStatus, Warehouse,
+};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+use crate::utils::format::{format_datetime, format_quantity};
+
+// 盘点面板属性
+#[derive(Properties, PartialEq)]
+pub struct CycleCountPanelProps {
+    pub warehouse: Warehouse,
+    pub on_changed: Callback<()>,
+}
+
+/// 盘点单状态标签
+fn count_status_badge(status: CycleCountStatus) -> Html {
+    let class = format!("status-badge status-{}", status.css_class());
+    html! { <span class={class}>{status.label()}</span> }
+}
+
+/// 差异显示：带正负号，非零差异高亮
+fn variance_cell(variance: Option<f64>) -> Html {
+    match variance {
+        None => html! { <td class="numeric">{"-"}</td> },
+        Some(v) if v.abs() <= f64::EPSILON => html! { <td class="numeric">{"0"}</td> },
+        Some(v) => html! {
+            <td class="numeric variance">{format!("{}{}", if v > 0.0 { "+" } else { "" }, format_quantity(v))}</td>
+        },
+    }
+}
+
+// 周期盘点：左侧盘点单列表，右侧录入实盘数量与差异审批
+#[function_component(CycleCountPanel)]
+pub fn cycle_count_panel(props: &CycleCountPanelProps) -> Html {
+    let counts = use_state(Vec::<CycleCount>::new);
+    let selected = use_state(|| None::<CycleCount>);
+    let zone = use_state(String::new);
+    let review_remark = use_state(String::new);
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+    let reload = use_state(|| 0u32);
+
+    {
+        let counts = counts.clone();
+        let error_message = error_message.clone();
+        let warehouse = props.warehouse.code.clone();
+        use_effect_with(*reload, move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_cycle_counts(Some(warehouse)).await {
+                    Ok(data) => counts.set(data),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    // 操作完成后刷新列表并显示最新的盘点单
+    let apply_result = {
+        let selected = selected.clone();
+        let reload = reload.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let review_remark = review_remark.clone();
+        move |result: Result<CycleCount, ApiError>| match result {
+            Ok(count) => {
+                selected.set(Some(count));
+                reload.set(*reload + 1);
+                errors.set(Vec::new());
+                error_message.set(None);
+                review_remark.set(String::new());
+            }
+            Err(ApiError::Validation { message, fields }) => {
+                errors.set(fields);
+                error_message.set(Some(message));
+            }
+            Err(error) => error_message.set(Some(error.user_message())),
+        }
+    };
+
+    let open_count = |id: u64| {
+        let apply_result = apply_result.clone();
+        Callback::from(move |_: MouseEvent| {
+            let apply_result = apply_result.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                apply_result(tauri::get_cycle_count(id).await);
+            });
+        })
+    };
+
+    let on_zone_change = {
+        let zone = zone.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            zone.set(select.value());
+        })
+    };
+
+    let on_start = {
+        let apply_result = apply_result.clone();
+        let zone = zone.clone();
+        let warehouse = props.warehouse.code.clone();
+        let on_changed = props.on_changed.clone();
+        Callback::from(move |_: MouseEvent| {
+            let draft = CycleCountDraft {
+                warehouse_code: warehouse.clone(),
+                zone_code: Some((*zone).clone()).filter(|z| !z.is_empty()),
+            };
+            let apply_result = apply_result.clone();
+            let on_changed = on_changed.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::start_cycle_count(draft).await;
+                // 新建盘点单会冻结库位
+                if result.is_ok() {
+                    on_changed.emit(());
+                }
+                apply_result(result);
+            });
+        })
+    };
+
+    let on_submit = {
+        let selected = selected.clone();
+        let errors = errors.clone();
+        let is_submitting = is_submitting.clone();
+        let apply_result = apply_result.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(count) = (*selected).clone() else {
+                return;
+            };
+            let local_errors = count.validate_counts();
+            if !local_errors.is_empty() {
+                errors.set(local_errors);
+                return;
+            }
+            let is_submitting = is_submitting.clone();
+            let apply_result = apply_result.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::submit_cycle_count(count.id, count.lines).await;
+                is_submitting.set(false);
+                apply_result(result);
+            });
+        })
+    };
+
+    let on_review = |approved: bool| {
+        let selected = selected.clone();
+        let review_remark = review_remark.clone();
+        let errors = errors.clone();
+        let is_submitting = is_submitting.clone();
+        let apply_result = apply_result.clone();
+        let on_changed = props.on_changed.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(count) = (*selected).clone() else {
+                return;
+            };
+            let remark = Some(review_remark.trim().to_string()).filter(|r| !r.is_empty());
+            if !approved && remark.is_none() {
+                errors.set(vec![FieldError {
+                    field: "remark".to_string(),
+                    message: "驳回时请填写原因".to_string(),
+                }]);
+                return;
+            }
+            let review = CountReview { approved, remark };
+            let is_submitting = is_submitting.clone();
+            let apply_result = apply_result.clone();
+            let on_changed = on_changed.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::review_cycle_count(count.id, review).await;
+                is_submitting.set(false);
+                // 批准后库存已调整
+                if result.is_ok() && approved {
+                    on_changed.emit(());
+                }
+                apply_result(result);
+            });
+        })
+    };
+
+    let on_remark_input = {
+        let review_remark = review_remark.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            review_remark.set(input.value());
+        })
+    };
+
+    let render_detail = |count: &CycleCount| {
+        let editable = count.status.is_editable();
+        html! {
+            <div class="detail-card">
+                <div class="detail-title">
+                    <h3>{format!("盘点单 {}", count.count_no)}</h3>
+                    { count_status_badge(count.status) }
+                </div>
+                <div class="detail-grid">
+                    <div class="detail-field">
+                        <div class="detail-label">{"范围"}</div>
+                        <div class="detail-value">
+                            {format!("{} {}", count.warehouse_code, count.zone_code.clone().unwrap_or_else(|| "全部库区".to_string()))}
+                        </div>
+                    </div>
+                    <div class="detail-field">
+                        <div class="detail-label">{"创建"}</div>
+                        <div class="detail-value">
+                            {format!("{} {}", count.created_by.clone().unwrap_or_default(), format_datetime(&count.created_at))}
+                        </div>
+                    </div>
+                    if let Some(submitted_by) = &count.submitted_by {
+                        <div class="detail-field">
+                            <div class="detail-label">{"提交"}</div>
+                            <div class="detail-value">
+                                {format!("{} {}", submitted_by, count.submitted_at.as_deref().map(format_datetime).unwrap_or_default())}
+                            </div>
+                        </div>
+                    }
+                    <div class="detail-field">
+                        <div class="detail-label">{"差异项"}</div>
+                        <div class="detail-value">{format!("{} / {}", count.variance_count(), count.lines.len())}</div>
+                    </div>
+                    if let Some(reviewed_by) = &count.reviewed_by {
+                        <div class="detail-field">
+                            <div class="detail-label">{"审批"}</div>
+                            <div class="detail-value">
+                                {format!("{} {}", reviewed_by, count.reviewed_at.as_deref().map(format_datetime).unwrap_or_default())}
+                            </div>
+                        </div>
+                    }
+                </div>
+                if let Some(remark) = &count.review_remark {
+                    <div class="detail-remark">{format!("审批意见：{}", remark)}</div>
+                }
+
+                <div class="table-container">
+                    <table class={classes!("data-table", editable.then_some("editable-table"))}>
+                        <thead>
+                            <tr>
+                                <th>{"库位"}</th>
+                                <th>{"物料 / 产品"}</th>
+                                <th>{"批次"}</th>
+                                <th class="numeric">{"账面数量"}</th>
+                                <th class="numeric">{"实盘数量"}</th>
+                                <th class="numeric">{"差异"}</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for count.lines.iter().enumerate().map(|(i, line)| {
+                                let field = format!("lines[{}].countedQuantity", i);
+                                let has_error = errors.iter().any(|e| e.field == field);
+                                let oninput = {
+                                    let selected = selected.clone();
+                                    Callback::from(move |e: InputEvent| {
+                                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                        let Some(mut next) = (*selected).clone() else {
+                                            return;
+                                        };
+                                        next.lines[i].counted_quantity = input.value().trim().parse().ok();
+                                        selected.set(Some(next));
+                                    })
+                                };
+                                html! {
+                                    <tr>
+                                        <td>{&line.bin_code}</td>
+                                        <td>{format!("{} {}", line.item_code, line.item_name)}</td>
+                                        <td>{line.lot_no.clone().unwrap_or_else(|| "-".to_string())}</td>
+                                        <td class="numeric">{format!("{} {}", format_quantity(line.book_quantity), line.unit)}</td>
+                                        <td class="numeric">
+                                            if editable {
+                                                <input class={classes!("form-input", has_error.then_some("input-error"))}
+                                                    type="number" min="0" step="any"
+                                                    value={line.counted_quantity.map(|q| q.to_string()).unwrap_or_default()}
+                                                    oninput={oninput} />
+                                            } else {
+                                                {line.counted_quantity.map(format_quantity).unwrap_or_else(|| "-".to_string())}
+                                            }
+                                        </td>
+                                        { variance_cell(line.variance()) }
+                                    </tr>
+                                }
+                            })}
+                        </tbody>
+                    </table>
+                </div>
+
+                if editable {
+                    <div class="form-actions">
+                        <button class="btn btn-primary" disabled={*is_submitting} onclick={on_submit.clone()}>
+                            { if *is_submitting { "提交中..." } else { "提交盘点结果" } }
+                        </button>
+                    </div>
+                }
+                if count.status == CycleCountStatus::Submitted {
+                    <div class="form-actions">
+                        <input class="form-input" type="text" placeholder="审批意见（驳回时必填）"
+                            value={(*review_remark).clone()} oninput={on_remark_input.clone()} />
+                        <button class="btn btn-danger" disabled={*is_submitting} onclick={on_review(false)}>{"驳回"}</button>
+                        <button class="btn btn-primary" disabled={*is_submitting} onclick={on_review(true)}>{"批准差异"}</button>
+                    </div>
+                    { field_error(&errors, "remark") }
+                }
+            </div>
+        }
+    };
+
+    html! {
+        <div class="split-view">
+            <div class="split-list">
+                <div class="toolbar-search">
+                    <select class="form-input" onchange={on_zone_change}>
+                        <option value="" selected={zone.is_empty()}>{"全部库区"}</option>
+                        { for props.warehouse.zones.iter().map(|z| html! {
+                            <option value={z.code.clone()} selected={*zone == z.code}>
+                                {format!("{} {}", z.code, z.name)}
+                            </option>
+                        })}
+                    </select>
+                    <button class="btn btn-primary" onclick={on_start}>{"新建盘点"}</button>
+                </div>
+                <div class="list-items">
+                    { for counts.iter().map(|count| {
+                        let active = selected.as_ref().is_some_and(|s| s.id == count.id);
+                        html! {
+                            <div class={classes!("list-item", active.then_some("active"))} onclick={open_count(count.id)}>
+                                <div class="list-item-title">
+                                    <span>{&count.count_no}</span>
+                                    { count_status_badge(count.status) }
+                                </div>
+                                <div class="list-item-description">
+                                    {format!(
+                                        "{} · {}",
+                                        count.zone_code.clone().unwrap_or_else(|| "全部库区".to_string()),
+                                        format_datetime(&count.created_at)
+                                    )}
+                                </div>
+                            </div>
+                        }
+                    })}
+                    if counts.is_empty() {
+                        <div class="table-empty">{"暂无盘点单"}</div>
+                    }
+                </div>
+            </div>
+
+            <div class="split-detail">
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+                { match (*selected).as_ref() {
+                    Some(count) => render_detail(count),
+                    None => html! { <div class="table-empty">{"选择或新建盘点单"}</div> },
+                }}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,121 @@
+// 仓储管理页面
+//
+// 仓库 → 库区 → 库位三级结构：库位占用看板（含移库）和盘点单（含差异审批）
+mod bins;
+mod cycle_count;
+mod transfer;
+
+use yew::prelude::*;
+use mes_win_shared::inventory::warehouse::Warehouse;
+use crate::services::tauri;
+use bins::BinBoard;
+use cycle_count::CycleCountPanel;
+
+// 页面视图
+#[derive(Clone, Copy, PartialEq)]
+enum WarehouseView {
+    Bins,
+    CycleCounts,
+}
+
+#[function_component(WarehousePage)]
+pub fn warehouse_page() -> Html {
+    let view = use_state(|| WarehouseView::Bins);
+    let warehouses = use_state(Vec::<Warehouse>::new);
+    let selected = use_state(|| None::<String>);
+    let reload = use_state(|| 0u32);
+    let error_message = use_state(|| None::<String>);
+
+    {
+        let warehouses = warehouses.clone();
+        let selected = selected.clone();
+        let error_message = error_message.clone();
+        use_effect_with(*reload, move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_warehouses().await {
+                    Ok(data) => {
+                        if selected.is_none() {
+                            selected.set(data.first().map(|w| w.code.clone()));
+                        }
+                        warehouses.set(data);
+                        error_message.set(None);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    let switch_view = |target: WarehouseView| {
+        let view = view.clone();
+        Callback::from(move |_: MouseEvent| view.set(target))
+    };
+
+    let on_warehouse_change = {
+        let selected = selected.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            selected.set(Some(select.value()));
+        })
+    };
+
+    // 移库或盘点审批后刷新库位占用
+    let on_changed = {
+        let reload = reload.clone();
+        Callback::from(move |_: ()| reload.set(*reload + 1))
+    };
+
+    let warehouse = warehouses
+        .iter()
+        .find(|w| Some(&w.code) == selected.as_ref())
+        .cloned();
+    let tab_class = |target: WarehouseView| classes!("btn", (*view == target).then_some("btn-primary"));
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"库存管理 - 仓库"}</h2>
+                <p>{"库位占用、库位间移库与周期盘点"}</p>
+            </div>
+
+            <div class="data-view">
+                <div class="toolbar">
+                    <div class="toolbar-actions">
+                        <button class={tab_class(WarehouseView::Bins)} onclick={switch_view(WarehouseView::Bins)}>
+                            {"库位占用"}
+                        </button>
+                        <button class={tab_class(WarehouseView::CycleCounts)} onclick={switch_view(WarehouseView::CycleCounts)}>
+                            {"盘点"}
+                        </button>
+                    </div>
+                    <div class="toolbar-actions">
+                        <select class="form-input" onchange={on_warehouse_change}>
+                            { for warehouses.iter().map(|w| html! {
+                                <option value={w.code.clone()} selected={Some(&w.code) == selected.as_ref()}>
+                                    {format!("{} {}", w.code, w.name)}
+                                </option>
+                            })}
+                        </select>
+                    </div>
+                </div>
+
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+
+                { match (warehouse, *view) {
+                    (None, _) => html! { <div class="table-empty">{"暂无仓库数据"}</div> },
+                    (Some(warehouse), WarehouseView::Bins) => {
+                        let key = warehouse.code.clone();
+                        html! { <BinBoard {key} {warehouse} {on_changed} /> }
+                    }
+                    (Some(warehouse), WarehouseView::CycleCounts) => {
+                        let key = warehouse.code.clone();
+                        html! { <CycleCountPanel {key} {warehouse} {on_changed} /> }
+                    }
+                }}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,189 @@
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::inventory::warehouse::{BinStock, BinTransfer, Warehouse};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+use crate::ui::components::modal::Modal;
+use crate::utils::format::format_quantity;
+
+// 移库对话框属性
+#[derive(Properties, PartialEq)]
+pub struct TransferDialogProps {
+    pub stock: BinStock,
+    pub warehouse: Warehouse,
+    pub on_close: Callback<()>,
+    pub on_done: Callback<()>,
+}
+
+// 库位间移库
+#[function_component(TransferDialog)]
+pub fn transfer_dialog(props: &TransferDialogProps) -> Html {
+    let transfer = use_state(|| BinTransfer {
+        item_code: props.stock.item_code.clone(),
+        lot_no: props.stock.lot_no.clone(),
+        from_bin: props.stock.bin_code.clone(),
+        ..BinTransfer::default()
+    });
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+
+    let on_target_change = {
+        let transfer = transfer.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*transfer).clone();
+            next.to_bin = select.value();
+            transfer.set(next);
+        })
+    };
+
+    let on_quantity_input = {
+        let transfer = transfer.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*transfer).clone();
+            next.quantity = input.value().parse().unwrap_or(0.0);
+            transfer.set(next);
+        })
+    };
+
+    let on_remark_input = {
+        let transfer = transfer.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*transfer).clone();
+            next.remark = Some(input.value()).filter(|v| !v.trim().is_empty());
+            transfer.set(next);
+        })
+    };
+
+    let on_submit = {
+        let transfer = transfer.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let on_done = props.on_done.clone();
+        let available = props.stock.quantity;
+        let warehouse = props.warehouse.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let mut local_errors = transfer.validate();
+            if transfer.quantity > available {
+                local_errors.push(FieldError {
+                    field: "quantity".to_string(),
+                    message: format!("超出库位库存（{}）", format_quantity(available)),
+                });
+            }
+            if let Some(room) = warehouse.find_bin(&transfer.to_bin).and_then(|bin| bin.available()) {
+                if transfer.quantity > room {
+                    local_errors.push(FieldError {
+                        field: "toBin".to_string(),
+                        message: format!("目标库位剩余容量不足（{}）", format_quantity(room)),
+                    });
+                }
+            }
+            if !local_errors.is_empty() {
+                errors.set(local_errors);
+                return;
+            }
+
+            let submitted = (*transfer).clone();
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            let on_done = on_done.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = tauri::transfer_stock(submitted).await;
+                is_submitting.set(false);
+                match result {
+                    // 离线时已保存到本机队列，按已受理处理
+                    Ok(_) | Err(ApiError::Queued { .. }) => on_done.emit(()),
+                    Err(ApiError::Validation { message, fields }) => {
+                        errors.set(fields);
+                        error_message.set(Some(message));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_cancel = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| on_close.emit(()))
+    };
+
+    let stock = &props.stock;
+    let quantity = if transfer.quantity > 0.0 { transfer.quantity.to_string() } else { String::new() };
+
+    html! {
+        <Modal title="库位移库" on_close={props.on_close.clone()}>
+            <form class="data-view" onsubmit={on_submit}>
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                }
+                <div class="detail-grid">
+                    <div class="detail-field">
+                        <div class="detail-label">{"物料 / 产品"}</div>
+                        <div class="detail-value">{format!("{} {}", stock.item_code, stock.item_name)}</div>
+                    </div>
+                    <div class="detail-field">
+                        <div class="detail-label">{"来源库位 / 批次"}</div>
+                        <div class="detail-value">
+                            {format!("{} / {}", stock.bin_code, stock.lot_no.clone().unwrap_or_else(|| "-".to_string()))}
+                        </div>
+                    </div>
+                    <div class="detail-field">
+                        <div class="detail-label">{"可移数量"}</div>
+                        <div class="detail-value">{format!("{} {}", format_quantity(stock.quantity), stock.unit)}</div>
+                    </div>
+                </div>
+                <div class="form-grid">
+                    <div class="form-field">
+                        <label class="required">{"目标库位"}</label>
+                        <select class="form-input" onchange={on_target_change}>
+                            <option value="" selected={transfer.to_bin.is_empty()}>{"请选择库位"}</option>
+                            { for props.warehouse.zones.iter().map(|zone| html! {
+                                <optgroup label={format!("{} {}", zone.code, zone.name)}>
+                                    { for zone.bins.iter()
+                                        .filter(|bin| !bin.blocked && bin.code != stock.bin_code)
+                                        .map(|bin| html! {
+                                            <option value={bin.code.clone()} selected={transfer.to_bin == bin.code}>
+                                                { match bin.available() {
+                                                    Some(room) => format!("{}（剩余 {}）", bin.code, format_quantity(room)),
+                                                    None => bin.code.clone(),
+                                                }}
+                                            </option>
+                                        })
+                                    }
+                                </optgroup>
+                            })}
+                        </select>
+                        { field_error(&errors, "toBin") }
+                    </div>
+                    <div class="form-field">
+                        <label class="required">{format!("移库数量（{}）", stock.unit)}</label>
+                        <input class="form-input" type="number" min="0" step="any" value={quantity}
+                            oninput={on_quantity_input} />
+                        { field_error(&errors, "quantity") }
+                    </div>
+                    <div class="form-field form-field-wide">
+                        <label>{"备注"}</label>
+                        <input class="form-input" type="text" value={transfer.remark.clone().unwrap_or_default()}
+                            oninput={on_remark_input} />
+                    </div>
+                </div>
+                <div class="form-actions">
+                    <button class="btn" type="button" onclick={on_cancel}>{"取消"}</button>
+                    <button class="btn btn-primary" type="submit" disabled={*is_submitting}>
+                        { if *is_submitting { "提交中..." } else { "确认移库" } }
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
@@ -0,0 +1,156 @@
+use yew::prelude::*;
+use mes_win_shared::production::work_order::{WorkOrder, WorkOrderDetail, WorkOrderStatus};
+use crate::services::tauri;
+use crate::utils::format::{format_datetime, format_quantity};
+use super::status_badge;
+
+// 工单详情属性
+#[derive(Properties, PartialEq)]
+pub struct WorkOrderDetailProps {
+    pub id: u64,
+    pub on_back: Callback<()>,
+    pub on_edit: Callback<WorkOrder>,
+}
+
+// 工单详情
+#[function_component(WorkOrderDetailView)]
+pub fn work_order_detail_view(props: &WorkOrderDetailProps) -> Html {
+    let detail = use_state(|| None::<WorkOrderDetail>);
+    let error_message = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+
+    {
+        let detail = detail.clone();
+        let error_message = error_message.clone();
+        use_effect_with(props.id, move |id| {
+            let id = *id;
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::get_work_order(id).await {
+                    Ok(data) => detail.set(Some(data)),
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+            || ()
+        });
+    }
+
+    // 执行状态流转
+    let on_transition = {
+        let detail = detail.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let id = props.id;
+        Callback::from(move |target: WorkOrderStatus| {
+            if target == WorkOrderStatus::Closed {
+                let confirmed = web_sys::window()
+                    .and_then(|w| w.confirm_with_message("关闭后工单不可再变更，确定要关闭吗？").ok())
+                    .unwrap_or(false);
+                if !confirmed {
+                    return;
+                }
+            }
+
+            let detail = detail.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::transition_work_order(id, target).await {
+                    Ok(data) => {
+                        detail.set(Some(data));
+                        error_message.set(None);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+                is_submitting.set(false);
+            });
+        })
+    };
+
+    let on_back = {
+        let on_back = props.on_back.clone();
+        Callback::from(move |_: MouseEvent| on_back.emit(()))
+    };
+
+    let Some(data) = (*detail).clone() else {
+        return html! {
+            <div class="data-view">
+                <div class="toolbar">
+                    <button class="btn" onclick={on_back}>{"返回列表"}</button>
+                </div>
+                if let Some(message) = (*error_message).clone() {
+                    <div class="error-banner">{message}</div>
+                } else {
+                    <div class="table-empty">{"加载中..."}</div>
+                }
+            </div>
+        };
+    };
+
+    let order = data.order.clone();
+    let on_edit = {
+        let on_edit = props.on_edit.clone();
+        let order = order.clone();
+        Callback::from(move |_: MouseEvent| on_edit.emit(order.clone()))
+    };
+
+    let field = |label: &str, value: String| html! {
+        <div class="detail-field">
+            <div class="detail-label">{label.to_string()}</div>
+            <div class="detail-value">{value}</div>
+        </div>
+    };
+
+    html! {
+        <div class="data-view">
+            <div class="toolbar">
+                <button class="btn" onclick={on_back}>{"返回列表"}</button>
+                <div class="toolbar-actions">
+                    if data.editable {
+                        <button class="btn" onclick={on_edit} disabled={*is_submitting}>{"编辑"}</button>
+                    }
+                    { for data.transitions.iter().map(|target| {
+                        let target = *target;
+                        let on_transition = on_transition.clone();
+                        let class = if target == WorkOrderStatus::Closed { "btn btn-danger" } else { "btn btn-primary" };
+                        html! {
+                            <button class={class} disabled={*is_submitting}
+                                onclick={Callback::from(move |_: MouseEvent| on_transition.emit(target))}>
+                                {target.action_label()}
+                            </button>
+                        }
+                    })}
+                </div>
+            </div>
+
+            if let Some(message) = (*error_message).clone() {
+                <div class="error-banner">{message}</div>
+            }
+
+            <div class="detail-card">
+                <div class="detail-title">
+                    <h3>{&order.order_no}</h3>
+                    {status_badge(order.status)}
+                </div>
+                <div class="detail-grid">
+                    { field("产品编码", order.product_code.clone()) }
+                    { field("产品名称", order.product_name.clone()) }
+                    { field("计划数量", format!("{} {}", format_quantity(order.quantity), order.unit)) }
+                    { field("已完工数量", format!("{} {}", format_quantity(order.completed_quantity), order.unit)) }
+                    { field("产线", order.line.clone().unwrap_or_else(|| "-".to_string())) }
+                    { field("优先级", order.priority.to_string()) }
+                    { field("计划开始", format_datetime(&order.planned_start)) }
+                    { field("计划完成", format_datetime(&order.planned_end)) }
+                    { field("创建时间", order.created_at.as_deref().map(format_datetime).unwrap_or_default()) }
+                    { field("更新时间", order.updated_at.as_deref().map(format_datetime).unwrap_or_default()) }
+                </div>
+                if let Some(remark) = order.remark.clone().filter(|r| !r.is_empty()) {
+                    <div class="detail-remark">
+                        <div class="detail-label">{"备注"}</div>
+                        <div class="detail-value">{remark}</div>
+                    </div>
+                }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,226 @@
+use yew::prelude::*;
+use mes_win_shared::error::FieldError;
+use mes_win_shared::master_data::{MasterDataItem, MasterDataKind};
+use mes_win_shared::production::work_order::{WorkOrder, WorkOrderDraft};
+use crate::core::error::ApiError;
+use crate::services::tauri;
+use crate::ui::components::form::field_error;
+use crate::ui::components::lookup::LookupInput;
+
+// 工单表单属性
+#[derive(Properties, PartialEq)]
+pub struct WorkOrderFormProps {
+    /// 编辑的工单，为空时新建
+    pub order: Option<WorkOrder>,
+    /// 保存成功后回调（工单ID）
+    pub on_saved: Callback<u64>,
+    pub on_cancel: Callback<()>,
+}
+
+// 工单创建/编辑表单
+#[function_component(WorkOrderForm)]
+pub fn work_order_form(props: &WorkOrderFormProps) -> Html {
+    let draft = use_state(|| props.order.as_ref().map(WorkOrderDraft::from).unwrap_or_default());
+    let errors = use_state(Vec::<FieldError>::new);
+    let error_message = use_state(|| None::<String>);
+    let is_submitting = use_state(|| false);
+
+    // 生成输入框回调，`apply` 把输入值写入表单
+    let bind_input = |apply: fn(&mut WorkOrderDraft, String)| {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*draft).clone();
+            apply(&mut next, input.value());
+            draft.set(next);
+        })
+    };
+
+    let on_product_input = {
+        let draft = draft.clone();
+        Callback::from(move |value: String| {
+            let mut next = (*draft).clone();
+            next.product_code = value;
+            draft.set(next);
+        })
+    };
+
+    // 从联想列表选中产品时带出名称和单位
+    let on_product_select = {
+        let draft = draft.clone();
+        Callback::from(move |item: MasterDataItem| {
+            let mut next = (*draft).clone();
+            next.product_code = item.code;
+            next.product_name = item.name;
+            if let Some(unit) = item.unit {
+                next.unit = unit;
+            }
+            draft.set(next);
+        })
+    };
+
+    let on_priority_change = {
+        let draft = draft.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*draft).clone();
+            next.priority = select.value().parse().unwrap_or(3);
+            draft.set(next);
+        })
+    };
+
+    let on_remark_input = {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            let textarea: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            let mut next = (*draft).clone();
+            next.remark = Some(textarea.value()).filter(|v| !v.trim().is_empty());
+            draft.set(next);
+        })
+    };
+
+    // 提交：先在本地校验，再调用命令（命令端使用同一套规则再次校验）
+    let on_submit = {
+        let draft = draft.clone();
+        let errors = errors.clone();
+        let error_message = error_message.clone();
+        let is_submitting = is_submitting.clone();
+        let order_id = props.order.as_ref().map(|order| order.id);
+        let on_saved = props.on_saved.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let local_errors = draft.validate();
+            if !local_errors.is_empty() {
+                errors.set(local_errors);
+                error_message.set(Some("请检查表单中标记的字段".to_string()));
+                return;
+            }
+
+            let submitted = (*draft).clone();
+            let errors = errors.clone();
+            let error_message = error_message.clone();
+            let is_submitting = is_submitting.clone();
+            let on_saved = on_saved.clone();
+            is_submitting.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = match order_id {
+                    Some(id) => tauri::update_work_order(id, submitted).await,
+                    None => tauri::create_work_order(submitted).await,
+                };
+
+                is_submitting.set(false);
+                match result {
+                    Ok(detail) => on_saved.emit(detail.order.id),
+                    Err(ApiError::Validation { message, fields }) => {
+                        errors.set(fields);
+                        error_message.set(Some(message));
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+            });
+        })
+    };
+
+    let on_cancel = {
+        let on_cancel = props.on_cancel.clone();
+        Callback::from(move |_: MouseEvent| on_cancel.emit(()))
+    };
+
+    let is_edit = props.order.is_some();
+    let quantity = if draft.quantity > 0.0 { draft.quantity.to_string() } else { String::new() };
+
+    html! {
+        <form class="data-view form-view" onsubmit={on_submit}>
+            <div class="toolbar">
+                <h3 class="form-title">{ if is_edit { "编辑工单" } else { "新建工单" } }</h3>
+            </div>
+
+            if let Some(message) = (*error_message).clone() {
+                <div class="error-banner">{message}</div>
+            }
+
+            <div class="form-grid">
+                <div class="form-field">
+                    <label>{"工单号"}</label>
+                    <input class="form-input" type="text" placeholder="留空自动生成"
+                        disabled={is_edit}
+                        value={draft.order_no.clone().unwrap_or_default()}
+                        oninput={bind_input(|d, v| d.order_no = Some(v).filter(|v| !v.trim().is_empty()))} />
+                </div>
+                <div class="form-field">
+                    <label>{"产线"}</label>
+                    <input class="form-input" type="text"
+                        value={draft.line.clone().unwrap_or_default()}
+                        oninput={bind_input(|d, v| d.line = Some(v).filter(|v| !v.trim().is_empty()))} />
+                </div>
+                <div class="form-field">
+                    <label class="required">{"产品编码"}</label>
+                    <LookupInput kind={MasterDataKind::Product} value={draft.product_code.clone()}
+                        placeholder="输入SKU或名称查找"
+                        on_input={on_product_input} on_select={on_product_select} />
+                    { field_error(&errors, "productCode") }
+                </div>
+                <div class="form-field">
+                    <label class="required">{"产品名称"}</label>
+                    <input class="form-input" type="text"
+                        value={draft.product_name.clone()}
+                        oninput={bind_input(|d, v| d.product_name = v)} />
+                    { field_error(&errors, "productName") }
+                </div>
+                <div class="form-field">
+                    <label class="required">{"计划数量"}</label>
+                    <input class="form-input" type="number" min="0" step="any"
+                        value={quantity}
+                        oninput={bind_input(|d, v| d.quantity = v.parse().unwrap_or(0.0))} />
+                    { field_error(&errors, "quantity") }
+                </div>
+                <div class="form-field">
+                    <label class="required">{"单位"}</label>
+                    <input class="form-input" type="text"
+                        value={draft.unit.clone()}
+                        oninput={bind_input(|d, v| d.unit = v)} />
+                    { field_error(&errors, "unit") }
+                </div>
+                <div class="form-field">
+                    <label class="required">{"计划开始"}</label>
+                    <input class="form-input" type="datetime-local"
+                        value={draft.planned_start.clone()}
+                        oninput={bind_input(|d, v| d.planned_start = v)} />
+                    { field_error(&errors, "plannedStart") }
+                </div>
+                <div class="form-field">
+                    <label class="required">{"计划完成"}</label>
+                    <input class="form-input" type="datetime-local"
+                        value={draft.planned_end.clone()}
+                        oninput={bind_input(|d, v| d.planned_end = v)} />
+                    { field_error(&errors, "plannedEnd") }
+                </div>
+                <div class="form-field">
+                    <label class="required">{"优先级"}</label>
+                    <select class="form-input" onchange={on_priority_change}>
+                        { for (1..=5u8).map(|level| html! {
+                            <option value={level.to_string()} selected={draft.priority == level}>
+                                { match level { 1 => "1 - 最高".to_string(), 5 => "5 - 最低".to_string(), _ => level.to_string() } }
+                            </option>
+                        })}
+                    </select>
+                    { field_error(&errors, "priority") }
+                </div>
+                <div class="form-field form-field-wide">
+                    <label>{"备注"}</label>
+                    <textarea class="form-input" rows="3"
+                        value={draft.remark.clone().unwrap_or_default()}
+                        oninput={on_remark_input} />
+                </div>
+            </div>
+
+            <div class="form-actions">
+                <button class="btn" type="button" onclick={on_cancel} disabled={*is_submitting}>{"取消"}</button>
+                <button class="btn btn-primary" type="submit" disabled={*is_submitting}>
+                    { if *is_submitting { "保存中..." } else { "保存" } }
+                </button>
+            </div>
+        </form>
+    }
+}
@@ -0,0 +1,206 @@
+use yew::prelude::*;
+use mes_win_shared::production::work_order::{
+    WorkOrder, WorkOrderQuery, WorkOrderSortField, WorkOrderStatus,
+};
+use mes_win_shared::response::PageData;
+use crate::services::tauri;
+use crate::ui::components::pagination::Pagination;
+use crate::utils::format::{format_datetime, format_quantity};
+use super::status_badge;
+
+// 工单列表属性
+#[derive(Properties, PartialEq)]
+pub struct WorkOrderListProps {
+    pub query: WorkOrderQuery,
+    pub on_query_change: Callback<WorkOrderQuery>,
+    pub on_open: Callback<u64>,
+    pub on_create: Callback<()>,
+}
+
+// 工单列表
+#[function_component(WorkOrderList)]
+pub fn work_order_list(props: &WorkOrderListProps) -> Html {
+    let page_data = use_state(PageData::<WorkOrder>::default);
+    let is_loading = use_state(|| false);
+    let error_message = use_state(|| None::<String>);
+    let keyword = use_state(|| props.query.keyword.clone().unwrap_or_default());
+
+    // 查询条件变化时重新加载
+    {
+        let page_data = page_data.clone();
+        let is_loading = is_loading.clone();
+        let error_message = error_message.clone();
+        use_effect_with(props.query.clone(), move |query| {
+            let query = query.clone();
+            is_loading.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match tauri::list_work_orders(query).await {
+                    Ok(data) => {
+                        page_data.set(data);
+                        error_message.set(None);
+                    }
+                    Err(error) => error_message.set(Some(error.user_message())),
+                }
+                is_loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    let on_keyword_input = {
+        let keyword = keyword.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            keyword.set(input.value());
+        })
+    };
+
+    // 按关键字查询（回到第一页）
+    let on_search = {
+        let keyword = keyword.clone();
+        let query = props.query.clone();
+        let on_query_change = props.on_query_change.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let mut next = query.clone();
+            next.keyword = Some((*keyword).clone()).filter(|k| !k.trim().is_empty());
+            next.page = 1;
+            on_query_change.emit(next);
+        })
+    };
+
+    let on_status_change = {
+        let query = props.query.clone();
+        let on_query_change = props.on_query_change.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut next = query.clone();
+            next.status = WorkOrderStatus::from_code(&select.value());
+            next.page = 1;
+            on_query_change.emit(next);
+        })
+    };
+
+    let on_page_change = {
+        let query = props.query.clone();
+        let on_query_change = props.on_query_change.clone();
+        Callback::from(move |page: u32| {
+            let mut next = query.clone();
+            next.page = page;
+            on_query_change.emit(next);
+        })
+    };
+
+    let on_create = {
+        let on_create = props.on_create.clone();
+        Callback::from(move |_: MouseEvent| on_create.emit(()))
+    };
+
+    // 可排序的表头：再次点击同一列时切换升降序
+    let sortable_header = |field: WorkOrderSortField, title: &str| {
+        let query = props.query.clone();
+        let on_query_change = props.on_query_change.clone();
+        let indicator = if query.sort_by != field {
+            ""
+        } else if query.descending {
+            " ▼"
+        } else {
+            " ▲"
+        };
+        let onclick = Callback::from(move |_: MouseEvent| {
+            let mut next = query.clone();
+            if next.sort_by == field {
+                next.descending = !next.descending;
+            } else {
+                next.sort_by = field;
+                next.descending = false;
+            }
+            next.page = 1;
+            on_query_change.emit(next);
+        });
+        html! {
+            <th class="sortable" onclick={onclick}>{format!("{}{}", title, indicator)}</th>
+        }
+    };
+
+    let selected_status = props.query.status.map(|status| status.code()).unwrap_or("");
+
+    html! {
+        <div class="data-view">
+            <div class="toolbar">
+                <form class="toolbar-search" onsubmit={on_search}>
+                    <input
+                        class="form-input"
+                        type="text"
+                        placeholder="工单号 / 产品编码 / 产品名称"
+                        value={(*keyword).clone()}
+                        oninput={on_keyword_input}
+                    />
+                    <select class="form-input" onchange={on_status_change}>
+                        <option value="" selected={selected_status.is_empty()}>{"全部状态"}</option>
+                        { for WorkOrderStatus::ALL.iter().map(|status| html! {
+                            <option value={status.code()} selected={selected_status == status.code()}>
+                                {status.label()}
+                            </option>
+                        })}
+                    </select>
+                    <button class="btn" type="submit">{"查询"}</button>
+                </form>
+                <button class="btn btn-primary" onclick={on_create}>{"新建工单"}</button>
+            </div>
+
+            if let Some(message) = (*error_message).clone() {
+                <div class="error-banner">{message}</div>
+            }
+
+            <div class="table-container">
+                <table class="data-table">
+                    <thead>
+                        <tr>
+                            { sortable_header(WorkOrderSortField::OrderNo, "工单号") }
+                            <th>{"产品"}</th>
+                            <th class="numeric">{"完工/计划"}</th>
+                            <th>{"产线"}</th>
+                            { sortable_header(WorkOrderSortField::PlannedStart, "计划开始") }
+                            { sortable_header(WorkOrderSortField::PlannedEnd, "计划完成") }
+                            { sortable_header(WorkOrderSortField::Priority, "优先级") }
+                            { sortable_header(WorkOrderSortField::Status, "状态") }
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { for page_data.items.iter().map(|order| {
+                            let id = order.id;
+                            let on_open = props.on_open.clone();
+                            html! {
+                                <tr class="clickable" onclick={Callback::from(move |_: MouseEvent| on_open.emit(id))}>
+                                    <td>{&order.order_no}</td>
+                                    <td>{format!("{} {}", order.product_code, order.product_name)}</td>
+                                    <td class="numeric">
+                                        {format!("{} / {} {}", format_quantity(order.completed_quantity), format_quantity(order.quantity), order.unit)}
+                                    </td>
+                                    <td>{order.line.clone().unwrap_or_else(|| "-".to_string())}</td>
+                                    <td>{format_datetime(&order.planned_start)}</td>
+                                    <td>{format_datetime(&order.planned_end)}</td>
+                                    <td>{order.priority}</td>
+                                    <td>{status_badge(order.status)}</td>
+                                </tr>
+                            }
+                        })}
+                    </tbody>
+                </table>
+                if page_data.items.is_empty() {
+                    <div class="table-empty">
+                        { if *is_loading { "加载中..." } else { "暂无工单" } }
+                    </div>
+                }
+            </div>
+
+            <Pagination
+                page={page_data.page}
+                total_pages={page_data.total_pages()}
+                total={page_data.total}
+                on_change={on_page_change}
+            />
+        </div>
+    }
+}
@@ -0,0 +1,106 @@
+// 生产工单管理页面
+//
+// 列表、详情、新建/编辑三个视图在同一页面内切换，返回列表时保留查询条件
+mod detail;
+mod form;
+mod list;
+
+use yew::prelude::*;
+use mes_win_shared::production::work_order::{WorkOrder, WorkOrderQuery, WorkOrderStatus};
+use mes_win_shared::search::SearchTarget;
+use detail::WorkOrderDetailView;
+use form::WorkOrderForm;
+use list::WorkOrderList;
+
+/// 工单状态标签
+pub fn status_badge(status: WorkOrderStatus) -> Html {
+    let class = format!("status-badge status-{}", status.code().to_lowercase().replace('_', "-"));
+    html! { <span class={class}>{status.label()}</span> }
+}
+
+// 当前视图
+#[derive(Clone, PartialEq)]
+enum WorkOrderView {
+    List,
+    Detail(u64),
+    Create,
+    Edit(Box<WorkOrder>),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct WorkOrdersPageProps {
+    /// 全局搜索跳转的目标，为工单时直接打开详情
+    #[prop_or_default]
+    pub focus: Option<SearchTarget>,
+}
+
+#[function_component(WorkOrdersPage)]
+pub fn work_orders_page(props: &WorkOrdersPageProps) -> Html {
+    let view = use_state(|| match props.focus {
+        Some(SearchTarget::WorkOrder { id }) => WorkOrderView::Detail(id),
+        _ => WorkOrderView::List,
+    });
+    let query = use_state(WorkOrderQuery::default);
+
+    let on_query_change = {
+        let query = query.clone();
+        Callback::from(move |next: WorkOrderQuery| query.set(next))
+    };
+
+    let show_list = {
+        let view = view.clone();
+        Callback::from(move |_: ()| view.set(WorkOrderView::List))
+    };
+
+    let show_detail = {
+        let view = view.clone();
+        Callback::from(move |id: u64| view.set(WorkOrderView::Detail(id)))
+    };
+
+    let show_create = {
+        let view = view.clone();
+        Callback::from(move |_: ()| view.set(WorkOrderView::Create))
+    };
+
+    let show_edit = {
+        let view = view.clone();
+        Callback::from(move |order: WorkOrder| view.set(WorkOrderView::Edit(Box::new(order))))
+    };
+
+    let content = match (*view).clone() {
+        WorkOrderView::List => html! {
+            <WorkOrderList
+                query={(*query).clone()}
+                on_query_change={on_query_change}
+                on_open={show_detail}
+                on_create={show_create}
+            />
+        },
+        WorkOrderView::Detail(id) => html! {
+            <WorkOrderDetailView id={id} on_back={show_list} on_edit={show_edit} />
+        },
+        WorkOrderView::Create => html! {
+            <WorkOrderForm order={None::<WorkOrder>} on_saved={show_detail} on_cancel={show_list} />
+        },
+        WorkOrderView::Edit(order) => {
+            let id = order.id;
+            let on_cancel = {
+                let show_detail = show_detail.clone();
+                Callback::from(move |_: ()| show_detail.emit(id))
+            };
+            html! {
+                <WorkOrderForm order={Some(*order)} on_saved={show_detail} on_cancel={on_cancel} />
+            }
+        }
+    };
+
+    html! {
+        <div class="content-panel">
+            <div class="panel-header">
+                <h2>{"生产管理 - 工单"}</h2>
+                <p>{"生产工单的创建、下达与执行跟踪"}</p>
+            </div>
+            { content }
+        </div>
+    }
+}
@@ -0,0 +1,17 @@
+// 界面显示格式化
+
+/// ISO时间显示为 `YYYY-MM-DD HH:MM`
+pub fn format_datetime(value: &str) -> String {
+    let value = value.replace('T', " ");
+    value.chars().take(16).collect()
+}
+
+/// 数量显示，整数不带小数位，其余最多保留3位小数
+pub fn format_quantity(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{:.0}", value)
+    } else {
+        let text = format!("{:.3}", value);
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
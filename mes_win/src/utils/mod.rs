@@ -1 +1,3 @@
 // 工具函数模块
+pub mod format; // 显示格式化
+pub mod time;   // 本地日期时间
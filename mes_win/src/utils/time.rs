@@ -0,0 +1,33 @@
+// 本地日期时间
+
+use mes_win_shared::time::parse_minutes;
+
+/// 今天零点（本地时间，自1970-01-01起的分钟数）
+pub fn today_start_minutes() -> i64 {
+    let now = js_sys::Date::new_0();
+    let today = format!(
+        "{:04}-{:02}-{:02}T00:00",
+        now.get_full_year(),
+        now.get_month() + 1,
+        now.get_date()
+    );
+    parse_minutes(&today).unwrap_or(0)
+}
+
+/// 今天（本地时间，自1970-01-01起的天数）
+pub fn today_days() -> i64 {
+    today_start_minutes() / 1440
+}
+
+/// Unix秒显示为本地时间 `YYYY-MM-DD HH:MM`
+pub fn format_unix_time(secs: u64) -> String {
+    let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(secs as f64 * 1000.0));
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        date.get_full_year(),
+        date.get_month() + 1,
+        date.get_date(),
+        date.get_hours(),
+        date.get_minutes()
+    )
+}